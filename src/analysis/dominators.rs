@@ -0,0 +1,129 @@
+// See Cooper, Harvey & Kennedy, "A Simple, Fast Dominance Algorithm".
+// https://www.cs.rice.edu/~keith/EMBED/dom.pdf
+use super::graph::{self, Graph, Reverse};
+
+pub struct Dominators {
+    // Immediate dominator, None for entries and unreachable nodes.
+    pub idom:     Vec<Option<usize>>,
+    // The dominator tree, entries are the roots.
+    pub children: Vec<Vec<usize>>,
+    pub roots:    Vec<usize>,
+    // Pre / post numbers in the dominator tree, for O(1) queries.
+    pre:  Vec<usize>,
+    post: Vec<usize>,
+}
+impl Dominators {
+    pub fn compute<G: Graph>(g: &G) -> Self {
+        let len = g.len();
+        // Every entry hangs off of a virtual root, so that
+        // graphs with several entries (functions) still work.
+        let root = len;
+        let rpo = graph::rpo(g);
+        let mut order = vec![usize::MAX; len + 1];
+        order[root] = 0;
+        for (i, n) in rpo.iter().enumerate() { order[*n] = i + 1; }
+        let preds = g.preds();
+        let mut entry = vec![false; len];
+        for e in g.entries() { entry[e] = true; }
+
+        let mut idom = vec![usize::MAX; len + 1];
+        idom[root] = root;
+        for n in &rpo {
+            if entry[*n] { idom[*n] = root; }
+        }
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for n in &rpo {
+                if entry[*n] { continue }
+                let mut new = usize::MAX;
+                for p in &preds[*n] {
+                    if idom[*p] == usize::MAX { continue }
+                    new = match new {
+                        usize::MAX => *p,
+                        _ => intersect(&idom, &order, *p, new)
+                    };
+                }
+                if idom[*n] != new {
+                    idom[*n] = new;
+                    changed = true;
+                }
+            }
+        }
+
+        let mut children = vec![Vec::new(); len];
+        let mut roots = Vec::new();
+        for n in &rpo {
+            match idom[*n] {
+                i if i == root => roots.push(*n),
+                i              => children[i].push(*n),
+            }
+        }
+        let (mut pre, mut post) = (vec![usize::MAX; len], vec![usize::MAX; len]);
+        let mut count = 0;
+        for r in &roots {
+            let mut stk = vec![(*r, false)];
+            while let Some((n, done)) = stk.pop() {
+                count += 1;
+                if done { post[n] = count; continue }
+                pre[n] = count;
+                stk.push((n, true));
+                for c in children[n].iter().rev() { stk.push((*c, false)); }
+            }
+        }
+        let idom = idom[..len].iter()
+            .map(|i| if *i == root || *i == usize::MAX { None } else { Some(*i) })
+            .collect();
+        return Dominators { idom, children, roots, pre, post };
+    }
+
+    // Post-dominators are just the dominators of the reversed graph.
+    pub fn post<G: Graph>(g: &G) -> Self {
+        return Self::compute(&Reverse::new(g));
+    }
+
+    pub fn reachable(&self, n: usize) -> bool {
+        return self.pre[n] != usize::MAX;
+    }
+
+    // Whether a dominates b, every node dominates itself.
+    pub fn dominates(&self, a: usize, b: usize) -> bool {
+        if !self.reachable(a) || !self.reachable(b) { return false }
+        return self.pre[a] <= self.pre[b] && self.post[b] <= self.post[a];
+    }
+
+    pub fn strictly_dominates(&self, a: usize, b: usize) -> bool {
+        return a != b && self.dominates(a, b);
+    }
+
+    // Where a's dominance stops, used for SSA and control dependence.
+    // Pass the reversed graph when these are post-dominators.
+    pub fn frontiers<G: Graph>(&self, g: &G) -> Vec<Vec<usize>> {
+        let mut df: Vec<Vec<usize>> = vec![Vec::new(); g.len()];
+        let preds = g.preds();
+        for n in 0..g.len() {
+            if !self.reachable(n) { continue }
+            let preds: Vec<usize> = preds[n].iter().copied()
+                .filter(|p| self.reachable(*p))
+                .collect();
+            if preds.len() < 2 { continue }
+            for p in preds {
+                let mut runner = Some(p);
+                while let Some(r) = runner {
+                    if Some(r) == self.idom[n] { break }
+                    if !df[r].contains(&n) { df[r].push(n); }
+                    runner = self.idom[r];
+                }
+            }
+        }
+        return df;
+    }
+}
+
+fn intersect(idom: &[usize], order: &[usize], mut a: usize, mut b: usize) -> usize {
+    while a != b {
+        while order[a] > order[b] { a = idom[a]; }
+        while order[b] > order[a] { b = idom[b]; }
+    }
+    return a;
+}
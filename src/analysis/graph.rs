@@ -0,0 +1,117 @@
+use crate::ir::cfg::CFG as IrCfg;
+use crate::asm::cfg::CFG as AsmCfg;
+use crate::asm::asm::AA;
+
+// The view of a CFG the analyses need.
+// Nodes are numbered 0..len(), some of them may be unreachable.
+pub trait Graph {
+    fn len(&self) -> usize;
+    fn entries(&self) -> Vec<usize>;
+    fn succs(&self, n: usize) -> Vec<usize>;
+    // Extra text to show for a node when printing.
+    fn label(&self, _n: usize) -> String {
+        return String::new();
+    }
+    fn preds(&self) -> Vec<Vec<usize>> {
+        let mut preds = vec![Vec::new(); self.len()];
+        for n in 0..self.len() {
+            for s in self.succs(n) { preds[s].push(n); }
+        }
+        return preds;
+    }
+}
+
+impl Graph for IrCfg {
+    fn len(&self) -> usize { self.nodes.len() }
+    fn entries(&self) -> Vec<usize> { self.starts.clone() }
+    fn succs(&self, n: usize) -> Vec<usize> {
+        let node = &self.nodes[n];
+        return node.t.iter().chain(node.f.iter()).copied().collect();
+    }
+}
+
+// Calls show up as edges in the asm CFG.
+// Here they are dropped, and every callee becomes an entry instead.
+impl<'l> Graph for AsmCfg<'l> {
+    fn len(&self) -> usize { self.nodes.len() }
+    fn entries(&self) -> Vec<usize> {
+        let mut entries = vec![self.start];
+        for node in &self.nodes {
+            let Some(AA::BL(l)) = self.asm.get(node.idx) else { continue };
+            if entries.contains(&(*l as usize)) { continue }
+            entries.push(*l as usize);
+        }
+        return entries;
+    }
+    fn succs(&self, n: usize) -> Vec<usize> {
+        let node = &self.nodes[n];
+        let call = matches!(self.asm.get(node.idx), Some(AA::BL(_)));
+        let t = if call { None } else { node.t };
+        return t.iter().chain(node.f.iter()).copied().collect();
+    }
+    fn label(&self, n: usize) -> String {
+        return match self.asm.get(self.nodes[n].idx) {
+            None    => String::new(),
+            Some(a) => format!("{}", a)
+        };
+    }
+}
+
+// The same graph with every edge flipped, used for post-dominators.
+// Nodes without successors become the entries.
+pub struct Reverse<'l, G: Graph> {
+    graph: &'l G,
+    preds: Vec<Vec<usize>>,
+}
+impl<'l, G: Graph> Reverse<'l, G> {
+    pub fn new(graph: &'l G) -> Self {
+        let preds = graph.preds();
+        return Reverse { graph, preds };
+    }
+}
+impl<'l, G: Graph> Graph for Reverse<'l, G> {
+    fn len(&self) -> usize { self.graph.len() }
+    fn entries(&self) -> Vec<usize> {
+        let reachable = reachable(self.graph);
+        return (0..self.len())
+            .filter(|n| reachable[*n] && self.graph.succs(*n).is_empty())
+            .collect();
+    }
+    fn succs(&self, n: usize) -> Vec<usize> { self.preds[n].clone() }
+    fn label(&self, n: usize) -> String { self.graph.label(n) }
+}
+
+pub fn reachable<G: Graph>(g: &G) -> Vec<bool> {
+    let mut seen = vec![false; g.len()];
+    let mut work = g.entries();
+    while let Some(n) = work.pop() {
+        if seen[n] { continue }
+        seen[n] = true;
+        work.extend(g.succs(n));
+    }
+    return seen;
+}
+
+// Reverse postorder over everything reachable from the entries.
+pub fn rpo<G: Graph>(g: &G) -> Vec<usize> {
+    let mut post = Vec::new();
+    let mut visited = vec![false; g.len()];
+    for e in g.entries() {
+        if visited[e] { continue }
+        visited[e] = true;
+        let mut stk = vec![(e, g.succs(e), 0)];
+        while let Some((n, succs, i)) = stk.pop() {
+            if i == succs.len() {
+                post.push(n);
+                continue;
+            }
+            let s = succs[i];
+            stk.push((n, succs, i + 1));
+            if visited[s] { continue }
+            visited[s] = true;
+            stk.push((s, g.succs(s), 0));
+        }
+    }
+    post.reverse();
+    return post;
+}
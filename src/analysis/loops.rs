@@ -0,0 +1,118 @@
+// Natural loops, nested into a forest.
+// A back edge is an edge whose target dominates its source.
+use super::dominators::Dominators;
+use super::graph::Graph;
+
+pub struct Loop {
+    pub header:   usize,
+    // Every node in the loop, including the header and inner loops.
+    pub body:     Vec<usize>,
+    // Sources of the back edges into the header.
+    pub latches:  Vec<usize>,
+    // Edges (from, to) which leave the loop.
+    pub exits:    Vec<(usize, usize)>,
+    pub parent:   Option<usize>,
+    pub children: Vec<usize>,
+    pub depth:    u32,
+}
+
+pub struct LoopForest {
+    pub loops:     Vec<Loop>,
+    // Innermost loop containing each node.
+    pub innermost: Vec<Option<usize>>,
+    member: Vec<Vec<bool>>,
+}
+impl LoopForest {
+    pub fn compute<G: Graph>(g: &G, dom: &Dominators) -> Self {
+        let len = g.len();
+        let preds = g.preds();
+        // Loops sharing a header are merged into one.
+        let mut latches: Vec<Vec<usize>> = vec![Vec::new(); len];
+        for n in 0..len {
+            if !dom.reachable(n) { continue }
+            for s in g.succs(n) {
+                if dom.dominates(s, n) { latches[s].push(n); }
+            }
+        }
+        let mut loops = Vec::new();
+        let mut member = Vec::new();
+        for h in 0..len {
+            if latches[h].is_empty() { continue }
+            let mut inside = vec![false; len];
+            inside[h] = true;
+            let mut work = latches[h].clone();
+            while let Some(n) = work.pop() {
+                if inside[n] || !dom.reachable(n) { continue }
+                inside[n] = true;
+                work.extend(preds[n].iter().copied());
+            }
+            let body: Vec<usize> = (0..len).filter(|n| inside[*n]).collect();
+            let mut exits = Vec::new();
+            for n in &body {
+                for s in g.succs(*n) {
+                    if !inside[s] { exits.push((*n, s)); }
+                }
+            }
+            loops.push(Loop {
+                header: h,
+                body,
+                latches: std::mem::take(&mut latches[h]),
+                exits,
+                parent: None,
+                children: Vec::new(),
+                depth: 0,
+            });
+            member.push(inside);
+        }
+
+        // Bigger loops go first, so inner loops overwrite them.
+        let mut ids: Vec<usize> = (0..loops.len()).collect();
+        ids.sort_by_key(|i| std::cmp::Reverse(loops[*i].body.len()));
+        let mut innermost = vec![None; len];
+        for id in ids {
+            let parent = innermost[loops[id].header];
+            loops[id].parent = parent;
+            if let Some(p) = parent {
+                loops[p].children.push(id);
+                loops[id].depth = loops[p].depth + 1;
+            } else {
+                loops[id].depth = 1;
+            }
+            for n in &loops[id].body { innermost[*n] = Some(id); }
+        }
+        return LoopForest { loops, innermost, member };
+    }
+
+    // How many loops contain n.
+    pub fn depth(&self, n: usize) -> u32 {
+        return match self.innermost[n] {
+            None    => 0,
+            Some(l) => self.loops[l].depth
+        };
+    }
+
+    pub fn contains(&self, l: usize, n: usize) -> bool {
+        return self.member[l][n];
+    }
+
+    pub fn is_header(&self, n: usize) -> bool {
+        return matches!(self.innermost[n], Some(l) if self.loops[l].header == n);
+    }
+
+    // Whether the edge from -> to leaves any loop containing from.
+    pub fn exits(&self, from: usize, to: usize) -> bool {
+        let mut cur = self.innermost[from];
+        while let Some(l) = cur {
+            if !self.contains(l, to) { return true }
+            cur = self.loops[l].parent;
+        }
+        return false;
+    }
+
+    // Outermost loops first.
+    pub fn roots(&self) -> Vec<usize> {
+        return (0..self.loops.len())
+            .filter(|l| self.loops[*l].parent.is_none())
+            .collect();
+    }
+}
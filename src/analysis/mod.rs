@@ -0,0 +1,4 @@
+pub mod dominators;
+pub mod graph;
+pub mod loops;
+pub mod printer;
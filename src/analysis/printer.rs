@@ -0,0 +1,105 @@
+use super::dominators::Dominators;
+use super::graph::Graph;
+use super::loops::LoopForest;
+pub struct Printer;
+// Dominator trees are drawn with solid edges, the CFG itself with dashed ones.
+impl Printer {
+    pub fn print_dominators<G: Graph>(g: &G, dom: &Dominators) {
+        println!("digraph Dominators {{");
+        println!(r#"  node [shape=box, fontname="Helvetica", fontsize=12]"#);
+        for n in 0..g.len() {
+            if !dom.reachable(n) { continue }
+            Self::label(n, &g.label(n));
+        }
+        Self::tree(dom);
+        Self::cfg(g, dom);
+        println!("}}\n");
+    }
+
+    pub fn print_frontiers<G: Graph>(g: &G, dom: &Dominators, df: &[Vec<usize>]) {
+        println!("digraph Frontiers {{");
+        println!(r#"  node [shape=box, fontname="Helvetica", fontsize=12]"#);
+        for n in 0..g.len() {
+            if !dom.reachable(n) { continue }
+            Self::label(n, &g.label(n));
+        }
+        Self::tree(dom);
+        for (n, f) in df.iter().enumerate() {
+            for m in f {
+                println!("    node{} -> node{} [color=red, constraint=false];", n, m);
+            }
+        }
+        println!("}}\n");
+    }
+
+    // Every loop becomes a cluster, nested the same way the loops are.
+    pub fn print_loops<G: Graph>(g: &G, dom: &Dominators, forest: &LoopForest) {
+        println!("digraph Loops {{");
+        println!(r#"  node [shape=box, fontname="Helvetica", fontsize=12]"#);
+        for l in forest.roots() {
+            Self::cluster(g, forest, l, 1);
+        }
+        for n in 0..g.len() {
+            if !dom.reachable(n) || forest.innermost[n].is_some() { continue }
+            Self::label(n, &g.label(n));
+        }
+        for n in 0..g.len() {
+            if !dom.reachable(n) { continue }
+            for s in g.succs(n) {
+                let back = dom.dominates(s, n);
+                println!("    node{} -> node{}{};", n, s,
+                    if back { " [color=red]" } else { "" });
+            }
+        }
+        println!("}}\n");
+    }
+
+    fn cluster<G: Graph>(g: &G, forest: &LoopForest, l: usize, tabs: usize) {
+        let pad = "  ".repeat(tabs);
+        let lp = &forest.loops[l];
+        println!("{}subgraph cluster_loop{} {{", pad, l);
+        println!("{}  label=\"Loop {} (depth {})\"", pad, lp.header, lp.depth);
+        println!("{}  style=rounded", pad);
+        for c in &lp.children {
+            Self::cluster(g, forest, *c, tabs + 1);
+        }
+        for n in &lp.body {
+            if forest.innermost[*n] != Some(l) { continue }
+            Self::label(*n, &g.label(*n));
+        }
+        println!("{}}}", pad);
+    }
+
+    fn tree(dom: &Dominators) {
+        for (n, c) in dom.children.iter().enumerate() {
+            for m in c {
+                println!("    node{} -> node{} [style=bold];", n, m);
+            }
+        }
+    }
+
+    fn cfg<G: Graph>(g: &G, dom: &Dominators) {
+        for n in 0..g.len() {
+            if !dom.reachable(n) { continue }
+            for s in g.succs(n) {
+                println!("    node{} -> node{} [style=dashed, color=gray, constraint=false];", n, s);
+            }
+        }
+    }
+
+    fn label(n: usize, s: &str) {
+        let s = s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;");
+        let row = match s.is_empty() {
+            true  => String::new(),
+            false => format!("          <tr><td align=\"center\">{}</td></tr>\n", s)
+        };
+        println!("{}",
+            format!( "  node{} [label=<\n", n) +
+            "    <table border=\"0\" cellborder=\"1\" cellspacing=\"0\" cellpadding=\"6\">\n" +
+            &format!("      <tr><td align=\"center\" bgcolor=\"lightblue\"><font color=\"black\"><b>Node {}</b></font></td></tr>\n", n) +
+            &row +
+            "    </table>\n" +
+            "  >]"
+        );
+    }
+}
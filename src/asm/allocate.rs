@@ -2,227 +2,516 @@
 use crate::registry::Registry;
 
 use super::asm::*;
-use std::collections::HashMap;
-use std::collections::HashSet;
-use std::mem::swap;
+use super::cfg::CFG;
+use super::liveness::Liveness;
+use super::target::{MachineInstr, Target};
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 
 type AdjMatrix = Vec<HashSet<usize>>;
 type AdjList   = Vec<Vec<usize>>;
 
-struct DSU { parent: Vec<i32> }
-impl DSU {
-    pub fn new(n: i32) -> Self {
-        let parent = vec![-1; n as usize];
-        return DSU { parent }
-    }
-    pub fn find(&mut self, idx: i32) -> i32 {
-        if self.parent[idx as usize] < 0 { return idx }
-        self.parent[idx as usize] = self.find(
-            self.parent[idx as usize]
-        );
-        return self.parent[idx as usize];
-    }
-    pub fn merge(&mut self, l: i32, r: i32) {
-        let mut large = self.find(l) as usize;
-        let mut small = self.find(r) as usize;
-        if large == small { return }
-        if self.parent[small] < self.parent[large] {
-            swap(&mut small, &mut large);
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum State { Precolored, Initial, Simplify, Freeze, Spill, Stack, Coalesced, Colored, Spilled }
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Move { Worklist, Active, Coalesced, Constrained, Frozen }
+
+// See https://dl.acm.org/doi/10.1145/229542.229546 (George & Appel)
+// Iterated register coalescing, names follow the paper.
+struct Irc {
+    amat:      AdjMatrix,
+    alist:     AdjList,
+    degree:    Vec<usize>,
+    state:     Vec<State>,
+    moves:     Vec<(usize, usize)>,
+    mstate:    Vec<Move>,
+    move_list: Vec<Vec<usize>>,
+    simplify:  BTreeSet<usize>,
+    freeze:    BTreeSet<usize>,
+    spill:     BTreeSet<usize>,
+    worklist:  BTreeSet<usize>,
+    stack:     Vec<usize>,
+    alias:     Vec<usize>,
+    color:     Vec<Option<usize>>,
+    // Definitions and uses, for picking what to spill.
+    occurs:    Vec<usize>,
+    // Temps made by spilling, spilling them again gains nothing.
+    fixed:     HashSet<usize>,
+    spilled:   Vec<usize>,
+    colors:    &'static [usize],
+    // Registers come first, then temps.
+    regs:      usize,
+    // The colors each temp may take, what its register classes share.
+    allowed:   Vec<Vec<usize>>,
+}
+impl Irc {
+    fn new<I: MachineInstr>(
+            nids:  u32,
+            live:  &Liveness<I>,
+            fixed: HashSet<usize>
+        ) -> Self {
+        let (amat, alist) = build_graph(nids, live);
+        let n = alist.len();
+        let regs = I::Target::REGS;
+        let colors = I::Target::colors();
+        let classes = I::Target::classes();
+        let mut g = Irc {
+            amat,
+            alist:     vec![Vec::new(); n],
+            degree:    vec![0; n],
+            state:     vec![State::Precolored; n],
+            moves:     Vec::new(),
+            mstate:    Vec::new(),
+            move_list: vec![Vec::new(); n],
+            simplify:  BTreeSet::new(),
+            freeze:    BTreeSet::new(),
+            spill:     BTreeSet::new(),
+            worklist:  BTreeSet::new(),
+            stack:     Vec::new(),
+            alias:     (0..n).collect(),
+            color:     (0..n).map(|i| (i < regs).then_some(i)).collect(),
+            occurs:    vec![0; n],
+            fixed,
+            spilled:   Vec::new(),
+            colors,
+            regs,
+            allowed:   vec![colors.to_vec(); n],
+        };
+        for ins in &live.asm {
+            let (defs, uses) = ins.defuse();
+            for reg in defs.iter().chain(uses.iter()) {
+                let i = I::Target::index(*reg);
+                if i < regs { continue }
+                g.occurs[i] += 1;
+                g.state[i] = State::Initial;
+                let class = classes[ins.class(*reg)];
+                g.allowed[i].retain(|c| class.contains(c));
+            }
+            let Some((d, s)) = ins.moves() else { continue };
+            let (d, s) = (I::Target::index(d), I::Target::index(s));
+            if d == s || g.reserved(d) || g.reserved(s) { continue }
+            g.move_list[d].push(g.moves.len());
+            g.move_list[s].push(g.moves.len());
+            g.worklist.insert(g.moves.len());
+            g.moves.push((d, s));
+            g.mstate.push(Move::Worklist);
+        }
+        // Only temps keep adjacency lists; reserved registers
+        // are never a color, so they don't count towards degree.
+        for i in regs..n {
+            if g.state[i] != State::Initial { continue }
+            g.alist[i] = alist[i].iter().copied().filter(|j| !g.reserved(*j)).collect();
+            g.degree[i] = g.alist[i].len();
+        }
+        for i in 0..regs { g.degree[i] = usize::MAX / 2; }
+        return g;
+    }
+    fn run(&mut self) {
+        self.make_worklist();
+        loop {
+            if let Some(n) = self.simplify.pop_first() {
+                self.simplify(n);
+            } else if let Some(m) = self.worklist.pop_first() {
+                self.coalesce(m);
+            } else if let Some(n) = self.freeze.pop_first() {
+                self.simplify.insert(n);
+                self.state[n] = State::Simplify;
+                self.freeze_moves(n);
+            } else if !self.spill.is_empty() {
+                self.select_spill();
+            } else { break }
+        }
+        self.assign_colors();
+    }
+    fn make_worklist(&mut self) {
+        for n in self.regs..self.state.len() {
+            if self.state[n] != State::Initial { continue }
+            self.push(n);
+        }
+    }
+    fn push(&mut self, n: usize) {
+        if self.degree[n] >= self.k(n) {
+            self.spill.insert(n);
+            self.state[n] = State::Spill;
+        } else if self.move_related(n) {
+            self.freeze.insert(n);
+            self.state[n] = State::Freeze;
+        } else {
+            self.simplify.insert(n);
+            self.state[n] = State::Simplify;
+        }
+    }
+    fn adjacent(&self, n: usize) -> Vec<usize> {
+        return self.alist[n].iter().copied().filter(|m| {
+            !matches!(self.state[*m], State::Stack | State::Coalesced)
+        }).collect();
+    }
+    fn node_moves(&self, n: usize) -> Vec<usize> {
+        return self.move_list[n].iter().copied().filter(|m| {
+            matches!(self.mstate[*m], Move::Active | Move::Worklist)
+        }).collect();
+    }
+    fn move_related(&self, n: usize) -> bool {
+        return !self.node_moves(n).is_empty();
+    }
+    fn precolored(&self, n: usize) -> bool { n < self.regs }
+    // How many colors n has to pick from, its K.
+    fn k(&self, n: usize) -> usize { self.allowed[n].len() }
+    fn reserved(&self, n: usize) -> bool { n < self.regs && !self.colors.contains(&n) }
+    fn simplify(&mut self, n: usize) {
+        self.stack.push(n);
+        self.state[n] = State::Stack;
+        for m in self.adjacent(n) {
+            self.decrement_degree(m);
+        }
+    }
+    fn decrement_degree(&mut self, m: usize) {
+        if self.precolored(m) { return }
+        let d = self.degree[m];
+        self.degree[m] -= 1;
+        if d != self.k(m) { return }
+        let mut nodes = self.adjacent(m);
+        nodes.push(m);
+        self.enable_moves(&nodes);
+        self.spill.remove(&m);
+        if self.move_related(m) {
+            self.freeze.insert(m);
+            self.state[m] = State::Freeze;
+        } else {
+            self.simplify.insert(m);
+            self.state[m] = State::Simplify;
         }
-        self.parent[large] += self.parent[small];
-        self.parent[small] = large as i32;
     }
-    // Forces r to become the child of f.
-    pub fn mergef(&mut self, l: i32, r: i32) {
-        let large = self.find(l) as usize;
-        let small = self.find(r) as usize;
-        self.parent[large] += self.parent[small];
-        self.parent[small] = large as i32;
+    fn enable_moves(&mut self, nodes: &[usize]) {
+        for n in nodes {
+            for m in self.node_moves(*n) {
+                if self.mstate[m] != Move::Active { continue }
+                self.mstate[m] = Move::Worklist;
+                self.worklist.insert(m);
+            }
+        }
+    }
+    fn alias(&self, n: usize) -> usize {
+        if self.state[n] == State::Coalesced { self.alias(self.alias[n]) } else { n }
+    }
+    fn coalesce(&mut self, m: usize) {
+        let (x, y) = (self.alias(self.moves[m].0), self.alias(self.moves[m].1));
+        let (u, v) = if self.precolored(y) { (y, x) } else { (x, y) };
+        if u == v {
+            self.mstate[m] = Move::Coalesced;
+            self.add_worklist(u);
+        } else if self.precolored(v) || self.amat[u].contains(&v) || !self.compatible(u, v) {
+            self.mstate[m] = Move::Constrained;
+            self.add_worklist(u);
+            self.add_worklist(v);
+        } else if self.can_coalesce(u, v) {
+            self.mstate[m] = Move::Coalesced;
+            self.combine(u, v);
+            self.add_worklist(u);
+        } else {
+            self.mstate[m] = Move::Active;
+        }
+    }
+    // Whether some color suits both u and v, u may be pre-colored.
+    fn compatible(&self, u: usize, v: usize) -> bool {
+        if self.precolored(u) { return self.allowed[v].contains(&u) }
+        return self.allowed[u].iter().any(|c| self.allowed[v].contains(c));
+    }
+    fn shared(&self, u: usize, v: usize) -> Vec<usize> {
+        return self.allowed[u].iter().copied().filter(|c| self.allowed[v].contains(c)).collect();
+    }
+    // George's test against pre-colored registers, Briggs' otherwise.
+    fn can_coalesce(&self, u: usize, v: usize) -> bool {
+        if self.precolored(u) {
+            return self.adjacent(v).iter().all(|t| {
+                self.degree[*t] < self.k(*t) || self.precolored(*t) || self.amat[*t].contains(&u)
+            });
+        }
+        let k = self.shared(u, v).len();
+        let mut nodes: BTreeSet<usize> = self.adjacent(u).into_iter().collect();
+        nodes.extend(self.adjacent(v));
+        return nodes.iter().filter(|n| self.degree[**n] >= self.k(**n)).count() < k;
+    }
+    fn add_worklist(&mut self, u: usize) {
+        if self.precolored(u) || self.move_related(u) || self.degree[u] >= self.k(u) { return }
+        self.freeze.remove(&u);
+        self.simplify.insert(u);
+        self.state[u] = State::Simplify;
+    }
+    fn combine(&mut self, u: usize, v: usize) {
+        self.freeze.remove(&v);
+        self.spill.remove(&v);
+        self.state[v] = State::Coalesced;
+        self.alias[v] = u;
+        if !self.precolored(u) { self.allowed[u] = self.shared(u, v); }
+        let moves = self.move_list[v].clone();
+        self.move_list[u].extend(moves);
+        self.enable_moves(&[v]);
+        for t in self.adjacent(v) {
+            self.add_edge(t, u);
+            self.decrement_degree(t);
+        }
+        if self.degree[u] >= self.k(u) && self.freeze.remove(&u) {
+            self.spill.insert(u);
+            self.state[u] = State::Spill;
+        }
+    }
+    fn add_edge(&mut self, u: usize, v: usize) {
+        if u == v || self.amat[u].contains(&v) { return }
+        self.amat[u].insert(v);
+        self.amat[v].insert(u);
+        if !self.precolored(u) {
+            self.alist[u].push(v);
+            self.degree[u] += 1;
+        }
+        if !self.precolored(v) {
+            self.alist[v].push(u);
+            self.degree[v] += 1;
+        }
+    }
+    fn freeze_moves(&mut self, u: usize) {
+        for m in self.node_moves(u) {
+            let (x, y) = self.moves[m];
+            let v = if self.alias(y) == self.alias(u) { self.alias(x) } else { self.alias(y) };
+            self.mstate[m] = Move::Frozen;
+            if self.state[v] != State::Freeze || self.move_related(v) { continue }
+            if self.degree[v] >= self.k(v) { continue }
+            self.freeze.remove(&v);
+            self.simplify.insert(v);
+            self.state[v] = State::Simplify;
+        }
+    }
+    // Cheapest to spill: few definitions and uses, many neighbours.
+    fn select_spill(&mut self) {
+        let cost = |n: usize| match self.fixed.contains(&n) {
+            true  => f64::INFINITY,
+            false => self.occurs[n] as f64 / self.degree[n].max(1) as f64,
+        };
+        let m = *self.spill.iter()
+            .min_by(|a, b| cost(**a).total_cmp(&cost(**b)))
+            .unwrap();
+        self.spill.remove(&m);
+        self.simplify.insert(m);
+        self.state[m] = State::Simplify;
+        self.freeze_moves(m);
+    }
+    fn assign_colors(&mut self) {
+        while let Some(n) = self.stack.pop() {
+            let mut ok = vec![true; self.regs];
+            for w in &self.alist[n] {
+                let a = self.alias(*w);
+                if let Some(c) = self.color[a] { ok[c] = false; }
+            }
+            match self.allowed[n].iter().copied().find(|c| ok[*c]) {
+                None => {
+                    self.state[n] = State::Spilled;
+                    self.spilled.push(n);
+                },
+                Some(c) => {
+                    self.state[n] = State::Colored;
+                    self.color[n] = Some(c);
+                }
+            }
+        }
+        for n in self.regs..self.state.len() {
+            if self.state[n] != State::Coalesced { continue }
+            self.color[n] = self.color[self.alias(n)];
+        }
     }
 }
-struct Node {
-    deg:   u32,
-    pos:   u32,
+
+
+pub fn allocate<I: MachineInstr>(r: &mut Registry, live: &Liveness<I>) -> Vec<I> {
+    let mut fixed = HashSet::new();
+    let mut g = Irc::new(r.nids, live, fixed.clone());
+    g.run();
+    let mut asm = live.asm.clone();
+    while !g.spilled.is_empty() {
+        if let Some(n) = g.spilled.iter().find(|n| fixed.contains(n)) {
+            panic!("Ran out of registers for {}!", I::Target::reg(*n));
+        }
+        asm = spill(r, asm, &g.spilled, &mut fixed);
+        let cfg = CFG::build(r, &asm);
+        g = Irc::new(r.nids, &Liveness::analyze(&cfg), fixed.clone());
+        g.run();
+    }
+    let asm = asm.iter().map(|a| a.map(&|r: Reg| {
+        // Temps that are never used don't matter.
+        I::Target::reg(g.color[I::Target::index(r)].unwrap_or(0))
+    })).collect();
+    return preserve(asm);
 }
 
-pub fn allocate(
-        r: &mut Registry,
-        mut live: Vec<(AA, Vec<bool>, Vec<bool>)>,
-    ) -> Vec<AA> {
-    let (mut amat, mut alist) = build_graph(r.nids, &mut live);
-    coalesce_graph(&mut live, &mut alist, &mut amat);
-    let colors = color_graph(alist);
-    rewrite(&mut live, |r: Reg| {
-        return Reg::from(colors[r.index()] as u32);
-    });
-    return live.into_iter().map(|x| x.0).collect();
+// Merges move related temps, as long as that keeps the graph colorable,
+// then rebuilds the graph over the rewritten code.
+pub fn coalesce_graph<I: MachineInstr>(
+        r:    &Registry,
+        live: &Liveness<I>
+    ) -> (Vec<I>, AdjMatrix, AdjList) {
+    let mut g = Irc::new(r.nids, live, HashSet::new());
+    g.run();
+    let asm: Vec<I> = live.asm.iter()
+        .map(|a| a.map(&|r: Reg| I::Target::reg(g.alias(I::Target::index(r)))))
+        .collect();
+    let (amat, alist) = build_graph(r.nids, &Liveness::analyze(&CFG::build(r, &asm)));
+    return (asm, amat, alist);
 }
 
-pub fn coalesce_graph(
-        live:  &mut Vec<(AA, Vec<bool>, Vec<bool>)>,
-        alist: &mut AdjList,
-        amat:  &mut AdjMatrix
-    ) {
-    // Things may get mapped multiple times.
-    // Use a dsu to keep track.
-    let mut dsu = DSU::new(alist.len() as i32);
-    // You can also iterate until you reach a fixed point.
-    for _ in 0..=1 {
-        let mut f   = HashMap::new();
-        for (ins, _, _) in live.iter() {
-            let AA::Mov2(d, s) = ins else { continue };
-            // println!("d: {}, s: {}", d.index(), s.index());
-            let (mut d, mut s) = (
-                dsu.find(d.index() as i32),
-                dsu.find(s.index() as i32)
-            );
-            // println!("ad: {}, as: {}", d, s);
-            if d == s { continue };
-            let c = amat[d as usize].contains(&(s as usize));
-            if c { continue };
+// A function's frame as the translator laid it out, see Target::frame.
+// Extra slots go below the locals.
+struct Frame { at: usize, size: i64, extra: i64, main: bool }
+impl Frame {
+    fn slot(&mut self) -> i64 {
+        self.extra += 8;
+        return -(self.size + self.extra);
+    }
+    fn sub(&self) -> usize {
+        if self.size > 0 { self.at + 1 } else { self.at }
+    }
+}
 
-            // If one register is pre-colored, change the other
-            // To match it.
-            if d < GPRS as i32 && s < GPRS as i32 {
-                continue;
-            } else if d < GPRS as i32 && s >= GPRS as i32 {
-                dsu.mergef(d, s);
-            } else if d >= GPRS as i32 && s < GPRS as i32 {
-                dsu.mergef(s, d);
-            } else {
-                dsu.merge(d, s);
-            }
-            // If s -> d, swap the two.
-            if dsu.find(s) == d {
-                swap(&mut d, &mut s);
-            }
-            // Map (d -> s)
-            let temp = std::mem::take(&mut alist[d as usize]);
-            for node in &temp {
-                amat[s as usize].insert(*node);
-                amat[*node].insert(s as usize);
-            }
-            alist[s as usize].extend(temp);
-            f.insert(d, s);
-        }
-        if f.len() == 0 { break }
-        rewrite(live, |r: Reg| {
-            match f.get(&(r.index() as i32)) {
-                None    => return r,
-                Some(i) => return Reg::from(*i as u32)
-            };
-        });
-        // The coloring algorithm won't work because
-        // We haven't updated amat and alist properly.
-        // Rebuild the graph.
-        (*amat, *alist) = build_graph(
-            alist.len() as u32, live
-        );
+fn frames<I: MachineInstr>(asm: &[I]) -> Vec<Frame> {
+    return (0..asm.len())
+        .filter_map(|i| I::Target::frame(asm, i).map(|(size, main)| {
+            Frame { at: i, size, extra: 0, main }
+        }))
+        .collect();
+}
+
+// Which frame each instruction belongs to.
+fn owners<I>(asm: &[I], frames: &[Frame]) -> Vec<usize> {
+    let mut res = vec![0; asm.len()];
+    let mut f = 0;
+    for i in 0..asm.len() {
+        while f + 1 < frames.len() && frames[f + 1].at <= i { f += 1; }
+        res[i] = f;
     }
+    return res;
 }
 
-fn color_graph(alist: AdjList) -> Vec<usize> {
-    // If no coloring was found, recolor.
-    let (mut degrees, mut stk);
-    loop {
-        let mut nodes = Vec::new();
-        let mut stacked = vec![false; alist.len()];
-        degrees = vec![0; alist.len()];
-        stk     = Vec::new();
-        for i in GPRS..alist.len() {
-            nodes.push(Node {
-                deg: alist[i].len() as u32,
-                pos: i as u32
-            });
-            degrees[i] = alist[i].len() as u32;
+// Rebuilds the code with whatever goes before, instead of and after each
+// instruction, then grows every frame that got extra slots.
+fn splice<I: MachineInstr>(
+        asm:     Vec<I>,
+        frames:  &[Frame],
+        mut before: Vec<Vec<I>>,
+        mut after:  Vec<Vec<I>>
+    ) -> Vec<I> {
+    let mut asm = asm;
+    for f in frames {
+        if f.extra == 0 { continue }
+        let size = (f.size + f.extra + 15) / 16 * 16;
+        let sub = I::Target::grow(size);
+        match f.size > 0 {
+            true  => asm[f.at + 1] = sub,
+            false => after[f.at].insert(0, sub),
         }
+    }
+    let mut res = Vec::new();
+    for (i, a) in asm.into_iter().enumerate() {
+        res.append(&mut before[i]);
+        res.push(a);
+        res.append(&mut after[i]);
+    }
+    return res;
+}
 
-        while let Some(Node { deg, pos }) = nodes.pop() {
-            if stacked[pos as usize] { continue }
-            // We've updated it multiple times, and this is an old version.
-            if degrees[pos as usize] < deg { continue }
-            if deg >= GPRS as u32 { continue }
-            for nbr in &alist[pos as usize] {
-                // We don't update the adjlist
-                // Hence, we need this check.
-                if stacked[*nbr] { continue }
-                if degrees[*nbr] == 0 { continue }
-                degrees[*nbr] -= 1;
-                nodes.push(Node {
-                    deg: degrees[*nbr],
-                    pos: *nbr as u32
-                });
+// Every spilled temp lives in a slot of its own, each definition is stored
+// right away and each use loaded right before, into a fresh temp.
+pub(super) fn spill<I: MachineInstr>(
+        r:       &mut Registry,
+        asm:     Vec<I>,
+        spilled: &[usize],
+        fixed:   &mut HashSet<usize>
+    ) -> Vec<I> {
+    use Reg::*;
+    let mut frames = frames(&asm);
+    let owner = owners(&asm, &frames);
+    let mut slots = HashMap::new();
+    let (mut before, mut after) = (vec![Vec::new(); asm.len()], vec![Vec::new(); asm.len()]);
+    let mut res = asm.clone();
+    for (i, a) in asm.iter().enumerate() {
+        let (defs, uses) = a.defuse();
+        let mut fresh = HashMap::new();
+        for reg in uses.iter().chain(defs.iter()) {
+            let n = I::Target::index(*reg);
+            if !spilled.contains(&n) || fresh.contains_key(&n) { continue }
+            let off = *slots.entry(n).or_insert_with(|| frames[owner[i]].slot());
+            let t = ID(r.nids);
+            r.nids += 1;
+            fixed.insert(I::Target::index(t));
+            fresh.insert(n, t);
+            if uses.contains(reg) {
+                before[i].push(I::Target::load(t, off));
+            }
+            if defs.contains(reg) {
+                after[i].push(I::Target::store(t, off));
             }
-            stk.push(pos);
-            stacked[pos as usize] = true;
-        }
-        if stk.len() != (alist.len() - GPRS) {
-            // Spill Logic.
         }
-        break;
+        if fresh.is_empty() { continue }
+        res[i] = a.map(&|reg: Reg| *fresh.get(&I::Target::index(reg)).unwrap_or(&reg));
     }
+    return splice(res, &frames, before, after);
+}
 
-    let mut legal  = vec![[true; GPRS]; degrees.len()];
-    let mut colors = vec![None; degrees.len()];
-    // Pre-Color Nodes.
-    for i in 0..GPRS {
-        colors[i as usize] = Some(i);
-        for nbr in &alist[i as usize] {
-            legal[*nbr][i] = false;
+// Nothing saves the callee-saved registers around calls, so a function
+// that writes any of them keeps the caller's values in its frame.
+pub(super) fn preserve<I: MachineInstr>(asm: Vec<I>) -> Vec<I> {
+    let mut frames = frames(&asm);
+    let owner = owners(&asm, &frames);
+    let mut used = vec![BTreeSet::new(); frames.len()];
+    let callee = I::Target::callee_saved();
+    for (i, a) in asm.iter().enumerate() {
+        for reg in a.defuse().0 {
+            if callee.contains(&reg) { used[owner[i]].insert(reg); }
         }
     }
-
-    // Determine the Colors...
-    while let Some(idx) = stk.pop() {
-        for (i, l) in legal[idx as usize].iter().enumerate() {
-            if !l { continue }
-            colors[idx as usize] = Some(i);
-            for nbr in &alist[idx as usize] {
-                legal[*nbr][i] = false;
+    let (mut before, mut after) = (vec![Vec::new(); asm.len()], vec![Vec::new(); asm.len()]);
+    for (f, frame) in frames.iter_mut().enumerate() {
+        if frame.main { continue }
+        let saves: Vec<(Reg, i64)> = used[f].iter().map(|r| (*r, frame.slot())).collect();
+        for (reg, off) in &saves {
+            after[frame.sub()].push(I::Target::store(*reg, *off));
+        }
+        // Restore before every epilogue.
+        for i in frame.at + 1..asm.len() {
+            if owner[i] != f { break }
+            if !I::Target::epilogue(&asm[i]) { continue }
+            for (reg, off) in &saves {
+                before[i].push(I::Target::load(*reg, *off));
             }
-            break;
         }
     }
-    // Ensure every ID has a color.
-    return colors
-        .into_iter()
-        .map(|x| x.unwrap())
-        .collect();
+    return splice(asm, &frames, before, after);
 }
 
-pub fn build_graph(
+pub fn build_graph<I: MachineInstr>(
         nids: u32,
-        live: &Vec<(AA, Vec<bool>, Vec<bool>)>
+        live: &Liveness<I>
     ) -> (AdjMatrix, AdjList) {
     // We need both because we access the graph
     // sequentially and randomly.
+    let index = I::Target::index;
     let mut amat = vec![
         HashSet::new();
-        (nids as usize) + GPRS
+        (nids as usize) + I::Target::REGS
     ];
     let mut alist = vec![
         Vec::new();
-        (nids as usize) + GPRS
+        (nids as usize) + I::Target::REGS
     ];
     // Literally the Chaitin Graph Building Algo.
     // 1. We store counts because there WILL be duplicates
     // because of graph coalescing.
     // 2. This has been cleverly designed to allow dynamically
     // recomputing the liveness, in linear time!
-    use AA::*;
-    let mut conflicts = HashMap::new();
-    for (asm, defdead, usedead) in live {
-        if let BB(v) = asm.clone() {
-            conflicts = HashMap::new();
-            for reg in v {
-                match conflicts.get(&reg.index()) {
-                    None    => conflicts.insert(reg.index(), 1),
-                    Some(i) => conflicts.insert(reg.index(), *i + 1)
-                };
-            }
-        } else {
+    for (b, blk) in live.blocks.iter().enumerate() {
+        let mut conflicts: HashMap<usize, usize> = live.live_in(b).iter().map(|r| (r, 1)).collect();
+        for (k, after) in live.after(b).iter().enumerate() {
+            let asm = &live.asm[blk.start + k];
             let (defs, uses) = asm.defuse();
+            let usedead: Vec<bool> = uses.iter().map(|r| !after.contains(index(*r))).collect();
+            let defdead: Vec<bool> = defs.iter().map(|r| !after.contains(index(*r))).collect();
             // If the register dies after this use, it doesn't produce a conflict.
             // The count is important here, if the count is non-zero, that means
             // That a duplicate of this register has been inserted (prbly bc coalescing)
@@ -230,97 +519,56 @@ pub fn build_graph(
             for (reg, dead) in uses.iter().zip(usedead.iter()) {
                 // println!("use: reg - {}, dead - {}", reg, dead);
                 if !*dead { continue }
-                match conflicts.get(&reg.index()).unwrap() {
-                    1  => conflicts.remove(&reg.index()),
-                    i  => conflicts.insert(reg.index(), *i - 1)
+                match conflicts.get(&index(*reg)).unwrap() {
+                    1  => conflicts.remove(&index(*reg)),
+                    i  => conflicts.insert(index(*reg), *i - 1)
                 };
             }
+            // A move doesn't make its operands interfere,
+            // they hold the same value.
+            let src = asm.moves().map(|(_, s)| index(s));
             for (reg, dead) in defs.iter().zip(defdead.iter()) {
                 // println!("def: reg - {}, dead - {}", reg, dead);
                 // Add edges between everything which conflicts with this definition.
                 for (key, _) in &conflicts {
-                    if amat[reg.index()].contains(key) { continue }
-                    amat[reg.index()].insert(*key); 
-                    amat[*key].insert(reg.index());
-                    alist[reg.index()].push(*key);
-                    alist[*key].push(reg.index());
+                    if Some(*key) == src || *key == index(*reg) { continue }
+                    if amat[index(*reg)].contains(key) { continue }
+                    amat[index(*reg)].insert(*key); 
+                    amat[*key].insert(index(*reg));
+                    alist[index(*reg)].push(*key);
+                    alist[*key].push(index(*reg));
                 }
                 if *dead { continue }
+                // Reading and writing the same register, like movk does,
+                // still leaves one value live, not a duplicate.
+                if uses.contains(reg) && conflicts.contains_key(&index(*reg)) { continue }
                 // We've redefined the variable (and it's used somewhere)
                 // Hence, it could conflict with stuff in the future.
-                match conflicts.get(&reg.index()) {
-                    None    => conflicts.insert(reg.index(), 1),
-                    Some(i) => conflicts.insert(reg.index(), *i + 1)
+                match conflicts.get(&index(*reg)) {
+                    None    => conflicts.insert(index(*reg), 1),
+                    Some(i) => conflicts.insert(index(*reg), *i + 1)
                 };
             }
         }
     }
-    use Reg as R;
-    // Prevent overwriting SP, RZR, PC
-    for illegal in vec![R::SP, R::RZR, R::PC] {
-        for reg in GPRS..alist.len() {
-            if amat[illegal.index()].contains(&reg) { continue }
-            amat[illegal.index()].insert(reg); 
-            amat[reg].insert(illegal.index());
-            alist[illegal.index()].push(reg);
-            alist[reg].push(illegal.index());
+    // Prevent overwriting the stack and frame pointers and the like.
+    for illegal in I::Target::reserved() {
+        let illegal = index(illegal);
+        for reg in I::Target::REGS..alist.len() {
+            if amat[illegal].contains(&reg) { continue }
+            amat[illegal].insert(reg); 
+            amat[reg].insert(illegal);
+            alist[illegal].push(reg);
+            alist[reg].push(illegal);
         }
     }
     return (amat, alist)
 }
 
-fn rewrite(
-        live: &mut Vec<(AA, Vec<bool>, Vec<bool>)>,
-        c: impl Fn(Reg) -> Reg
-    ) {
-    use AA::*;
-    for (asm, _, _) in live {
-        *asm = match asm.clone() {
-            Label(l)           => Label(l),
-            Mov1(d, s)         => Mov1(c(d), s),
-            Mov2(d, s)         => Mov2(c(d), c(s)),
-            Add1(d, l, r)      => Add1(c(d), c(l), r),
-            Add2(d, l, r)      => Add2(c(d), c(l), c(r)),
-            Sub1(d, l, r)      => Sub1(c(d), c(l), r),
-            Sub2(d, l, r)      => Sub2(c(d), c(l), c(r)),
-            Neg1(d, s)         => Neg1(c(d), s),
-            Neg2(d, s)         => Neg2(c(d), c(s)),
-            SMAddL(d, l, m, r) => SMAddL(c(d), c(l), c(m), c(r)),
-            SMNegL(d, l, r)    => SMNegL(c(d), c(l), c(r)),
-            SMSubL(d, l, m, r) => SMSubL(c(d), c(l), c(m), c(r)),
-            SMulL(d, l, r)     => SMulL(c(d), c(l), c(r)),
-            SDiv(d, l, r)      => SDiv(c(d), c(l), c(r)),
-            And1(d, l, r)      => And1(c(d),  c(l), r),
-            And2(d, l, r)      => And2(c(d),  c(l), c(r)),
-            Or1(d, l, r)       => Or1(c(d), c(l), r),
-            Or2(d, l, r)       => Or2(c(d), c(l), c(r)),
-            Mvn1(d, s)         => Mvn1(c(d), s),
-            Mvn2(d, s)         => Mvn2(c(d), c(s)),
-            B1(l)              => B1(l),
-            B2(c, l)           => B2(c, l),
-            BL(l)              => BL(l),
-            CBZ(r, l)          => CBZ(c(r), l),
-            CBNZ(r, l)         => CBNZ(c(r), l),
-            CMP1(d, s)         => CMP1(c(d), s),
-            CMP2(d, s)         => CMP2(c(d), c(s)),
-            CSET(d, s)         => CSET(c(d), s),
-            LDR1(d, l, r)      => LDR1(c(d), c(l), r),
-            LDR2(d, s)         => LDR2(c(d), c(s)),
-            STR1(d, l, r)      => STR1(c(d), c(l), r),
-            STR2(d, s)         => STR2(c(d), c(s)),
-            BB(v)              => BB(v.iter().map(|r| c(*r)).collect()),
-            SVC(c)             => SVC(c),
-            Ret                => Ret,
-        };
-    }
-}
-
 // Utilities...
 pub fn print_graph(alist: AdjList) {
     println!("digraph interference_graph {{");
-    let mut added = vec![
-        false; alist.len() + GPRS as usize
-    ];
+    let mut added = vec![false; alist.len()];
     for (idx, v) in alist.into_iter().enumerate() {
         if v.len() == 0 { continue }
         println!("    node{} [label=\"{}\"]",
@@ -1,9 +1,13 @@
 use core::fmt;
 use std::fmt::Display;
 use std::cmp::Ordering;
+use super::target::{MachineInstr, Target};
+use super::strings;
 
 pub type Label = u32;
-pub const GPRS: usize = 33;
+// The register file every target numbers into: R(0)-R(31), SP, the zero
+// register and PC. Temps come after.
+pub const GPRS: usize = 35;
 // Presume everything costs the same.
 
 #[derive(Clone)]
@@ -11,6 +15,8 @@ pub enum AA {
     Label(Label),
     Mov1(Reg, Const),
     Mov2(Reg, Reg),
+    MovZ(Reg, Const, u8),       // Xd = imm << shift
+    MovK(Reg, Const, u8),       // Xd[shift + 15:shift] = imm
     Add1(Reg, Reg, Const),
     Add2(Reg, Reg, Reg),
     Sub1(Reg, Reg, Const),
@@ -26,8 +32,24 @@ pub enum AA {
     And2(Reg, Reg, Reg),
     Or1(Reg, Reg, Const),
     Or2(Reg, Reg, Reg),
+    Eor1(Reg, Reg, Const),
+    Eor2(Reg, Reg, Reg),
+    Lsl1(Reg, Reg, Const),
+    Lsl2(Reg, Reg, Reg),
+    Asr1(Reg, Reg, Const),
+    Asr2(Reg, Reg, Reg),
     Mvn1(Reg, Const),           // Xd = ~Xs
     Mvn2(Reg, Reg),             // Xd = ~Xs
+    // Floats live in general purpose registers as raw bits,
+    // these move them through D16/D17 for the actual operation.
+    FAdd(Reg, Reg, Reg),
+    FSub(Reg, Reg, Reg),
+    FMul(Reg, Reg, Reg),
+    FDiv(Reg, Reg, Reg),
+    FNeg(Reg, Reg),
+    FCmp(Reg, Reg),
+    SCvtF(Reg, Reg),            // Xd = (double) Xs
+    FCvtZS(Reg, Reg),           // Xd = (long) Xs
     B1(Label),
     B2(CC, Label),
     BL(Label),                  // R30 = SP
@@ -40,10 +62,17 @@ pub enum AA {
     LDR2(Reg, Reg),
     STR1(Reg, Reg, Const),
     STR2(Reg, Reg),
-    SVC(Const),
+    LDRB(Reg, Reg, Const),      // Xd = zero extended byte at [Xn, #off]
+    STRB(Reg, Reg, Const),      // Low byte of Xs to [Xn, #off]
+    Adr(Reg, Vec<u8>),          // Xd = address of an interned string
+    SVC(Const),                 // Darwin: X16 is the call, X0-X5 its arguments
     Ret,
 
     BB(Vec<Reg>),              // Pseudo-OP
+    Clobber(Vec<Reg>),         // Pseudo-OP, what inline asm overwrites
+    Table(Reg, u32),           // Xd = address of jump table k
+    Switch(Reg, Reg),          // br to entry Xs of the table at Xd, clobbers Xd
+    Case(u32, u32, Label),     // Pseudo-OP, entry i of table k, only there for the CFG
 }
 impl AA {
     #[allow(unused_variables)]
@@ -54,6 +83,8 @@ impl AA {
             Label(l)           => (vec![],    vec![]),
             Mov1(d, s)         => (vec![d],   vec![]),
             Mov2(d, s)         => (vec![d],   vec![s]),
+            MovZ(d, _, _)      => (vec![d],   vec![]),
+            MovK(d, _, _)      => (vec![d],   vec![d]),
             Add1(d, l, r)      => (vec![d],   vec![l]),
             Add2(d, l, r)      => (vec![d],   vec![l, r]),
             Sub1(d, l, r)      => (vec![d],   vec![l]),
@@ -62,18 +93,32 @@ impl AA {
             Neg2(d, s)         => (vec![d],   vec![s]),
             SMAddL(d, l, m, r) => (vec![d],   vec![l, m, r]),
             SMNegL(d, l, r)    => (vec![d],   vec![l, r]),
-            SMSubL(d, l, m, r) => (vec![d],   vec![l, r]),
+            SMSubL(d, l, m, r) => (vec![d],   vec![l, m, r]),
             SMulL(d, l, r)     => (vec![d],   vec![l, r]),
             SDiv(d, l, r)      => (vec![d],   vec![l, r]),
             And1(d, l, r)      => (vec![d],   vec![l]),
             And2(d, l, r)      => (vec![d],   vec![l, r]),
             Or1(d, l, r)       => (vec![d],   vec![l]),
             Or2(d, l, r)       => (vec![d],   vec![l, r]),
+            Eor1(d, l, r)      => (vec![d],   vec![l]),
+            Eor2(d, l, r)      => (vec![d],   vec![l, r]),
+            Lsl1(d, l, r)      => (vec![d],   vec![l]),
+            Lsl2(d, l, r)      => (vec![d],   vec![l, r]),
+            Asr1(d, l, r)      => (vec![d],   vec![l]),
+            Asr2(d, l, r)      => (vec![d],   vec![l, r]),
             Mvn1(d, s)         => (vec![d],   vec![]),
             Mvn2(d, s)         => (vec![d],   vec![s]),
+            FAdd(d, l, r)      => (vec![d],   vec![l, r]),
+            FSub(d, l, r)      => (vec![d],   vec![l, r]),
+            FMul(d, l, r)      => (vec![d],   vec![l, r]),
+            FDiv(d, l, r)      => (vec![d],   vec![l, r]),
+            FNeg(d, s)         => (vec![d],   vec![s]),
+            FCmp(l, r)         => (vec![],    vec![l, r]),
+            SCvtF(d, s)        => (vec![d],   vec![s]),
+            FCvtZS(d, s)       => (vec![d],   vec![s]),
             B1(l)              => (vec![],    vec![]),
             B2(c, l)           => (vec![],    vec![]),
-            BL(l)              => (vec![],    vec![]),
+            BL(l)              => (AArch64::caller_saved(), vec![]),
             CBZ(r, l)          => (vec![],    vec![r]),
             CBNZ(r, l)         => (vec![],    vec![r]),
             CMP1(d, s)         => (vec![],    vec![d]),
@@ -81,14 +126,146 @@ impl AA {
             CSET(d, s)         => (vec![d],   vec![]),
             LDR1(d, l, r)      => (vec![d],   vec![l]),
             LDR2(d, s)         => (vec![d],   vec![s]),
-            STR1(d, l, r)      => (vec![d],   vec![l]),
-            STR2(d, s)         => (vec![d],   vec![s]),
+            STR1(d, l, r)      => (vec![],    vec![d, l]),
+            STR2(d, s)         => (vec![],    vec![d, s]),
+            LDRB(d, l, r)      => (vec![d],   vec![l]),
+            STRB(d, l, r)      => (vec![],    vec![d, l]),
+            Adr(d, s)          => (vec![d],   vec![]),
             BB(v)              => (v.clone(), vec![]),
-            SVC(_)             => (vec![],    vec![]),
-            Ret                => (vec![SP],  vec![R(29)]),
+            Clobber(v)         => (v.clone(), vec![]),
+            Table(d, _)        => (vec![d],   vec![]),
+            Switch(d, s)       => (vec![d],   vec![d, s]),
+            Case(..)           => (vec![],    vec![]),
+            SVC(_)             => (vec![R(0), R(1)], (0..=5).chain([16]).map(R).collect()),
+            Ret                => (vec![],    vec![R(0), R(30)]),
         };
     }
 }
+impl MachineInstr for AA {
+    type Target = AArch64;
+    fn defuse(&self) -> (Vec<Reg>, Vec<Reg>) { AA::defuse(self) }
+    fn label(&self) -> Option<Label> {
+        return match self { AA::Label(l) => Some(*l), _ => None };
+    }
+    fn target(&self) -> Option<Label> {
+        use AA::*;
+        return match self {
+            B1(l) | B2(_, l) | BL(l) | CBZ(_, l) | CBNZ(_, l) | Case(_, _, l) => Some(*l),
+            _ => None
+        };
+    }
+    fn falls(&self) -> bool { !matches!(self, AA::B1(_) | AA::Ret) }
+    fn moves(&self) -> Option<(Reg, Reg)> {
+        return match self { AA::Mov2(d, s) => Some((*d, *s)), _ => None };
+    }
+    fn map(&self, c: &dyn Fn(Reg) -> Reg) -> Self {
+        use AA::*;
+        return match self.clone() {
+            Label(l)           => Label(l),
+            Mov1(d, s)         => Mov1(c(d), s),
+            Mov2(d, s)         => Mov2(c(d), c(s)),
+            MovZ(d, k, sh)     => MovZ(c(d), k, sh),
+            MovK(d, k, sh)     => MovK(c(d), k, sh),
+            Add1(d, l, r)      => Add1(c(d), c(l), r),
+            Add2(d, l, r)      => Add2(c(d), c(l), c(r)),
+            Sub1(d, l, r)      => Sub1(c(d), c(l), r),
+            Sub2(d, l, r)      => Sub2(c(d), c(l), c(r)),
+            Neg1(d, s)         => Neg1(c(d), s),
+            Neg2(d, s)         => Neg2(c(d), c(s)),
+            SMAddL(d, l, m, r) => SMAddL(c(d), c(l), c(m), c(r)),
+            SMNegL(d, l, r)    => SMNegL(c(d), c(l), c(r)),
+            SMSubL(d, l, m, r) => SMSubL(c(d), c(l), c(m), c(r)),
+            SMulL(d, l, r)     => SMulL(c(d), c(l), c(r)),
+            SDiv(d, l, r)      => SDiv(c(d), c(l), c(r)),
+            And1(d, l, r)      => And1(c(d),  c(l), r),
+            And2(d, l, r)      => And2(c(d),  c(l), c(r)),
+            Or1(d, l, r)       => Or1(c(d), c(l), r),
+            Or2(d, l, r)       => Or2(c(d), c(l), c(r)),
+            Eor1(d, l, r)      => Eor1(c(d), c(l), r),
+            Eor2(d, l, r)      => Eor2(c(d), c(l), c(r)),
+            Lsl1(d, l, r)      => Lsl1(c(d), c(l), r),
+            Lsl2(d, l, r)      => Lsl2(c(d), c(l), c(r)),
+            Asr1(d, l, r)      => Asr1(c(d), c(l), r),
+            Asr2(d, l, r)      => Asr2(c(d), c(l), c(r)),
+            Mvn1(d, s)         => Mvn1(c(d), s),
+            Mvn2(d, s)         => Mvn2(c(d), c(s)),
+            FAdd(d, l, r)      => FAdd(c(d), c(l), c(r)),
+            FSub(d, l, r)      => FSub(c(d), c(l), c(r)),
+            FMul(d, l, r)      => FMul(c(d), c(l), c(r)),
+            FDiv(d, l, r)      => FDiv(c(d), c(l), c(r)),
+            FNeg(d, s)         => FNeg(c(d), c(s)),
+            FCmp(l, r)         => FCmp(c(l), c(r)),
+            SCvtF(d, s)        => SCvtF(c(d), c(s)),
+            FCvtZS(d, s)       => FCvtZS(c(d), c(s)),
+            B1(l)              => B1(l),
+            B2(c, l)           => B2(c, l),
+            BL(l)              => BL(l),
+            CBZ(r, l)          => CBZ(c(r), l),
+            CBNZ(r, l)         => CBNZ(c(r), l),
+            CMP1(d, s)         => CMP1(c(d), s),
+            CMP2(d, s)         => CMP2(c(d), c(s)),
+            CSET(d, s)         => CSET(c(d), s),
+            LDR1(d, l, r)      => LDR1(c(d), c(l), r),
+            LDR2(d, s)         => LDR2(c(d), c(s)),
+            STR1(d, l, r)      => STR1(c(d), c(l), r),
+            STR2(d, s)         => STR2(c(d), c(s)),
+            LDRB(d, l, r)      => LDRB(c(d), c(l), r),
+            STRB(d, l, r)      => STRB(c(d), c(l), r),
+            Adr(d, s)          => Adr(c(d), s),
+            BB(v)              => BB(v.iter().map(|r| c(*r)).collect()),
+            Clobber(v)         => Clobber(v.iter().map(|r| c(*r)).collect()),
+            Table(d, k)        => Table(c(d), k),
+            Switch(d, s)       => Switch(c(d), c(s)),
+            Case(k, i, l)      => Case(k, i, l),
+            SVC(c)             => SVC(c),
+            Ret                => Ret,
+        };
+    }
+    fn marker(live: Vec<Reg>) -> Self { AA::BB(live) }
+    fn is_marker(&self) -> bool { matches!(self, AA::BB(_)) }
+}
+
+// X0-X28 are handed out, X29 is the frame pointer and X30 the link register.
+pub struct AArch64;
+impl Target for AArch64 {
+    type Instr = AA;
+    const REGS: usize = GPRS;
+    fn colors() -> &'static [usize] {
+        return &[
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14,
+            15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28
+        ];
+    }
+    fn reserved() -> Vec<Reg> {
+        use Reg::*;
+        return vec![SP, RZR, PC, R(29), R(30)];
+    }
+    // X0-X18 and the link register, X19-X28 are the callee's to save.
+    fn caller_saved() -> Vec<Reg> {
+        return (0..=18).chain([30]).map(Reg::R).collect();
+    }
+    fn callee_saved() -> Vec<Reg> {
+        return (19..=28).map(Reg::R).collect();
+    }
+    // The translator lays frames out as `mov X29, SP`, then
+    // `sub SP, SP, #size` when there are locals. Functions other
+    // than main save X29 and X30 right before.
+    fn frame(asm: &[AA], i: usize) -> Option<(i64, bool)> {
+        use Reg::*;
+        let AA::Mov2(R(29), SP) = asm[i] else { return None };
+        let size = match asm.get(i + 1) {
+            Some(AA::Sub1(SP, SP, Const::Int(n))) => *n,
+            _ => 0
+        };
+        let main = !matches!(i.checked_sub(1).map(|j| &asm[j]), Some(AA::STR1(R(30), SP, _)));
+        return Some((size, main));
+    }
+    fn grow(size: i64) -> AA { AA::Sub1(Reg::SP, Reg::SP, Const::Int(size)) }
+    fn load(d: Reg, off: i64) -> AA { AA::LDR1(d, Reg::R(29), Const::Int(off)) }
+    fn store(s: Reg, off: i64) -> AA { AA::STR1(s, Reg::R(29), Const::Int(off)) }
+    fn epilogue(a: &AA) -> bool { matches!(a, AA::Mov2(Reg::SP, Reg::R(29))) }
+}
+
 impl Display for AA {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -110,11 +287,19 @@ impl Display for AA {
             R::PC    => format!("PC"),
             R::ID(i) => format!("ID({})", i),
         }};
+        let fbin = |op: &str, d: &Reg, l: &Reg, r: &Reg| format!(
+            "fmov D16, {}\nfmov D17, {}\n{} D16, D16, D17\nfmov {}, D16",
+            l, r, op, d
+        );
         let res = match self {
             Label(l) if *l != 0 => format!("l{}: ", l),
             Label(l)           => format!("__start: "),
             Mov1(d, s)         => format!("mov {}, #{}", d, s),
             Mov2(d, s)         => format!("mov {}, {}", d, s),
+            MovZ(d, c, 0)      => format!("movz {}, #{}", d, c),
+            MovZ(d, c, sh)     => format!("movz {}, #{}, lsl #{}", d, c, sh),
+            MovK(d, c, 0)      => format!("movk {}, #{}", d, c),
+            MovK(d, c, sh)     => format!("movk {}, #{}, lsl #{}", d, c, sh),
             Add1(d, l, r)      => format!("add {}, {}, #{}", d, l, r),
             Add2(d, l, r)      => format!("add {}, {}, {}", d, l, r),
             Sub1(d, l, r)      => format!("sub {}, {}, #{}", d, l, r),
@@ -128,10 +313,28 @@ impl Display for AA {
             SDiv(d, l, r)      => format!("sdiv {}, {}, {}", d, l, r),
             And1(d, l, r)      => format!("and {}, {}, #{}", d, l, r),
             And2(d, l, r)      => format!("and {}, {}, {}", d, l, r),
-            Or1(d, l, r)       => format!("or {}, {}, #{}", d, l, r),
-            Or2(d, l, r)       => format!("or {}, {}, {}", d, l, r),
+            Or1(d, l, r)       => format!("orr {}, {}, #{}", d, l, r),
+            Or2(d, l, r)       => format!("orr {}, {}, {}", d, l, r),
+            Eor1(d, l, r)      => format!("eor {}, {}, #{}", d, l, r),
+            Eor2(d, l, r)      => format!("eor {}, {}, {}", d, l, r),
+            Lsl1(d, l, r)      => format!("lsl {}, {}, #{}", d, l, r),
+            Lsl2(d, l, r)      => format!("lsl {}, {}, {}", d, l, r),
+            Asr1(d, l, r)      => format!("asr {}, {}, #{}", d, l, r),
+            Asr2(d, l, r)      => format!("asr {}, {}, {}", d, l, r),
             Mvn1(d, s)         => format!("mvn {}, #{}", d, s),
             Mvn2(d, s)         => format!("mvn {}, {}", d, s),
+            FAdd(d, l, r)      => fbin("fadd", d, l, r),
+            FSub(d, l, r)      => fbin("fsub", d, l, r),
+            FMul(d, l, r)      => fbin("fmul", d, l, r),
+            FDiv(d, l, r)      => fbin("fdiv", d, l, r),
+            FNeg(d, s)         => format!(
+                "fmov D16, {}\nfneg D16, D16\nfmov {}, D16", s, d),
+            FCmp(l, r)         => format!(
+                "fmov D16, {}\nfmov D17, {}\nfcmp D16, D17", l, r),
+            SCvtF(d, s)        => format!(
+                "scvtf D16, {}\nfmov {}, D16", s, d),
+            FCvtZS(d, s)       => format!(
+                "fmov D16, {}\nfcvtzs {}, D16", s, d),
             B1(l)              => format!("b l{}", l),
             B2(c, l)           => format!("b.{} l{}", c, l),
             BL(l)              => format!("bl l{}", l),
@@ -144,6 +347,9 @@ impl Display for AA {
             LDR2(d, s)         => format!("ldr {}, [{}]", d, s),
             STR1(d, l, r)      => format!("str {}, [{}, #{}]", d, l, r),
             STR2(d, s)         => format!("str {}, [{}]", d, s),
+            LDRB(d, l, r)      => format!("ldrb {}, [{}, #{}]", w(d), l, r),
+            STRB(d, l, r)      => format!("strb {}, [{}, #{}]", w(d), l, r),
+            Adr(d, s)          => format!("adr {}, \"{}\"", d, strings::escape(s)),
             Ret                => format!("ret"),
             SVC(c)             => format!("svc #{}", c),
             BB(v)              => {
@@ -153,6 +359,16 @@ impl Display for AA {
                     .join(", ");
                 format!("Basic Block {}", res)
             }
+            Clobber(v)         => {
+                let res = v.iter()
+                    .map(|x| format!("{}", x))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("// clobbers {}", res)
+            }
+            Table(d, k)        => format!("adr {}, table{}", d, k),
+            Switch(d, s)       => format!("ldr {}, [{}, {}, lsl #3]\nbr {}", d, d, s, d),
+            Case(k, i, l)      => format!("// table{}[{}] = l{}", k, i, l),
         };
         write!(f, "{res}")
     }
@@ -171,7 +387,7 @@ pub enum Reg {
 impl Reg {
     pub fn index(&self) -> usize {
         use Reg::*;
-        // Only 30 other registers.
+        // Only 31 other registers.
         match self {
             R(i)  => *i as usize,
             SP    => GPRS - 3,
@@ -213,14 +429,42 @@ impl Display for Reg {
     }
 }
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Eq)]
 pub enum CC {
     EQ,
     NE,
     GE,
     LT,
     GT,
-    LE
+    LE,
+    // Unsigned, x86 float comparisons come out this way.
+    HI,
+    LS,
+    HS,
+    LO,
+    // Negative or not, AArch64 float < comes out this way.
+    MI,
+    PL,
+}
+impl CC {
+    // The condition that holds exactly when this one doesn't.
+    pub fn invert(self) -> CC {
+        use CC::*;
+        return match self {
+            EQ => NE,
+            NE => EQ,
+            GE => LT,
+            LT => GE,
+            GT => LE,
+            LE => GT,
+            HI => LS,
+            LS => HI,
+            HS => LO,
+            LO => HS,
+            MI => PL,
+            PL => MI,
+        };
+    }
 }
 impl Display for CC {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -232,11 +476,17 @@ impl Display for CC {
             LT => write!(f, "LT"),
             GT => write!(f, "GT"),
             LE => write!(f, "LE"),
+            HI => write!(f, "HI"),
+            LS => write!(f, "LS"),
+            HS => write!(f, "HS"),
+            LO => write!(f, "LO"),
+            MI => write!(f, "MI"),
+            PL => write!(f, "PL"),
         }
     }
 }
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq)]
 pub enum Const {
     Int(i64),
     Float(f64)
@@ -0,0 +1,206 @@
+// BURG-style instruction selection.
+// See https://dl.acm.org/doi/10.1145/75277.75279 (Aho, Ganapathi & Tjiang)
+// Every node of an expression tree is labelled bottom-up with the cheapest
+// rule that reduces it to each nonterminal, then the tree is reduced top-down
+// from whatever nonterminal the statement asks for.
+use std::collections::HashMap;
+use crate::ir::ir::{Expr, Operator};
+use super::asm::{AA, Reg, Const, CC};
+
+// What a subtree can be reduced to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NT {
+    Reg,     // The value sits in a register.
+    Const,   // The value is a constant, not yet in any register.
+    Arith,   // A constant that fits add/sub/cmp.
+    Logical, // A constant that fits and/orr/eor.
+    Offset,  // A constant that fits a load/store offset.
+    Addr,    // Base register plus offset, for loads and stores.
+    Flags,   // A comparison, already in the condition flags.
+    ByteAddr, // Like Addr, with an offset ldrb/strb can encode.
+    Shift,   // A constant shift amount, 0 to 63.
+}
+const NTS: usize = 9;
+
+// The result of reducing a subtree.
+#[derive(Clone, Copy)]
+pub enum Operand {
+    Reg(Reg),
+    Const(Const),
+    Addr(Reg, Const),
+    Cond(CC),
+}
+impl Operand {
+    pub fn reg(&self) -> Reg {
+        let Operand::Reg(r) = self else { panic!("Operand is not a register!") };
+        return *r;
+    }
+    pub fn imm(&self) -> Const {
+        let Operand::Const(c) = self else { panic!("Operand is not a constant!") };
+        return *c;
+    }
+    pub fn int(&self) -> i64 {
+        let Const::Int(i) = self.imm() else { panic!("Operand is not an integer!") };
+        return i;
+    }
+    pub fn addr(&self) -> (Reg, Const) {
+        let Operand::Addr(r, c) = self else { panic!("Operand is not an address!") };
+        return (*r, *c);
+    }
+    pub fn cond(&self) -> CC {
+        let Operand::Cond(c) = self else { panic!("Operand is not a condition!") };
+        return *c;
+    }
+}
+
+// Tree patterns over ir::Expr. NT leaves match any subtree
+// which can be reduced to that nonterminal.
+pub enum Pat {
+    NT(NT),
+    Const,
+    Temp,
+    Str,
+    UnOp(&'static [Operator], Box<Pat>),
+    BinOp(Box<Pat>, &'static [Operator], Box<Pat>),
+    Mem(Box<Pat>),
+    Address(Box<Pat>),
+    Convert(Box<Pat>),
+}
+
+// `when` guards a rule on whatever the pattern can't see (types, frames),
+// `emit` gets the operands of the NT leaves in the order they appear.
+// Every backend has its own table, I is its instruction type.
+pub struct Rule<C, I = AA> {
+    pub lhs:  NT,
+    pub pat:  Pat,
+    pub cost: u32,
+    pub when: fn(&C, &Expr) -> bool,
+    pub emit: fn(&mut C, &Expr, &[Operand]) -> (Vec<I>, Operand),
+}
+
+type Label = [Option<(u32, usize)>; NTS];
+
+pub struct Selector<C, I = AA> {
+    rules:  Vec<Rule<C, I>>,
+    labels: HashMap<usize, Label>,
+}
+impl<C, I> Selector<C, I> {
+    pub fn new(rules: Vec<Rule<C, I>>) -> Self {
+        Self { rules, labels: HashMap::new() }
+    }
+    // Labels are keyed on node addresses, which are
+    // only stable while the statement is alive.
+    pub fn clear(&mut self) {
+        self.labels.clear();
+    }
+    pub fn select(&mut self, c: &mut C, e: &Expr, nt: NT) -> (Vec<I>, Operand) {
+        self.label(c, e);
+        return self.reduce(c, e, nt);
+    }
+    fn label(&mut self, c: &C, e: &Expr) {
+        if self.labels.contains_key(&e.addr()) { return }
+        for kid in children(e) {
+            self.label(c, kid);
+        }
+        let mut best: Label = [None; NTS];
+        for (i, r) in self.rules.iter().enumerate() {
+            if matches!(r.pat, Pat::NT(_)) || !(r.when)(c, e) { continue }
+            let Some(cost) = self.matches(&r.pat, e) else { continue };
+            let cost = cost + r.cost;
+            match best[r.lhs as usize] {
+                Some((b, _)) if b <= cost => (),
+                _ => best[r.lhs as usize] = Some((cost, i))
+            }
+        }
+        // Chain rules (NT <- NT) until nothing gets cheaper.
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (i, r) in self.rules.iter().enumerate() {
+                let Pat::NT(from) = r.pat else { continue };
+                let Some((cost, _)) = best[from as usize] else { continue };
+                if !(r.when)(c, e) { continue }
+                let cost = cost + r.cost;
+                match best[r.lhs as usize] {
+                    Some((b, _)) if b <= cost => (),
+                    _ => {
+                        best[r.lhs as usize] = Some((cost, i));
+                        changed = true;
+                    }
+                }
+            }
+        }
+        self.labels.insert(e.addr(), best);
+    }
+    fn matches(&self, p: &Pat, e: &Expr) -> Option<u32> {
+        return match (p, e) {
+            (Pat::NT(n), _) => self.labels[&e.addr()][*n as usize]
+                .map(|(cost, _)| cost),
+            (Pat::Const, Expr::Const(_)) => Some(0),
+            (Pat::Temp, Expr::Temp(_, _)) => Some(0),
+            (Pat::Str, Expr::Str(_)) => Some(0),
+            (Pat::UnOp(ops, p), Expr::UnOp(op, e))
+                if ops.contains(op) => self.matches(p, e),
+            (Pat::BinOp(pl, ops, pr), Expr::BinOp(l, op, r))
+                if ops.contains(op) => Some(
+                    self.matches(pl, l)? + self.matches(pr, r)?
+                ),
+            (Pat::Mem(p), Expr::Mem(e, _)) |
+            (Pat::Address(p), Expr::Address(e)) |
+            (Pat::Convert(p), Expr::Convert(_, e)) => self.matches(p, e),
+            _ => None
+        };
+    }
+    fn leaves<'e>(p: &Pat, e: &'e Expr, out: &mut Vec<(&'e Expr, NT)>) {
+        match (p, e) {
+            (Pat::NT(n), _) => out.push((e, *n)),
+            (Pat::UnOp(_, p), Expr::UnOp(_, e)) |
+            (Pat::Mem(p), Expr::Mem(e, _)) |
+            (Pat::Address(p), Expr::Address(e)) |
+            (Pat::Convert(p), Expr::Convert(_, e)) => Self::leaves(p, e, out),
+            (Pat::BinOp(pl, _, pr), Expr::BinOp(l, _, r)) => {
+                Self::leaves(pl, l, out);
+                Self::leaves(pr, r, out);
+            },
+            _ => ()
+        }
+    }
+    fn reduce(&mut self, c: &mut C, e: &Expr, nt: NT) -> (Vec<I>, Operand) {
+        let Some((_, r)) = self.labels[&e.addr()][nt as usize] else {
+            panic!("No tile reduces this expression to {:?}!", nt);
+        };
+        let mut kids = Vec::new();
+        Self::leaves(&self.rules[r].pat, e, &mut kids);
+        let mut asm = Vec::new();
+        let mut ops = Vec::new();
+        for (kid, n) in kids {
+            let (a, o) = self.reduce(c, kid, n);
+            asm.extend(a);
+            ops.push(o);
+        }
+        let (a, o) = (self.rules[r].emit)(c, e, &ops);
+        asm.extend(a);
+        return (asm, o);
+    }
+}
+
+fn children(e: &Expr) -> Vec<&Expr> {
+    use Expr::*;
+    return match e {
+        UnOp(_, e) | Mem(e, _) | Address(e) | Convert(_, e) => vec![e],
+        BinOp(l, _, r) => vec![l, r],
+        Call(_, v, _) => v.iter().map(|e| &**e).collect(),
+        ESeq(_, e) => vec![e],
+        Const(_) | Temp(_, _) | Str(_) => vec![],
+    };
+}
+
+// Shorthands for writing tile tables.
+pub fn nt(n: NT) -> Pat { Pat::NT(n) }
+pub fn un(ops: &'static [Operator], p: Pat) -> Pat {
+    Pat::UnOp(ops, Box::new(p))
+}
+pub fn bin(l: Pat, ops: &'static [Operator], r: Pat) -> Pat {
+    Pat::BinOp(Box::new(l), ops, Box::new(r))
+}
+pub fn mem(p: Pat) -> Pat { Pat::Mem(Box::new(p)) }
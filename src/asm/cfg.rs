@@ -1,4 +1,5 @@
 use super::asm::*;
+use super::target::MachineInstr;
 use crate::registry::Registry;
 
 #[derive(Clone)]
@@ -7,14 +8,14 @@ pub struct Node {
     pub t:   Option<usize>,
     pub f:   Option<usize>
 }
-pub struct CFG<'l> { 
-    pub asm:    &'l Vec<AA>,
+pub struct CFG<'l, I = AA> {
+    pub asm:    &'l Vec<I>,
     pub nodes:  Vec<Node>,
     pub start:  usize
 }
 
-impl<'l> CFG<'l> {
-    pub fn build(r: &Registry, stmts: &'l Vec<AA>) -> CFG<'l> {
+impl<'l, I: MachineInstr> CFG<'l, I> {
+    pub fn build(r: &Registry, stmts: &'l Vec<I>) -> CFG<'l, I> {
         let mut nodes: Vec<Node> = vec![
             Node { idx: usize::MAX, t: None, f: None};
             r.nlabels as usize
@@ -24,31 +25,27 @@ impl<'l> CFG<'l> {
         let mut cur = nodes.len();
         let mut marked: Vec<bool> = vec![false; stmts.len()];
         while let Some(stmt) = iter.next() {
-            use AA::*;
             idx += 1;
-            if !matches!(stmt, Label(_)) {
+            if stmt.label().is_none() {
                 nodes.push(Node { idx: idx - 1, t: None, f: None });
                 marked[idx - 1] = true;
                 cur = nodes.len() - 1;
             }
-            match *stmt {
-                B1(b) | B2(_, b)       => nodes[cur].t = Some(b as usize),
-                BL(b)                  => nodes[cur].t = Some(b as usize),
-                CBZ(_, b) | CBNZ(_, b) => nodes[cur].t = Some(b as usize),
-                Label(b) => {
-                    cur = b as usize;
-                    nodes[cur].idx = idx - 1;
-                },
-                _ => ()
+            if let Some(b) = stmt.target() {
+                nodes[cur].t = Some(b as usize);
             }
-            if matches!(stmt, B1(_) | B2(_, _) | Ret) { continue }
+            if let Some(b) = stmt.label() {
+                cur = b as usize;
+                nodes[cur].idx = idx - 1;
+            }
+            if !stmt.falls() { continue }
             let Some(pk) = iter.peek() else { continue };
-            if let Label(l) = *pk { 
-                nodes[cur].f = Some(*l as usize);
+            if let Some(l) = pk.label() {
+                nodes[cur].f = Some(l as usize);
             } else {
                 nodes[cur].f = Some(nodes.len());
             }
         }
         return CFG { asm: stmts, nodes, start: 0 }
     }
-}
\ No newline at end of file
+}
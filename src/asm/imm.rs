@@ -0,0 +1,63 @@
+// Which constants fit in which AArch64 instruction encodings.
+use super::asm::{AA, Reg, Const};
+
+// add/sub: 12 bits, optionally shifted left by 12.
+// Negative values flip add into sub, and the other way around.
+pub fn arith(v: i64) -> bool {
+    let v = v.unsigned_abs();
+    return v < (1 << 12) || (v & 0xfff == 0 && v < (1 << 24));
+}
+
+// and/orr/eor: a run of ones, rotated, repeated across the register.
+pub fn logical(v: i64) -> bool {
+    let v = v as u64;
+    if v == 0 || v == u64::MAX { return false }
+    // Find the smallest element the value is a repetition of.
+    let mut size = 64;
+    while size > 2 {
+        let half = size / 2;
+        let mask = (1u64 << half) - 1;
+        if v & mask != (v >> half) & mask { break }
+        size = half;
+    }
+    let mask = if size == 64 { u64::MAX } else { (1u64 << size) - 1 };
+    let e = v & mask;
+    // Some rotation of the element has to be a run of ones from bit 0.
+    return (0..size).any(|r| {
+        let rot = ((e >> r) | (e << (size - r) % size)) & mask;
+        rot & (rot + 1) == 0
+    });
+}
+
+// ldr/str offsets: unsigned and scaled by the access size,
+// or anything in a signed byte (which assembles to ldur/stur).
+pub fn offset(v: i64) -> bool {
+    return (v >= 0 && v % 8 == 0 && v / 8 < (1 << 12)) || (-256..256).contains(&v);
+}
+// The same for ldrb/strb, whose scale is one.
+pub fn byte_offset(v: i64) -> bool {
+    return (-256..(1 << 12)).contains(&v);
+}
+
+// What `mov #imm` can encode: one movz, one movn, or a logical immediate.
+pub fn mov(v: i64) -> bool {
+    let chunks = |x: u64| (0..4).filter(|i| (x >> (16 * i)) & 0xffff != 0).count();
+    return chunks(v as u64) <= 1 || chunks(!v as u64) <= 1 || logical(v);
+}
+
+// Builds any 64 bit constant 16 bits at a time.
+pub fn movzk(d: Reg, v: i64) -> Vec<AA> {
+    let v = v as u64;
+    let mut asm = Vec::new();
+    for i in 0..4 {
+        let chunk = (v >> (16 * i)) & 0xffff;
+        if chunk == 0 { continue }
+        let c = Const::Int(chunk as i64);
+        asm.push(match asm.is_empty() {
+            true  => AA::MovZ(d, c, 16 * i),
+            false => AA::MovK(d, c, 16 * i),
+        });
+    }
+    if asm.is_empty() { asm.push(AA::MovZ(d, Const::Int(0), 0)) }
+    return asm;
+}
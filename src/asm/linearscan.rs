@@ -0,0 +1,144 @@
+// See https://dl.acm.org/doi/10.1145/330249.330250 (Poletto & Sarkar)
+// One pass over the code in layout order, no interference graph.
+// Intervals aren't split, a temp that doesn't fit is spilled everywhere:
+// every use reloads into a fresh temp with a tiny interval, and the
+// whole scan runs again.
+use crate::registry::Registry;
+use super::allocate::{preserve, spill};
+use super::asm::*;
+use super::cfg::CFG;
+use super::liveness::Liveness;
+use super::target::{MachineInstr, Target};
+use std::collections::HashSet;
+
+// A temp's live interval, [start, end) in instructions.
+// A value live out of `p` keeps the interval open past `p`.
+#[derive(Clone, Copy)]
+struct Interval {
+    temp:  usize,
+    start: usize,
+    end:   usize,
+}
+
+// Temps get one interval each, the hull of everywhere they're live.
+// Registers get the exact positions where they're written or live out,
+// since arguments and results make them live all over the place.
+fn intervals<I: MachineInstr>(live: &Liveness<I>) -> (Vec<Interval>, Vec<Vec<usize>>) {
+    let mut hull: Vec<Option<(usize, usize)>> = Vec::new();
+    let regs = I::Target::REGS;
+    let index = I::Target::index;
+    let mut busy = vec![Vec::new(); regs];
+    let touch = |hull: &mut Vec<Option<(usize, usize)>>, i: usize, lo: usize, hi: usize| {
+        if i < regs { return }
+        if hull.len() <= i { hull.resize(i + 1, None); }
+        hull[i] = match hull[i] {
+            None         => Some((lo, hi)),
+            Some((s, e)) => Some((s.min(lo), e.max(hi))),
+        };
+    };
+    let mut pos = 0;
+    for (b, blk) in live.blocks.iter().enumerate() {
+        for i in live.live_in(b).iter() { touch(&mut hull, i, pos, pos); }
+        for (k, after) in live.after(b).iter().enumerate() {
+            let (defs, uses) = live.asm[blk.start + k].defuse();
+            for reg in &uses { touch(&mut hull, index(*reg), pos, pos); }
+            for reg in &defs {
+                touch(&mut hull, index(*reg), pos, pos);
+                if index(*reg) < regs { busy[index(*reg)].push(pos); }
+            }
+            for i in after.iter() {
+                touch(&mut hull, i, pos, pos + 1);
+                if i < regs { busy[i].push(pos); }
+            }
+            pos += 1;
+        }
+    }
+    for b in &mut busy { b.dedup(); }
+    let mut res: Vec<Interval> = hull.iter().enumerate()
+        .filter_map(|(temp, h)| h.map(|(start, end)| Interval { temp, start, end }))
+        .collect();
+    res.sort_by_key(|i| (i.start, i.temp));
+    return (res, busy);
+}
+
+// Whether register c is written or holding something inside [start, end).
+fn taken(busy: &Vec<Vec<usize>>, c: usize, i: &Interval) -> bool {
+    let at = busy[c].partition_point(|p| *p < i.start);
+    return at < busy[c].len() && busy[c][at] < i.end;
+}
+
+// One scan, the color of every temp and what had to be spilled.
+fn scan<I: MachineInstr>(
+        nids:  u32,
+        live:  &Liveness<I>,
+        fixed: &HashSet<usize>
+    ) -> (Vec<Option<usize>>, Vec<usize>) {
+    let colors = I::Target::colors();
+    let classes = I::Target::classes();
+    let regs = I::Target::REGS;
+    let (intervals, busy) = intervals(live);
+    // The colors each temp may take, what its register classes share.
+    let mut allowed = vec![colors.to_vec(); regs + nids as usize];
+    for ins in &live.asm {
+        let (defs, uses) = ins.defuse();
+        for reg in defs.iter().chain(uses.iter()) {
+            let i = I::Target::index(*reg);
+            if i < regs { continue }
+            let class = classes[ins.class(*reg)];
+            allowed[i].retain(|c| class.contains(c));
+        }
+    }
+    let mut color: Vec<Option<usize>> = (0..regs + nids as usize)
+        .map(|i| (i < regs).then_some(i))
+        .collect();
+    let mut free = vec![true; regs];
+    let mut active: Vec<Interval> = Vec::new();
+    let mut spilled = Vec::new();
+    for cur in intervals {
+        // Expire everything that ended before this one starts.
+        active.retain(|a| {
+            if a.end > cur.start { return true }
+            free[color[a.temp].unwrap()] = true;
+            return false;
+        });
+        let fits = |c: usize| !taken(&busy, c, &cur) && allowed[cur.temp].contains(&c);
+        if let Some(c) = allowed[cur.temp].iter().copied().find(|c| free[*c] && fits(*c)) {
+            free[c] = false;
+            color[cur.temp] = Some(c);
+            active.push(cur);
+            continue;
+        }
+        // Spill whichever ends last, out of the ones that could make room.
+        let victim = active.iter().enumerate()
+            .filter(|(_, a)| !fixed.contains(&a.temp) && fits(color[a.temp].unwrap()))
+            .max_by_key(|(_, a)| a.end)
+            .map(|(i, a)| (i, *a));
+        match victim {
+            Some((i, a)) if a.end > cur.end || fixed.contains(&cur.temp) => {
+                color[cur.temp] = color[a.temp].take();
+                spilled.push(a.temp);
+                active[i] = cur;
+            },
+            _ if fixed.contains(&cur.temp) => {
+                panic!("Ran out of registers for {}!", I::Target::reg(cur.temp));
+            },
+            _ => spilled.push(cur.temp),
+        }
+    }
+    return (color, spilled);
+}
+
+pub fn allocate<I: MachineInstr>(r: &mut Registry, live: &Liveness<I>) -> Vec<I> {
+    let mut fixed = HashSet::new();
+    let (mut color, mut spilled) = scan(r.nids, live, &fixed);
+    let mut asm = live.asm.clone();
+    while !spilled.is_empty() {
+        asm = spill(r, asm, &spilled, &mut fixed);
+        let cfg = CFG::build(r, &asm);
+        (color, spilled) = scan(r.nids, &Liveness::analyze(&cfg), &fixed);
+    }
+    let asm = asm.iter().map(|a| a.map(&|r: Reg| {
+        I::Target::reg(color[I::Target::index(r)].unwrap_or(0))
+    })).collect();
+    return preserve(asm);
+}
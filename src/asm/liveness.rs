@@ -1,109 +1,278 @@
 // See https://www.cs.cornell.edu/courses/cs4120/2023sp/notes/#iterative
+// Backwards dataflow over basic blocks, one bit per register.
 use super::cfg::{CFG, Node};
 use super::asm::{AA, Reg};
+use super::target::{MachineInstr, Target};
+use std::cell::OnceCell;
 use std::collections::{VecDeque, HashSet};
-pub struct Liveness;
-impl Liveness {
-    pub fn compute(cfg: CFG) -> Vec<(AA, Vec<bool>, Vec<bool>)> {
-        let mut pred = vec![Vec::new();     cfg.nodes.len()];
-        // It may not be necessary to decouple the two.
-        let mut lin  = vec![Vec::new();     cfg.nodes.len()];
-        let mut has  = vec![HashSet::new(); cfg.nodes.len()];
-        // There's at most three uses per asm instruction, hashset unnecessary.
-        let mut queue: VecDeque<(usize, Vec<Reg>)> = VecDeque::new();
-        for i in 0..cfg.nodes.len() {
-            let node = &cfg.nodes[i];
-            let idx = node.idx;
-            if let Some(t) = node.t {
-                let tidx = cfg.nodes[t].idx;
-                pred[tidx].push(idx);
-            }
-            if let Some(f) = node.f {
-                let fidx = cfg.nodes[f].idx;
-                pred[fidx].push(idx);
-            }
-            let (_, uses) = cfg.asm[idx].defuse();
-            queue.push_back((idx, uses));
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct BitSet { words: Vec<u64> }
+impl BitSet {
+    pub fn new(n: usize) -> Self {
+        Self { words: vec![0; (n + 63) / 64] }
+    }
+    pub fn insert(&mut self, i: usize) {
+        self.words[i / 64] |= 1 << (i % 64);
+    }
+    pub fn remove(&mut self, i: usize) {
+        self.words[i / 64] &= !(1 << (i % 64));
+    }
+    pub fn contains(&self, i: usize) -> bool {
+        return self.words.get(i / 64).is_some_and(|w| w & (1 << (i % 64)) != 0);
+    }
+    // Returns whether anything was added.
+    pub fn union(&mut self, o: &BitSet) -> bool {
+        let mut changed = false;
+        for (w, x) in self.words.iter_mut().zip(o.words.iter()) {
+            changed |= *x & !*w != 0;
+            *w |= x;
         }
-        while let Some((idx, delta)) = queue.pop_front() {
-            let mut new_delta: Vec<Reg> = Vec::new();
-            for change in delta {
-                // If we use it, OR we don't overwrite it.
-                let (defs, uses) = cfg.asm[idx].defuse();
-                if !has[idx].contains(&change) &&
-                    (uses.contains(&change) ||
-                    !defs.contains(&change)) {
-                    lin[idx].push(change);
-                    has[idx].insert(change);
-                    new_delta.push(change);
+        return changed;
+    }
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        return self.words.iter().enumerate().flat_map(|(i, w)| {
+            (0..64).filter(move |b| w & (1 << b) != 0).map(move |b| i * 64 + b)
+        });
+    }
+}
+
+pub struct Block {
+    pub start: usize, // First instruction.
+    pub end:   usize, // One past the last.
+    pub succ:  Vec<usize>,
+}
+
+pub struct Liveness<I = AA> {
+    pub asm:    Vec<I>,
+    pub blocks: Vec<Block>,
+    // Which block each instruction is in.
+    pub block:  Vec<usize>,
+    live_in:    Vec<BitSet>,
+    live_out:   Vec<BitSet>,
+    // Live right after each instruction, a block at a time as it's asked for.
+    after:      Vec<OnceCell<Vec<BitSet>>>,
+    size:       usize,
+}
+impl<I: MachineInstr> Liveness<I> {
+    pub fn analyze(cfg: &CFG<I>) -> Self {
+        let asm = cfg.asm;
+        let size = asm.iter()
+            .flat_map(|a| { let (d, u) = defuse(a); d.into_iter().chain(u) })
+            .map(|r| I::Target::index(r) + 1)
+            .max()
+            .unwrap_or(0)
+            .max(I::Target::REGS);
+        let (blocks, block) = blocks(cfg);
+        // use = read before written in the block, def = written in the block.
+        let mut uses = vec![BitSet::new(size); blocks.len()];
+        let mut defs = vec![BitSet::new(size); blocks.len()];
+        for (b, blk) in blocks.iter().enumerate() {
+            for i in (blk.start..blk.end).rev() {
+                let (d, u) = defuse(&asm[i]);
+                for r in d {
+                    uses[b].remove(I::Target::index(r));
+                    defs[b].insert(I::Target::index(r));
                 }
+                for r in u { uses[b].insert(I::Target::index(r)); }
             }
-            if new_delta.len() == 0 { continue }
-            for p in &pred[idx] {
-                queue.push_back((
-                    *p, std::mem::take(&mut new_delta)
-                ));
+        }
+        let mut live_in  = uses.clone();
+        let mut live_out = vec![BitSet::new(size); blocks.len()];
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for b in (0..blocks.len()).rev() {
+                for s in &blocks[b].succ {
+                    live_out[b].union(&live_in[*s]);
+                }
+                let mut inn = live_out[b].clone();
+                for (w, (d, u)) in inn.words.iter_mut().zip(defs[b].words.iter().zip(uses[b].words.iter())) {
+                    *w = (*w & !d) | u;
+                }
+                if inn != live_in[b] {
+                    live_in[b] = inn;
+                    changed = true;
+                }
             }
         }
+        let after = (0..blocks.len()).map(|_| OnceCell::new()).collect();
+        return Liveness { asm: asm.clone(), blocks, block, live_in, live_out, after, size };
+    }
+    pub fn live_in(&self, b: usize) -> &BitSet { &self.live_in[b] }
+    pub fn live_out(&self, b: usize) -> &BitSet { &self.live_out[b] }
+    // Live right after each instruction of a block.
+    pub fn after(&self, b: usize) -> &[BitSet] {
+        return self.after[b].get_or_init(|| {
+            let blk = &self.blocks[b];
+            let mut cur = self.live_out[b].clone();
+            let mut res = vec![BitSet::new(self.size); blk.end - blk.start];
+            for i in (blk.start..blk.end).rev() {
+                res[i - blk.start] = cur.clone();
+                let (d, u) = defuse(&self.asm[i]);
+                for r in d { cur.remove(I::Target::index(r)); }
+                for r in u { cur.insert(I::Target::index(r)); }
+            }
+            res
+        });
+    }
+    pub fn live_after(&self, i: usize) -> &BitSet {
+        let b = self.block[i];
+        return &self.after(b)[i - self.blocks[b].start];
+    }
+    // Live right before instruction i.
+    pub fn live_at(&self, i: usize) -> BitSet {
+        let mut cur = self.live_after(i).clone();
+        let (d, u) = defuse(&self.asm[i]);
+        for r in d { cur.remove(I::Target::index(r)); }
+        for r in u { cur.insert(I::Target::index(r)); }
+        return cur;
+    }
+    // Whether nothing reads r after instruction i.
+    pub fn dead_after(&self, i: usize, r: Reg) -> bool {
+        return !self.live_after(i).contains(I::Target::index(r));
+    }
+}
 
-        let mut res = Vec::new();
-        // We need to iterate over asm in order.
-        let mut nodes: Vec<Node> = vec![
-            Node { idx: usize::MAX, t: None, f: None};
-            cfg.nodes.len()
-        ];
-        for node in &cfg.nodes {
-            nodes[node.idx] = node.clone();
+// Block markers are only annotations here.
+fn defuse<I: MachineInstr>(a: &I) -> (Vec<Reg>, Vec<Reg>) {
+    return match a.is_marker() {
+        true  => (vec![], vec![]),
+        false => a.defuse(),
+    };
+}
+
+// Blocks start at labels and after anything that branches.
+fn blocks<I: MachineInstr>(cfg: &CFG<I>) -> (Vec<Block>, Vec<usize>) {
+    let asm = cfg.asm;
+    let mut node = vec![usize::MAX; asm.len()];
+    for (n, nd) in cfg.nodes.iter().enumerate() {
+        if nd.idx < asm.len() { node[nd.idx] = n; }
+    }
+    let mut blocks: Vec<Block> = Vec::new();
+    let mut block = vec![0; asm.len()];
+    for i in 0..asm.len() {
+        let lead = i == 0 || asm[i].label().is_some() || {
+            let p = &asm[i - 1];
+            p.target().is_some() || !p.falls()
+        };
+        if lead {
+            if let Some(b) = blocks.last_mut() { b.end = i; }
+            blocks.push(Block { start: i, end: asm.len(), succ: Vec::new() });
         }
-        for (idx, node) in nodes.into_iter().enumerate() {
-            assert!(node.idx == idx);
-            use AA::*;
-            // Insert Basic Block Pseudo-Ops
-            let bb = if idx == 0 { true } else {
-                match (&cfg.asm[idx - 1], &cfg.asm[idx]) {
-                    (B1(_) | B2(_, _) | BL(_), _) => true,
-                    (_, Label(_))     => true,
-                    _                 => false
-                }
-            };
-            if bb {
-                res.push((
-                    BB(lin[idx].clone()),
-                    vec![false; lin[idx].len()],
-                    vec![]
-                ));
-            }
+        block[i] = blocks.len() - 1;
+    }
+    for b in 0..blocks.len() {
+        let last = blocks[b].end - 1;
+        let nd = &cfg.nodes[node[last]];
+        for s in [nd.t, nd.f].into_iter().flatten() {
+            let idx = cfg.nodes[s].idx;
+            if idx < asm.len() { blocks[b].succ.push(block[idx]); }
+        }
+    }
+    return (blocks, block);
+}
 
-            // Process normal ASM.
+// The original per-register worklist propagation,
+// kept to check the bitsets against and benchmark them.
+pub fn worklist(cfg: CFG) -> Vec<(AA, Vec<bool>, Vec<bool>)> {
+    let mut pred = vec![Vec::new();     cfg.nodes.len()];
+    // It may not be necessary to decouple the two.
+    let mut lin  = vec![Vec::new();     cfg.nodes.len()];
+    let mut has  = vec![HashSet::new(); cfg.nodes.len()];
+    // There's at most three uses per asm instruction, hashset unnecessary.
+    let mut queue: VecDeque<(usize, Vec<Reg>)> = VecDeque::new();
+    for i in 0..cfg.nodes.len() {
+        let node = &cfg.nodes[i];
+        let idx = node.idx;
+        if let Some(t) = node.t {
+            let tidx = cfg.nodes[t].idx;
+            pred[tidx].push(idx);
+        }
+        if let Some(f) = node.f {
+            let fidx = cfg.nodes[f].idx;
+            pred[fidx].push(idx);
+        }
+        let (_, uses) = cfg.asm[idx].defuse();
+        queue.push_back((idx, uses));
+    }
+    while let Some((idx, delta)) = queue.pop_front() {
+        let mut new_delta: Vec<Reg> = Vec::new();
+        for change in delta {
+            // If we use it, OR we don't overwrite it.
             let (defs, uses) = cfg.asm[idx].defuse();
-            let mut defdead = vec![true; defs.len()];
-            for (i, reg) in defs.iter().enumerate() {
-                if let Some(t) = node.t {
-                    let tidx = cfg.nodes[t].idx;
-                    defdead[i] &= !lin[tidx].contains(&reg);
-                }
-                if let Some(f) = node.f {
-                    let fidx = cfg.nodes[f].idx;
-                    defdead[i] &= !lin[fidx].contains(&reg);
-                }
+            if !has[idx].contains(&change) &&
+                (uses.contains(&change) ||
+                !defs.contains(&change)) {
+                lin[idx].push(change);
+                has[idx].insert(change);
+                new_delta.push(change);
             }
-            let mut usedead = vec![true; uses.len()];
-            for (i, reg) in uses.iter().enumerate() {
-                if let Some(t) = node.t {
-                    let tidx = cfg.nodes[t].idx;
-                    usedead[i] &= !lin[tidx].contains(&reg);
-                }
-                if let Some(f) = node.f {
-                    let fidx = cfg.nodes[f].idx;
-                    usedead[i] &= !lin[fidx].contains(&reg);
-                }
+        }
+        if new_delta.len() == 0 { continue }
+        // Every predecessor needs the whole delta.
+        for p in &pred[idx] {
+            queue.push_back((*p, new_delta.clone()));
+        }
+    }
+
+    let mut res = Vec::new();
+    // We need to iterate over asm in order.
+    let mut nodes: Vec<Node> = vec![
+        Node { idx: usize::MAX, t: None, f: None};
+        cfg.nodes.len()
+    ];
+    for node in &cfg.nodes {
+        nodes[node.idx] = node.clone();
+    }
+    for (idx, node) in nodes.into_iter().enumerate() {
+        assert!(node.idx == idx);
+        use AA::*;
+        // Insert Basic Block Pseudo-Ops
+        let bb = if idx == 0 { true } else {
+            match (&cfg.asm[idx - 1], &cfg.asm[idx]) {
+                (B1(_) | B2(_, _) | BL(_), _) => true,
+                (_, Label(_))     => true,
+                _                 => false
             }
+        };
+        if bb {
             res.push((
-                cfg.asm[idx].clone(),
-                defdead,
-                usedead
+                BB(lin[idx].clone()),
+                vec![false; lin[idx].len()],
+                vec![]
             ));
         }
-        return res;
+
+        // Process normal ASM.
+        let (defs, uses) = cfg.asm[idx].defuse();
+        let mut defdead = vec![true; defs.len()];
+        for (i, reg) in defs.iter().enumerate() {
+            if let Some(t) = node.t {
+                let tidx = cfg.nodes[t].idx;
+                defdead[i] &= !lin[tidx].contains(&reg);
+            }
+            if let Some(f) = node.f {
+                let fidx = cfg.nodes[f].idx;
+                defdead[i] &= !lin[fidx].contains(&reg);
+            }
+        }
+        let mut usedead = vec![true; uses.len()];
+        for (i, reg) in uses.iter().enumerate() {
+            if let Some(t) = node.t {
+                let tidx = cfg.nodes[t].idx;
+                usedead[i] &= !lin[tidx].contains(&reg);
+            }
+            if let Some(f) = node.f {
+                let fidx = cfg.nodes[f].idx;
+                usedead[i] &= !lin[fidx].contains(&reg);
+            }
+        }
+        res.push((
+            cfg.asm[idx].clone(),
+            defdead,
+            usedead
+        ));
     }
-}
\ No newline at end of file
+    return res;
+}
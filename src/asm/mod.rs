@@ -1,8 +1,18 @@
 pub mod asm;
+pub mod burs;
 pub mod allocate;
 pub mod cfg;
 pub mod cfgprinter;
+pub mod imm;
+pub mod linearscan;
 pub mod liveness;
 pub mod parser;
+pub mod peephole;
 pub mod printer;
-pub mod translator;
\ No newline at end of file
+pub mod schedule;
+pub mod strings;
+pub mod tables;
+pub mod target;
+pub mod tiles;
+pub mod translator;
+pub mod verify;
\ No newline at end of file
@@ -1,5 +1,6 @@
 use std::fmt;
-use super::asm::{AA, Reg, Const, GPRS};
+use super::asm::{AA, Reg, Const, CC, Label};
+use super::strings;
 
 pub enum ParseError {
     Quote(u32, String),
@@ -8,7 +9,12 @@ pub enum ParseError {
     InvalidChar(u32, String),
     MissingOp(String),
     MissingArgs(String),
-    InvalidOp(String)
+    InvalidOp(String),
+    Operand(u32, String),
+    Label(u32, String),
+    Keyword(String),
+    Escape(String),
+    Character(String)
 }
 impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -44,8 +50,33 @@ impl fmt::Display for ParseError {
                 writeln!(f, "{}", s)?;
                 writeln!(f, "{}^", "-".repeat(s.len()))?;
             },
+            P::Operand(i, s) => {
+                writeln!(f, "Asm uses an operand it doesn't bind.")?;
+                writeln!(f, "{}", s)?;
+                writeln!(f, "{}^", "-".repeat(*i as usize))?;
+            },
+            P::Label(i, s) => {
+                writeln!(f, "Asm label is undefined or defined twice.")?;
+                writeln!(f, "{}", s)?;
+                writeln!(f, "{}^", "-".repeat(*i as usize))?;
+            },
             P::InvalidOp(s) => {
-                writeln!(f, "Asm does not use a supported op! Note that Control Flow and Labels are not currently supported.")?;
+                writeln!(f, "Asm does not use a supported op!")?;
+                writeln!(f, "{}", s)?;
+                writeln!(f, "^")?;
+            },
+            P::Keyword(s) => {
+                writeln!(f, "Asm expects in, out, inout or clobber here.")?;
+                writeln!(f, "{}", s)?;
+                writeln!(f, "^")?;
+            },
+            P::Escape(s) => {
+                writeln!(f, "Literal has an invalid escape.")?;
+                writeln!(f, "{}", s)?;
+                writeln!(f, "^")?;
+            },
+            P::Character(s) => {
+                writeln!(f, "Character literal is not one byte.")?;
                 writeln!(f, "{}", s)?;
                 writeln!(f, "^")?;
             }
@@ -61,155 +92,422 @@ impl fmt::Debug for ParseError {
 
 enum State {
     Quoted, 
+    Escaped, // Right after a backslash in quotes.
     Unquoted
 }
-// Parses a single line.
-pub fn parse(asm: String) -> Result<AA, ParseError> {
+// What an operand turned out to be.
+enum Arg {
+    R(Reg),
+    C(Const),
+    // [base, #offset], with a ! after it writing the address back.
+    Mem(Reg, Option<Const>, bool),
+    // Labels, conditions, functions, `lsl`.
+    Word(String),
+    // A string literal, for adr.
+    Str(Vec<u8>),
+}
+
+// A register by its name, the way lines write them.
+// X and W name the same register, R is what clobber lists used first.
+// Which of the two a line may use is up to the instruction, see `wide`.
+fn register(token: &str) -> Option<Reg> {
+    use Reg as R;
+    let token = token.to_uppercase();
+    match token.as_ref() {
+        "SP" | "WSP" => return Some(R::SP),
+        "XZR" | "WZR" | "RZR" => return Some(R::RZR),
+        "PC" => return Some(R::PC),
+        _ => ()
+    };
+    let i = token.strip_prefix(&['X', 'W', 'R'][..])?;
+    if !i.chars().all(|c| c.is_ascii_digit()) { return None }
+    let i = i.parse::<u8>().ok()?;
+    // X31 is SP or XZR depending on the instruction, never a register of its own.
+    return if i <= 30 { Some(R::R(i)) } else { None };
+}
+
+// Whether a register name is the X (true) or the W (false) one.
+// R, SP, PC and operands fit either.
+fn wide(token: &str) -> Option<bool> {
+    register(token)?;
+    let token = token.to_uppercase();
+    if token == "SP" || token == "PC" || token.starts_with('R') { return None }
+    return Some(!token.starts_with('W'));
+}
+
+// An immediate, in decimal, hex or as a float. The # is optional,
+// without it the token has to start like a number.
+fn immediate(token: &str) -> Option<Const> {
+    use Const as C;
+    let t = match token.strip_prefix('#') {
+        Some(t) => t,
+        None if token.starts_with(|c: char| c.is_ascii_digit() || c == '-') => token,
+        None => return None
+    };
+    let (neg, digits) = match t.strip_prefix('-') {
+        Some(d) => (true, d),
+        None    => (false, t)
+    };
+    if let Some(h) = digits.strip_prefix("0x").or(digits.strip_prefix("0X")) {
+        let v = u64::from_str_radix(h, 16).ok()? as i64;
+        return Some(C::Int(if neg { v.wrapping_neg() } else { v }));
+    }
+    if !digits.starts_with(|c: char| c.is_ascii_digit()) { return None }
+    if let Ok(i) = t.parse::<i64>() { return Some(C::Int(i)) }
+    return t.parse::<f64>().ok().map(C::Float);
+}
+
+// A register in a clobber list.
+pub fn clobber(token: String) -> Result<Reg, ParseError> {
+    return register(&token).ok_or(ParseError::Register(0, token));
+}
+
+// A condition the way b.cond spells it.
+fn condition(token: &str) -> Option<CC> {
+    use CC::*;
+    return Some(match token.to_lowercase().as_ref() {
+        "eq" => EQ,
+        "ne" => NE,
+        "ge" => GE,
+        "lt" => LT,
+        "gt" => GT,
+        "le" => LE,
+        "hi" => HI,
+        "ls" => LS,
+        "hs" => HS,
+        "lo" => LO,
+        "mi" => MI,
+        "pl" => PL,
+        _ => return None
+    });
+}
+
+// Parses a whole block. Labels are local to it, `name:` defines label i
+// and branches to it come out as Label(i) or B1(i) until the translator
+// gives it a fresh one. `bl name` comes out as BL(i), i indexing the
+// functions it returns, for the analyzer to resolve.
+pub fn block(lines: Vec<String>, operands: &[String]) -> Result<(Vec<AA>, Vec<String>, Vec<String>), ParseError> {
+    let mut labels: Vec<String> = Vec::new();
+    for line in &lines {
+        let tokens = tokenize(line)?;
+        let Some((_, token)) = tokens.first() else { continue };
+        let Some(name) = token.strip_suffix(':') else { continue };
+        if labels.iter().any(|l| l == name) {
+            return Err(ParseError::Label(0, line.clone()));
+        }
+        labels.push(name.to_string());
+    }
+    let mut calls = Vec::new();
+    let mut asm = Vec::new();
+    for s in lines {
+        asm.extend(parse(s, operands, &labels, &mut calls)?);
+    }
+    return Ok((asm, labels, calls));
+}
+
+// Splits a line into tokens, each with where it starts.
+// Brackets and the writeback ! are tokens of their own,
+// quoted strings keep their quotes and escapes.
+fn tokenize(asm: &str) -> Result<Vec<(usize, String)>, ParseError> {
     use State as S;
     use ParseError as P;
     let mut state = S::Unquoted;
     let mut quoteidx = 0;
     let mut token = String::new();
-    let mut tokens: Vec<String> = Vec::new();
-    let mut tokenidxs = Vec::new();
+    let mut tokens = Vec::new();
     for (i, c) in asm.chars().enumerate() {
         match state {
             S::Unquoted => {
                 match c {
                     'a'..='z' | 'A'..='Z' |
-                    '0'..='9' | '#' | '-' => token.push(c),
-                    '[' | ']' => (), // I don't feel like tracking if they're open or not.
+                    '0'..='9' | '#' | '-' |
+                    '{' | '}' | '_' |
+                    '.' | ':' => token.push(c),
                     '\"' => {
                         state = S::Quoted;
                         quoteidx = i;
                     },
-                    ' ' | ','  => {
-                        if token.len() == 0 { continue }
-                        tokens.push(token);
-                        tokenidxs.push(i - 1);
-                        token = String::new();
+                    ' ' | ',' | '[' | ']' | '!' => {
+                        if token.len() != 0 {
+                            tokens.push((i - token.len(), token));
+                            token = String::new();
+                        }
+                        if matches!(c, '[' | ']' | '!') {
+                            tokens.push((i, c.to_string()));
+                        }
                     },
                     _ => return Err(P::InvalidChar(
-                        i as u32, asm
+                        i as u32, asm.to_string()
                     )),
                 }
             },
-            // We'll handle escaped stuff another day.
             S::Quoted => {
                 match c {
                     '\"' => {
                         state = S::Unquoted;
-                        tokens.push(token);
-                        tokenidxs.push(i);
+                        tokens.push((quoteidx, format!("\"{}\"", token)));
                         token = String::new();
                     },
+                    '\\' => {
+                        state = S::Escaped;
+                        token.push(c);
+                    },
                     _    => token.push(c)
                 }
             },
+            S::Escaped => {
+                state = S::Quoted;
+                token.push(c);
+            },
         }
     }
-    tokens.push(token);
     match state {
         S::Unquoted => (),
-        S::Quoted => return Err(P::Quote(
-            quoteidx as u32, asm
+        S::Quoted | S::Escaped => return Err(P::Quote(
+            quoteidx as u32, asm.to_string()
         ))
     }
+    if token.len() != 0 {
+        tokens.push((asm.chars().count() - token.len(), token));
+    }
+    return Ok(tokens);
+}
 
-    let op = match tokens.get(0) {
-        None => return Err(P::MissingOp(asm)),
-        Some(s) => s
+// Parses a single line, a label can start it on its own or in front of
+// an instruction.
+fn parse(asm: String, operands: &[String], labels: &[String], calls: &mut Vec<String>) -> Result<Vec<AA>, ParseError> {
+    use ParseError as P;
+    let tokens = tokenize(&asm)?;
+    let Some(((i, first), rest)) = tokens.split_first() else {
+        return Err(P::MissingOp(asm))
+    };
+    let Some(name) = first.strip_suffix(':') else {
+        return instruction(asm, &tokens, operands, labels, calls);
+    };
+    let Some(l) = labels.iter().position(|l| l == name) else {
+        return Err(P::Label(*i as u32, asm))
     };
+    let mut out = vec![AA::Label(l as Label)];
+    if rest.len() != 0 {
+        out.extend(instruction(asm, rest, operands, labels, calls)?);
+    }
+    return Ok(out);
+}
 
-    let access = |idx: usize| {
-        match &tokens.get(idx) {
-            None => Err(P::MissingArgs(asm.clone())),
-            Some(s) => Ok(s.clone())
-        }
+// Parses an instruction. `{name}` stands for the operand of that name,
+// operand i comes out as ID(i) until the translator gives it a temp.
+// Pre- and post-index addressing have no AA of their own, so they come
+// out as the plain access and the add that moves the base.
+fn instruction(asm: String, tokens: &[(usize, String)], operands: &[String], labels: &[String], calls: &mut Vec<String>) -> Result<Vec<AA>, ParseError> {
+    use ParseError as P;
+    use Arg::*;
+    let Some((_, op)) = tokens.first() else {
+        return Err(P::MissingOp(asm))
     };
+    let op = op.to_lowercase();
 
-    let reg = |idx| {
-        use Reg as R;
-        let token = access(idx + 1)?;
-        match token.as_ref() {
-            "SP"  => return Ok(R::SP),
-            "RZR" => return Ok(R::RZR),
-            "PC"  => return Ok(R::PC),
-            _ => ()
+    let reg = |i: usize, token: &str| -> Result<Reg, ParseError> {
+        let Some(name) = token.strip_prefix('{').and_then(|t| t.strip_suffix('}')) else {
+            return register(token).ok_or(P::Register(i as u32, asm.clone()));
         };
-        match token.chars().nth(0) {
-            Some(s) if s == 'R' => (),
-            _ => return Err(P::Register(
-                tokenidxs[idx] as u32,
-                asm.clone()
-            )),
-        }
-        match token[1..].parse::<u8>() {
-            Ok(i) if i < GPRS as u8 => Ok(R::R(i)),
-            _ => Err(P::Register(
-                tokenidxs[idx] as u32,
-                asm.clone()
-            )),
+        return match operands.iter().position(|o| o == name) {
+            Some(o) => Ok(Reg::ID(o as u32)),
+            None => Err(P::Operand(i as u32, asm.clone())),
+        };
+    };
+    let arg = |i: usize, token: &str| -> Result<Arg, ParseError> {
+        if let Some(s) = token.strip_prefix('"').and_then(|t| t.strip_suffix('"')) {
+            return strings::unescape(s).map(Str).ok_or(P::Quote(i as u32, asm.clone()));
         }
+        if token.starts_with('{') { return Ok(R(reg(i, token)?)) }
+        if let Some(r) = register(token) { return Ok(R(r)) }
+        if let Some(c) = immediate(token) { return Ok(C(c)) }
+        if token.starts_with('#') { return Err(P::Register(i as u32, asm.clone())) }
+        return Ok(Word(token.to_string()));
+    };
+
+    // AA has no 32 bit ops, so W names only go where the printer puts
+    // them too: the byte of ldrb and strb and the factors of the long
+    // multiplies. Anywhere else they would quietly mean the X register.
+    let narrow: &[usize] = match op.as_ref() {
+        "ldrb" | "strb" => &[0],
+        "smaddl" | "smnegl" | "smsubl" | "smull" => &[1, 2],
+        _ => &[],
+    };
+    let sized = |i: usize, token: &str, w: bool| -> Result<(), ParseError> {
+        return match wide(token) {
+            Some(x) if x == w => Err(P::Register(i as u32, asm.clone())),
+            _ => Ok(()),
+        };
     };
 
-    let con = |idx| {
-        use Const as C;
-        let token = access(idx + 1)?;
-        match token.chars().nth(0) {
-            Some(c) if c == '#' => (),
-            _ => return Err(P::Register(
-                tokenidxs[idx] as u32,
-                asm.clone()
-            )),
+    let mut args: Vec<(usize, Arg)> = Vec::new();
+    let mut rest = tokens[1..].iter();
+    while let Some((i, token)) = rest.next() {
+        if token != "[" {
+            if token == "]" || token == "!" {
+                return Err(P::Delimiter(*i as u32, asm));
+            }
+            sized(*i, token, narrow.contains(&args.len()))?;
+            args.push((*i, arg(*i, token)?));
+            continue;
         }
-        let no_prefix = token.trim_start_matches("#0x");
-        if let Ok(i) = token[1..].parse::<i64>() {
-            Ok(C::Int(i))
-        } else if let Ok(h) = i64::from_str_radix(&no_prefix, 16) {
-            Ok(C::Int(h))
-        } else if let Ok(f) = token[1..].parse::<f64>() {
-            Ok(C::Float(f))
-        } else {
-            Err(P::Register(
-                tokenidxs[idx] as u32,
-                asm.clone()
-            ))
+        // [base], [base, #offset] or [base, #offset]!
+        let mut inner = Vec::new();
+        loop {
+            match rest.next() {
+                None => return Err(P::Delimiter(asm.len() as u32, asm)),
+                Some((_, t)) if t == "]" => break,
+                Some((j, t)) if t == "[" || t == "!" => {
+                    return Err(P::Delimiter(*j as u32, asm));
+                },
+                Some((j, t)) => {
+                    sized(*j, t, false)?;
+                    inner.push((*j, arg(*j, t)?));
+                },
+            }
         }
+        let back = rest.clone().next().is_some_and(|(_, t)| t == "!");
+        if back { rest.next(); }
+        let mem = match inner.as_slice() {
+            [(_, R(b))] => Mem(*b, None, false),
+            [(_, R(b)), (_, C(c @ Const::Int(_)))] => Mem(*b, Some(*c), back),
+            [(j, _), ..] => return Err(P::Register(*j as u32, asm)),
+            [] => return Err(P::MissingArgs(asm)),
+        };
+        if back && matches!(mem, Mem(_, None, _)) {
+            return Err(P::Delimiter(*i as u32, asm));
+        }
+        args.push((*i, mem));
+    }
+    let shapes: Vec<&Arg> = args.iter().map(|(_, a)| a).collect();
+
+    let label = |i: usize, name: &str| -> Result<Label, ParseError> {
+        return match labels.iter().position(|l| l == name) {
+            Some(l) => Ok(l as Label),
+            None => Err(P::Label(i as u32, asm.clone())),
+        };
+    };
+    // Where the line stops making sense, for when nothing matches.
+    let bad = || -> ParseError {
+        let at = args.iter().find(|(_, a)| matches!(a, Word(_)));
+        return match (at, args.last()) {
+            (Some((i, _)), _) => P::Register(*i as u32, asm.clone()),
+            (None, Some((i, _))) => P::Register(*i as u32, asm.clone()),
+            (None, None) => P::MissingArgs(asm.clone()),
+        };
+    };
+    // Moves the base of a writeback access.
+    let step = |b: Reg, c: Const| match c {
+        Const::Int(k) if k < 0 => AA::Sub1(b, b, Const::Int(-k)),
+        _ => AA::Add1(b, b, c),
     };
 
     use AA as A;
-    // We don't support Labels right now.
-    // This is because Labels have IDs, and we don't know what IDs are valid.
-    // Could be fixed if we reserved special names for inline asm IDs.
-    return Ok(match op.as_ref() {
-        "mov"  if con(1).is_ok() => A::Mov1(reg(0)?, con(1)?),
-        "mov"  if reg(1).is_ok() => A::Mov2(reg(0)?, reg(1)?),
-        "add"  if con(2).is_ok() => A::Add1(reg(0)?, reg(1)?, con(2)?), 
-        "add"  if reg(2).is_ok() => A::Add2(reg(0)?, reg(1)?, reg(2)?),
-        "sub"  if con(2).is_ok() => A::Sub1(reg(0)?, reg(1)?, con(2)?), 
-        "sub"  if reg(2).is_ok() => A::Sub2(reg(0)?, reg(1)?, reg(2)?),
-        "neg"  if con(1).is_ok() => A::Neg1(reg(0)?, con(1)?),
-        "neg"  if reg(1).is_ok() => A::Neg2(reg(0)?, reg(1)?),
-        "smaddl" => A::SMAddL(reg(0)?, reg(1)?, reg(2)?, reg(3)?),
-        "smsubl" => A::SMSubL(reg(0)?, reg(1)?, reg(2)?, reg(3)?),
-        "smnegl" => A::SMNegL(reg(0)?, reg(1)?, reg(2)?),
-        "smull"  => A::SMulL(reg(0)?, reg(1)?, reg(2)?),
-        "sdiv"   => A::SDiv(reg(0)?, reg(1)?, reg(2)?),
-        "and"  if con(1).is_ok() => A::And1(reg(0)?, reg(1)?, con(2)?),
-        "and"  if reg(1).is_ok() => A::And2(reg(0)?, reg(1)?, reg(2)?),
-        "or"   if con(1).is_ok() => A::And1(reg(0)?, reg(1)?, con(2)?),
-        "or"   if reg(1).is_ok() => A::And2(reg(0)?, reg(1)?, reg(2)?),
-        "movn" if con(1).is_ok() => A::Mvn1(reg(0)?, con(1)?),
-        "movn" if reg(1).is_ok() => A::Mvn2(reg(0)?, reg(1)?),
-        "cmp"  if con(1).is_ok() => A::CMP1(reg(0)?, con(1)?),
-        "cmp"  if reg(1).is_ok() => A::CMP2(reg(0)?, reg(1)?),
-        "ldr"  if access(2).is_ok()  => A::LDR1(reg(0)?, reg(1)?, con(2)?),
-        "ldr"  if access(2).is_err() => A::LDR2(reg(0)?, reg(1)?),
-        "str"  if con(2).is_ok()  => A::STR1(reg(0)?, reg(1)?, con(2)?),
-        "str"  if con(2).is_err() => A::STR2(reg(0)?, reg(1)?),
-        "svc"  => A::SVC(con(0)?),
-        "ret"  => A::Ret,
+    if let Some(cond) = op.strip_prefix("b.") {
+        let Some(cc) = condition(cond) else { return Err(P::InvalidOp(asm)) };
+        return match args.as_slice() {
+            [(i, Word(l))] => Ok(vec![A::B2(cc, label(*i, l)?)]),
+            _ => Err(bad()),
+        };
+    }
+    let ins = match (op.as_ref(), shapes.as_slice()) {
+        ("mov",  [R(d), C(s)])                   => A::Mov1(*d, *s),
+        ("mov",  [R(d), R(s)])                   => A::Mov2(*d, *s),
+        ("movz", [R(d), C(c)])                   => A::MovZ(*d, *c, 0),
+        ("movk", [R(d), C(c)])                   => A::MovK(*d, *c, 0),
+        ("movz" | "movk", [R(d), C(c), Word(lsl), C(Const::Int(sh))])
+            if lsl.to_lowercase() == "lsl" && (0..64).contains(sh) => match op.as_ref() {
+            "movz" => A::MovZ(*d, *c, *sh as u8),
+            _      => A::MovK(*d, *c, *sh as u8),
+        },
+        ("add",  [R(d), R(l), C(r)])             => A::Add1(*d, *l, *r),
+        ("add",  [R(d), R(l), R(r)])             => A::Add2(*d, *l, *r),
+        ("sub",  [R(d), R(l), C(r)])             => A::Sub1(*d, *l, *r),
+        ("sub",  [R(d), R(l), R(r)])             => A::Sub2(*d, *l, *r),
+        ("neg",  [R(d), C(s)])                   => A::Neg1(*d, *s),
+        ("neg",  [R(d), R(s)])                   => A::Neg2(*d, *s),
+        ("smaddl", [R(d), R(l), R(m), R(r)])     => A::SMAddL(*d, *l, *m, *r),
+        ("smnegl", [R(d), R(l), R(r)])           => A::SMNegL(*d, *l, *r),
+        ("smsubl", [R(d), R(l), R(m), R(r)])     => A::SMSubL(*d, *l, *m, *r),
+        ("smull",  [R(d), R(l), R(r)])           => A::SMulL(*d, *l, *r),
+        ("sdiv",   [R(d), R(l), R(r)])           => A::SDiv(*d, *l, *r),
+        ("and",  [R(d), R(l), C(r)])             => A::And1(*d, *l, *r),
+        ("and",  [R(d), R(l), R(r)])             => A::And2(*d, *l, *r),
+        ("orr" | "or", [R(d), R(l), C(r)])       => A::Or1(*d, *l, *r),
+        ("orr" | "or", [R(d), R(l), R(r)])       => A::Or2(*d, *l, *r),
+        ("eor",  [R(d), R(l), C(r)])             => A::Eor1(*d, *l, *r),
+        ("eor",  [R(d), R(l), R(r)])             => A::Eor2(*d, *l, *r),
+        ("lsl",  [R(d), R(l), C(r)])             => A::Lsl1(*d, *l, *r),
+        ("lsl",  [R(d), R(l), R(r)])             => A::Lsl2(*d, *l, *r),
+        ("asr",  [R(d), R(l), C(r)])             => A::Asr1(*d, *l, *r),
+        ("asr",  [R(d), R(l), R(r)])             => A::Asr2(*d, *l, *r),
+        ("mvn",  [R(d), C(s)])                   => A::Mvn1(*d, *s),
+        ("mvn",  [R(d), R(s)])                   => A::Mvn2(*d, *s),
+        // Floats are raw bits in general purpose registers, these are the
+        // AA ops themselves rather than the fmov sequences they print as.
+        ("fadd", [R(d), R(l), R(r)])             => A::FAdd(*d, *l, *r),
+        ("fsub", [R(d), R(l), R(r)])             => A::FSub(*d, *l, *r),
+        ("fmul", [R(d), R(l), R(r)])             => A::FMul(*d, *l, *r),
+        ("fdiv", [R(d), R(l), R(r)])             => A::FDiv(*d, *l, *r),
+        ("fneg", [R(d), R(s)])                   => A::FNeg(*d, *s),
+        ("fcmp", [R(l), R(r)])                   => A::FCmp(*l, *r),
+        ("scvtf",  [R(d), R(s)])                 => A::SCvtF(*d, *s),
+        ("fcvtzs", [R(d), R(s)])                 => A::FCvtZS(*d, *s),
+        ("b",    [Word(l)])                      => A::B1(label(args[0].0, l)?),
+        ("cbz",  [R(r), Word(l)])                => A::CBZ(*r, label(args[1].0, l)?),
+        ("cbnz", [R(r), Word(l)])                => A::CBNZ(*r, label(args[1].0, l)?),
+        ("bl",   [Word(f)]) => match calls.iter().position(|c| c == f) {
+            Some(c) => A::BL(c as Label),
+            None => {
+                calls.push(f.clone());
+                A::BL((calls.len() - 1) as Label)
+            }
+        },
+        ("cmp",  [R(l), C(r)])                   => A::CMP1(*l, *r),
+        ("cmp",  [R(l), R(r)])                   => A::CMP2(*l, *r),
+        ("cset", [R(d), Word(c)]) => match condition(c) {
+            Some(cc) => A::CSET(*d, cc),
+            None => return Err(bad()),
+        },
+        ("ldr",  [R(d), Mem(b, None, _)])        => A::LDR2(*d, *b),
+        ("ldr",  [R(d), Mem(b, Some(c), false)]) => A::LDR1(*d, *b, *c),
+        ("ldr",  [R(d), Mem(b, Some(c), true)])  => return Ok(vec![step(*b, *c), A::LDR2(*d, *b)]),
+        ("ldr",  [R(d), Mem(b, None, _), C(c @ Const::Int(_))]) => {
+            return Ok(vec![A::LDR2(*d, *b), step(*b, *c)]);
+        },
+        ("str",  [R(s), Mem(b, None, _)])        => A::STR2(*s, *b),
+        ("str",  [R(s), Mem(b, Some(c), false)]) => A::STR1(*s, *b, *c),
+        ("str",  [R(s), Mem(b, Some(c), true)])  => return Ok(vec![step(*b, *c), A::STR2(*s, *b)]),
+        ("str",  [R(s), Mem(b, None, _), C(c @ Const::Int(_))]) => {
+            return Ok(vec![A::STR2(*s, *b), step(*b, *c)]);
+        },
+        ("ldrb", [R(d), Mem(b, None, _)])        => A::LDRB(*d, *b, Const::Int(0)),
+        ("ldrb", [R(d), Mem(b, Some(c), false)]) => A::LDRB(*d, *b, *c),
+        ("ldrb", [R(d), Mem(b, Some(c), true)])  => {
+            return Ok(vec![step(*b, *c), A::LDRB(*d, *b, Const::Int(0))]);
+        },
+        ("ldrb", [R(d), Mem(b, None, _), C(c @ Const::Int(_))]) => {
+            return Ok(vec![A::LDRB(*d, *b, Const::Int(0)), step(*b, *c)]);
+        },
+        ("strb", [R(s), Mem(b, None, _)])        => A::STRB(*s, *b, Const::Int(0)),
+        ("strb", [R(s), Mem(b, Some(c), false)]) => A::STRB(*s, *b, *c),
+        ("strb", [R(s), Mem(b, Some(c), true)])  => {
+            return Ok(vec![step(*b, *c), A::STRB(*s, *b, Const::Int(0))]);
+        },
+        ("strb", [R(s), Mem(b, None, _), C(c @ Const::Int(_))]) => {
+            return Ok(vec![A::STRB(*s, *b, Const::Int(0)), step(*b, *c)]);
+        },
+        ("adr",  [R(d), Str(s)])                 => A::Adr(*d, s.clone()),
+        ("svc",  [C(c)])                         => A::SVC(*c),
+        ("ret",  [])                             => A::Ret,
+        ("mov" | "movz" | "movk" | "add" | "sub" | "neg" | "smaddl" |
+         "smnegl" | "smsubl" | "smull" | "sdiv" | "and" | "orr" | "or" |
+         "eor" | "lsl" | "asr" | "mvn" | "fadd" | "fsub" | "fmul" | "fdiv" | "fneg" | "fcmp" |
+         "scvtf" | "fcvtzs" | "b" | "cbz" | "cbnz" | "bl" | "cmp" |
+         "cset" | "ldr" | "str" | "ldrb" | "strb" | "adr" | "svc" |
+         "ret", _) => return Err(bad()),
         _ => return Err(P::InvalidOp(asm))
-    });
-}
\ No newline at end of file
+    };
+    return Ok(vec![ins]);
+}
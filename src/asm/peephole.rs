@@ -0,0 +1,166 @@
+// Window-based peephole optimization, after register allocation.
+// Every rule looks at a fixed number of consecutive instructions,
+// with the liveness of their operands, and may replace all of them.
+use std::collections::{HashMap, HashSet};
+use super::asm::{AA, Const, Reg};
+use super::cfg::CFG;
+use super::liveness::Liveness;
+use crate::registry::Registry;
+
+type Line = (AA, Vec<bool>, Vec<bool>);
+
+pub struct Rule {
+    pub name:  &'static str,
+    pub size:  usize,
+    // Gets `size` instructions, returns their replacement.
+    pub apply: fn(&[Line]) -> Option<Vec<AA>>,
+}
+
+// To add a rule, add it here; earlier rules get the first shot.
+pub fn rules() -> Vec<Rule> {
+    use AA::*;
+    return vec![
+        Rule { // mov X1, X1
+            name: "self-move", size: 1,
+            apply: |w| match &w[0].0 {
+                Mov2(d, s) if d == s => Some(vec![]),
+                _ => None
+            }
+        },
+        Rule { // mov X1, X2; mov X2, X1
+            name: "move-back", size: 2,
+            apply: |w| match (&w[0].0, &w[1].0) {
+                (Mov2(a, b), Mov2(c, d)) if a == d && b == c => Some(vec![w[0].0.clone()]),
+                _ => None
+            }
+        },
+        Rule { // b l1; l1:
+            name: "branch-next", size: 2,
+            apply: |w| match (&w[0].0, &w[1].0) {
+                (B1(l) | B2(_, l) | CBZ(_, l) | CBNZ(_, l), Label(m)) if l == m =>
+                    Some(vec![w[1].0.clone()]),
+                _ => None
+            }
+        },
+        Rule { // b.cc l1; b l2; l1:
+            name: "branch-over", size: 3,
+            apply: |w| {
+                let Label(m) = w[2].0 else { return None };
+                let jump = match (&w[0].0, &w[1].0) {
+                    (B2(c, l), B1(t))   if *l == m => B2(c.invert(), *t),
+                    (CBZ(r, l), B1(t))  if *l == m => CBNZ(*r, *t),
+                    (CBNZ(r, l), B1(t)) if *l == m => CBZ(*r, *t),
+                    _ => return None
+                };
+                Some(vec![jump, w[2].0.clone()])
+            }
+        },
+        Rule { // str X1, [X2, #8]; ldr X3, [X2, #8]
+            name: "store-load", size: 2,
+            apply: |w| {
+                let (s, b, o1, d, a, o2) = match (&w[0].0, &w[1].0) {
+                    (STR1(s, b, o1), LDR1(d, a, o2)) => (s, b, *o1, d, a, *o2),
+                    (STR2(s, b), LDR2(d, a)) => (s, b, Const::Int(0), d, a, Const::Int(0)),
+                    _ => return None
+                };
+                if b != a || o1 != o2 { return None }
+                match d == s {
+                    true  => Some(vec![w[0].0.clone()]),
+                    false => Some(vec![w[0].0.clone(), Mov2(*d, *s)]),
+                }
+            }
+        },
+        Rule { // cset X1, cc; cbnz X1, l1 (X1 dead after)
+            name: "cset-branch", size: 2,
+            apply: |w| match (&w[0].0, &w[1].0) {
+                (CSET(d, c), CBNZ(r, l)) if d == r && w[1].2[0] => Some(vec![B2(*c, *l)]),
+                (CSET(d, c), CBZ(r, l))  if d == r && w[1].2[0] => Some(vec![B2(c.invert(), *l)]),
+                _ => None
+            }
+        },
+    ];
+}
+
+pub fn optimize(r: &Registry, asm: Vec<AA>) -> Vec<AA> {
+    // Basic block markers are only for the allocator,
+    // and they'd read as definitions to liveness.
+    let mut asm: Vec<AA> = asm.into_iter()
+        .filter(|a| !matches!(a, AA::BB(_)))
+        .collect();
+    let rules = rules();
+    let before = entries(r, &asm);
+    let mut lines = live(r, &asm);
+    loop {
+        let (next, changed) = sweep(&rules, lines);
+        asm = next;
+        if !changed { break }
+        lines = live(r, &asm);
+    }
+    if let Err(e) = check(&before, &entries(r, &asm)) {
+        panic!("Peephole changed the program: {}", e);
+    }
+    return asm;
+}
+
+// Applies the first rule that matches at every position, left to right.
+fn sweep(rules: &[Rule], lines: Vec<Line>) -> (Vec<AA>, bool) {
+    let mut res = Vec::new();
+    let mut changed = false;
+    let mut i = 0;
+    'outer: while i < lines.len() {
+        for rule in rules {
+            if i + rule.size > lines.len() { continue }
+            if let Some(rep) = (rule.apply)(&lines[i..i + rule.size]) {
+                res.extend(rep);
+                i += rule.size;
+                changed = true;
+                continue 'outer;
+            }
+        }
+        res.push(lines[i].0.clone());
+        i += 1;
+    }
+    return (res, changed);
+}
+
+// One line per instruction, with whether each def and use dies there.
+fn live(r: &Registry, asm: &Vec<AA>) -> Vec<Line> {
+    let cfg = CFG::build(r, asm);
+    let live = Liveness::analyze(&cfg);
+    return asm.iter().enumerate().map(|(i, a)| {
+        let (defs, uses) = a.defuse();
+        let dead = |r: &Reg| live.dead_after(i, *r);
+        (a.clone(), defs.iter().map(dead).collect(), uses.iter().map(dead).collect())
+    }).collect();
+}
+
+// What's live going into the program and into every label.
+fn entries(r: &Registry, asm: &Vec<AA>) -> HashMap<Option<u32>, HashSet<usize>> {
+    let cfg = CFG::build(r, asm);
+    let live = Liveness::analyze(&cfg);
+    let mut res = HashMap::new();
+    for (b, blk) in live.blocks.iter().enumerate() {
+        let at = match asm[blk.start] {
+            AA::Label(l) => Some(l),
+            _ if b == 0 => None,
+            _ => continue
+        };
+        res.insert(at, live.live_in(b).iter().collect());
+    }
+    return res;
+}
+
+// A rewrite may only make values dead, it can never
+// start reading a register nobody wrote to before.
+fn check(
+        before: &HashMap<Option<u32>, HashSet<usize>>,
+        after:  &HashMap<Option<u32>, HashSet<usize>>
+    ) -> Result<(), String> {
+    for (at, regs) in after {
+        let old = before.get(at).cloned().unwrap_or_default();
+        if let Some(r) = regs.difference(&old).next() {
+            return Err(format!("R{} is now live into {:?}", r, at));
+        }
+    }
+    return Ok(());
+}
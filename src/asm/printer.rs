@@ -1,19 +1,39 @@
 use super::asm::*;
+use super::strings::Pool;
+use super::tables::Tables;
 pub struct Printer;
 impl Printer {
     // Removes no-ops.
     pub fn print(instructions: &[AA]) {
-        println!(".global __start");
-        println!(".align 16");
+        print!("{}", Self::text(instructions));
+    }
+
+    // What `print` writes, ready for `as`. String literals and jump tables
+    // go into __TEXT,__const (Mach-O's .rodata), reached through their page.
+    pub fn text(instructions: &[AA]) -> String {
+        let mut res = String::from(".global __start\n.align 16\n");
+        let mut pool = Pool::new();
+        let mut tables = Tables::new();
         for ins in instructions {
             match ins {
-                AA::BB(_) => (),
-                AA::Label(_) => println!("\n{}", ins),
+                AA::BB(_) | AA::Clobber(_) => (),
+                AA::Case(k, i, l) => tables.add(*k, *i, *l),
+                AA::Table(d, k) => res += &format!(
+                    "adrp {}, table{}@PAGE\nadd {}, {}, table{}@PAGEOFF\n", d, k, d, d, k
+                ),
+                AA::Label(_) => res += &format!("\n{}\n", ins),
                 AA::Mov2(d, s) if d == s => (),
-                _ => println!("{}", ins)
+                AA::Adr(d, s) => {
+                    let l = pool.label(s);
+                    res += &format!("adrp {}, {}@PAGE\nadd {}, {}, {}@PAGEOFF\n", d, l, d, d, l);
+                },
+                _ => res += &format!("{}\n", ins)
             }
         }
-        println!("\n\n\n");
+        res += &pool.section("\n.section __TEXT,__const");
+        res += &tables.section("\n.section __TEXT,__const");
+        res += "\n\n\n\n";
+        return res;
     }
 
     pub fn print_raw(instructions: &[AA]) {
@@ -28,4 +48,4 @@ impl Printer {
         }
         println!("\n\n\n");
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,244 @@
+// List scheduling within basic blocks, see
+// https://dl.acm.org/doi/10.1145/12276.13312 (Gibbons & Muchnick).
+// Before allocation it also keeps an eye on how many temps are live,
+// like Goodman & Hsu's integrated prepass scheduling, so it doesn't
+// hoist every load to the top of a block and drown the allocator.
+use std::collections::HashMap;
+use super::asm::*;
+use super::cfg::CFG;
+use super::liveness::{BitSet, Liveness};
+use super::target::Target;
+use crate::registry::Registry;
+
+// Past this many live temps, freeing registers comes before latency.
+// Arguments and calls pin down a few, so it's a bit under what there is.
+fn pressure() -> usize { AArch64::colors().len() - 8 }
+
+// Roughly a Cortex-A72, in cycles until the result can be used.
+// Floats go through D16/D17 and back, hence the extra.
+pub fn latency(a: &AA) -> usize {
+    use AA::*;
+    return match a {
+        SMAddL(..) | SMNegL(..) | SMSubL(..) | SMulL(..) => 3,
+        SDiv(..)                                         => 12,
+        LDR1(..) | LDR2(..) | LDRB(..)                   => 4,
+        FNeg(..) | FCmp(..)                              => 4,
+        FAdd(..) | FSub(..) | FMul(..)                   => 6,
+        SCvtF(..) | FCvtZS(..)                           => 6,
+        FDiv(..)                                         => 15,
+        _                                                => 1,
+    };
+}
+
+// Nothing moves across these. Control flow obviously, svc has effects
+// beyond its registers, and the allocator finds frames by their shape.
+fn barrier(a: &AA) -> bool {
+    use AA::*;
+    use Reg::*;
+    return match a {
+        Label(_) | B1(_) | B2(..) | BL(_) | CBZ(..) | CBNZ(..) | Ret | SVC(_) | BB(_) |
+        Switch(..) | Case(..) => true,
+        _ => a.defuse().0.iter().any(|r| matches!(r, SP | R(29))),
+    };
+}
+
+// Whether an instruction sets or reads the condition flags.
+fn flags(a: &AA) -> (bool, bool) {
+    use AA::*;
+    return match a {
+        CMP1(..) | CMP2(..) | FCmp(..) => (true, false),
+        CSET(..)                       => (false, true),
+        _                              => (false, false),
+    };
+}
+
+// Some(true) for stores, Some(false) for loads.
+fn memory(a: &AA) -> Option<bool> {
+    use AA::*;
+    return match a {
+        STR1(..) | STR2(..) | STRB(..) => Some(true),
+        LDR1(..) | LDR2(..) | LDRB(..) => Some(false),
+        _                              => None,
+    };
+}
+
+// With `pressure`, expects temps and counts them against pressure().
+pub fn schedule(r: &Registry, asm: Vec<AA>, pressure: bool) -> Vec<AA> {
+    // Block boundaries first, liveness borrows the code.
+    let mut blocks = Vec::new();
+    let mut i = 0;
+    while i < asm.len() {
+        if barrier(&asm[i]) { i += 1; continue }
+        let start = i;
+        while i < asm.len() && !barrier(&asm[i]) { i += 1; }
+        blocks.push((start, i));
+    }
+    let outs: Vec<Option<BitSet>> = match pressure {
+        true  => {
+            let cfg = CFG::build(r, &asm);
+            let live = Liveness::analyze(&cfg);
+            blocks.iter().map(|(_, e)| Some(live.live_after(e - 1).clone())).collect()
+        },
+        false => vec![None; blocks.len()],
+    };
+    let mut res = Vec::with_capacity(asm.len());
+    let mut next = blocks.iter().zip(outs.iter()).peekable();
+    let mut i = 0;
+    while i < asm.len() {
+        match next.peek() {
+            Some(((s, e), out)) if *s == i => {
+                res.extend(block(&asm[*s..*e], out.as_ref()));
+                i = *e;
+                next.next();
+            },
+            _ => { res.push(asm[i].clone()); i += 1; }
+        }
+    }
+    return res;
+}
+
+struct Node {
+    succ:  Vec<(usize, usize)>, // Successor and latency.
+    preds: usize,
+    // Longest latency path to the end of the block.
+    height: usize,
+}
+
+// Register, memory and flag dependences, each edge from earlier to later.
+fn graph(code: &[AA]) -> Vec<Node> {
+    let mut nodes: Vec<Node> = code.iter()
+        .map(|a| Node { succ: Vec::new(), preds: 0, height: latency(a) })
+        .collect();
+    let edge = |nodes: &mut Vec<Node>, from: usize, to: usize, lat: usize| {
+        if from == to { return }
+        nodes[from].succ.push((to, lat));
+        nodes[to].preds += 1;
+    };
+    let mut def: HashMap<usize, usize> = HashMap::new();
+    let mut readers: HashMap<usize, Vec<usize>> = HashMap::new();
+    let (mut store, mut loads) = (None, Vec::new());
+    let (mut setter, mut testers) = (None, Vec::new());
+    for (i, a) in code.iter().enumerate() {
+        let (defs, uses) = a.defuse();
+        for u in uses.iter().map(|u| u.index()) {
+            if let Some(d) = def.get(&u) { edge(&mut nodes, *d, i, latency(&code[*d])); }
+            readers.entry(u).or_default().push(i);
+        }
+        for d in defs.iter().map(|d| d.index()) {
+            for r in readers.remove(&d).unwrap_or_default() { edge(&mut nodes, r, i, 0); }
+            if let Some(p) = def.insert(d, i) { edge(&mut nodes, p, i, 1); }
+        }
+        match memory(a) {
+            Some(true) => {
+                for l in loads.drain(..) { edge(&mut nodes, l, i, 0); }
+                if let Some(s) = store.replace(i) { edge(&mut nodes, s, i, 1); }
+            },
+            Some(false) => {
+                if let Some(s) = store { edge(&mut nodes, s, i, latency(&code[s])); }
+                loads.push(i);
+            },
+            None => ()
+        }
+        match flags(a) {
+            (true, _) => {
+                for t in testers.drain(..) { edge(&mut nodes, t, i, 0); }
+                if let Some(s) = setter.replace(i) { edge(&mut nodes, s, i, 1); }
+            },
+            (_, true) => {
+                if let Some(s) = setter { edge(&mut nodes, s, i, latency(&code[s])); }
+                testers.push(i);
+            },
+            _ => ()
+        }
+    }
+    for i in (0..nodes.len()).rev() {
+        let h = nodes[i].succ.iter().map(|(s, l)| l + nodes[*s].height).max();
+        nodes[i].height = nodes[i].height.max(h.unwrap_or(0));
+    }
+    return nodes;
+}
+
+// `out` is what's live after the block, when counting pressure.
+fn block(code: &[AA], out: Option<&BitSet>) -> Vec<AA> {
+    let mut nodes = graph(code);
+    let temp = |r: &Reg| r.index() >= GPRS;
+    // Temps still to be read inside the block.
+    let mut remaining: HashMap<usize, usize> = HashMap::new();
+    for a in code {
+        for u in a.defuse().1.iter().filter(|r| temp(r)) {
+            *remaining.entry(u.index()).or_default() += 1;
+        }
+    }
+    let escapes = |i: usize| out.is_some_and(|o| o.contains(i));
+    // What's live going in, walking back from the end.
+    let mut live: BitSet = out.cloned().unwrap_or_else(|| BitSet::new(0));
+    if out.is_some() {
+        for a in code.iter().rev() {
+            let (defs, uses) = a.defuse();
+            for d in defs.iter().filter(|r| temp(r)) { live.remove(d.index()); }
+            for u in uses.iter().filter(|r| temp(r)) { live.insert(u.index()); }
+        }
+    }
+    let mut count = live.iter().filter(|i| *i >= GPRS).count();
+    // Registers it frees minus the ones it starts.
+    let delta = |a: &AA, live: &BitSet, remaining: &HashMap<usize, usize>| -> i64 {
+        let (defs, uses) = a.defuse();
+        let mut d = 0;
+        for u in uses.iter().filter(|r| temp(r)) {
+            if remaining[&u.index()] == 1 && !escapes(u.index()) && !defs.contains(u) { d -= 1; }
+        }
+        for r in defs.iter().filter(|r| temp(r)) {
+            if !live.contains(r.index()) { d += 1; }
+        }
+        return d;
+    };
+
+    let mut earliest = vec![0; code.len()];
+    let mut ready: Vec<usize> = (0..code.len()).filter(|i| nodes[*i].preds == 0).collect();
+    let mut res = Vec::with_capacity(code.len());
+    let mut cycle = 0;
+    while !ready.is_empty() {
+        let now: Vec<usize> = ready.iter().copied().filter(|i| earliest[*i] <= cycle).collect();
+        if now.is_empty() {
+            cycle = ready.iter().map(|i| earliest[*i]).min().unwrap();
+            continue;
+        }
+        let crowded = out.is_some() && count >= pressure();
+        let best = *now.iter().max_by_key(|i| {
+            let relief = match crowded {
+                true  => -delta(&code[**i], &live, &remaining),
+                false => 0,
+            };
+            // Ties go to the original order.
+            (relief, nodes[**i].height, std::cmp::Reverse(**i))
+        }).unwrap();
+        ready.retain(|i| *i != best);
+        let a = &code[best];
+        if out.is_some() {
+            let (defs, uses) = a.defuse();
+            for u in uses.iter().filter(|r| temp(r)) {
+                let n = remaining.get_mut(&u.index()).unwrap();
+                *n -= 1;
+                if *n == 0 && !escapes(u.index()) && live.contains(u.index()) {
+                    live.remove(u.index());
+                    count -= 1;
+                }
+            }
+            for d in defs.iter().filter(|r| temp(r)) {
+                let used = remaining.get(&d.index()).is_some_and(|n| *n > 0) || escapes(d.index());
+                if used && !live.contains(d.index()) {
+                    live.insert(d.index());
+                    count += 1;
+                }
+            }
+        }
+        res.push(a.clone());
+        for (s, lat) in std::mem::take(&mut nodes[best].succ) {
+            earliest[s] = earliest[s].max(cycle + lat);
+            nodes[s].preds -= 1;
+            if nodes[s].preds == 0 { ready.push(s); }
+        }
+        cycle += 1;
+    }
+    return res;
+}
@@ -0,0 +1,86 @@
+// String literals. The lexer hands them over escaped, the printers escape
+// them again for .asciz and intern them into a read-only data section.
+
+// The bytes a literal stands for, None if it has an escape we don't know.
+// \n \t \r \\ \' \", up to three octal digits and \xHH.
+pub fn unescape(s: &str) -> Option<Vec<u8>> {
+    let mut res = Vec::new();
+    let mut iter = s.bytes().peekable();
+    while let Some(c) = iter.next() {
+        if c != b'\\' {
+            res.push(c);
+            continue;
+        }
+        res.push(match iter.next()? {
+            b'n'  => b'\n',
+            b't'  => b'\t',
+            b'r'  => b'\r',
+            b'\\' => b'\\',
+            b'\'' => b'\'',
+            b'"'  => b'"',
+            b'x'  => {
+                let hi = (iter.next()? as char).to_digit(16)?;
+                let lo = (iter.next()? as char).to_digit(16)?;
+                (hi * 16 + lo) as u8
+            },
+            d @ b'0'..=b'7' => {
+                let mut v = (d - b'0') as u32;
+                for _ in 0..2 {
+                    let Some(d @ b'0'..=b'7') = iter.peek().copied() else { break };
+                    v = v * 8 + (d - b'0') as u32;
+                    iter.next();
+                }
+                u8::try_from(v).ok()?
+            },
+            _ => return None
+        });
+    }
+    return Some(res);
+}
+
+// The other way around, what the assembler reads back as the same bytes.
+pub fn escape(s: &[u8]) -> String {
+    let mut res = String::new();
+    for c in s {
+        match c {
+            b'\n' => res += "\\n",
+            b'\t' => res += "\\t",
+            b'\r' => res += "\\r",
+            b'\\' => res += "\\\\",
+            b'"'  => res += "\\\"",
+            0x20..=0x7e => res.push(*c as char),
+            _ => res += &format!("\\{:03o}", c),
+        }
+    }
+    return res;
+}
+
+// Every distinct string once, in the order the code first uses them.
+pub struct Pool {
+    strs: Vec<Vec<u8>>,
+}
+impl Pool {
+    pub fn new() -> Self {
+        Self { strs: Vec::new() }
+    }
+    // The label a string lives at.
+    pub fn label(&mut self, s: &[u8]) -> String {
+        let i = match self.strs.iter().position(|t| t == s) {
+            Some(i) => i,
+            None => {
+                self.strs.push(s.to_vec());
+                self.strs.len() - 1
+            }
+        };
+        return format!("str{}", i);
+    }
+    // The strings, under whatever directive starts the section.
+    pub fn section(&self, header: &str) -> String {
+        if self.strs.is_empty() { return String::new() }
+        let mut res = format!("{}\n", header);
+        for (i, s) in self.strs.iter().enumerate() {
+            res += &format!("str{}:\n.asciz \"{}\"\n", i, escape(s));
+        }
+        return res;
+    }
+}
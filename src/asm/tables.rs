@@ -0,0 +1,32 @@
+// Jump tables. A switch indexes one with its value, every entry is the
+// label of a Case pseudo-op, which the printers collect on their way.
+use super::asm::Label;
+
+pub struct Tables {
+    entries: Vec<Vec<Option<Label>>>, // By table, then index.
+}
+impl Tables {
+    pub fn new() -> Self {
+        Self { entries: Vec::new() }
+    }
+    pub fn add(&mut self, k: u32, i: u32, l: Label) {
+        let (k, i) = (k as usize, i as usize);
+        if self.entries.len() <= k { self.entries.resize(k + 1, Vec::new()) }
+        let t = &mut self.entries[k];
+        if t.len() <= i { t.resize(i + 1, None) }
+        t[i] = Some(l);
+    }
+    // The tables, 8 byte addresses each, under whatever starts the section.
+    pub fn section(&self, header: &str) -> String {
+        if self.entries.iter().all(|t| t.is_empty()) { return String::new() }
+        let mut res = format!("{}\n.p2align 3\n", header);
+        for (k, t) in self.entries.iter().enumerate() {
+            if t.is_empty() { continue }
+            res += &format!("table{}:\n", k);
+            for l in t {
+                res += &format!(".quad l{}\n", l.expect("Jump table with a hole!"));
+            }
+        }
+        return res;
+    }
+}
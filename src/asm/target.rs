@@ -0,0 +1,76 @@
+// What the machine independent parts of the backend (CFG, liveness and the
+// allocators) need to know about a machine. Every backend has its own
+// instruction enum, they all share `Reg` for registers and temps.
+use std::fmt::Display;
+use super::asm::Reg;
+
+type Label = u32;
+
+pub trait MachineInstr: Clone + Display {
+    type Target: Target<Instr = Self>;
+    // Registers written and read, calls write everything they clobber.
+    fn defuse(&self) -> (Vec<Reg>, Vec<Reg>);
+    // The label this instruction puts down.
+    fn label(&self) -> Option<Label>;
+    // Where it jumps or calls to.
+    fn target(&self) -> Option<Label>;
+    // Whether the next instruction can run after it.
+    fn falls(&self) -> bool;
+    // A plain register to register copy, destination first.
+    fn moves(&self) -> Option<(Reg, Reg)>;
+    fn map(&self, c: &dyn Fn(Reg) -> Reg) -> Self;
+    // The basic block pseudo-op holding what's live into the block,
+    // defuse lists those as its definitions.
+    fn marker(live: Vec<Reg>) -> Self;
+    fn is_marker(&self) -> bool;
+    // The register class r has to come from here, an index into
+    // Target::classes. Most operands take any register.
+    fn class(&self, _r: Reg) -> usize { 0 }
+}
+
+// Register conventions and frame layout.
+pub trait Target {
+    type Instr: MachineInstr<Target = Self>;
+    // How many registers the allocators number: R(i) below REGS - 3, then
+    // SP, RZR and PC. Temps come after them.
+    const REGS: usize;
+    fn index(r: Reg) -> usize {
+        use Reg::*;
+        return match r {
+            R(i)  => i as usize,
+            SP    => Self::REGS - 3,
+            RZR   => Self::REGS - 2,
+            PC    => Self::REGS - 1,
+            ID(i) => Self::REGS + i as usize,
+        };
+    }
+    fn reg(i: usize) -> Reg {
+        use Reg::*;
+        return match i {
+            i if i < Self::REGS - 3 => R(i as u8),
+            i if i == Self::REGS - 3 => SP,
+            i if i == Self::REGS - 2 => RZR,
+            i if i == Self::REGS - 1 => PC,
+            i => ID((i - Self::REGS) as u32),
+        };
+    }
+    // What the allocator hands out, in order of preference.
+    fn colors() -> &'static [usize];
+    // Register classes, each a subset of colors in the same order. The
+    // first is all of them; floats live in general purpose registers too.
+    fn classes() -> Vec<&'static [usize]> { vec![Self::colors()] }
+    // Never handed out, nor coalesced with.
+    fn reserved() -> Vec<Reg>;
+    // What a call may overwrite, and what it has to give back intact.
+    fn caller_saved() -> Vec<Reg>;
+    fn callee_saved() -> Vec<Reg>;
+    // Whether a frame starts at i, returns its size and whether it's main's.
+    fn frame(asm: &[Self::Instr], i: usize) -> Option<(i64, bool)>;
+    // Makes room for a frame of this size.
+    fn grow(size: i64) -> Self::Instr;
+    // Loads and stores relative to the frame pointer.
+    fn load(d: Reg, off: i64) -> Self::Instr;
+    fn store(s: Reg, off: i64) -> Self::Instr;
+    // Where a function starts tearing its frame down.
+    fn epilogue(a: &Self::Instr) -> bool;
+}
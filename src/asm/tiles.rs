@@ -0,0 +1,436 @@
+use crate::ir::ir::{Expr, Operator, Primitive, Type};
+use super::asm::{AA, Reg, CC, Const};
+use super::imm;
+use super::burs::{NT, Operand, Pat, Rule, nt, un, bin, mem};
+use super::translator::Translator;
+
+// The tile table, costs are the number of instructions emitted.
+// To add a tile, add a rule; the selector works out when it's the cheapest.
+pub fn tiles() -> Vec<Rule<Translator>> {
+    use Operator::*;
+    const ARITH: &[Operator] = &[Add, Sub, Mul, Div, And, Or, Xor, Shl, Shr];
+    const FARITH: &[Operator] = &[Add, Sub, Mul, Div];
+    const CMP: &[Operator] = &[Eq, Neq, Leq, Geq, Lt, Gt];
+    let reg = || nt(NT::Reg);
+    return vec![
+        /*----------------LEAVES--------------------*/
+        Rule { // CONST
+            lhs: NT::Const, pat: Pat::Const, cost: 0, when: any,
+            emit: |_, e, _| (vec![], Operand::Const(constant(e)))
+        },
+        Rule { // MOV TEMP <== CONST
+            lhs: NT::Reg, pat: nt(NT::Const), cost: 1,
+            when: |_, e| imm::mov(value(e)),
+            emit: |t, _, o| {
+                let d = t.temp();
+                (vec![AA::Mov1(d, o[0].imm())], Operand::Reg(d))
+            }
+        },
+        Rule { // MOVZ/MOVK TEMP <== CONST
+            lhs: NT::Reg, pat: nt(NT::Const), cost: 2,
+            when: |_, e| !imm::mov(value(e)),
+            emit: |t, _, o| {
+                let d = t.temp();
+                (imm::movzk(d, o[0].int()), Operand::Reg(d))
+            }
+        },
+        Rule { // ARITHMETIC IMMEDIATE
+            lhs: NT::Arith, pat: nt(NT::Const), cost: 0,
+            when: |_, e| imm::arith(value(e)),
+            emit: |_, _, o| (vec![], o[0])
+        },
+        Rule { // LOGICAL IMMEDIATE
+            lhs: NT::Logical, pat: nt(NT::Const), cost: 0,
+            when: |_, e| imm::logical(value(e)),
+            emit: |_, _, o| (vec![], o[0])
+        },
+        Rule { // LOAD/STORE OFFSET
+            lhs: NT::Offset, pat: nt(NT::Const), cost: 0,
+            when: |_, e| imm::offset(value(e)),
+            emit: |_, _, o| (vec![], o[0])
+        },
+        Rule { // SHIFT IMMEDIATE
+            lhs: NT::Shift, pat: nt(NT::Const), cost: 0,
+            when: |_, e| (0..64).contains(&value(e)),
+            emit: |_, _, o| (vec![], o[0])
+        },
+        Rule { // TEMP
+            lhs: NT::Reg, pat: Pat::Temp, cost: 0, when: |t, e| !framed(t, e),
+            emit: |t, e, _| {
+                let Expr::Temp(i, _) = e else { unreachable!() };
+                (vec![], Operand::Reg(t.temp_reg(*i)))
+            }
+        },
+        Rule { // LOAD TEMP <== FRAME SLOT
+            lhs: NT::Reg, pat: Pat::Temp, cost: 1, when: framed,
+            emit: |t, e, _| {
+                let Expr::Temp(i, ty) = e else { unreachable!() };
+                let d = t.temp();
+                let off = t.slot(*i).unwrap();
+                (vec![load(d, Operand::Addr(Reg::R(29), Const::Int(off)), *ty)], Operand::Reg(d))
+            }
+        },
+        /*----------------MEMORY--------------------*/
+        Rule { // [BASE]
+            lhs: NT::Addr, pat: reg(), cost: 0, when: any,
+            emit: |_, _, o| (vec![], Operand::Addr(o[0].reg(), Const::Int(0)))
+        },
+        Rule { // [BASE, #OFFSET]
+            lhs: NT::Addr, pat: bin(reg(), &[Add], nt(NT::Offset)), cost: 0, when: any,
+            emit: |_, _, o| (vec![], Operand::Addr(o[0].reg(), o[1].imm()))
+        },
+        Rule { // BYTE [BASE]
+            lhs: NT::ByteAddr, pat: reg(), cost: 0, when: any,
+            emit: |_, _, o| (vec![], Operand::Addr(o[0].reg(), Const::Int(0)))
+        },
+        Rule { // BYTE [BASE, #OFFSET]
+            lhs: NT::ByteAddr, pat: bin(reg(), &[Add], nt(NT::Const)), cost: 0,
+            when: |_, e| matches!(e, Expr::BinOp(_, _, r)
+                if matches!(**r, Expr::Const(Primitive::Int(i)) if imm::byte_offset(i))),
+            emit: |_, _, o| (vec![], Operand::Addr(o[0].reg(), o[1].imm()))
+        },
+        Rule { // LOAD TEMP <== MEM
+            lhs: NT::Reg, pat: mem(nt(NT::Addr)), cost: 1, when: |_, e| e.ty() != Type::I8,
+            emit: |t, _, o| {
+                let d = t.temp();
+                (vec![load(d, o[0], Type::I64)], Operand::Reg(d))
+            }
+        },
+        Rule { // LOAD TEMP <== BYTE MEM
+            lhs: NT::Reg, pat: mem(nt(NT::ByteAddr)), cost: 1, when: |_, e| e.ty() == Type::I8,
+            emit: |t, _, o| {
+                let d = t.temp();
+                (vec![load(d, o[0], Type::I8)], Operand::Reg(d))
+            }
+        },
+        Rule { // ADDRESS OF A STRING
+            lhs: NT::Reg, pat: Pat::Str, cost: 1, when: any,
+            emit: |t, e, _| {
+                let Expr::Str(s) = e else { unreachable!() };
+                let d = t.temp();
+                (vec![AA::Adr(d, s.clone())], Operand::Reg(d))
+            }
+        },
+        Rule { // ADDRESS OF A FRAME SLOT
+            lhs: NT::Reg, pat: Pat::Address(Box::new(Pat::Temp)), cost: 1, when: any,
+            emit: |t, e, _| {
+                let Expr::Address(a) = e else { unreachable!() };
+                let Expr::Temp(i, _) = **a else { unreachable!() };
+                let Some(off) = t.slot(i) else {
+                    panic!("Address of T({}) taken, but it has no slot!", i);
+                };
+                let d = t.temp();
+                (vec![AA::Sub1(d, Reg::R(29), Const::Int(-off))], Operand::Reg(d))
+            }
+        },
+        Rule { // &*EXPR
+            lhs: NT::Reg, pat: Pat::Address(Box::new(mem(reg()))), cost: 0, when: any,
+            emit: |_, _, o| (vec![], o[0])
+        },
+        /*----------------INTEGERS--------------------*/
+        Rule { // LOAD TEMP <== Expr OP Expr
+            lhs: NT::Reg, pat: bin(reg(), ARITH, reg()), cost: 1, when: int,
+            emit: |t, e, o| {
+                let d = t.temp();
+                let (l, r) = (o[0].reg(), o[1].reg());
+                let a = match op(e) {
+                    Add => AA::Add2(d, l, r),
+                    Sub => AA::Sub2(d, l, r),
+                    Mul => AA::SMulL(d, l, r),
+                    Div => AA::SDiv(d, l, r),
+                    And => AA::And2(d, l, r),
+                    Or  => AA::Or2(d, l, r),
+                    Xor => AA::Eor2(d, l, r),
+                    Shl => AA::Lsl2(d, l, r),
+                    Shr => AA::Asr2(d, l, r),
+                    _   => unreachable!()
+                };
+                (vec![a], Operand::Reg(d))
+            }
+        },
+        Rule { // LOAD TEMP <== Expr +- IMM
+            lhs: NT::Reg, pat: bin(reg(), &[Add, Sub], nt(NT::Arith)), cost: 1, when: int,
+            emit: |t, e, o| {
+                let d = t.temp();
+                let (l, v) = (o[0].reg(), o[1].int());
+                (vec![add(d, l, if op(e) == Add { v } else { -v })], Operand::Reg(d))
+            }
+        },
+        Rule { // LOAD TEMP <== IMM + Expr
+            lhs: NT::Reg, pat: bin(nt(NT::Arith), &[Add], reg()), cost: 1, when: int,
+            emit: |t, _, o| {
+                let d = t.temp();
+                (vec![add(d, o[1].reg(), o[0].int())], Operand::Reg(d))
+            }
+        },
+        Rule { // LOAD TEMP <== Expr AND/OR/EOR IMM
+            lhs: NT::Reg, pat: bin(reg(), &[And, Or, Xor], nt(NT::Logical)), cost: 1, when: int,
+            emit: |t, e, o| {
+                let d = t.temp();
+                let (l, c) = (o[0].reg(), o[1].imm());
+                let a = match op(e) {
+                    And => AA::And1(d, l, c),
+                    Or  => AA::Or1(d, l, c),
+                    _   => AA::Eor1(d, l, c),
+                };
+                (vec![a], Operand::Reg(d))
+            }
+        },
+        Rule { // LOAD TEMP <== IMM AND/OR/EOR Expr
+            lhs: NT::Reg, pat: bin(nt(NT::Logical), &[And, Or, Xor], reg()), cost: 1, when: int,
+            emit: |t, e, o| {
+                let d = t.temp();
+                let (c, r) = (o[0].imm(), o[1].reg());
+                let a = match op(e) {
+                    And => AA::And1(d, r, c),
+                    Or  => AA::Or1(d, r, c),
+                    _   => AA::Eor1(d, r, c),
+                };
+                (vec![a], Operand::Reg(d))
+            }
+        },
+        Rule { // LOAD TEMP <== Expr LSL/ASR IMM
+            lhs: NT::Reg, pat: bin(reg(), &[Shl, Shr], nt(NT::Shift)), cost: 1, when: int,
+            emit: |t, e, o| {
+                let d = t.temp();
+                let (l, c) = (o[0].reg(), o[1].imm());
+                let a = match op(e) {
+                    Shl => AA::Lsl1(d, l, c),
+                    _   => AA::Asr1(d, l, c),
+                };
+                (vec![a], Operand::Reg(d))
+            }
+        },
+        Rule { // MOV TEMP <== Expr ^ -1
+            lhs: NT::Reg, pat: bin(reg(), &[Xor], nt(NT::Const)), cost: 1,
+            when: |t, e| int(t, e) && matches!(e,
+                Expr::BinOp(_, _, r) if matches!(**r, Expr::Const(Primitive::Int(-1)))),
+            emit: |t, _, o| {
+                let d = t.temp();
+                (vec![AA::Mvn2(d, o[0].reg())], Operand::Reg(d))
+            }
+        },
+        Rule { // LOAD TEMP <== Expr % Expr
+            lhs: NT::Reg, pat: bin(reg(), &[Mod], reg()), cost: 3, when: int,
+            emit: |t, _, o| {
+                let (d, q) = (t.temp(), t.temp());
+                let (l, r) = (o[0].reg(), o[1].reg());
+                (vec![
+                    AA::SDiv(q, l, r),
+                    AA::SMulL(d, q, r),
+                    AA::Sub2(d, l, d),
+                ], Operand::Reg(d))
+            }
+        },
+        Rule { // FLAGS <== Expr CMP Expr
+            lhs: NT::Flags, pat: bin(reg(), CMP, reg()), cost: 1, when: int,
+            emit: |_, e, o| {
+                (vec![AA::CMP2(o[0].reg(), o[1].reg())], Operand::Cond(cc(op(e))))
+            }
+        },
+        Rule { // FLAGS <== Expr CMP IMM
+            lhs: NT::Flags, pat: bin(reg(), CMP, nt(NT::Arith)), cost: 1,
+            when: |t, e| int(t, e) && unsigned(t, e),
+            emit: |_, e, o| {
+                (vec![AA::CMP1(o[0].reg(), o[1].imm())], Operand::Cond(cc(op(e))))
+            }
+        },
+        Rule { // FLAGS <== !FLAGS
+            lhs: NT::Flags, pat: un(&[Not], nt(NT::Flags)), cost: 0, when: any,
+            emit: |_, _, o| (vec![], Operand::Cond(o[0].cond().invert()))
+        },
+        Rule { // LOAD TEMP <== FLAGS
+            lhs: NT::Reg, pat: nt(NT::Flags), cost: 1, when: any,
+            emit: |t, _, o| {
+                let d = t.temp();
+                (vec![AA::CSET(d, o[0].cond())], Operand::Reg(d))
+            }
+        },
+        Rule { // LOAD TEMP <== MUL-ADD
+            lhs: NT::Reg, pat: bin(reg(), &[Add, Sub], bin(reg(), &[Mul], reg())),
+            cost: 1, when: int,
+            emit: |t, e, o| {
+                let d = t.temp();
+                let (a, l, r) = (o[0].reg(), o[1].reg(), o[2].reg());
+                let ins = match op(e) {
+                    Add => AA::SMAddL(d, l, r, a),
+                    _   => AA::SMSubL(d, l, r, a),
+                };
+                (vec![ins], Operand::Reg(d))
+            }
+        },
+        Rule { // LOAD TEMP <== Neg-Mul
+            lhs: NT::Reg, pat: un(&[Neg], bin(reg(), &[Mul], reg())), cost: 1, when: int,
+            emit: |t, _, o| {
+                let d = t.temp();
+                (vec![AA::SMNegL(d, o[0].reg(), o[1].reg())], Operand::Reg(d))
+            }
+        },
+        Rule { // LOAD TEMP <== Neg
+            lhs: NT::Reg, pat: un(&[Neg], reg()), cost: 1, when: int,
+            emit: |t, _, o| {
+                let d = t.temp();
+                (vec![AA::Neg2(d, o[0].reg())], Operand::Reg(d))
+            }
+        },
+        Rule { // MOV TEMP <== ~CONST
+            lhs: NT::Reg, pat: un(&[Not], nt(NT::Const)), cost: 1, when: int,
+            emit: |t, _, o| {
+                let d = t.temp();
+                (vec![AA::Mvn1(d, o[0].imm())], Operand::Reg(d))
+            }
+        },
+        Rule { // MOV TEMP <== ~EXPR
+            lhs: NT::Reg, pat: un(&[Not], reg()), cost: 1, when: int,
+            emit: |t, _, o| {
+                let d = t.temp();
+                (vec![AA::Mvn2(d, o[0].reg())], Operand::Reg(d))
+            }
+        },
+        /*----------------FLOATS--------------------*/
+        Rule { // LOAD TEMP <== FExpr OP FExpr
+            lhs: NT::Reg, pat: bin(reg(), FARITH, reg()), cost: 1, when: float,
+            emit: |t, e, o| {
+                let d = t.temp();
+                let (l, r) = (o[0].reg(), o[1].reg());
+                let a = match op(e) {
+                    Add => AA::FAdd(d, l, r),
+                    Sub => AA::FSub(d, l, r),
+                    Mul => AA::FMul(d, l, r),
+                    Div => AA::FDiv(d, l, r),
+                    _   => unreachable!()
+                };
+                (vec![a], Operand::Reg(d))
+            }
+        },
+        // The flags fcmp sets make every condition the exact opposite
+        // of its inverse, unordered included, so !FLAGS works here too.
+        Rule { // FLAGS <== FExpr CMP FExpr
+            lhs: NT::Flags, pat: bin(reg(), CMP, reg()), cost: 1, when: float,
+            emit: |_, e, o| {
+                (vec![AA::FCmp(o[0].reg(), o[1].reg())], Operand::Cond(fcc(op(e))))
+            }
+        },
+        Rule { // LOAD TEMP <== FNeg
+            lhs: NT::Reg, pat: un(&[Neg], reg()), cost: 1, when: float,
+            emit: |t, _, o| {
+                let d = t.temp();
+                (vec![AA::FNeg(d, o[0].reg())], Operand::Reg(d))
+            }
+        },
+        Rule { // LOAD TEMP <== CONVERT
+            lhs: NT::Reg, pat: Pat::Convert(Box::new(reg())), cost: 1, when: any,
+            emit: |t, e, o| {
+                let Expr::Convert(to, from) = e else { unreachable!() };
+                let (d, s) = (t.temp(), o[0].reg());
+                let a = match (from.ty(), *to) {
+                    (Type::F64, Type::F64) => vec![AA::Mov2(d, s)],
+                    (Type::F64, Type::I8) => vec![
+                        AA::FCvtZS(d, s),
+                        AA::And1(d, d, Const::Int(255)),
+                    ],
+                    (Type::F64, _) => vec![AA::FCvtZS(d, s)],
+                    (_, Type::F64) => vec![AA::SCvtF(d, s)],
+                    // Bytes stay zero extended in registers.
+                    (_, Type::I8) => vec![AA::And1(d, s, Const::Int(255))],
+                    // Pointers and integers are the same thing down here.
+                    _ => vec![AA::Mov2(d, s)],
+                };
+                (a, Operand::Reg(d))
+            }
+        },
+    ];
+}
+
+fn any(_: &Translator, _: &Expr) -> bool { true }
+
+// Operators are typed by their operands.
+fn float(_: &Translator, e: &Expr) -> bool {
+    return match e {
+        Expr::BinOp(l, _, _) => l.ty() == Type::F64,
+        Expr::UnOp(_, e)     => e.ty() == Type::F64,
+        _ => e.ty() == Type::F64
+    };
+}
+fn int(t: &Translator, e: &Expr) -> bool { !float(t, e) }
+
+fn framed(t: &Translator, e: &Expr) -> bool {
+    let Expr::Temp(i, _) = e else { return false };
+    return t.slot(*i).is_some();
+}
+
+// There's no cmn tile, negative constants go through a register.
+// Guards run before the pattern is matched, so this can't assume a BinOp.
+fn unsigned(_: &Translator, e: &Expr) -> bool {
+    return match e {
+        Expr::BinOp(_, _, r) => matches!(**r, Expr::Const(Primitive::Int(i)) if i >= 0),
+        _ => false
+    };
+}
+
+fn op(e: &Expr) -> Operator {
+    return match e {
+        Expr::BinOp(_, op, _) | Expr::UnOp(op, _) => *op,
+        _ => unreachable!()
+    };
+}
+
+// Floats are loaded as their bit pattern.
+fn value(e: &Expr) -> i64 {
+    return match e {
+        Expr::Const(Primitive::Int(i))   => *i,
+        Expr::Const(Primitive::Float(f)) => f.to_bits() as i64,
+        _ => unreachable!()
+    };
+}
+fn constant(e: &Expr) -> Const {
+    return Const::Int(value(e));
+}
+
+// Adding a negative immediate is a subtraction.
+fn add(d: Reg, l: Reg, v: i64) -> AA {
+    return match v < 0 {
+        true  => AA::Sub1(d, l, Const::Int(-v)),
+        false => AA::Add1(d, l, Const::Int(v)),
+    };
+}
+
+// Bytes are always written with their offset, even when it's zero.
+pub fn load(d: Reg, a: Operand, t: Type) -> AA {
+    return match (a.addr(), t) {
+        ((b, off), Type::I8)  => AA::LDRB(d, b, off),
+        ((b, Const::Int(0)), _) => AA::LDR2(d, b),
+        ((b, off), _)           => AA::LDR1(d, b, off),
+    };
+}
+
+pub fn store(s: Reg, a: Operand, t: Type) -> AA {
+    return match (a.addr(), t) {
+        ((b, off), Type::I8)  => AA::STRB(s, b, off),
+        ((b, Const::Int(0)), _) => AA::STR2(s, b),
+        ((b, off), _)           => AA::STR1(s, b, off),
+    };
+}
+
+pub fn cc(op: Operator) -> CC {
+    use Operator::*;
+    return match op {
+        Eq  => CC::EQ,
+        Neq => CC::NE,
+        Leq => CC::LE,
+        Geq => CC::GE,
+        Lt  => CC::LT,
+        Gt  => CC::GT,
+        _   => panic!("{:?} is not a comparison!", op)
+    };
+}
+
+// Unordered operands (NaNs) set C and V after fcmp, which LT and LE
+// take for less than. MI and LS only hold when it really is.
+pub fn fcc(op: Operator) -> CC {
+    return match op {
+        Operator::Lt  => CC::MI,
+        Operator::Leq => CC::LS,
+        _ => cc(op)
+    };
+}
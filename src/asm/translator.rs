@@ -1,61 +1,35 @@
-use std::collections::BTreeMap;
-use crate::ir::ir::{self, Statement, Expr, Operator};
-use super::asm::{self, AA, Reg, CC, Const};
+use crate::ir::ir::{self, Statement, Expr};
+use crate::ir::cfgframer::Frames;
+use super::asm::{self, AA, Reg, Const};
+use super::burs::{NT, Operand, Selector};
+use super::tiles::{tiles, store};
 use crate::registry::Registry;
 
 type ID = u32;
 
-// The better way is to not clone every time you return
-// Allocate Vec<AA> in a linked list, prepend or post-pend nodes.
-// The issue is this sounds like hell when it comes to rust...
-#[derive(Clone)]
-struct Info {
-    cost: u32,
-    temp: ID,
-    asm : Vec<AA>
-}
-impl Info {
-    pub fn new(reg: u32) -> Self {
-        Info {
-            cost: u32::MAX,
-            temp: reg,
-            asm:  Vec::new()
-        }
-    }
-    pub fn update(&mut self, cost: u32, asm: Vec<AA>) {
-        if cost < self.cost {
-            self.cost = cost;
-            self.asm = asm;
-        }
-    }
-}
-pub struct Translator { 
-    opt:    BTreeMap<usize, Info>,
-    frames: Vec<usize>,
+pub struct Translator {
+    sel:    Option<Selector<Translator>>,
+    frames: Frames,
+    size:   i64,
     count:  usize,
     retid:  u32,
     main:   bool
 }
 
 impl Translator {
-    pub fn new(reg: &Registry, flist: Vec<usize>) -> Self { 
-        Self { 
-            opt:    BTreeMap::new(),
-            frames: flist,
+    pub fn new(reg: &Registry, frames: Frames) -> Self {
+        Self {
+            sel:    Some(Selector::new(tiles())),
+            frames,
+            size:   0,
             count:  reg.nids as usize,
             retid:  reg.ret,
             main:   false
         }
     }
-    pub fn translate(r: &mut Registry, flist: Vec<usize>, 
+    pub fn translate(r: &mut Registry, frames: Frames,
         stmts: Vec<Box<Statement>>) -> Vec<AA> {
-        let mut t = Self { 
-            opt:    BTreeMap::new(),
-            frames: flist,
-            count:  r.nids as usize,
-            retid:  r.ret,
-            main:   false
-        };
+        let mut t = Self::new(r, frames);
         let mut res = Vec::<AA>::new();
         for s in stmts {
             res.extend(t.statement(&s));
@@ -65,6 +39,7 @@ impl Translator {
     }
     fn statement(&mut self, s: &Statement) -> Vec<AA> {
         use Statement::*;
+        if let Some(sel) = &mut self.sel { sel.clear(); }
         match s {
             Expr(e)        => self.call(e),
             Move(d, s)     => self._move(d, s),
@@ -74,39 +49,75 @@ impl Translator {
             Jump(j)        => vec![AA::B1(*j)],
             Label(l)       => vec![AA::Label(*l)],
             Asm(a)         => vec![a.clone()],
+            Table(e, k)    => {
+                let (mut asm, s) = self.select(e);
+                let d = self.temp();
+                asm.push(AA::Table(d, *k));
+                asm.push(AA::Switch(d, s));
+                asm
+            },
+            Case(k, i, l)  => vec![AA::Case(*k, *i, *l)],
             _ => unreachable!()
         }
     }
     fn function(&mut self, f: u32, v: &Vec<u32>) -> Vec<AA> {
         // Set up frame.
+        // X29 points at the saved X29/X30 pair, the locals sit below it.
         use asm::Const as C;
+        use Reg::*;
         self.main = f == 0;
+        self.size = self.frames.sizes[f as usize] as i64;
         let mut asm = vec![AA::Label(f)];
         if !self.main {
-            asm.push(AA::STR1(Reg::R(29), Reg::SP, C::Int(-16)));
-            asm.push(AA::Sub1(Reg::SP, Reg::SP, C::Int(-16)));
+            asm.push(AA::Sub1(SP, SP, C::Int(16)));
+            asm.push(AA::STR1(R(29), SP, C::Int(0)));
+            asm.push(AA::STR1(R(30), SP, C::Int(8)));
+        }
+        asm.push(AA::Mov2(R(29), SP));
+        if self.size > 0 {
+            asm.push(AA::Sub1(SP, SP, C::Int(self.size)));
         }
         for (i, t) in v.iter().enumerate() {
             if i >= 8 { panic!("Unimplemented!") }
             asm.push(AA::Mov2(
-                Reg::ID(*t),
-                Reg::R(i as u8)
+                ID(*t),
+                R(i as u8)
             ));
+            if let Some(off) = self.slot(*t) {
+                let at = Operand::Addr(R(29), C::Int(off));
+                asm.push(store(ID(*t), at, self.frames.ty(*t)));
+            }
         }
         return asm;
     }
+    fn epilogue(&self) -> Vec<AA> {
+        use asm::Const as C;
+        use Reg::*;
+        return vec![
+            AA::Mov2(SP, R(29)),
+            AA::LDR1(R(29), SP, C::Int(0)),
+            AA::LDR1(R(30), SP, C::Int(8)),
+            AA::Add1(SP, SP, C::Int(16)),
+            AA::Ret
+        ];
+    }
+    // Offset of a framed temp from X29.
+    pub(super) fn slot(&self, i: ID) -> Option<i64> {
+        return self.frames.slot(i).map(|s| s as i64 - self.size);
+    }
     fn _return(&mut self, r: &Option<Box<Expr>>) -> Vec<AA> {
-        match r { 
+        match r {
+            None if !self.main => return self.epilogue(),
             None => return vec![AA::Ret],
             Some(e) if !self.main => {
-                let Info { mut asm, temp, .. } = self.expression(e);
-                asm.push(AA::Mov2(Reg::R(0), Reg::ID(temp)));
-                asm.push(AA::Ret);
+                let (mut asm, r) = self.select(e);
+                asm.push(AA::Mov2(Reg::R(0), r));
+                asm.extend(self.epilogue());
                 return asm;
             },
             Some(e) => {
-                let Info { mut asm, temp, .. } = self.expression(e);
-                asm.push(AA::Mov2(Reg::R(0), Reg::ID(temp)));
+                let (mut asm, r) = self.select(e);
+                asm.push(AA::Mov2(Reg::R(0), r));
                 asm.push(AA::Mov1(Reg::R(16), Const::Int(1)));
                 asm.push(AA::SVC(Const::Int(128)));
                 return asm;
@@ -116,15 +127,18 @@ impl Translator {
     fn call(&mut self, e: &Expr) -> Vec<AA> {
         use ir::Expr::*;
         use Reg::*;
-        let Call(f, args) = e else { unreachable!(); };
+        let Call(f, args, _) = e else { unreachable!(); };
         let mut asm = Vec::<AA>::new();
         // You can actually avoid pre-coloring the registers.
         // Make each call load into one set of temps, 
         // Pull from the same set of temps here
+        // Floats are passed and returned in X registers as well, not in
+        // D0-D7 like AAPCS64 wants. That's deliberate: everything we call
+        // is ours, and calling C isn't supported.
         let arg_reg = vec![R(0), R(1), R(2), R(3), R(4), R(5), R(6), R(7)];
         if args.len() > 8 { panic!("Too many arguments!"); }
         for i in 0..args.len() {
-            let Temp(r) = *args[i] else { unreachable!() };
+            let Temp(r, _) = *args[i] else { unreachable!() };
             asm.push(AA::Mov2(arg_reg[i as usize], Reg::ID(r)));
         }
         asm.push(AA::BL(*f));
@@ -133,331 +147,79 @@ impl Translator {
     fn _move(&mut self, d: &Expr, s: &Expr) -> Vec<AA> {
         use Expr::*;
         match (d, s) {
-            (Mem(t), e)   => {
-                let Info { temp: mtemp, asm: masm, .. } = self.expression(t);
-                let Info { temp: etemp, asm: easm, .. } = self.expression(e);
-                let mut asm = masm;
+            (Mem(t, ty), e)   => {
+                let nt = if *ty == ir::Type::I8 { NT::ByteAddr } else { NT::Addr };
+                let (mut asm, m) = self.reduce(t, nt);
+                let (easm, e) = self.select(e);
                 asm.extend(easm);
-                asm.push(AA::STR2(
-                    Reg::ID(mtemp),
-                    Reg::ID(etemp)
-                ));
+                asm.push(store(e, m, *ty));
                 return asm;
             },
-            (Temp(a), e)    => {
-                let Info { cost: _, temp, mut asm } = self.expression(e);
-                let r = if *a == self.retid { Reg::R(0) } else { Reg::ID(*a) };
-                asm.push(AA::Mov2(r, Reg::ID(temp)));
+            (Temp(a, ty), e)    => {
+                let (mut asm, s) = self.select(e);
+                match self.slot(*a) {
+                    None      => asm.push(AA::Mov2(self.temp_reg(*a), s)),
+                    Some(off) => asm.push(store(
+                        s, Operand::Addr(Reg::R(29), asm::Const::Int(off)), *ty
+                    ))
+                }
                 return asm;
             },
             _ => unreachable!()
         }
     }
     fn cjump(&mut self, j: &Expr, t: ir::Label) -> Vec<AA> {
-        let Info { mut asm, temp, .. } = self.expression(j);
-        // Condition evalutes to 1 if true, and 0 otherwise.
-        asm.push(AA::CBNZ(Reg::ID(temp), t));
-        return asm;
-    }
-    fn expression(&mut self, e: &Expr) -> Info {
-        let nid = e.addr();
-        match self.opt.get(&nid) {
-            None => (),
-            Some(s) => return s.clone()
+        use ir::Expr::*;
+        use ir::Operator::*;
+        // The exporter negates conditions with Not, peel those off.
+        let (mut e, mut neg) = (j, false);
+        while let UnOp(Not, i) = e {
+            (e, neg) = (&**i, !neg);
         }
-        use Expr::*;
-        let ans = match e {
-            UnOp(op, e)        => self.unary(*op, e,),
-            BinOp(l, op, r)    => self.binary(l, *op, r),
-            Mem(m)             => self.mem(m),
-            Address(e)         => self.address(e),
-            Const(c)           => self._const(c),
-            Temp(i)            => self._temp(*i),
-            _ => unreachable!()
-        };
-        self.opt.insert(nid, ans.clone());
-        return ans;
-    }
-    fn _const(&mut self, p: &ir::Primitive) -> Info {
-        use ir::Primitive as P;
-        use asm::Const as C;
-        let c = match p {
-            P::Int(i)   => C::Int(*i as i64),
-            P::Float(f) => C::Float(*f as f64),
-        };
-        let res = self.create_temp();
-        let mut ans = Info::new(res);
-        let asm = vec![AA::Mov1(Reg::ID(res), c)];
-        ans.update(asm.len() as u32, asm);
-        return ans;
-    }
-    fn _temp(&mut self, i: u32) -> Info {
-        let res = self.create_temp();
-        let mut ans = Info::new(res);
-        let r = if i == self.retid { Reg::R(0) } else { Reg::ID(i) };
-        let asm = vec![AA::Mov2(Reg::ID(res), r)];
-        // If the variable exists it must be stored on the stack.
-        /* if self.frames[i as usize] != usize::MAX {
-            asm.push(AA::STR1(r, Reg::SP, self.frames[i as usize]));
-        } */
-        ans.update(asm.len() as u32, asm);
-        return ans;
-    }
-    fn unary(&mut self, op: Operator, e: &Expr) -> Info {
-        let res = self.create_temp();
-        let mut ans = Info::new(res);
-        use Expr::*;
-        case!({ // LOAD TEMP <== Neg-Mul
-            if op != Operator::Neg { break };
-            let BinOp(l, Operator::Mul, r) = e else { break };
-            let Info { temp: ltmp, asm: lasm, .. } = self.expression(l);
-            let Info { temp: rtmp, asm: rasm, .. } = self.expression(r);
-            let mut asm = lasm.clone();
-            asm.extend(rasm);
-            asm.push(AA::SMNegL(
-                Reg::ID(res),
-                Reg::ID(ltmp),
-                Reg::ID(rtmp)
-            ));
-            ans.update(asm.len() as u32, asm);
-        });
-        case!({ // LOAD TEMP <== Neg
-            if op != Operator::Neg { break };
-            let Info { cost: _, temp, asm } = self.expression(e);
-            let mut asm = asm.clone();
-            asm.push(AA::Neg2(
-                Reg::ID(res),
-                Reg::ID(temp)
-            ));
-            ans.update(asm.len() as u32, asm);
-        });
-        case!({ // MOV TEMP <== ~CONST
-            use ir::Primitive as P;
-            use asm::Const as C;
-            if op != Operator::Not { break };
-            let Const(p) = e else { break };
-            let asm = vec![AA::Mvn1(
-                Reg::ID(res),
-                match p {
-                    P::Int(i)   => C::Int(*i as i64),
-                    P::Float(f) => C::Float(*f as f64),
-                }
-            )];
-            ans.update(asm.len() as u32, asm);
-        });
-        case!({ // MOV TEMP <== ~EXPR
-            if op != Operator::Not { break };
-            let Info { cost: _, temp, asm } = self.expression(e);
-            let mut asm = asm.clone();
-            asm.push(AA::Mvn2(
-                Reg::ID(res),
-                Reg::ID(temp)
-            ));
-            ans.update(asm.len() as u32, asm);
-        });
-        return ans;
-    }
-    fn binary(&mut self, l: &Expr, op: Operator, r: &Expr) -> Info {
-        let res = self.create_temp();
-        let mut ans = Info::new(res);
-        use Expr::*;
-        case!({ // LOAD TEMP <== MUL-ADD
-            if op != Operator::Add && op != Operator::Sub { break };
-            let BinOp(l2, Operator::Mul, r2) = r else { break };
-            let Info { temp: ltmp,  asm: lasm,  .. } = self.expression(l);
-            let Info { temp: l2tmp, asm: l2asm, .. } = self.expression(&l2);
-            let Info { temp: r2tmp, asm: r2asm, .. } = self.expression(&r2);
-            let mut asm = lasm.clone();
-            asm.extend(l2asm);
-            asm.extend(r2asm);
-            if op == Operator::Add {
-                asm.push(AA::SMAddL(
-                    Reg::ID(res),   Reg::ID(l2tmp),
-                    Reg::ID(r2tmp), Reg::ID(ltmp)
-                ));
-            } else {
-                asm.push(AA::SMSubL(
-                    Reg::ID(res),   Reg::ID(l2tmp),
-                    Reg::ID(r2tmp), Reg::ID(ltmp)
-                ));
-            }
-            ans.update(asm.len() as ID, asm);
-        });
-        case!({ // LOAD TEMP <== Expr OP Expr
-            let Info { temp: ltmp, asm: lasm, .. } = self.expression(l);
-            let Info { temp: rtmp, asm: rasm, .. } = self.expression(r);
-            let mut asm = lasm.clone();
-            asm.extend(rasm.clone());
-            asm.extend(match op {
-                Operator::Add => vec![AA::Add2(
-                    Reg::ID(res),
-                    Reg::ID(ltmp),
-                    Reg::ID(rtmp),
-                )],
-                Operator::Sub => vec![AA::Sub2(
-                    Reg::ID(res),
-                    Reg::ID(ltmp),
-                    Reg::ID(rtmp),
-                )],
-                Operator::Mul => vec![AA::SMulL(
-                    Reg::ID(res),
-                    Reg::ID(ltmp),
-                    Reg::ID(rtmp),
-                )],
-                Operator::Div => vec![AA::SDiv(
-                    Reg::ID(res),
-                    Reg::ID(ltmp),
-                    Reg::ID(rtmp),
-                )],
-                Operator::And => vec![AA::And2(
-                    Reg::ID(res),
-                    Reg::ID(ltmp),
-                    Reg::ID(rtmp),
-                )],
-                Operator::Or => vec![AA::Or2(
-                    Reg::ID(res),
-                    Reg::ID(ltmp),
-                    Reg::ID(rtmp),
-                )],
-                Operator::Eq => vec![
-                    AA::CMP2(
-                        Reg::ID(ltmp),
-                        Reg::ID(rtmp),
-                    ),
-                    AA::CSET(
-                        Reg::ID(res),
-                        CC::EQ
-                    )
-                ],
-                Operator::Neq => vec![
-                    AA::CMP2(
-                        Reg::ID(ltmp),
-                        Reg::ID(rtmp),
-                    ),
-                    AA::CSET(
-                        Reg::ID(res),
-                        CC::NE
-                    )
-                ],
-                Operator::Leq => vec![
-                    AA::CMP2(
-                        Reg::ID(ltmp),
-                        Reg::ID(rtmp),
-                    ),
-                    AA::CSET(
-                        Reg::ID(res),
-                        CC::LE
-                    )
-                ],
-                Operator::Geq => vec![
-                    AA::CMP2(
-                        Reg::ID(ltmp),
-                        Reg::ID(rtmp),
-                    ),
-                    AA::CSET(
-                        Reg::ID(res),
-                        CC::GE
-                    )
-                ],
-                Operator::Lt => vec![
-                    AA::CMP2(
-                        Reg::ID(ltmp),
-                        Reg::ID(rtmp),
-                    ),
-                    AA::CSET(
-                        Reg::ID(res),
-                        CC::LT
-                    )
-                ],
-                Operator::Gt => vec![
-                    AA::CMP2(
-                        Reg::ID(ltmp),
-                        Reg::ID(rtmp),
-                    ),
-                    AA::CSET(
-                        Reg::ID(res),
-                        CC::GT
-                    )
-                ],
-                Operator::Mod => {
-                    let t1 = self.create_temp();
-                    vec![
-                        AA::SDiv(
-                            Reg::ID(t1),
-                            Reg::ID(ltmp),
-                            Reg::ID(rtmp),
-                        ),
-                        AA::SMulL(
-                            Reg::ID(res),
-                            Reg::ID(t1),
-                            Reg::ID(rtmp)
-                        ),
-                        AA::Sub2(
-                            Reg::ID(res),
-                            Reg::ID(ltmp),
-                            Reg::ID(res)
-                        )
-                    ]
-                },
-                _ => unreachable!()
-            });
-            ans.update(asm.len() as ID, asm);
-        });
-        return ans;
-    }
-    fn mem(&mut self, m: &Expr) -> Info {
-        let res = self.create_temp();
-        let mut ans = Info::new(res);
-        // use Expr::*;
-        case!({ // LOAD TEMP <== Neg-Mul
-            /* let Const(p) = m else { break };
-            let asm = vec![AA::LDR1(
-                Reg::ID(res),
-                p.bits()
-            )];
-            update(asm.len() as u32, asm); */
-        });
-        case!({ // LOAD TEMP <== Neg-Mul
-            let Info { cost: _, temp, asm } = self.expression(m);
-            let mut asm = asm.clone();
-            asm.push(AA::LDR2(
-                Reg::ID(res),
-                Reg::ID(temp)
-            ));
-            ans.update(asm.len() as u32, asm);
-        });
-        return ans;
-    }
-    fn address(&mut self, e: &Expr) -> Info {
-        use Expr::*;
-        use asm::Const as C;
-        let res = self.create_temp();
-        let mut ans = Info::new(res);
+        let zero = |e: &Expr| matches!(e, Const(ir::Primitive::Int(0)));
         match e {
-            Temp(i) => {
-                let asm = vec![AA::LDR1(
-                    asm::Reg::ID(res), Reg::R(29),
-                    C::Int(self.frames[*i as usize] as i64)
-                )];
-                ans.update(asm.len() as u32, asm);
+            // x == 0 and x != 0 don't need the flags.
+            BinOp(l, op @ (Eq | Neq), r) if zero(r) && l.ty() != ir::Type::F64 => {
+                let (mut asm, r) = self.select(l);
+                asm.push(match (*op == Eq) != neg {
+                    true  => AA::CBZ(r, t),
+                    false => AA::CBNZ(r, t),
+                });
+                return asm;
+            },
+            BinOp(_, Eq | Neq | Lt | Gt | Leq | Geq, _) => {
+                let (mut asm, c) = self.reduce(j, NT::Flags);
+                asm.push(AA::B2(c.cond(), t));
+                return asm;
+            },
+            _ => {
+                // Anything else is true when it's not zero.
+                let (mut asm, r) = self.select(e);
+                asm.push(match neg {
+                    true  => AA::CBZ(r, t),
+                    false => AA::CBNZ(r, t),
+                });
+                return asm;
             }
-            Mem(e) => return self.expression(e),
-            _ => unreachable!()
         }
-        return ans;
     }
-    fn create_temp(&mut self) -> ID {
-        self.count += 1;
-        return (self.count - 1) as ID;
+    // Reduces an expression into some register.
+    fn select(&mut self, e: &Expr) -> (Vec<AA>, Reg) {
+        let (asm, r) = self.reduce(e, NT::Reg);
+        return (asm, r.reg());
     }
-}
-
-macro_rules! case {
-    ($code:block) => {
-        loop {
-            $code
-            break;
-        }
+    fn reduce(&mut self, e: &Expr, nt: NT) -> (Vec<AA>, Operand) {
+        let mut sel = self.sel.take().unwrap();
+        let res = sel.select(self, e, nt);
+        self.sel = Some(sel);
+        return res;
+    }
+    // The register a temp lives in, when it isn't framed.
+    pub(super) fn temp_reg(&self, i: ID) -> Reg {
+        if i == self.retid { Reg::R(0) } else { Reg::ID(i) }
+    }
+    pub(super) fn temp(&mut self) -> Reg {
+        self.count += 1;
+        return Reg::ID((self.count - 1) as ID);
     }
 }
-pub(crate) use case;
\ No newline at end of file
@@ -0,0 +1,235 @@
+// Checks a register allocation by symbolic execution, the idea is from
+// https://cfallin.org/blog/2021/03/15/cranelift-isel-3/ (Fallin).
+// Every register and spill slot holds a set of temps whose values it
+// currently has, a use is fine when its register holds the right temp.
+// Paths join by intersection, so a value is only trusted when it
+// arrives the same way along every path.
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{self, Display};
+use std::mem::discriminant;
+use super::asm::*;
+use super::cfg::CFG;
+use super::target::{MachineInstr, Target};
+use crate::registry::Registry;
+
+pub struct Violation {
+    pub at:  usize, // Index into the allocated code.
+    pub msg: String,
+}
+impl Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.at, self.msg)
+    }
+}
+
+// What each allocated instruction was before allocation.
+#[derive(Clone, Copy)]
+enum Origin {
+    Was(usize), // The instruction at this index.
+    Spill,      // A load or store the allocator added.
+    Frame,      // The allocator grew the frame.
+}
+
+#[derive(Clone, PartialEq)]
+struct State {
+    regs:   Vec<BTreeSet<usize>>,
+    slots:  BTreeMap<i64, BTreeSet<usize>>,
+    // Entered through a call, so X19-X28 have to survive.
+    callee: bool,
+}
+impl State {
+    // Every register holds its own value at entry.
+    fn entry(callee: bool) -> Self {
+        Self {
+            regs:   (0..GPRS).map(|i| BTreeSet::from([i])).collect(),
+            slots:  BTreeMap::new(),
+            callee,
+        }
+    }
+    fn join(&mut self, o: &State) {
+        for (r, s) in self.regs.iter_mut().zip(o.regs.iter()) {
+            r.retain(|v| s.contains(v));
+        }
+        self.slots.retain(|k, _| o.slots.contains_key(k));
+        for (k, v) in self.slots.iter_mut() {
+            v.retain(|x| o.slots[k].contains(x));
+        }
+        self.callee &= o.callee;
+    }
+}
+
+pub fn verify(r: &Registry, before: &[AA], after: &[AA]) -> Result<(), Vec<Violation>> {
+    let before: Vec<&AA> = before.iter().filter(|a| !matches!(a, AA::BB(_))).collect();
+    let after: Vec<AA> = after.iter().filter(|a| !matches!(a, AA::BB(_))).cloned().collect();
+    let origin = align(&before, &after)?;
+
+    let cfg = CFG::build(r, &after);
+    let mut node = vec![usize::MAX; after.len()];
+    for (n, nd) in cfg.nodes.iter().enumerate() {
+        if nd.idx < after.len() { node[nd.idx] = n; }
+    }
+    let succ = |j: usize| -> Vec<usize> {
+        let nd = &cfg.nodes[node[j]];
+        // Calls come back to the next instruction.
+        let t = if matches!(after[j], AA::BL(_)) { None } else { nd.t };
+        return [t, nd.f].into_iter().flatten()
+            .map(|s| cfg.nodes[s].idx)
+            .filter(|i| *i < after.len())
+            .collect();
+    };
+
+    let mut states: Vec<Option<State>> = vec![None; after.len()];
+    let mut work = Vec::new();
+    if !after.is_empty() {
+        states[0] = Some(State::entry(false));
+        work.push(0);
+    }
+    // Functions are only ever called, whatever falls through
+    // into them (the exit syscall, say) isn't really an edge.
+    let mut funcs = vec![false; after.len()];
+    for a in &after {
+        let AA::BL(l) = a else { continue };
+        let i = cfg.nodes[*l as usize].idx;
+        if i < after.len() && !funcs[i] {
+            funcs[i] = true;
+            states[i] = Some(State::entry(true));
+            work.push(i);
+        }
+    }
+    while let Some(j) = work.pop() {
+        let mut s = states[j].clone().unwrap();
+        step(&before, &after, &origin, j, &mut s, &mut Vec::new());
+        for k in succ(j) {
+            if funcs[k] { continue }
+            let next = match &states[k] {
+                None    => s.clone(),
+                Some(o) => { let mut o = o.clone(); o.join(&s); o }
+            };
+            if states[k].as_ref() != Some(&next) {
+                states[k] = Some(next);
+                work.push(k);
+            }
+        }
+    }
+    // Everything's settled, now complain.
+    let mut errs = Vec::new();
+    for j in 0..after.len() {
+        let Some(mut s) = states[j].clone() else { continue };
+        step(&before, &after, &origin, j, &mut s, &mut errs);
+    }
+    return if errs.is_empty() { Ok(()) } else { Err(errs) };
+}
+
+// Matches allocated instructions up with the originals, in order.
+// Anything extra has to be something the allocator is allowed to add.
+fn align(before: &[&AA], after: &[AA]) -> Result<Vec<Origin>, Vec<Violation>> {
+    use Reg::*;
+    let shape = |a: &AA| format!("{}", a.map(&|_| RZR));
+    let mut res = Vec::new();
+    let mut i = 0;
+    for (j, a) in after.iter().enumerate() {
+        let same = i < before.len() && match (before[i], a) {
+            (AA::Sub1(SP, SP, _), AA::Sub1(SP, SP, _)) => true,
+            (b, a) => discriminant(b) == discriminant(a) && shape(b) == shape(a),
+        };
+        if same {
+            res.push(Origin::Was(i));
+            i += 1;
+            continue;
+        }
+        res.push(match a {
+            AA::LDR1(_, R(29), _) | AA::STR1(_, R(29), _) => Origin::Spill,
+            AA::Sub1(SP, SP, _) => Origin::Frame,
+            _ => return Err(vec![Violation {
+                at:  j,
+                msg: format!("{} doesn't correspond to anything", a),
+            }])
+        });
+    }
+    if i < before.len() {
+        return Err(vec![Violation {
+            at:  after.len(),
+            msg: format!("{} went missing", before[i]),
+        }]);
+    }
+    return Ok(res);
+}
+
+fn step(
+        before: &[&AA],
+        after:  &[AA],
+        origin: &[Origin],
+        j:      usize,
+        s:      &mut State,
+        errs:   &mut Vec<Violation>
+    ) {
+    use Reg::*;
+    let a = &after[j];
+    let mut err = |msg: String| errs.push(Violation { at: j, msg });
+    let i = match origin[j] {
+        Origin::Frame => return,
+        Origin::Spill => {
+            match a {
+                AA::STR1(q, _, Const::Int(off)) => {
+                    s.slots.insert(*off, s.regs[q.index()].clone());
+                },
+                AA::LDR1(q, _, Const::Int(off)) => match s.slots.get(off) {
+                    Some(v) if !v.is_empty() => s.regs[q.index()] = v.clone(),
+                    _ => {
+                        err(format!("reloads [X29, #{}] which isn't stored on every path", off));
+                        s.regs[q.index()].clear();
+                    }
+                },
+                _ => unreachable!()
+            }
+            return;
+        },
+        Origin::Was(i) => i,
+    };
+    let b = before[i];
+    let (bdefs, buses) = b.defuse();
+    let (adefs, auses) = a.defuse();
+    for (vb, va) in buses.iter().zip(auses.iter()) {
+        if let ID(_) = va {
+            err(format!("{} was never given a register", va));
+            continue;
+        }
+        if vb.index() < GPRS && vb != va {
+            err(format!("{} has to be in {}, not {}", vb, vb, va));
+        } else if !s.regs[va.index()].contains(&vb.index()) {
+            err(format!("{} should hold {}, but it was overwritten", va, vb));
+        }
+    }
+    if matches!(a, AA::Ret) && s.callee {
+        for p in AArch64::callee_saved() {
+            if !s.regs[p.index()].contains(&p.index()) {
+                err(format!("{} isn't restored before returning", p));
+            }
+        }
+    }
+    // A move only adds a name to a value.
+    let copied = match a {
+        AA::Mov2(_, q) => s.regs[q.index()].clone(),
+        _ => BTreeSet::new(),
+    };
+    for (vb, va) in bdefs.iter().zip(adefs.iter()) {
+        if vb.index() < GPRS && vb != va {
+            err(format!("{} has to be in {}, not {}", vb, vb, va));
+        }
+        // Old copies are stale now.
+        for r in s.regs.iter_mut() { r.remove(&vb.index()); }
+        for v in s.slots.values_mut() { v.remove(&vb.index()); }
+        if let ID(_) = va {
+            err(format!("{} was never given a register", va));
+            continue;
+        }
+        let mut vals = copied.clone();
+        vals.insert(vb.index());
+        s.regs[va.index()] = vals;
+    }
+    // Stores of the program's own don't touch spill slots,
+    // but they might alias them for all we know.
+    if let AA::STR1(_, R(29), Const::Int(off)) | AA::STRB(_, R(29), Const::Int(off)) = a {
+        s.slots.remove(off);
+    }
+}
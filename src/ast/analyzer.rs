@@ -1,10 +1,14 @@
+use std::collections::HashMap;
 use super::ast::*;
+use crate::asm::asm::AA;
 use crate::registry::Registry;
 use super::symboltable::{SymbolTable, VSymbol, FSymbol};
 pub struct Analyzer<'l> {
     fname: String,
     vsym: SymbolTable<VSymbol>,
     fsym: SymbolTable<FSymbol>,
+    structs: HashMap<u32, StructDeclaration>, // Laid out, by tag.
+    cases: Vec<Vec<Option<i64>>>, // Labels seen in each enclosing switch.
     reg:  &'l mut Registry
 }
 // TODO: for proper error detection,
@@ -16,10 +20,15 @@ impl<'l> Analyzer<'l> {
             fname: String::new(),
             vsym: SymbolTable::new(),
             fsym: SymbolTable::new(),
+            structs: HashMap::new(),
+            cases: Vec::new(),
             reg:  registry
         }
     }
     pub fn analyze(&mut self, m: &mut Module) {
+        for s in &mut m.structs {
+            self.struct_declaration(s);
+        }
         // Forward Declarations!
         for i in 0..m.functions.len() {
             if m.functions[i].name != "main" { continue; }
@@ -36,7 +45,45 @@ impl<'l> Analyzer<'l> {
         }
         self.reg.nids = self.vsym.count;
     }
+    // Members go at the next offset their alignment allows, and the
+    // struct is padded out to its own alignment so arrays of it work.
+    fn struct_declaration(&mut self, s: &mut StructDeclaration) {
+        let name = self.reg.tag_name(s.tag).to_string();
+        if self.structs.contains_key(&s.tag) {
+            panic!("Defining already defined struct {}!", name);
+        }
+        let (mut end, mut align) = (0, 1);
+        for i in 0..s.members.len() {
+            let m = &s.members[i];
+            if s.members[..i].iter().any(|n| n.name == m.name) {
+                panic!("Member {} of struct {} defined twice!", m.name, name);
+            }
+            let (size, a) = self.layout(m.kind);
+            s.members[i].offset = (end + a - 1) / a * a;
+            end = s.members[i].offset + size;
+            align = align.max(a);
+        }
+        s.size = (end + align - 1) / align * align;
+        s.align = align;
+        self.structs.insert(s.tag, s.clone());
+    }
+    // Size and alignment of a kind, structs have to be complete by now.
+    fn layout(&self, k: Kind) -> (u32, u32) {
+        if k.indir > 0 { return (8, 8) }
+        return match k.prim {
+            Primitive::Char => (1, 1),
+            Primitive::Int | Primitive::Float => (8, 8),
+            Primitive::Void => panic!("Nothing can be void!"),
+            Primitive::Struct(t) => match self.structs.get(&t) {
+                Some(s) => (s.size, s.align),
+                None => panic!("struct {} is incomplete!", self.reg.tag_name(t))
+            }
+        };
+    }
     fn function_declaration(&mut self, f: &mut FunctionDeclaration) {
+        if f.ret.aggregate() || f.params.iter().any(|p| p.kind.aggregate()) {
+            panic!("Structs are passed and returned by pointer, in {}!", f.name);
+        }
         self.vsym.scope_in();
         for p in &mut f.params {
             p.id = self.vsym.insert(&p.name, p.kind);
@@ -55,17 +102,72 @@ impl<'l> Analyzer<'l> {
             While       (ref mut w) => self.while_statement(w),
             Compound    (ref mut c) => self.compound_statement(c),
             Jump        (ref mut j) => self.jump_statement(j),
-            Asm         (_)         => ()
+            Asm         (ref mut a) => self.asm_statement(a),
+            Switch      (ref mut s) => self.switch_statement(s),
+            Case        (ref mut c) => self.case_statement(c)
+        }
+    }
+    fn switch_statement(&mut self, s: &mut SwitchStatement) {
+        self.expression(&mut s.expr);
+        let kind = s.expr.kind().unwrap();
+        assert!(kind.integral(), "Cannot switch on {}!", kind.name(self.reg));
+        self.cases.push(Vec::new());
+        self.statement(&mut s.stmt);
+        self.cases.pop();
+    }
+    fn case_statement(&mut self, c: &mut CaseStatement) {
+        let Some(seen) = self.cases.last_mut() else {
+            panic!("Case outside of a switch!");
+        };
+        if seen.contains(&c.value) {
+            match c.value {
+                None => panic!("Switch has two defaults!"),
+                Some(v) => panic!("Case {} appears twice in one switch!", v)
+            }
+        }
+        seen.push(c.value);
+        self.statement(&mut c.stmt);
+    }
+    fn asm_statement(&mut self, a: &mut AsmStatement) {
+        for i in 0..a.operands.len() {
+            let o = &mut a.operands[i];
+            self.expression(&mut o.expr);
+            if o.expr.kind().unwrap().aggregate() {
+                panic!("Asm operand {} can't be a whole struct!", o.name);
+            }
+            if o.dir != AsmDir::In {
+                let lvalue = match &*o.expr {
+                    Expr::Ident(_) | Expr::Member(_) => true,
+                    Expr::Unary(u) => matches!(u.unary_op, UnaryOp::Star),
+                    _ => false
+                };
+                assert!(lvalue, "Asm output {} has to be assignable!", o.name);
+            }
+            if a.operands[..i].iter().any(|p| p.name == a.operands[i].name) {
+                panic!("Asm operand {} bound twice!", a.operands[i].name);
+            }
+        }
+        let ids: Vec<u32> = a.calls.iter().map(|name| match self.fsym.get(name) {
+            None => panic!("Reference To Non-Existing Function {}", name),
+            Some(f) => f.id
+        }).collect();
+        for line in &mut a.asm {
+            if let AA::BL(i) = line { *i = ids[*i as usize]; }
         }
     }
     fn declare_statement(&mut self, d: &mut DeclareStatement) {
         if self.vsym.contains_key_in_scope(&d.name) {
             panic!("Defining already defined variable!");
         }
+        if d.kind.aggregate() {
+            self.layout(d.kind);
+            assert!(d.val.is_none(), "Struct {} can't be initialized!", d.name);
+        }
         d.id = self.vsym.insert(&d.name, d.kind);
         if let Some(e) = &mut d.val {
             self.expression(e);
-            assert!(d.kind == e.kind().unwrap(), "{}",
+            let k = e.kind().unwrap();
+            assert!(d.kind == k || (d.kind.integral() && k.integral()), "{}",
                 &format!(
                     "variable should have type {:?}, but is actually {:?}.",
                     d.kind, e.kind()
@@ -120,7 +222,8 @@ impl<'l> Analyzer<'l> {
             }
         };
         let func = self.fsym.get(&self.fname).unwrap();
-        assert!(func.kind == kind.unwrap());
+        let kind = kind.unwrap();
+        assert!(func.kind == kind || (func.kind.integral() && kind.integral()));
     }
     fn function_call(&mut self, f: &mut FunctionCall) {
         for e in &mut f.args { self.expression(e); }
@@ -135,7 +238,7 @@ impl<'l> Analyzer<'l> {
         for idx in 0..f.args.len() {
             let argk = f.args[idx].kind().unwrap();
             let fk = fsym.args[idx];
-            if fk != argk {
+            if fk != argk && !(fk.integral() && argk.integral()) {
                 panic!("Argument Type Mismatch!")
             }
         }
@@ -150,9 +253,41 @@ impl<'l> Analyzer<'l> {
             Unary    (ref mut i) => self.unary(i),
             Binary   (ref mut i) => self.binary(i),
             Ident    (ref mut i) => self.identifier(i),
+            Member   (ref mut i) => self.member(i),
             _                    => ()
         }
     }
+    fn member(&mut self, m: &mut MemberExpr) {
+        self.expression(&mut m.expr);
+        let mut kind = m.expr.kind().unwrap();
+        if m.arrow {
+            assert!(kind.indir == 1, "-> needs a pointer to a struct, not {}!", kind.name(self.reg));
+            kind.indir = 0;
+        } else {
+            // The struct has to be somewhere in memory to pick a member out of it.
+            let place = match &*m.expr {
+                Expr::Ident(_) | Expr::Member(_) => true,
+                Expr::Unary(u) => matches!(u.unary_op, UnaryOp::Star),
+                _ => false
+            };
+            assert!(place, "Cannot take member {} of a temporary!", m.name);
+        }
+        let Primitive::Struct(t) = kind.prim else {
+            panic!("Member {} of {}, which is no struct!", m.name, kind.name(self.reg));
+        };
+        assert!(kind.indir == 0, "Member {} of a pointer, use ->!", m.name);
+        let s = match self.structs.get(&t) {
+            None => panic!("struct {} is incomplete!", self.reg.tag_name(t)),
+            Some(s) => s
+        };
+        match s.members.iter().find(|f| f.name == m.name) {
+            None => panic!("struct {} has no member {}!", self.reg.tag_name(t), m.name),
+            Some(f) => {
+                m.offset = f.offset;
+                m.kind = Some(f.kind);
+            }
+        }
+    }
     fn access(&mut self, _a: &mut AccessExpr) {
         panic!("THERE ARE NO ACCESSES...");
     }
@@ -160,8 +295,11 @@ impl<'l> Analyzer<'l> {
         self.expression(&mut u.expr);
         let mut kind = u.expr.kind().unwrap();
         match u.unary_op {
+            _ if kind.aggregate() && !matches!(u.unary_op, UnaryOp::Address) => {
+                panic!("Cannot apply {:?} to a whole struct!", u.unary_op);
+            },
             UnaryOp::Address => {
-                if matches!(*u.expr, Expr::Ident(_)) {
+                if matches!(*u.expr, Expr::Ident(_) | Expr::Member(_)) {
                     kind.indir += 1;
                     u.kind = Some(kind);
                 } else {
@@ -181,7 +319,9 @@ impl<'l> Analyzer<'l> {
                 }
             },
             UnaryOp::Neg => {
-                if kind.indir == 0 {
+                if kind.integral() {
+                    u.kind = Some(Kind::int());
+                } else if kind.indir == 0 {
                     u.kind = Some(kind);
                 } else {
                     panic!("Cannot Negate Address!");
@@ -194,6 +334,10 @@ impl<'l> Analyzer<'l> {
                     panic!("Cannot only NOT Integers!");
                 }
             },
+            UnaryOp::BitNot => {
+                assert!(kind.integral(), "Cannot complement {}!", kind.name(self.reg));
+                u.kind = Some(Kind::int());
+            },
         }
     }
     fn binary(&mut self, b: &mut BinaryExpr) {
@@ -201,6 +345,35 @@ impl<'l> Analyzer<'l> {
         self.expression(&mut b.right);
         let lkind = b.left.kind().unwrap();
         let rkind = b.right.kind().unwrap();
+        // Copying structs around whole can come with passing them by value.
+        if lkind.aggregate() || rkind.aggregate() {
+            panic!("Cannot apply {:?} to a whole struct!", b.binary_op);
+        }
+        // Bit operations only make sense on integers, whatever the two are.
+        let bits = matches!(b.binary_op,
+            BinaryOp::BitOr | BinaryOp::BitXor | BinaryOp::BitAnd |
+            BinaryOp::Shl | BinaryOp::Shr
+        );
+        let bits_assign = matches!(b.binary_op,
+            BinaryOp::Aeq | BinaryOp::Oeq | BinaryOp::Xeq |
+            BinaryOp::Shleq | BinaryOp::Shreq
+        );
+        if bits || bits_assign {
+            if !lkind.integral() || !rkind.integral() {
+                panic!("Cannot apply {:?} to {} and {}!", b.binary_op, lkind.name(self.reg), rkind.name(self.reg));
+            }
+            b.kind = Some(if bits { Kind::int() } else { lkind });
+            return;
+        }
+        let assign = matches!(b.binary_op,
+            BinaryOp::Peq | BinaryOp::Seq | BinaryOp::Teq |
+            BinaryOp::Deq | BinaryOp::Assign
+        );
+        // Chars are ints once they're out of memory.
+        if lkind == rkind && lkind == Kind::char() && !assign {
+            b.kind = Some(Kind::int());
+            return;
+        }
         if lkind == rkind { 
             b.kind = Some(lkind); 
             return;
@@ -210,7 +383,11 @@ impl<'l> Analyzer<'l> {
             BinaryOp::Teq | BinaryOp::Deq |
             BinaryOp::Assign => {
                 if lkind == Kind::float() &&
-                    rkind == Kind::int() {
+                    rkind.integral() {
+                    b.kind = Some(lkind);
+                    return;
+                }
+                if lkind.integral() && rkind.integral() {
                     b.kind = Some(lkind);
                     return;
                 }
@@ -223,9 +400,9 @@ impl<'l> Analyzer<'l> {
                     b.kind = Some(Kind::float());
                 } else if lkind.indir != 0 ||
                     rkind.indir != 0 {
-                    if lkind == Kind::int() {
+                    if lkind.integral() {
                         b.kind = Some(rkind);
-                    } else if rkind == Kind::int() {
+                    } else if rkind.integral() {
                         b.kind = Some(lkind);
                     } else {
                         panic!("Cannot apply Binary Op to two pointers!");
@@ -253,6 +430,7 @@ impl<'l> Analyzer<'l> {
                     panic!("Can only OR or AND two Integers!");
                 }
             }
+            _ => unreachable!()
         }
     }
     fn identifier(&mut self, i: &mut Identifier) {
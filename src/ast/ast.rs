@@ -1,10 +1,29 @@
 use std::fmt;
 use crate::asm::asm;
+use crate::registry::Registry;
 use Primitive::*;
 
 //--------Modules------------
 pub struct Module {
     pub functions: Vec<Box<FunctionDeclaration>>,
+    pub structs: Vec<StructDeclaration>,
+}
+
+//--------Structs------------
+// The analyzer lays members out, until then offsets, size and align are 0.
+#[derive(Clone)]
+pub struct StructDeclaration {
+    pub tag: u32,
+    pub members: Vec<Member>,
+    pub size: u32,
+    pub align: u32,
+}
+
+#[derive(Clone)]
+pub struct Member {
+    pub kind: Kind,
+    pub name: String,
+    pub offset: u32,
 }
 
 //--------Functions------------
@@ -32,11 +51,33 @@ pub enum Statement {
     While(WhileStatement),
     Compound(CompoundStatement),
     Jump(JumpStatement),
-    Asm(AsmStatement)
+    Asm(AsmStatement),
+    Switch(SwitchStatement),
+    Case(CaseStatement),
 }
 
 pub struct AsmStatement {
-    pub asm: Vec<asm::AA>
+    pub asm: Vec<asm::AA>,
+    // Lines refer to operand i as ID(i).
+    pub operands: Vec<AsmOperand>,
+    pub clobbers: Vec<asm::Reg>,
+    // Local labels, lines refer to label i as Label(i).
+    pub labels: Vec<String>,
+    // Functions the lines call, BL(i) until the analyzer resolves them.
+    pub calls: Vec<String>,
+}
+
+pub struct AsmOperand {
+    pub dir: AsmDir,
+    pub name: String,
+    pub expr: Box<Expr>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AsmDir {
+    In,
+    Out,
+    InOut,
 }
 
 pub struct DeclareStatement {
@@ -68,6 +109,17 @@ pub struct WhileStatement {
     pub stmt: Box<Statement>,
 }
 
+pub struct SwitchStatement {
+    pub expr: Box<Expr>,
+    pub stmt: Box<Statement>,
+}
+
+// `default:` has no value.
+pub struct CaseStatement {
+    pub value: Option<i64>,
+    pub stmt: Box<Statement>,
+}
+
 pub struct CompoundStatement {
     pub stmts: Vec<Statement>,
 }
@@ -85,7 +137,10 @@ pub enum Expr {
     Binary(BinaryExpr),
     Integer(i64),
     Float(f64),
+    Char(u8),
+    Str(Vec<u8>),
     Ident(Identifier),
+    Member(MemberExpr),
 }
 impl Expr {
     pub fn kind(&self) -> Option<Kind> {
@@ -97,7 +152,10 @@ impl Expr {
             Binary(i) => i.kind,
             Integer(_) => Some(Kind::int()),
             Float(_) => Some(Kind::float()),
+            Char(_) => Some(Kind::char()),
+            Str(_) => Some(Kind { indir: 1, prim: Primitive::Char }),
             Ident(i) => i.kind,
+            Member(i) => i.kind,
         }
     }
     pub fn id(&self) -> u32 {
@@ -138,6 +196,15 @@ pub struct BinaryExpr {
     pub kind: Option<Kind>,
 }
 
+// s.name, or p->name when arrow is set.
+pub struct MemberExpr {
+    pub expr: Box<Expr>,
+    pub name: String,
+    pub arrow: bool,
+    pub offset: u32,
+    pub kind: Option<Kind>,
+}
+
 pub struct Identifier {
     pub name: String,
     pub kind: Option<Kind>,
@@ -164,6 +231,16 @@ pub enum BinaryOp {
     Neq,
     Or,
     And,
+    BitOr,
+    BitXor,
+    BitAnd,
+    Shl,
+    Shr,
+    Aeq,
+    Oeq,
+    Xeq,
+    Shleq,
+    Shreq,
     Assign,
 }
 
@@ -173,6 +250,7 @@ pub enum UnaryOp {
     Not,
     Neg,
     Address,
+    BitNot,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -207,6 +285,28 @@ impl Kind {
             prim: Float,
         }
     }
+    pub fn char() -> Self {
+        Self {
+            indir: 0,
+            prim: Char,
+        }
+    }
+    // A struct itself, rather than a pointer to one.
+    pub fn aggregate(&self) -> bool {
+        return self.indir == 0 && matches!(self.prim, Struct(_));
+    }
+    // Ints and chars, which mix freely.
+    pub fn integral(&self) -> bool {
+        return self.indir == 0 && matches!(self.prim, Int | Char);
+    }
+    // Display, with struct names looked up in the registry.
+    pub fn name(&self, r: &Registry) -> String {
+        let prim = match self.prim {
+            Struct(t) => format!("struct {}", r.tag_name(t)),
+            p         => format!("{}", p),
+        };
+        return format!("{}{}", prim, "*".repeat(self.indir as usize));
+    }
 }
 impl fmt::Display for Kind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -219,13 +319,17 @@ pub enum Primitive {
     Void,
     Int,
     Float,
+    Char,
+    Struct(u32),
 }
 impl fmt::Display for Primitive {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", match self {
-            Void => "void",
-            Int => "int",
-            Float => "float",
-        })
+        match self {
+            Void => write!(f, "void"),
+            Int => write!(f, "int"),
+            Float => write!(f, "float"),
+            Char => write!(f, "char"),
+            Struct(t) => write!(f, "struct #{}", t),
+        }
     }
 }
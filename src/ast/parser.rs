@@ -1,29 +1,33 @@
-// auto-generated: "lalrpop 0.20.0"
-// sha3: 76d247fea685af81a03edb5142966dd3c2867fb5173f07c2e37139231c605c14
+// auto-generated: "lalrpop 0.23.1"
+// sha3: 85b2ef09af582c07044facb486071049b7a31d6e727983ed5d8aa1c1ea26aa28
 use lalrpop_util::ParseError;
 use std::str::FromStr;
 use super::ast::*;
 use crate::asm;
+use crate::asm::strings;
+use crate::registry::Registry;
 #[allow(unused_extern_crates)]
 extern crate lalrpop_util as __lalrpop_util;
 #[allow(unused_imports)]
 use self::__lalrpop_util::state_machine as __state_machine;
-extern crate core;
+#[allow(unused_extern_crates)]
 extern crate alloc;
 
 #[rustfmt::skip]
-#[allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens, clippy::all)]
+#[allow(explicit_outlives_requirements, non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens, clippy::needless_lifetimes, clippy::type_complexity, clippy::needless_return, clippy::too_many_arguments, clippy::match_single_binding, clippy::clone_on_copy, clippy::unit_arg)]
 mod __parse__module {
 
     use lalrpop_util::ParseError;
     use std::str::FromStr;
     use super::super::ast::*;
     use crate::asm;
+    use crate::asm::strings;
+    use crate::registry::Registry;
     #[allow(unused_extern_crates)]
     extern crate lalrpop_util as __lalrpop_util;
     #[allow(unused_imports)]
     use self::__lalrpop_util::state_machine as __state_machine;
-    extern crate core;
+    #[allow(unused_extern_crates)]
     extern crate alloc;
     use self::__lalrpop_util::lexer::Token;
     #[allow(dead_code)]
@@ -34,371 +38,520 @@ mod __parse__module {
         Variant2(alloc::vec::Vec<Parameter>),
         Variant3(BinaryOp),
         Variant4(Vec<Parameter>),
-        Variant5(core::option::Option<Vec<Parameter>>),
+        Variant5(Option<Vec<Parameter>>),
         Variant6(Box<Expr>),
         Variant7(UnaryOp),
         Variant8(Module),
-        Variant9(asm::asm::AA),
-        Variant10(Vec<asm::asm::AA>),
-        Variant11(Box<Statement>),
-        Variant12(core::option::Option<Box<Expr>>),
-        Variant13(Vec<Expr>),
-        Variant14(core::option::Option<Vec<Expr>>),
-        Variant15(f64),
-        Variant16(Box<FunctionDeclaration>),
-        Variant17(String),
-        Variant18(i64),
-        Variant19(u32),
-        Variant20(core::option::Option<u32>),
-        Variant21(Vec<Statement>),
-        Variant22(core::option::Option<Vec<Statement>>),
-        Variant23(Primitive),
-        Variant24(Kind),
+        Variant9(String),
+        Variant10((Vec<String>, Vec<AsmOperand>, Vec<String>)),
+        Variant11(AsmOperand),
+        Variant12(Box<Statement>),
+        Variant13(i64),
+        Variant14(u8),
+        Variant15(Option<Box<Expr>>),
+        Variant16(Vec<Expr>),
+        Variant17(Option<Vec<Expr>>),
+        Variant18(f64),
+        Variant19(Box<FunctionDeclaration>),
+        Variant20(Member),
+        Variant21(alloc::vec::Vec<Member>),
+        Variant22(u32),
+        Variant23(Option<u32>),
+        Variant24(Vec<Statement>),
+        Variant25(Option<Vec<Statement>>),
+        Variant26(Vec<u8>),
+        Variant27(StructDeclaration),
+        Variant28(Primitive),
+        Variant29(Kind),
     }
     const __ACTION: &[i16] = &[
         // State 0
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 0, 0, 51, 0, 52, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 0, 0, 74, 0, 0, 75, 0, 5, 0, 76, 0, 0, 0, 0, 0, 0, 0,
         // State 1
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 0, 0, 51, 0, 52, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 0, 0, 74, 0, 0, 75, 0, 5, 0, 76, 0, 0, 0, 0, 0, 0, 0,
         // State 2
-        0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -116, 0, 0,
+        0, 0, -162, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 3
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 0, 0,
+        0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 4
-        0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 0, 0, 51, 0, 52, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 5
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 0, 0, 51, 0, 52, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 0, 0, 74, 0, 0, 75, 0, 11, 0, 76, 0, 0, 0, 0, 0, 0, 0,
         // State 6
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 0, 0, 74, 0, 0, 75, 0, 11, 0, 76, 0, 0, 0, 0, 0, 0, 0,
         // State 7
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 0, 0, 74, 0, 0, 75, 0, 11, 0, 76, 0, 0, 0, 0, 0, 0, 0,
         // State 8
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0,
+        0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 9
-        86, 0, 0, 87, 0, 21, 0, 88, 0, 0, 0, 0, 89, 0, 0, 0, 90, 0, 0, 0, 0, 0, 0, 0, 0, 91, 92, 93, 0, 50, 94, 95, 51, 22, 52, 96, 10, 0, 97, 0, 57, 98, 99,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0,
         // State 10
-        0, -45, 0, 0, -45, 0, -45, 0, 0, 100, 0, -45, 101, 0, 0, 0, -45, -45, -45, 0, -45, -45, -45, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -45, 0, 0, 0, 0, 0,
+        0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 11
-        0, 0, 0, 0, 102, 0, -46, 0, 0, 0, 0, -46, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 0, 0, 74, 0, 0, 75, 0, 11, 0, 76, 0, 0, 0, 0, 0, 94, 0,
         // State 12
-        0, 103, 0, 0, -55, 0, -55, 0, 0, 0, 0, -55, 0, 0, 0, 0, -55, 0, 0, 0, 104, 0, 0, 0, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -55, 0, 0, 0, 0, 0,
+        0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 13
-        0, -82, 105, 0, -82, 0, -82, 106, 0, -82, 0, -82, -82, 0, 107, 0, -82, -82, -82, 0, -82, -82, -82, 0, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0,
         // State 14
-        0, 0, 0, 0, 0, 0, -83, 0, 0, 0, 0, -83, 0, 0, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 108, 0, 0, 0, 0, 0,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 129, 130, 31, 73, 131, 132, 0, 74, 133, 134, 75, 32, 11, 135, 76, 136, 15, 0, 0, 0, 137, 138,
         // State 15
-        0, -96, 0, 0, -96, 0, -96, 0, 0, 0, 0, -96, 0, 0, 0, 0, -96, 109, 110, 0, -96, 111, 112, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -96, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -64, 0, -64, -64, 0, 0, -64, 0, 0, 145, 0, -64, 146, 0, 0, 0, 0, 0, 0, -64, -64, -64, 0, -64, 0, -64, -64, -64, -64, 0, 0, -64, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -64, 0, -64, -64, 0,
         // State 16
-        0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 98, 99,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 147, 0, 0, -65, 0, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -65, -65, 0,
         // State 17
-        86, 0, 0, 87, 0, 21, 0, 88, 0, 0, 0, 0, 89, 0, 0, 0, 90, 0, 0, 0, 0, 0, 0, 0, 0, 91, 92, 93, 0, 50, 94, 95, 51, 22, 52, 96, 10, 0, 116, 0, 57, 98, 99,
+        0, 0, 0, 0, 0, 0, 0, 0, 148, -73, 0, 0, -73, 0, 0, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -73, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -73, 0, -73, -73, 0,
         // State 18
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -74, 0, 0, -74, 0, 0, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 149, 0, -74, -74, 0,
         // State 19
-        0, -35, -35, 0, -35, 0, -35, -35, 118, -35, 119, -35, -35, 120, -35, 121, -35, -35, -35, 122, -35, -35, -35, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -75, 0, 0, -75, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75, 150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75, 0, -75, -75, 0,
         // State 20
-        86, 0, 0, 87, 0, 21, 0, 88, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 98, 99,
+        0, 0, 0, 0, 0, 0, 151, 0, -83, -83, 0, 0, -83, 0, 0, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0, -83, 0, 0, 0, 0, 0, 152, 0, 0, 0, 0, 0, -83, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -83, 0, -83, -83, 0,
         // State 21
-        86, 0, 0, 87, 0, 21, 0, 88, 0, 0, 0, 0, 89, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 98, 99,
+        0, 0, 0, 0, 0, 0, -117, 153, -117, -117, 0, 0, -117, 154, 0, -117, 0, -117, -117, 0, 0, 0, 155, 0, 0, -117, -117, -117, 0, -117, 0, -117, -117, -117, -117, 0, 0, -117, -117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -117, 0, -117, -117, 0,
         // State 22
-        86, 0, 0, 87, 0, 21, 0, 88, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 98, 99,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -118, 0, 0, 0, 0, -118, 0, 0, 0, 0, 0, 0, 0, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 156, -118, 0,
         // State 23
-        86, 0, 0, 87, 0, 21, 0, 88, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 98, 99,
+        0, 0, 0, 0, 0, 0, -135, 0, -135, -135, 0, 0, -135, 0, 0, 0, 0, -135, 0, 0, 0, 0, 0, 0, 0, -135, 157, 0, 0, 158, 0, -135, 159, 160, 0, 0, 0, -135, -135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -135, 0, -135, -135, 0,
         // State 24
-        86, 0, 0, 87, 0, 21, 0, 88, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 98, 99,
+        0, 0, 0, 0, 0, 0, -139, 0, -139, -139, 0, 0, -139, 0, 0, 0, 0, -139, 0, 0, 0, 0, 0, 0, 0, -139, -139, 161, 0, -139, 0, -139, -139, -139, 162, 0, 0, -139, -139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -139, 0, -139, -139, 0,
         // State 25
-        86, 0, 0, 87, 0, 21, 0, 88, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 98, 99,
+        139, 140, 82, 141, 142, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 26
-        86, 0, 0, 87, 0, 21, 0, 88, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 98, 99,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 129, 130, 31, 73, 131, 132, 0, 74, 133, 134, 75, 32, 11, 135, 76, 136, 15, 0, 0, 0, 166, 138,
         // State 27
-        86, 0, 0, 87, 0, 21, 0, 88, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 98, 99,
+        0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 28
-        86, 0, 0, 87, 0, 21, 137, 88, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 98, 99,
+        0, 0, 0, 0, 0, 0, -51, -51, -51, -51, 168, 0, -51, -51, 169, -51, 170, -51, -51, 171, 0, 0, -51, 172, 0, -51, -51, -51, 173, -51, 174, -51, -51, -51, -51, 175, 0, -51, -51, 176, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51, 177, -51, -51, 0,
         // State 29
-        86, 0, 0, 87, 0, 21, 0, 88, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 98, 99,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138,
         // State 30
-        86, 0, 0, 87, 0, 21, 0, 88, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 98, 99,
+        0, 140, 0, 141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 31
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 185, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138,
         // State 32
-        86, 0, 0, 87, 0, 21, 0, 88, 0, 0, 0, 0, 89, 0, 0, 0, 90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 0, 0, 51, 0, 52, 0, 0, 0, 0, 0, 57, 98, 99,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138,
         // State 33
-        86, 0, 0, 87, 0, 21, 0, 88, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 98, 99,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138,
         // State 34
-        86, 0, 0, 87, 0, 21, 0, 88, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 98, 99,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138,
         // State 35
-        86, 0, 0, 87, 0, 21, 0, 88, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 98, 99,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138,
         // State 36
-        86, 0, 0, 87, 0, 21, 0, 88, 0, 0, 0, 0, 89, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 98, 99,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138,
         // State 37
-        86, 0, 0, 87, 0, 21, 0, 88, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 98, 99,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138,
         // State 38
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138,
         // State 39
-        86, 0, 0, 87, 0, 21, 44, 88, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 98, 99,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138,
         // State 40
-        86, 0, 0, 87, 0, 21, 0, 88, 0, 0, 0, 0, 89, 0, 0, 0, 90, 0, 0, 0, 0, 0, 0, 0, 0, 91, 92, 93, 0, 50, 94, 95, 51, 22, 52, 96, 10, 0, 0, 0, 57, 98, 99,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138,
         // State 41
-        86, 0, 0, 87, 0, 21, 0, 88, 0, 0, 0, 0, 89, 0, 0, 0, 90, 0, 0, 0, 0, 0, 0, 0, 0, 91, 92, 93, 0, 50, 94, 95, 51, 22, 52, 96, 10, 0, 0, 0, 57, 98, 99,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138,
         // State 42
-        86, 0, 0, 87, 0, 21, 45, 88, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 98, 99,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 199, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138,
         // State 43
-        86, 0, 0, 87, 0, 21, 0, 88, 0, 0, 0, 0, 89, 0, 0, 0, 90, 0, 0, 0, 0, 0, 0, 0, 0, 91, 92, 93, 0, 50, 94, 95, 51, 22, 52, 96, 10, 0, 0, 0, 57, 98, 99,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138,
         // State 44
-        86, 0, 0, 87, 0, 21, 0, 88, 0, 0, 0, 0, 89, 0, 0, 0, 90, 0, 0, 0, 0, 0, 0, 0, 0, 91, 92, 93, 0, 50, 94, 95, 51, 22, 52, 96, 10, 0, 0, 0, 57, 98, 99,
+        0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 45
-        86, 0, 0, 87, 0, 21, 0, 88, 0, 0, 0, 0, 89, 0, 0, 0, 90, 0, 0, 0, 0, 0, 0, 0, 0, 91, 92, 93, 0, 50, 94, 95, 51, 22, 52, 96, 10, 0, 0, 0, 57, 98, 99,
+        0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 46
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138,
         // State 47
-        86, 0, 0, 87, 0, 21, 0, 88, 0, 0, 0, 0, 89, 0, 0, 0, 90, 0, 0, 0, 0, 0, 0, 0, 0, 91, 92, 93, 0, 50, 94, 95, 51, 22, 52, 96, 10, 0, 0, 0, 57, 98, 99,
+        139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 48
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -80, 0, 0, -80, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 129, 130, 31, 73, 131, 132, 0, 74, 133, 134, 75, 32, 11, 135, 76, 136, 15, 0, 0, 0, 0, 138,
         // State 49
-        0, 0, 0, 0, 0, 0, 0, -114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -114, 0, 0,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 0, 0, 74, 0, 0, 75, 0, 11, 0, 76, 0, 0, 0, 0, 0, 0, 138,
         // State 50
-        0, 0, 0, 0, 0, 0, 0, -113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -113, 0, 0,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138,
         // State 51
-        0, 0, 0, 0, 0, 0, 0, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -112, 0, 0,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138,
         // State 52
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81, 0, 0, -81, 0, -81, 0, 0, 0, 0, 0, 0, 0, 0,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138,
         // State 53
-        0, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -115, 0, 0,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138,
         // State 54
-        0, 0, 0, 0, 0, 0, 0, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -85, 0, 0,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 129, 130, 31, 73, 131, 132, 0, 74, 133, 134, 75, 32, 11, 135, 76, 136, 15, 0, 0, 0, 0, 138,
         // State 55
-        0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138,
         // State 56
-        0, -69, -69, 0, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -69, 0, 0, 0, 0, 0,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138,
         // State 57
-        0, 0, 0, 0, 0, 0, 0, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -86, 0, 0,
+        139, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 58
-        0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 65, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138,
         // State 59
-        0, 0, 0, 0, 0, 0, -14, 0, 0, 0, 0, 62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 129, 130, 31, 73, 131, 132, 0, 74, 133, 134, 75, 32, 11, 135, 76, 136, 15, 0, 0, 0, 0, 138,
         // State 60
-        0, 0, 0, 0, 0, 0, -15, 0, 0, 0, 0, 65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 129, 130, 31, 73, 131, 132, 0, 74, 133, 134, 75, 32, 11, 135, 76, 136, 15, 0, 0, 0, 0, 138,
         // State 61
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -4, 0, 0, -4, 0, -4, 0, 0, 0, 0, 0, 0, 0, 0,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 129, 130, 31, 73, 131, 132, 0, 74, 133, 134, 75, 32, 11, 135, 76, 136, 15, 0, 0, 0, 0, 138,
         // State 62
-        0, 0, 0, 0, 0, 0, -84, 0, 0, 0, 0, -84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        139, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 63
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -68, 0, 0, -68, 0, -68, 0, 0, 0, 0, 0, 0, 0, 0,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 66, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138,
         // State 64
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -5, 0, 0, -5, 0, -5, 0, 0, 0, 0, 0, 0, 0, 0,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 129, 130, 31, 73, 131, 132, 0, 74, 133, 134, 75, 32, 11, 135, 76, 136, 15, 0, 0, 0, 0, 138,
         // State 65
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -67, 0, 0, -67, 0, -67, 0, 0, 0, 0, 0, 0, 0, 0,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 129, 130, 31, 73, 131, 132, 0, 74, 133, 134, 75, 32, 11, 135, 76, 136, 15, 0, 0, 0, 0, 138,
         // State 66
-        0, -39, 0, 0, -39, 0, -39, 0, 0, 0, 0, -39, 0, 0, 0, 0, -39, -39, -39, 0, -39, -39, -39, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 129, 130, 31, 73, 131, 132, 0, 74, 133, 134, 75, 32, 11, 135, 76, 136, 15, 0, 0, 0, 0, 138,
         // State 67
-        0, 0, 0, 0, 0, 0, -37, 0, 0, 0, 0, -37, 0, 0, 0, 0, -37, 0, 0, 0, 0, 0, 0, 0, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -37, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0,
         // State 68
-        -107, 0, 0, -107, 0, -107, 0, -107, 0, 0, 0, 0, -107, 0, 0, 0, -107, 0, 0, 0, 0, 0, 0, 0, 0, -107, -107, -107, 0, -107, -107, -107, -107, -107, -107, -107, -107, 0, -107, 0, -107, -107, -107,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138,
         // State 69
-        -101, 0, 0, -101, 0, -101, 0, -101, 0, 0, 0, 0, -101, 0, 0, 0, -101, 0, 0, 0, 0, 0, 0, 0, 0, -101, -101, -101, 0, -101, -101, -101, -101, -101, -101, -101, -101, 0, -101, 0, -101, -101, -101,
+        139, 140, 82, 141, 142, 124, 0, 0, 125, 0, 0, 30, 0, 126, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 129, 130, 31, 73, 131, 132, 0, 74, 133, 134, 75, 32, 11, 135, 76, 136, 15, 0, 0, 0, 0, 138,
         // State 70
-        -102, 0, 0, -102, 0, -102, 0, -102, 0, 0, 0, 0, -102, 0, 0, 0, -102, 0, 0, 0, 0, 0, 0, 0, 0, -102, -102, -102, 0, -102, -102, -102, -102, -102, -102, -102, -102, 0, -102, 0, -102, -102, -102,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -113, 0, 0, 0, -113, 0, 0, -113, 0, -113, 0, -113, 0, 0, 0, 0, 0, 0, 0,
         // State 71
-        0, 0, 0, 0, -31, 0, -31, 0, 0, 0, 0, -31, 0, 0, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -114, 0, 0, 0, -114, 0, 0, -114, 0, -114, 0, -114, 0, 0, 0, 0, 0, 0, 0,
         // State 72
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, -159, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -159, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 73
-        -103, 0, 0, -103, 0, -103, 0, -103, 0, 0, 0, 0, -103, 0, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, -103, -103, -103, 0, -103, -103, -103, -103, -103, -103, -103, -103, 0, -103, 0, -103, -103, -103,
+        0, 0, -158, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -158, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 74
-        0, -91, -91, 0, -91, 0, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -91, 0, 0, 0, 0, 0,
+        0, 0, -157, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -157, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 75
-        0, -89, -89, 0, -89, 29, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, 30, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -89, 0, 0, 0, 0, 0,
+        0, 0, -156, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -156, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 76
-        0, -90, -90, 0, -90, 0, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, 0, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -90, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -115, 0, 0, 0, -115, 0, 0, -115, 0, -115, 0, -115, 0, 0, 0, 0, 0, 0, 0,
         // State 77
-        -105, 0, 0, -105, 0, -105, 0, -105, 0, 0, 0, 0, -105, 0, 0, 0, -105, 0, 0, 0, 0, 0, 0, 0, 0, -105, -105, -105, 0, -105, -105, -105, -105, -105, -105, -105, -105, 0, -105, 0, -105, -105, -105,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -116, 0, 0, 0, -116, 0, 0, -116, 0, -116, 0, -116, 0, 0, 0, 0, 0, 0, 0,
         // State 78
-        -106, 0, 0, -106, 0, -106, 0, -106, 0, 0, 0, 0, -106, 0, 0, 0, -106, 0, 0, 0, 0, 0, 0, 0, 0, -106, -106, -106, 0, -106, -106, -106, -106, -106, -106, -106, -106, 0, -106, 0, -106, -106, -106,
+        0, 0, -161, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 79
-        0, -29, 0, 0, -29, 0, -29, 0, 0, -29, 0, -29, -29, 0, 0, 0, -29, -29, -29, 0, -29, -29, -29, 0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -29, 0, 0, 0, 0, 0,
+        0, 0, -120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 80
-        0, 0, 0, 0, 0, 0, -57, 0, 0, 0, 0, -57, 0, 0, 0, 0, -57, 0, 0, 0, 0, 0, 0, 0, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 81
-        0, -117, -117, 0, -117, 0, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, 0, -117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -117, 0, 0, 0, 0, 0,
+        -97, 0, -97, 0, 0, 0, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, 0, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -97, -97, -97, -97, -97, 0,
         // State 82
-        0, -33, 0, 0, -33, 0, -33, 0, 0, 0, 0, -33, 0, 0, 0, 0, -33, 0, 0, 0, -33, 0, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -33, 0, 0, 0, 0, 0,
+        0, 0, -160, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -160, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0,
         // State 83
-        -104, 0, 0, -104, 0, -104, 0, -104, 0, 0, 0, 0, -104, 0, 0, 0, -104, 0, 0, 0, 0, 0, 0, 0, 0, -104, -104, -104, 0, -104, -104, -104, -104, -104, -104, -104, -104, 0, -104, 0, -104, -104, -104,
+        0, 0, -121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 84
-        -108, 0, 0, -108, 0, -108, 0, -108, 0, 0, 0, 0, -108, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, -108, -108, -108, 0, -108, -108, -108, -108, -108, -108, -108, -108, 0, -108, 0, -108, -108, -108,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 85
-        0, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41, -41, -41,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -22, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 86
-        0, 0, 0, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -43, -43, -43,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -111, 0, 0, 0, -111, 0, 0, -111, 0, -111, 0, -111, 0, 0, 0, 0, 0, -111, 0,
         // State 87
-        0, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -40, -40, -40,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -23, 0, 0, 0, 0, 96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 88
-        0, 0, 0, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42, -42, -42,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -4, 0, 0, 0, -4, 0, 0, -4, 0, -4, 0, -4, 0, 0, 0, 0, 0, 0, 0,
         // State 89
-        -65, 0, 0, -65, 0, -65, 0, -65, 0, 0, 0, 0, -65, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, -65, -65, -65, 0, -65, -65, -65, -65, -65, -65, -65, -65, 0, -65, 0, -65, -65, -65,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -119, 0, 0, 0, 0, -119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 90
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -96, 0, 0, 0, -96, 0, 0, -96, 0, -96, 0, -96, 0, 0, 0, 0, 0, 0, 0,
         // State 91
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, -160, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -160, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 92
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -112, 0, 0, 0, -112, 0, 0, -112, 0, -112, 0, -112, 0, 0, 0, 0, 0, -112, 0,
         // State 93
-        0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 94
-        0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 95
-        0, 0, 0, 0, 0, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -5, 0, 0, 0, -5, 0, 0, -5, 0, -5, 0, -5, 0, 0, 0, 0, 0, 0, 0,
         // State 96
-        -52, 0, 0, -52, 0, -52, 0, -52, 0, 0, 0, 0, -52, 0, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, 0, -52, 0, -52, -52, -52,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -95, 0, 0, 0, -95, 0, 0, -95, 0, -95, 0, -95, 0, 0, 0, 0, 0, 0, 0,
         // State 97
-        0, -70, -70, 0, -70, 0, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -57, 0, -57, -57, 0, 0, -57, 0, 0, 0, 0, -57, 0, 0, 0, 0, 0, 0, 0, -57, -57, -57, 0, -57, 0, -57, -57, -57, -57, 0, 0, -57, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57, 0, -57, -57, 0,
         // State 98
-        0, -66, -66, 0, -66, 0, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, 0, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -66, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53, 0, 0, 0, 0, -53, 0, 0, 0, 0, 0, 0, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53, -53, 0,
         // State 99
-        -6, 0, 0, -6, 0, -6, 0, -6, 0, 0, 0, 0, -6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -6, -6, -6,
+        -149, -149, -149, -149, -149, -149, 0, 0, -149, 0, 0, -149, 0, -149, 0, 0, 0, 0, -149, 0, 0, 0, 0, 0, 0, -149, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -149, -149, -149, -149, -149, -149, 0, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, 0, 0, 0, -149, -149,
         // State 100
-        -7, 0, 0, -7, 0, -7, 0, -7, 0, 0, 0, 0, -7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -7, -7, -7,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -47, 0, 0, -47, 0, 0, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47, 0, -47, -47, 0,
         // State 101
-        -8, 0, 0, -8, 0, -8, 0, -8, 0, 0, 0, 0, -8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -8, -8, -8,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0, 0, -41, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41, -41, 0,
         // State 102
-        -19, 0, 0, -19, 0, -19, 0, -19, 0, 0, 0, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19, -19, -19,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -45, 0, 0, -45, 0, 0, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -45, 0, -45, -45, 0,
         // State 103
-        -18, 0, 0, -18, 0, -18, 0, -18, 0, 0, 0, 0, -18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -18, -18, -18,
+        0, 0, 0, 0, 0, 0, -127, -127, -127, -127, -127, 0, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, 0, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, 0, -127, -127, -127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -127, -127, -127, -127, 0,
         // State 104
-        -22, 0, 0, -22, 0, -22, 0, -22, 0, 0, 0, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -22, -22, -22,
+        -142, -142, -142, -142, -142, -142, 0, 0, -142, 0, 0, -142, 0, -142, 0, 0, 0, 0, -142, 0, 0, 0, 0, 0, 0, -142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -142, -142, -142, -142, -142, -142, 0, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, 0, 0, 0, -142, -142,
         // State 105
-        -20, 0, 0, -20, 0, -20, 0, -20, 0, 0, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, -20, -20,
+        -143, -143, -143, -143, -143, -143, 0, 0, -143, 0, 0, -143, 0, -143, 0, 0, 0, 0, -143, 0, 0, 0, 0, 0, 0, -143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -143, -143, -143, -143, -143, -143, 0, -143, -143, -143, -143, -143, -143, -143, -143, -143, -143, 0, 0, 0, -143, -143,
         // State 106
-        -21, 0, 0, -21, 0, -21, 0, -21, 0, 0, 0, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -21, -21, -21,
+        0, 0, 0, 0, 0, 0, 0, 0, -43, -43, 0, 0, -43, 0, 0, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -43, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -43, 0, -43, -43, 0,
         // State 107
-        -23, 0, 0, -23, 0, -23, 0, -23, 0, 0, 0, 0, -23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -23, -23, -23,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 108
-        -25, 0, 0, -25, 0, -25, 0, -25, 0, 0, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -25, -25, -25,
+        -144, -144, -144, -144, -144, -144, 0, 0, -144, 0, 0, -144, 0, -144, 0, 0, 0, 0, -144, 0, 0, 0, 0, 0, 0, -144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -144, -144, -144, -144, -144, -144, 0, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, 0, 0, 0, -144, -144,
         // State 109
-        -27, 0, 0, -27, 0, -27, 0, -27, 0, 0, 0, 0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -27, -27, -27,
+        0, 0, 0, 0, 0, 0, -126, -126, -126, -126, -126, 0, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, 0, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, 0, -126, -126, -126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -126, -126, -126, -126, 0,
         // State 110
-        -24, 0, 0, -24, 0, -24, 0, -24, 0, 0, 0, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -24, -24, -24,
+        0, 0, 0, 0, 0, 0, -124, -124, -124, -124, -124, 43, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, 0, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, 44, -124, -124, -124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -124, -124, -124, -124, 0,
         // State 111
-        -26, 0, 0, -26, 0, -26, 0, -26, 0, 0, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26, -26, -26,
+        0, 0, 0, 0, 0, 0, -125, -125, -125, -125, -125, 0, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, 0, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, 0, -125, -125, -125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -125, -125, -125, -125, 0,
         // State 112
-        0, -118, -118, 0, -118, 0, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, 0, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -118, 0, 0, 0, 0, 0,
+        -146, -146, -146, -146, -146, -146, 0, 0, -146, 0, 0, -146, 0, -146, 0, 0, 0, 0, -146, 0, 0, 0, 0, 0, 0, -146, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -146, -146, -146, -146, -146, -146, 0, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, 0, 0, 0, -146, -146,
         // State 113
-        -64, 0, 0, -64, 0, -64, 0, -64, 0, 0, 0, 0, -64, 0, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, -64, -64, -64, 0, -64, -64, -64, -64, -64, -64, -64, -64, 0, -64, 0, -64, -64, -64,
+        -147, -147, -147, -147, -147, -147, 0, 0, -147, 0, 0, -147, 0, -147, 0, 0, 0, 0, -147, 0, 0, 0, 0, 0, 0, -147, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -147, -147, -147, -147, -147, -147, 0, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, 0, 0, 0, -147, -147,
         // State 114
-        -109, 0, 0, -109, 0, -109, 0, -109, 0, 0, 0, 0, -109, 0, 0, 0, -109, 0, 0, 0, 0, 0, 0, 0, 0, -109, -109, -109, 0, -109, -109, -109, -109, -109, -109, -109, -109, 0, -109, 0, -109, -109, -109,
+        -148, -148, -148, -148, -148, -148, 0, 0, -148, 0, 0, -148, 0, -148, 0, 0, 0, 0, -148, 0, 0, 0, 0, 0, 0, -148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -148, -148, -148, -148, -148, -148, 0, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, 0, 0, 0, -148, -148,
         // State 115
-        -51, 0, 0, -51, 0, -51, 0, -51, 0, 0, 0, 0, -51, 0, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, 0, -51, 0, -51, -51, -51,
+        0, 0, 0, 0, 0, 0, -39, 0, -39, -39, 0, 0, -39, 0, 0, -39, 0, -39, -39, 0, 0, 0, 0, 0, 0, -39, -39, -39, 0, -39, 0, -39, -39, -39, -39, 0, 0, -39, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, -39, -39, 0,
         // State 116
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -85, 0, 0, 0, 0, -85, 0, 0, 0, 0, 0, 0, 0, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -85, 0,
         // State 117
-        -11, 0, 0, -11, 0, -11, 0, -11, 0, 0, 0, 0, -11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -11, -11, -11,
+        0, 0, 0, 0, 0, 0, -163, -163, -163, -163, -163, 0, -163, -163, -163, -163, -163, -163, -163, -163, 45, 46, -163, -163, 0, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, 0, -163, -163, -163, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -163, -163, -163, -163, 0,
         // State 118
-        -9, 0, 0, -9, 0, -9, 0, -9, 0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9, -9, -9,
+        0, 0, 0, 0, 0, 0, -49, 0, -49, -49, 0, 0, -49, 0, 0, 0, 0, -49, 0, 0, 0, 0, 0, 0, 0, -49, 0, 0, 0, 0, 0, -49, 0, 0, 0, 0, 0, -49, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49, 0, -49, -49, 0,
         // State 119
-        -10, 0, 0, -10, 0, -10, 0, -10, 0, 0, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10, -10, -10,
+        -145, -145, -145, -145, -145, -145, 0, 0, -145, 0, 0, -145, 0, -145, 0, 0, 0, 0, -145, 0, 0, 0, 0, 0, 0, -145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -145, -145, -145, -145, -145, -145, 0, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, 0, 0, 0, -145, -145,
         // State 120
-        -12, 0, 0, -12, 0, -12, 0, -12, 0, 0, 0, 0, -12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -12, -12, -12,
+        0, 0, 0, 0, 0, 0, -55, 0, -55, -55, 0, 0, -55, 0, 0, 0, 0, -55, 0, 0, 0, 0, 0, 0, 0, -55, -55, 0, 0, -55, 0, -55, -55, -55, 0, 0, 0, -55, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -55, 0, -55, -55, 0,
         // State 121
-        -13, 0, 0, -13, 0, -13, 0, -13, 0, 0, 0, 0, -13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -13, -13, -13,
+        -150, -150, -150, -150, -150, -150, 0, 0, -150, 0, 0, -150, 0, -150, 0, 0, 0, 0, -150, 0, 0, 0, 0, 0, 0, -150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -150, -150, -150, -150, -150, -150, 0, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, 0, 0, 0, -150, -150,
         // State 122
-        0, 0, 0, 0, 0, 0, 141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -128, -128, -128, -128, -128, 0, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, 0, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, 0, -128, -128, -128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -128, -128, -128, -128, 0,
         // State 123
-        -77, 0, 0, -77, 0, -77, 0, -77, 0, 0, 0, 0, -77, 0, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, -77, -77, -77, 0, -77, -77, -77, -77, -77, -77, -77, -77, 0, -77, 0, -77, -77, -77,
+        -59, -59, -59, -59, -59, 0, 0, 0, 0, 0, 0, -59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 124
-        -76, 0, 0, -76, 0, -76, 0, -76, 0, 0, 0, 0, -76, 0, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, -76, -76, -76, 0, -76, -76, -76, -76, -76, -76, -76, -76, 0, -76, 0, -76, -76, -76,
+        -61, -61, -61, -61, -61, 0, 0, 0, 0, 0, 0, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 125
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -58, -58, -58, -58, -58, 0, 0, 0, 0, 0, 0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 126
-        -79, 0, 0, -79, 0, -79, 0, -79, 0, 0, 0, 0, -79, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, 0, -79, -79, -79, 0, -79, -79, -79, -79, -79, -79, -79, -79, 0, -79, 0, -79, -79, -79,
+        -60, -60, -60, -60, -60, 0, 0, 0, 0, 0, 0, -60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 127
-        0, -28, 0, 0, -28, 0, -28, 0, 0, -28, 0, -28, -28, 0, 0, 0, -28, -28, -28, 0, -28, -28, -28, 0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -28, 0, 0, 0, 0, 0,
+        -93, -93, -93, -93, -93, -93, 0, 0, -93, 0, 0, -93, 0, -93, 0, 0, 0, 0, -93, 0, 0, 0, 0, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, -93, -93, -93, -93, -93, 0, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, 0, 0, 0, -93, -93,
         // State 128
-        0, -35, -35, 0, -35, 0, -35, -35, 0, -35, 0, -35, -35, 0, -35, 0, -35, -35, -35, 0, -35, -35, -35, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0,
         // State 129
-        0, 0, 0, 0, -30, 0, -30, 0, 0, 0, 0, -30, 0, 0, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 130
-        0, -32, 0, 0, -32, 0, -32, 0, 0, 0, 0, -32, 0, 0, 0, 0, -32, 0, 0, 0, -32, 0, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -32, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 131
-        0, -34, -34, 0, -34, 0, -34, -34, 0, -34, 0, -34, -34, 0, -34, 0, -34, -34, -34, 0, -34, -34, -34, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -34, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 132
-        0, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, -36, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 133
-        0, -38, 0, 0, -38, 0, -38, 0, 0, 0, 0, -38, 0, 0, 0, 0, -38, -38, -38, 0, -38, -38, -38, 0, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -38, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 134
-        0, 0, 0, 0, 0, 0, -60, 0, 0, 0, 0, -60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 135
-        0, 0, 0, 0, 0, 0, 150, 0, 0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 136
-        0, -94, -94, 0, -94, 0, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -94, 0, 0, 0, 0, 0,
+        -80, -80, -80, -80, -80, -80, 0, 0, -80, 0, 0, -80, 0, -80, 0, 0, 0, 0, -80, 0, 0, 0, 0, 0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, 0, 0, 0, -80, -80,
         // State 137
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 151, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -62, -62, -62, -62, -62, 0, 0, 0, 0, 0, 0, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 138
-        -53, 0, 0, -53, 0, -53, 0, -53, 0, 0, 0, 0, -53, 0, 0, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, -53, -53, -53, 0, -53, -53, -53, -53, -53, -53, -53, -53, 0, -53, 0, -53, -53, -53,
+        0, 0, 0, 0, 0, 0, -154, -154, -154, -154, -154, 0, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, 0, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, 0, -154, -154, -154, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -154, -154, -154, -154, 0,
         // State 139
-        0, 0, 0, 0, 0, 0, -56, 0, 0, 0, 0, -56, 0, 0, 0, 0, -56, 0, 0, 0, 0, 0, 0, 0, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -78, -78, -78, -78, -78, 0, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, 0, -78, -78, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -78, -78, -78, -78, 0,
         // State 140
-        0, -95, -95, 0, -95, 0, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -95, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -98, -98, -98, -98, -98, 0, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, 0, -98, -98, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -98, -98, -98, -98, 0,
         // State 141
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -48, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -94, -94, -94, -94, -94, 0, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, 0, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, 0, -94, -94, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -94, -94, -94, -94, 0,
         // State 142
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 153, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -155, 0, 0, 0, -155, 0, 0, -155, 0, -155, 0, -155, 0, 0, 0, 0, 0, 0, 0,
         // State 143
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -110, 0, 0, 0, -110, 0, 0, -110, 0, -110, 0, -110, 0, 0, 0, 0, 0, -110, 0,
         // State 144
-        -99, 0, 0, -99, 0, -99, 0, -99, 0, 0, 0, 0, -99, 0, 0, 0, -99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -99, -99, -99,
+        -6, -6, -6, -6, -6, -6, 0, 0, -6, 0, 0, -6, 0, -6, 0, 0, 0, 0, -6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -6,
         // State 145
-        -100, 0, 0, -100, 0, -100, 0, -100, 0, 0, 0, 0, -100, 0, 0, 0, -100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -100, -100, -100,
+        -7, -7, -7, -7, -7, -7, 0, 0, -7, 0, 0, -7, 0, -7, 0, 0, 0, 0, -7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -7,
         // State 146
-        0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -8, -8, -8, -8, -8, -8, 0, 0, -8, 0, 0, -8, 0, -8, 0, 0, 0, 0, -8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -8,
         // State 147
-        -78, 0, 0, -78, 0, -78, 0, -78, 0, 0, 0, 0, -78, 0, 0, 0, -78, 0, 0, 0, 0, 0, 0, 0, 0, -78, -78, -78, 0, -78, -78, -78, -78, -78, -78, -78, -78, 0, -78, 0, -78, -78, -78,
+        -19, -19, -19, -19, -19, -19, 0, 0, -19, 0, 0, -19, 0, -19, 0, 0, 0, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19,
         // State 148
-        0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -20, -20, -20, -20, -20, -20, 0, 0, -20, 0, 0, -20, 0, -20, 0, 0, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20,
         // State 149
-        0, -93, -93, 0, -93, 0, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, 0, 0, 0, 0, 0,
+        -21, -21, -21, -21, -21, -21, 0, 0, -21, 0, 0, -21, 0, -21, 0, 0, 0, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -21,
         // State 150
-        0, -92, -92, 0, -92, 0, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, 0, 0, 0, 0, 0,
+        -27, -27, -27, -27, -27, -27, 0, 0, -27, 0, 0, -27, 0, -27, 0, 0, 0, 0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -27,
         // State 151
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 156, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -26, -26, -26, -26, -26, -26, 0, 0, -26, 0, 0, -26, 0, -26, 0, 0, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26,
         // State 152
-        -50, 0, 0, -50, 0, -50, 0, -50, 0, 0, 0, 0, -50, 0, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, -50, -50, -50, 0, -50, -50, -50, -50, -50, -50, -50, -50, 0, -50, 0, -50, -50, -50,
+        -30, -30, -30, -30, -30, -30, 0, 0, -30, 0, 0, -30, 0, -30, 0, 0, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30,
         // State 153
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -28, -28, -28, -28, -28, -28, 0, 0, -28, 0, 0, -28, 0, -28, 0, 0, 0, 0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -28,
         // State 154
-        0, 0, 0, 0, 0, 0, -61, 0, 0, 0, 0, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -29, -29, -29, -29, -29, -29, 0, 0, -29, 0, 0, -29, 0, -29, 0, 0, 0, 0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -29,
         // State 155
-        -54, 0, 0, -54, 0, -54, 0, -54, 0, 0, 0, 0, -54, 0, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, 0, -54, -54, -54, 0, -54, -54, -54, -54, -54, -54, -54, -54, 0, -54, 0, -54, -54, -54,
+        -31, -31, -31, -31, -31, -31, 0, 0, -31, 0, 0, -31, 0, -31, 0, 0, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31,
         // State 156
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49, 0, 0, 0, 0,
+        -33, -33, -33, -33, -33, -33, 0, 0, -33, 0, 0, -33, 0, -33, 0, 0, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -33,
         // State 157
-        0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -35, -35, -35, -35, -35, -35, 0, 0, -35, 0, 0, -35, 0, -35, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35,
         // State 158
-        -101, 0, 0, -101, 0, -101, 0, -101, 0, 0, 0, 0, -101, 0, 0, 0, -101, 0, 0, 0, 0, 0, 0, 0, 0, -101, -101, -101, 47, -101, -101, -101, -101, -101, -101, -101, -101, 0, -101, 0, -101, -101, -101,
+        -32, -32, -32, -32, -32, -32, 0, 0, -32, 0, 0, -32, 0, -32, 0, 0, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -32,
         // State 159
-        -97, 0, 0, -97, 0, -97, 0, -97, 0, 0, 0, 0, -97, 0, 0, 0, -97, 0, 0, 0, 0, 0, 0, 0, 0, -97, -97, -97, 0, -97, -97, -97, -97, -97, -97, -97, -97, 0, -97, 0, -97, -97, -97,
+        -34, -34, -34, -34, -34, -34, 0, 0, -34, 0, 0, -34, 0, -34, 0, 0, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -34,
         // State 160
-        -71, 0, 0, -71, 0, -71, 0, -71, 0, 0, 0, 0, -71, 0, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, -71, -71, -71, 0, -71, -71, -71, -71, -71, -71, -71, -71, 0, -71, 0, -71, -71, -71,
+        -36, -36, -36, -36, -36, -36, 0, 0, -36, 0, 0, -36, 0, -36, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36,
         // State 161
-        0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -37, -37, -37, -37, -37, -37, 0, 0, -37, 0, 0, -37, 0, -37, 0, 0, 0, 0, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -37,
         // State 162
-        -75, 0, 0, -75, 0, -75, 0, -75, 0, 0, 0, 0, -75, 0, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, -75, -75, -75, 0, -75, -75, -75, -75, -75, -75, -75, -75, 0, -75, 0, -75, -75, -75,
+        0, 0, 0, 0, 0, 0, -164, -164, -164, -164, -164, 0, -164, -164, -164, -164, -164, -164, -164, -164, 45, 46, -164, -164, 0, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, 0, -164, -164, -164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -164, -164, -164, -164, 0,
         // State 163
-        -73, 0, 0, -73, 0, -73, 0, -73, 0, 0, 0, 0, -73, 0, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, -73, -73, -73, 0, -73, -73, -73, -73, -73, -73, -73, -73, 0, -73, 0, -73, -73, -73,
+        -92, -92, -92, -92, -92, -92, 0, 0, -92, 0, 0, -92, 0, -92, 0, 0, 0, 0, -92, 0, 0, 0, 0, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, -92, -92, -92, -92, -92, 0, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, 0, 0, 0, -92, -92,
         // State 164
-        -74, 0, 0, -74, 0, -74, 0, -74, 0, 0, 0, 0, -74, 0, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, -74, -74, -74, 0, -74, -74, -74, -74, -74, -74, -74, -74, 0, -74, 0, -74, -74, -74,
+        -151, -151, -151, -151, -151, -151, 0, 0, -151, 0, 0, -151, 0, -151, 0, 0, 0, 0, -151, 0, 0, 0, 0, 0, 0, -151, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -151, -151, -151, -151, -151, -151, 0, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, 0, 0, 0, -151, -151,
         // State 165
-        -98, 0, 0, -98, 0, -98, 0, -98, 0, 0, 0, 0, -98, 0, 0, 0, -98, 0, 0, 0, 0, 0, 0, 0, 0, -98, -98, -98, 0, -98, -98, -98, -98, -98, -98, -98, -98, 0, -98, 0, -98, -98, -98,
+        -79, -79, -79, -79, -79, -79, 0, 0, -79, 0, 0, -79, 0, -79, 0, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, 0, 0, 0, -79, -79,
         // State 166
-        -72, 0, 0, -72, 0, -72, 0, -72, 0, 0, 0, 0, -72, 0, 0, 0, -72, 0, 0, 0, 0, 0, 0, 0, 0, -72, -72, -72, 0, -72, -72, -72, -72, -72, -72, -72, -72, 0, -72, 0, -72, -72, -72,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 203, 0, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 167
+        -13, -13, -13, -13, -13, -13, 0, 0, -13, 0, 0, -13, 0, -13, 0, 0, 0, 0, -13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -13,
+        // State 168
+        -11, -11, -11, -11, -11, -11, 0, 0, -11, 0, 0, -11, 0, -11, 0, 0, 0, 0, -11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -11,
+        // State 169
+        -9, -9, -9, -9, -9, -9, 0, 0, -9, 0, 0, -9, 0, -9, 0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9,
+        // State 170
+        -10, -10, -10, -10, -10, -10, 0, 0, -10, 0, 0, -10, 0, -10, 0, 0, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10,
+        // State 171
+        -12, -12, -12, -12, -12, -12, 0, 0, -12, 0, 0, -12, 0, -12, 0, 0, 0, 0, -12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -12,
+        // State 172
+        -16, -16, -16, -16, -16, -16, 0, 0, -16, 0, 0, -16, 0, -16, 0, 0, 0, 0, -16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -16,
+        // State 173
+        -18, -18, -18, -18, -18, -18, 0, 0, -18, 0, 0, -18, 0, -18, 0, 0, 0, 0, -18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -18,
+        // State 174
+        -17, -17, -17, -17, -17, -17, 0, 0, -17, 0, 0, -17, 0, -17, 0, 0, 0, 0, -17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -17,
+        // State 175
+        -15, -15, -15, -15, -15, -15, 0, 0, -15, 0, 0, -15, 0, -15, 0, 0, 0, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15,
+        // State 176
+        -14, -14, -14, -14, -14, -14, 0, 0, -14, 0, 0, -14, 0, -14, 0, 0, 0, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -14,
+        // State 177
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 205, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 178
+        -105, -105, -105, -105, -105, -105, 0, 0, -105, 0, 0, -105, 0, -105, 0, 0, 0, 0, -105, 0, 0, 0, 0, 0, 0, -105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -105, -105, -105, -105, -105, -105, 0, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, 0, 0, 0, -105, -105,
+        // State 179
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 180
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 181
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 182
+        -104, -104, -104, -104, -104, -104, 0, 0, -104, 0, 0, -104, 0, -104, 0, 0, 0, 0, -104, 0, 0, 0, 0, 0, 0, -104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -104, -104, -104, -104, -104, -104, 0, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, 0, 0, 0, -104, -104,
+        // State 183
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 213, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 184
+        -107, -107, -107, -107, -107, -107, 0, 0, -107, 0, 0, -107, 0, -107, 0, 0, 0, 0, -107, 0, 0, 0, 0, 0, 0, -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -107, -107, -107, -107, -107, -107, 0, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, 0, 0, 0, -107, -107,
+        // State 185
+        0, 0, 0, 0, 0, 0, -38, 0, -38, -38, 0, 0, -38, 0, 0, -38, 0, -38, -38, 0, 0, 0, 0, 0, 0, -38, -38, -38, 0, -38, 0, -38, -38, -38, -38, 0, 0, -38, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -38, 0, -38, -38, 0,
+        // State 186
+        0, 0, 0, 0, 0, 0, -51, -51, -51, -51, 0, 0, -51, -51, 0, -51, 0, -51, -51, 0, 0, 0, -51, 0, 0, -51, -51, -51, 0, -51, 0, -51, -51, -51, -51, 0, 0, -51, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51, 0, -51, -51, 0,
+        // State 187
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, -40, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -40, -40, 0,
+        // State 188
+        0, 0, 0, 0, 0, 0, 0, 0, -42, -42, 0, 0, -42, 0, 0, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42, 0, -42, -42, 0,
+        // State 189
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -44, 0, 0, -44, 0, 0, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -44, 0, -44, -44, 0,
+        // State 190
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -46, 0, 0, -46, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46, 0, -46, -46, 0,
+        // State 191
+        0, 0, 0, 0, 0, 0, -48, 0, -48, -48, 0, 0, -48, 0, 0, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, -48, 0, 0, 0, 0, 0, -48, 0, 0, 0, 0, 0, -48, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -48, 0, -48, -48, 0,
+        // State 192
+        0, 0, 0, 0, 0, 0, -50, -50, -50, -50, 0, 0, -50, -50, 0, -50, 0, -50, -50, 0, 0, 0, -50, 0, 0, -50, -50, -50, 0, -50, 0, -50, -50, -50, -50, 0, 0, -50, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -50, 0, -50, -50, 0,
+        // State 193
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52, 0, 0, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52, -52, 0,
+        // State 194
+        0, 0, 0, 0, 0, 0, -54, 0, -54, -54, 0, 0, -54, 0, 0, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, -54, -54, 0, 0, -54, 0, -54, -54, -54, 0, 0, 0, -54, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -54, 0, -54, -54, 0,
+        // State 195
+        0, 0, 0, 0, 0, 0, -56, 0, -56, -56, 0, 0, -56, 0, 0, 0, 0, -56, 0, 0, 0, 0, 0, 0, 0, -56, -56, -56, 0, -56, 0, -56, -56, -56, -56, 0, 0, -56, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -56, 0, -56, -56, 0,
+        // State 196
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -88, 0, 0, 0, 0, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 197
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 216, 0, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 198
+        0, 0, 0, 0, 0, 0, -131, -131, -131, -131, -131, 0, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, 0, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, 0, -131, -131, -131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -131, -131, -131, -131, 0,
+        // State 199
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 217, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 200
+        0, 0, 0, 0, 0, 0, -133, -133, -133, -133, -133, 0, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, 0, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, 0, -133, -133, -133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -133, -133, -133, -133, 0,
+        // State 201
+        0, 0, 0, 0, 0, 0, -132, -132, -132, -132, -132, 0, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, 0, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, 0, -132, -132, -132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -132, -132, -132, -132, 0,
+        // State 202
+        -81, -81, -81, -81, -81, -81, 0, 0, -81, 0, 0, -81, 0, -81, 0, 0, 0, 0, -81, 0, 0, 0, 0, 0, 0, -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81, -81, -81, -81, -81, -81, 0, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, 0, 0, 0, -81, -81,
+        // State 203
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -84, 0, 0, 0, 0, -84, 0, 0, 0, 0, 0, 0, 0, -84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -84, 0,
+        // State 204
+        0, 0, 0, 0, 0, 0, -134, -134, -134, -134, -134, 0, -134, -134, -134, -134, -134, -134, -134, -134, -134, -134, -134, -134, 0, -134, -134, -134, -134, -134, -134, -134, -134, -134, -134, -134, 0, -134, -134, -134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -134, -134, -134, -134, 0,
+        // State 205
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -67, 0,
+        // State 206
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 219, 0,
+        // State 207
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -66, 0,
+        // State 208
+        -109, -109, -109, -109, -109, -109, 0, 0, -109, 0, 0, -109, 0, -109, 0, 0, 0, 0, -109, 0, 0, 0, 0, 0, 0, -109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -109, -109, -109, -109, -109, -109, 0, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, 0, 0, 0, -109, -109,
+        // State 209
+        -140, -140, -140, -140, -140, -140, 0, 0, -140, 0, 0, -140, 0, -140, 0, 0, 0, 0, -140, 0, 0, 0, 0, 0, 0, -140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -140,
+        // State 210
+        -141, -141, -141, -141, -141, -141, 0, 0, -141, 0, 0, -141, 0, -141, 0, 0, 0, 0, -141, 0, 0, 0, 0, 0, 0, -141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -141,
+        // State 211
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 212
+        -106, -106, -106, -106, -106, -106, 0, 0, -106, 0, 0, -106, 0, -106, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, -106, -106, -106, -106, -106, 0, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, 0, 0, 0, -106, -106,
+        // State 213
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 214
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 215
+        0, 0, 0, 0, 0, 0, -130, -130, -130, -130, -130, 0, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, 0, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, 0, -130, -130, -130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -130, -130, -130, -130, 0,
+        // State 216
+        0, 0, 0, 0, 0, 0, -129, -129, -129, -129, -129, 0, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, 0, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, 0, -129, -129, -129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -129, -129, -129, -129, 0,
+        // State 217
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 223, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 218
+        -72, -72, -72, -72, -72, -72, 0, 0, -72, 0, 0, -72, 0, -72, 0, 0, 0, 0, -72, 0, 0, 0, 0, 0, 0, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72, -72, -72, -72, -72, -72, 0, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, 0, 0, 0, -72, -72,
+        // State 219
+        -108, -108, -108, -108, -108, -108, 0, 0, -108, 0, 0, -108, 0, -108, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, -108, -108, -108, -108, -108, 0, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, 0, 0, 0, -108, -108,
+        // State 220
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 221
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -89, 0, 0, 0, 0, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 222
+        -82, -82, -82, -82, -82, -82, 0, 0, -82, 0, 0, -82, 0, -82, 0, 0, 0, 0, -82, 0, 0, 0, 0, 0, 0, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82, -82, -82, -82, -82, -82, 0, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, 0, 0, 0, -82, -82,
+        // State 223
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -68, 0,
+        // State 224
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -69, 0,
+        // State 225
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 226
+        -142, -142, -142, -142, -142, -142, 0, 0, -142, 0, 0, -142, 0, -142, 0, 0, 0, 0, -142, 0, 0, 0, 0, 0, 0, -142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -142, -142, -142, -142, -142, -142, 68, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, 0, 0, 0, -142, -142,
+        // State 227
+        -136, -136, -136, -136, -136, -136, 0, 0, -136, 0, 0, -136, 0, -136, 0, 0, 0, 0, -136, 0, 0, 0, 0, 0, 0, -136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -136, -136, -136, -136, -136, -136, 0, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, 0, 0, 0, -136, -136,
+        // State 228
+        -138, -138, -138, -138, -138, -138, 0, 0, -138, 0, 0, -138, 0, -138, 0, 0, 0, 0, -138, 0, 0, 0, 0, 0, 0, -138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -138, -138, -138, -138, -138, -138, 0, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, 0, 0, 0, -138, -138,
+        // State 229
+        -99, -99, -99, -99, -99, -99, 0, 0, -99, 0, 0, -99, 0, -99, 0, 0, 0, 0, -99, 0, 0, 0, 0, 0, 0, -99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -99, -99, -99, -99, -99, -99, 0, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, 0, 0, 0, -99, -99,
+        // State 230
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, 0,
+        // State 231
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 232
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 233
+        -103, -103, -103, -103, -103, -103, 0, 0, -103, 0, 0, -103, 0, -103, 0, 0, 0, 0, -103, 0, 0, 0, 0, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -103, -103, -103, -103, -103, -103, 0, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, 0, 0, 0, -103, -103,
+        // State 234
+        -101, -101, -101, -101, -101, -101, 0, 0, -101, 0, 0, -101, 0, -101, 0, 0, 0, 0, -101, 0, 0, 0, 0, 0, 0, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -101, -101, -101, -101, -101, -101, 0, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, 0, 0, 0, -101, -101,
+        // State 235
+        -102, -102, -102, -102, -102, -102, 0, 0, -102, 0, 0, -102, 0, -102, 0, 0, 0, 0, -102, 0, 0, 0, 0, 0, 0, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -102, -102, -102, -102, -102, -102, 0, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, 0, 0, 0, -102, -102,
+        // State 236
+        -137, -137, -137, -137, -137, -137, 0, 0, -137, 0, 0, -137, 0, -137, 0, 0, 0, 0, -137, 0, 0, 0, 0, 0, 0, -137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -137, -137, -137, -137, -137, -137, 0, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, 0, 0, 0, -137, -137,
+        // State 237
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0,
+        // State 238
+        -100, -100, -100, -100, -100, -100, 0, 0, -100, 0, 0, -100, 0, -100, 0, 0, 0, 0, -100, 0, 0, 0, 0, 0, 0, -100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -100, -100, -100, -100, -100, -100, 0, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, 0, 0, 0, -100, -100,
     ];
     fn __action(state: i16, integer: usize) -> i16 {
-        __ACTION[(state as usize) * 43 + integer]
+        __ACTION[(state as usize) * 62 + integer]
     }
     const __EOF_ACTION: &[i16] = &[
         // State 0
         0,
         // State 1
-        -44,
+        -63,
         // State 2
         0,
         // State 3
@@ -492,7 +645,7 @@ mod __parse__module {
         // State 47
         0,
         // State 48
-        -80,
+        0,
         // State 49
         0,
         // State 50
@@ -500,7 +653,7 @@ mod __parse__module {
         // State 51
         0,
         // State 52
-        -81,
+        0,
         // State 53
         0,
         // State 54
@@ -522,11 +675,11 @@ mod __parse__module {
         // State 62
         0,
         // State 63
-        -68,
+        0,
         // State 64
         0,
         // State 65
-        -67,
+        0,
         // State 66
         0,
         // State 67
@@ -536,9 +689,9 @@ mod __parse__module {
         // State 69
         0,
         // State 70
-        0,
+        -113,
         // State 71
-        0,
+        -114,
         // State 72
         0,
         // State 73
@@ -548,9 +701,9 @@ mod __parse__module {
         // State 75
         0,
         // State 76
-        0,
+        -115,
         // State 77
-        0,
+        -116,
         // State 78
         0,
         // State 79
@@ -576,7 +729,7 @@ mod __parse__module {
         // State 89
         0,
         // State 90
-        0,
+        -96,
         // State 91
         0,
         // State 92
@@ -588,7 +741,7 @@ mod __parse__module {
         // State 95
         0,
         // State 96
-        -52,
+        -95,
         // State 97
         0,
         // State 98
@@ -626,7 +779,7 @@ mod __parse__module {
         // State 114
         0,
         // State 115
-        -51,
+        0,
         // State 116
         0,
         // State 117
@@ -668,7 +821,7 @@ mod __parse__module {
         // State 135
         0,
         // State 136
-        0,
+        -80,
         // State 137
         0,
         // State 138
@@ -680,7 +833,7 @@ mod __parse__module {
         // State 141
         0,
         // State 142
-        0,
+        -155,
         // State 143
         0,
         // State 144
@@ -726,145 +879,357 @@ mod __parse__module {
         // State 164
         0,
         // State 165
-        0,
+        -79,
         // State 166
         0,
+        // State 167
+        0,
+        // State 168
+        0,
+        // State 169
+        0,
+        // State 170
+        0,
+        // State 171
+        0,
+        // State 172
+        0,
+        // State 173
+        0,
+        // State 174
+        0,
+        // State 175
+        0,
+        // State 176
+        0,
+        // State 177
+        0,
+        // State 178
+        0,
+        // State 179
+        0,
+        // State 180
+        0,
+        // State 181
+        0,
+        // State 182
+        0,
+        // State 183
+        0,
+        // State 184
+        0,
+        // State 185
+        0,
+        // State 186
+        0,
+        // State 187
+        0,
+        // State 188
+        0,
+        // State 189
+        0,
+        // State 190
+        0,
+        // State 191
+        0,
+        // State 192
+        0,
+        // State 193
+        0,
+        // State 194
+        0,
+        // State 195
+        0,
+        // State 196
+        0,
+        // State 197
+        0,
+        // State 198
+        0,
+        // State 199
+        0,
+        // State 200
+        0,
+        // State 201
+        0,
+        // State 202
+        0,
+        // State 203
+        0,
+        // State 204
+        0,
+        // State 205
+        0,
+        // State 206
+        0,
+        // State 207
+        0,
+        // State 208
+        0,
+        // State 209
+        0,
+        // State 210
+        0,
+        // State 211
+        0,
+        // State 212
+        0,
+        // State 213
+        0,
+        // State 214
+        0,
+        // State 215
+        0,
+        // State 216
+        0,
+        // State 217
+        0,
+        // State 218
+        0,
+        // State 219
+        0,
+        // State 220
+        0,
+        // State 221
+        0,
+        // State 222
+        0,
+        // State 223
+        0,
+        // State 224
+        0,
+        // State 225
+        0,
+        // State 226
+        0,
+        // State 227
+        0,
+        // State 228
+        0,
+        // State 229
+        0,
+        // State 230
+        0,
+        // State 231
+        0,
+        // State 232
+        0,
+        // State 233
+        0,
+        // State 234
+        0,
+        // State 235
+        0,
+        // State 236
+        0,
+        // State 237
+        0,
+        // State 238
+        0,
     ];
     fn __goto(state: i16, nt: usize) -> i16 {
         match nt {
-            2 => 5,
-            3 => 22,
-            4 => 23,
-            5 => 30,
-            6 => 58,
-            8 => 24,
-            9 => 25,
-            10 => 26,
-            11 => 27,
-            12 => 10,
-            13 => 11,
-            14 => 12,
-            15 => 13,
-            16 => 14,
-            17 => 15,
-            18 => 16,
-            20 => match state {
-                27 => 133,
-                _ => 66,
-            },
-            21 => match state {
-                26 => 132,
-                _ => 67,
-            },
-            22 => match state {
-                38 => 156,
-                _ => 141,
-            },
-            23 => 142,
-            24 => 68,
-            25 => match state {
-                7 => 63,
-                8 => 65,
-                40 => 158,
-                46 => 165,
-                _ => 69,
-            },
-            26 => match state {
-                32 => 144,
-                _ => 70,
-            },
-            27 => match state {
-                23 => 129,
-                _ => 71,
-            },
+            2 => 7,
+            3 => 32,
+            4 => 33,
+            5 => 46,
+            6 => 34,
+            7 => 35,
+            8 => 36,
+            9 => 84,
+            11 => 37,
+            12 => 38,
+            13 => 39,
+            14 => 40,
+            15 => 41,
+            16 => 15,
+            17 => 16,
+            18 => 17,
+            19 => 18,
+            20 => 19,
+            21 => 20,
+            22 => 21,
+            23 => 22,
+            24 => 23,
+            25 => 24,
+            26 => 25,
             28 => match state {
-                20 => 122,
-                21 => 125,
-                28..=29 => 134,
-                30 => 139,
-                33 => 146,
-                34 => 148,
-                35 => 151,
-                36 => 153,
-                37 => 154,
-                39 => 157,
-                42 => 161,
-                _ => 72,
+                41 => 195,
+                _ => 97,
             },
-            30 => match state {
-                29 => 137,
-                _ => 135,
+            29 => match state {
+                39 => 193,
+                _ => 98,
             },
-            32 => match state {
-                32 => 145,
-                _ => 73,
+            30 => match state {
+                57 => 223,
+                62 => 230,
+                _ => 205,
             },
-            33 => 74,
+            31 => 206,
+            32 => 224,
+            33 => 99,
             34 => match state {
-                1 => 52,
-                _ => 48,
+                36 => 190,
+                _ => 100,
             },
             35 => match state {
-                3 => 55,
-                6 => 62,
-                18 => 116,
-                _ => 75,
+                33 => 187,
+                _ => 101,
+            },
+            36 => match state {
+                35 => 189,
+                _ => 102,
+            },
+            37 => 179,
+            38 => match state {
+                30 => 180,
+                _ => 103,
+            },
+            39 => match state {
+                9 => 90,
+                13 => 96,
+                59 => 226,
+                67 => 236,
+                _ => 104,
             },
-            36 => 76,
-            37 => 77,
-            38 => 78,
-            39 => 1,
             40 => match state {
-                22 => 127,
-                _ => 79,
+                49 => 209,
+                _ => 105,
+            },
+            41 => match state {
+                34 => 188,
+                _ => 106,
             },
-            41 => 80,
             42 => match state {
-                5 => 60,
-                _ => 59,
+                29 => 177,
+                31 => 183,
+                42..=43 => 196,
+                46 => 203,
+                50 => 211,
+                51 => 213,
+                52 => 214,
+                53 => 217,
+                55 => 220,
+                56 => 221,
+                58 => 225,
+                63 => 232,
+                68 => 237,
+                _ => 107,
             },
-            43 => 53,
-            45 => match state {
-                16 => 112,
-                _ => 81,
+            44 => match state {
+                43 => 199,
+                _ => 197,
             },
             46 => match state {
-                24 => 130,
-                _ => 82,
+                49 => 210,
+                _ => 108,
+            },
+            47 => 109,
+            48 => match state {
+                1 => 76,
+                _ => 70,
             },
-            47 => 83,
-            48 => 36,
             49 => match state {
-                17 => 114,
-                40 => 159,
-                41 => 160,
-                43 => 162,
-                44 => 163,
-                45 => 164,
-                47 => 166,
-                _ => 84,
+                57 => 62,
+                3 => 80,
+                4 => 82,
+                8 => 89,
+                10 => 91,
+                12 => 94,
+                27 => 166,
+                44 => 200,
+                45 => 201,
+                62 => 231,
+                _ => 110,
             },
-            50 => 17,
-            52 => 2,
-            53 => match state {
-                0..=1 => 3,
-                4..=5 => 6,
-                _ => 18,
+            50 => match state {
+                30 => 181,
+                _ => 111,
             },
+            51 => 112,
+            52 => 113,
+            53 => 114,
             54 => match state {
-                22..=24 | 26..=27 => 128,
-                25 => 131,
-                _ => 19,
+                11 => 92,
+                _ => 86,
+            },
+            55 => 11,
+            56 => 1,
+            57 => match state {
+                32 => 185,
+                _ => 115,
+            },
+            58 => 116,
+            59 => match state {
+                7 => 87,
+                _ => 85,
+            },
+            60 => 78,
+            62 => match state {
+                25 => 162,
+                _ => 117,
+            },
+            63 => match state {
+                37 => 191,
+                _ => 118,
+            },
+            64 => 119,
+            65 => match state {
+                40 => 194,
+                _ => 120,
+            },
+            66 => 55,
+            67 => match state {
+                26 => 164,
+                48 => 208,
+                54 => 219,
+                59 => 227,
+                60 => 228,
+                61 => 229,
+                64 => 233,
+                65 => 234,
+                66 => 235,
+                69 => 238,
+                _ => 121,
+            },
+            68 => 26,
+            70 => match state {
+                47 | 57 | 62 => 207,
+                _ => 122,
+            },
+            71 => match state {
+                1 => 77,
+                _ => 71,
+            },
+            72 => 2,
+            73 => match state {
+                0..=1 => 3,
+                5 | 7 => 8,
+                6 | 11 => 12,
+                _ => 27,
+            },
+            74 => match state {
+                32..=37 | 39..=41 => 186,
+                38 => 192,
+                _ => 28,
             },
             _ => 0,
         }
     }
+    #[allow(clippy::needless_raw_string_hashes)]
     const __TERMINAL: &[&str] = &[
+        r###"r#"\"([^\"\\\\\\n]|\\\\[^\\n])*\""#"###,
+        r###"r#"'([^'\\\\\\n]|\\\\[^\\n][0-9a-fA-F]*)'"#"###,
+        r###"r#"[a-zA-Z][a-zA-Z0-9_]*"#"###,
+        r###"r#"\\-?[0-9]*"#"###,
+        r###"r#"\\-?[0-9]+\\.[0-9]*"#"###,
         r###""!""###,
         r###""!=""###,
         r###""%""###,
         r###""&""###,
         r###""&&""###,
+        r###""&=""###,
         r###""(""###,
         r###"")""###,
         r###""*""###,
@@ -874,35 +1239,48 @@ mod __parse__module {
         r###"",""###,
         r###""-""###,
         r###""-=""###,
+        r###""->""###,
+        r###"".""###,
         r###""/""###,
         r###""/=""###,
+        r###"":""###,
         r###"";""###,
         r###""<""###,
+        r###""<<""###,
+        r###""<<=""###,
         r###""<=""###,
         r###""=""###,
         r###""==""###,
         r###"">""###,
         r###"">=""###,
+        r###"">>""###,
+        r###"">>=""###,
         r###""[""###,
         r###""]""###,
+        r###""^""###,
+        r###""^=""###,
         r###""asm!""###,
         r###""break""###,
+        r###""case""###,
+        r###""char""###,
         r###""continue""###,
+        r###""default""###,
         r###""else""###,
         r###""float""###,
         r###""for""###,
         r###""if""###,
         r###""int""###,
         r###""return""###,
+        r###""struct""###,
+        r###""switch""###,
         r###""void""###,
         r###""while""###,
         r###""{""###,
+        r###""|""###,
+        r###""|=""###,
         r###""||""###,
         r###""}""###,
-        r###"r#"\"[a-zA-Z0-9\\-,#\\[\\] ]*\""#"###,
-        r###"r#"[a-zA-Z][a-zA-Z0-9_]*"#"###,
-        r###"r#"\\-?[0-9]*"#"###,
-        r###"r#"\\-?[0-9]+\\.[0-9]*"#"###,
+        r###""~""###,
     ];
     fn __expected_tokens(__state: i16) -> alloc::vec::Vec<alloc::string::String> {
         __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
@@ -916,26 +1294,28 @@ mod __parse__module {
     }
     fn __expected_tokens_from_states<
         'input,
+        'r,
     >(
         __states: &[i16],
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> alloc::vec::Vec<alloc::string::String>
     {
         __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
-            if __accepts(None, __states, Some(index), core::marker::PhantomData::<(&())>) {
+            if __accepts(None, __states, Some(index), core::marker::PhantomData::<(&(), &())>) {
                 Some(alloc::string::ToString::to_string(terminal))
             } else {
                 None
             }
         }).collect()
     }
-    pub(crate) struct __StateMachine<'input>
+    struct __StateMachine<'input, 'r>
     where 
     {
+        reg: &'r mut Registry,
         input: &'input str,
-        __phantom: core::marker::PhantomData<(&'input ())>,
+        __phantom: core::marker::PhantomData<(&'input (), &'r ())>,
     }
-    impl<'input> __state_machine::ParserDefinition for __StateMachine<'input>
+    impl<'input, 'r> __state_machine::ParserDefinition for __StateMachine<'input, 'r>
     where 
     {
         type Location = usize;
@@ -961,7 +1341,7 @@ mod __parse__module {
 
         #[inline]
         fn token_to_index(&self, token: &Self::Token) -> Option<usize> {
-            __token_to_integer(token, core::marker::PhantomData::<(&())>)
+            __token_to_integer(token, core::marker::PhantomData::<(&(), &())>)
         }
 
         #[inline]
@@ -971,7 +1351,7 @@ mod __parse__module {
 
         #[inline]
         fn error_action(&self, state: i16) -> i16 {
-            __action(state, 43 - 1)
+            __action(state, 62 - 1)
         }
 
         #[inline]
@@ -985,7 +1365,7 @@ mod __parse__module {
         }
 
         fn token_to_symbol(&self, token_index: usize, token: Self::Token) -> Self::Symbol {
-            __token_to_symbol(token_index, token, core::marker::PhantomData::<(&())>)
+            __token_to_symbol(token_index, token, core::marker::PhantomData::<(&(), &())>)
         }
 
         fn expected_tokens(&self, state: i16) -> alloc::vec::Vec<alloc::string::String> {
@@ -993,7 +1373,7 @@ mod __parse__module {
         }
 
         fn expected_tokens_from_states(&self, states: &[i16]) -> alloc::vec::Vec<alloc::string::String> {
-            __expected_tokens_from_states(states, core::marker::PhantomData::<(&())>)
+            __expected_tokens_from_states(states, core::marker::PhantomData::<(&(), &())>)
         }
 
         #[inline]
@@ -1017,84 +1397,107 @@ mod __parse__module {
             symbols: &mut alloc::vec::Vec<__state_machine::SymbolTriple<Self>>,
         ) -> Option<__state_machine::ParseResult<Self>> {
             __reduce(
+                self.reg,
                 self.input,
                 action,
                 start_location,
                 states,
                 symbols,
-                core::marker::PhantomData::<(&())>,
+                core::marker::PhantomData::<(&(), &())>,
             )
         }
 
         fn simulate_reduce(&self, action: i16) -> __state_machine::SimulatedReduce<Self> {
-            __simulate_reduce(action, core::marker::PhantomData::<(&())>)
+            __simulate_reduce(action, core::marker::PhantomData::<(&(), &())>)
         }
     }
     fn __token_to_integer<
         'input,
+        'r,
     >(
         __token: &Token<'input>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> Option<usize>
     {
-        match *__token {
-            Token(4, _) if true => Some(0),
-            Token(5, _) if true => Some(1),
-            Token(6, _) if true => Some(2),
-            Token(7, _) if true => Some(3),
-            Token(8, _) if true => Some(4),
-            Token(9, _) if true => Some(5),
-            Token(10, _) if true => Some(6),
-            Token(11, _) if true => Some(7),
-            Token(12, _) if true => Some(8),
-            Token(13, _) if true => Some(9),
-            Token(14, _) if true => Some(10),
-            Token(15, _) if true => Some(11),
-            Token(16, _) if true => Some(12),
-            Token(17, _) if true => Some(13),
-            Token(18, _) if true => Some(14),
-            Token(19, _) if true => Some(15),
-            Token(20, _) if true => Some(16),
-            Token(21, _) if true => Some(17),
-            Token(22, _) if true => Some(18),
-            Token(23, _) if true => Some(19),
-            Token(24, _) if true => Some(20),
-            Token(25, _) if true => Some(21),
-            Token(26, _) if true => Some(22),
-            Token(27, _) if true => Some(23),
-            Token(28, _) if true => Some(24),
-            Token(29, _) if true => Some(25),
-            Token(30, _) if true => Some(26),
-            Token(31, _) if true => Some(27),
-            Token(32, _) if true => Some(28),
-            Token(33, _) if true => Some(29),
-            Token(34, _) if true => Some(30),
-            Token(35, _) if true => Some(31),
-            Token(36, _) if true => Some(32),
-            Token(37, _) if true => Some(33),
-            Token(38, _) if true => Some(34),
-            Token(39, _) if true => Some(35),
-            Token(40, _) if true => Some(36),
-            Token(41, _) if true => Some(37),
-            Token(42, _) if true => Some(38),
-            Token(0, _) if true => Some(39),
-            Token(1, _) if true => Some(40),
-            Token(2, _) if true => Some(41),
-            Token(3, _) if true => Some(42),
+        #[warn(unused_variables)]
+        match __token {
+            Token(0, _) if true => Some(0),
+            Token(1, _) if true => Some(1),
+            Token(2, _) if true => Some(2),
+            Token(3, _) if true => Some(3),
+            Token(4, _) if true => Some(4),
+            Token(5, _) if true => Some(5),
+            Token(6, _) if true => Some(6),
+            Token(7, _) if true => Some(7),
+            Token(8, _) if true => Some(8),
+            Token(9, _) if true => Some(9),
+            Token(10, _) if true => Some(10),
+            Token(11, _) if true => Some(11),
+            Token(12, _) if true => Some(12),
+            Token(13, _) if true => Some(13),
+            Token(14, _) if true => Some(14),
+            Token(15, _) if true => Some(15),
+            Token(16, _) if true => Some(16),
+            Token(17, _) if true => Some(17),
+            Token(18, _) if true => Some(18),
+            Token(19, _) if true => Some(19),
+            Token(20, _) if true => Some(20),
+            Token(21, _) if true => Some(21),
+            Token(22, _) if true => Some(22),
+            Token(23, _) if true => Some(23),
+            Token(24, _) if true => Some(24),
+            Token(25, _) if true => Some(25),
+            Token(26, _) if true => Some(26),
+            Token(27, _) if true => Some(27),
+            Token(28, _) if true => Some(28),
+            Token(29, _) if true => Some(29),
+            Token(30, _) if true => Some(30),
+            Token(31, _) if true => Some(31),
+            Token(32, _) if true => Some(32),
+            Token(33, _) if true => Some(33),
+            Token(34, _) if true => Some(34),
+            Token(35, _) if true => Some(35),
+            Token(36, _) if true => Some(36),
+            Token(37, _) if true => Some(37),
+            Token(38, _) if true => Some(38),
+            Token(39, _) if true => Some(39),
+            Token(40, _) if true => Some(40),
+            Token(41, _) if true => Some(41),
+            Token(42, _) if true => Some(42),
+            Token(43, _) if true => Some(43),
+            Token(44, _) if true => Some(44),
+            Token(45, _) if true => Some(45),
+            Token(46, _) if true => Some(46),
+            Token(47, _) if true => Some(47),
+            Token(48, _) if true => Some(48),
+            Token(49, _) if true => Some(49),
+            Token(50, _) if true => Some(50),
+            Token(51, _) if true => Some(51),
+            Token(52, _) if true => Some(52),
+            Token(53, _) if true => Some(53),
+            Token(54, _) if true => Some(54),
+            Token(55, _) if true => Some(55),
+            Token(56, _) if true => Some(56),
+            Token(57, _) if true => Some(57),
+            Token(58, _) if true => Some(58),
+            Token(59, _) if true => Some(59),
+            Token(60, _) if true => Some(60),
+            Token(61, _) if true => Some(61),
             _ => None,
         }
     }
     fn __token_to_symbol<
         'input,
+        'r,
     >(
         __token_index: usize,
         __token: Token<'input>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> __Symbol<'input>
     {
-        match __token_index {
-            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 | 37 | 38 | 39 | 40 | 41 | 42 => match __token {
-                Token(4, __tok0) | Token(5, __tok0) | Token(6, __tok0) | Token(7, __tok0) | Token(8, __tok0) | Token(9, __tok0) | Token(10, __tok0) | Token(11, __tok0) | Token(12, __tok0) | Token(13, __tok0) | Token(14, __tok0) | Token(15, __tok0) | Token(16, __tok0) | Token(17, __tok0) | Token(18, __tok0) | Token(19, __tok0) | Token(20, __tok0) | Token(21, __tok0) | Token(22, __tok0) | Token(23, __tok0) | Token(24, __tok0) | Token(25, __tok0) | Token(26, __tok0) | Token(27, __tok0) | Token(28, __tok0) | Token(29, __tok0) | Token(30, __tok0) | Token(31, __tok0) | Token(32, __tok0) | Token(33, __tok0) | Token(34, __tok0) | Token(35, __tok0) | Token(36, __tok0) | Token(37, __tok0) | Token(38, __tok0) | Token(39, __tok0) | Token(40, __tok0) | Token(41, __tok0) | Token(42, __tok0) | Token(0, __tok0) | Token(1, __tok0) | Token(2, __tok0) | Token(3, __tok0) if true => __Symbol::Variant0(__tok0),
+        #[allow(clippy::manual_range_patterns)]match __token_index {
+            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 | 37 | 38 | 39 | 40 | 41 | 42 | 43 | 44 | 45 | 46 | 47 | 48 | 49 | 50 | 51 | 52 | 53 | 54 | 55 | 56 | 57 | 58 | 59 | 60 | 61 => match __token {
+                Token(0, __tok0) | Token(1, __tok0) | Token(2, __tok0) | Token(3, __tok0) | Token(4, __tok0) | Token(5, __tok0) | Token(6, __tok0) | Token(7, __tok0) | Token(8, __tok0) | Token(9, __tok0) | Token(10, __tok0) | Token(11, __tok0) | Token(12, __tok0) | Token(13, __tok0) | Token(14, __tok0) | Token(15, __tok0) | Token(16, __tok0) | Token(17, __tok0) | Token(18, __tok0) | Token(19, __tok0) | Token(20, __tok0) | Token(21, __tok0) | Token(22, __tok0) | Token(23, __tok0) | Token(24, __tok0) | Token(25, __tok0) | Token(26, __tok0) | Token(27, __tok0) | Token(28, __tok0) | Token(29, __tok0) | Token(30, __tok0) | Token(31, __tok0) | Token(32, __tok0) | Token(33, __tok0) | Token(34, __tok0) | Token(35, __tok0) | Token(36, __tok0) | Token(37, __tok0) | Token(38, __tok0) | Token(39, __tok0) | Token(40, __tok0) | Token(41, __tok0) | Token(42, __tok0) | Token(43, __tok0) | Token(44, __tok0) | Token(45, __tok0) | Token(46, __tok0) | Token(47, __tok0) | Token(48, __tok0) | Token(49, __tok0) | Token(50, __tok0) | Token(51, __tok0) | Token(52, __tok0) | Token(53, __tok0) | Token(54, __tok0) | Token(55, __tok0) | Token(56, __tok0) | Token(57, __tok0) | Token(58, __tok0) | Token(59, __tok0) | Token(60, __tok0) | Token(61, __tok0) if true => __Symbol::Variant0(__tok0),
                 _ => unreachable!(),
             },
             _ => unreachable!(),
@@ -1102,10 +1505,11 @@ mod __parse__module {
     }
     fn __simulate_reduce<
         'input,
+        'r,
     >(
         __reduce_index: i16,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> __state_machine::SimulatedReduce<__StateMachine<'input>>
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> __state_machine::SimulatedReduce<__StateMachine<'input, 'r>>
     {
         match __reduce_index {
             0 => {
@@ -1189,49 +1593,49 @@ mod __parse__module {
             13 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 6,
+                    nonterminal_produced: 5,
                 }
             }
             14 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 6,
+                    states_to_pop: 1,
+                    nonterminal_produced: 5,
                 }
             }
             15 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 7,
+                    nonterminal_produced: 5,
                 }
             }
             16 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 7,
+                    states_to_pop: 1,
+                    nonterminal_produced: 5,
                 }
             }
             17 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 8,
+                    nonterminal_produced: 5,
                 }
             }
             18 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 8,
+                    nonterminal_produced: 6,
                 }
             }
             19 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 9,
+                    nonterminal_produced: 7,
                 }
             }
             20 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 9,
+                    nonterminal_produced: 8,
                 }
             }
             21 => {
@@ -1242,20 +1646,20 @@ mod __parse__module {
             }
             22 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 10,
+                    states_to_pop: 2,
+                    nonterminal_produced: 9,
                 }
             }
             23 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 11,
+                    nonterminal_produced: 10,
                 }
             }
             24 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 11,
+                    states_to_pop: 0,
+                    nonterminal_produced: 10,
                 }
             }
             25 => {
@@ -1272,7 +1676,7 @@ mod __parse__module {
             }
             27 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
+                    states_to_pop: 1,
                     nonterminal_produced: 12,
                 }
             }
@@ -1284,8 +1688,8 @@ mod __parse__module {
             }
             29 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 13,
+                    states_to_pop: 1,
+                    nonterminal_produced: 12,
                 }
             }
             30 => {
@@ -1296,7 +1700,7 @@ mod __parse__module {
             }
             31 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
+                    states_to_pop: 1,
                     nonterminal_produced: 14,
                 }
             }
@@ -1308,55 +1712,55 @@ mod __parse__module {
             }
             33 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 15,
+                    states_to_pop: 1,
+                    nonterminal_produced: 14,
                 }
             }
             34 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    nonterminal_produced: 14,
                 }
             }
             35 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 15,
                 }
             }
             36 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 16,
+                    nonterminal_produced: 15,
                 }
             }
             37 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 3,
-                    nonterminal_produced: 17,
+                    nonterminal_produced: 16,
                 }
             }
             38 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 17,
+                    nonterminal_produced: 16,
                 }
             }
             39 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 18,
+                    states_to_pop: 3,
+                    nonterminal_produced: 17,
                 }
             }
             40 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 18,
+                    nonterminal_produced: 17,
                 }
             }
             41 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 3,
                     nonterminal_produced: 18,
                 }
             }
@@ -1366,232 +1770,232 @@ mod __parse__module {
                     nonterminal_produced: 18,
                 }
             }
-            43 => __state_machine::SimulatedReduce::Accept,
+            43 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 19,
+                }
+            }
             44 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 19,
                 }
             }
             45 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 21,
+                    states_to_pop: 3,
+                    nonterminal_produced: 20,
                 }
             }
             46 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 22,
+                    nonterminal_produced: 20,
                 }
             }
             47 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 23,
+                    states_to_pop: 3,
+                    nonterminal_produced: 21,
                 }
             }
             48 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 23,
+                    states_to_pop: 1,
+                    nonterminal_produced: 21,
                 }
             }
             49 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 24,
+                    states_to_pop: 3,
+                    nonterminal_produced: 22,
                 }
             }
             50 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 25,
+                    states_to_pop: 1,
+                    nonterminal_produced: 22,
                 }
             }
             51 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 25,
+                    states_to_pop: 3,
+                    nonterminal_produced: 23,
                 }
             }
             52 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 26,
+                    states_to_pop: 1,
+                    nonterminal_produced: 23,
                 }
             }
             53 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 26,
+                    states_to_pop: 3,
+                    nonterminal_produced: 24,
                 }
             }
             54 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 27,
+                    nonterminal_produced: 24,
                 }
             }
             55 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 3,
-                    nonterminal_produced: 28,
+                    nonterminal_produced: 25,
                 }
             }
             56 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 28,
+                    nonterminal_produced: 25,
                 }
             }
             57 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 29,
+                    nonterminal_produced: 26,
                 }
             }
             58 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 29,
+                    states_to_pop: 1,
+                    nonterminal_produced: 26,
                 }
             }
             59 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 30,
+                    nonterminal_produced: 26,
                 }
             }
             60 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 30,
+                    states_to_pop: 1,
+                    nonterminal_produced: 26,
                 }
             }
             61 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 31,
-                }
-            }
-            62 => {
-                __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 31,
+                    nonterminal_produced: 26,
                 }
             }
+            62 => __state_machine::SimulatedReduce::Accept,
             63 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 32,
+                    states_to_pop: 1,
+                    nonterminal_produced: 28,
                 }
             }
             64 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 32,
+                    nonterminal_produced: 29,
                 }
             }
             65 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 33,
+                    nonterminal_produced: 30,
                 }
             }
             66 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 6,
-                    nonterminal_produced: 34,
+                    states_to_pop: 1,
+                    nonterminal_produced: 31,
                 }
             }
             67 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 34,
+                    states_to_pop: 3,
+                    nonterminal_produced: 31,
                 }
             }
             68 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 35,
+                    states_to_pop: 3,
+                    nonterminal_produced: 31,
                 }
             }
             69 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 36,
+                    states_to_pop: 4,
+                    nonterminal_produced: 31,
                 }
             }
             70 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 37,
+                    states_to_pop: 4,
+                    nonterminal_produced: 32,
                 }
             }
             71 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 8,
-                    nonterminal_produced: 37,
+                    states_to_pop: 4,
+                    nonterminal_produced: 33,
                 }
             }
             72 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 7,
-                    nonterminal_produced: 37,
+                    states_to_pop: 1,
+                    nonterminal_produced: 34,
                 }
             }
             73 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 7,
-                    nonterminal_produced: 37,
+                    states_to_pop: 1,
+                    nonterminal_produced: 35,
                 }
             }
             74 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 6,
-                    nonterminal_produced: 37,
+                    states_to_pop: 1,
+                    nonterminal_produced: 36,
                 }
             }
             75 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 38,
+                    states_to_pop: 1,
+                    nonterminal_produced: 37,
                 }
             }
             76 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 38,
+                    states_to_pop: 1,
+                    nonterminal_produced: 37,
                 }
             }
             77 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
+                    states_to_pop: 1,
                     nonterminal_produced: 38,
                 }
             }
             78 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 38,
+                    states_to_pop: 3,
+                    nonterminal_produced: 39,
                 }
             }
             79 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 2,
                     nonterminal_produced: 39,
                 }
             }
             80 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 39,
+                    states_to_pop: 3,
+                    nonterminal_produced: 40,
                 }
             }
             81 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 5,
                     nonterminal_produced: 40,
                 }
             }
@@ -1603,38 +2007,38 @@ mod __parse__module {
             }
             83 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
+                    states_to_pop: 3,
                     nonterminal_produced: 42,
                 }
             }
             84 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 43,
+                    nonterminal_produced: 42,
                 }
             }
             85 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
+                    states_to_pop: 1,
                     nonterminal_produced: 43,
                 }
             }
             86 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 44,
+                    states_to_pop: 0,
+                    nonterminal_produced: 43,
                 }
             }
             87 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
+                    states_to_pop: 1,
                     nonterminal_produced: 44,
                 }
             }
             88 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 45,
+                    states_to_pop: 3,
+                    nonterminal_produced: 44,
                 }
             }
             89 => {
@@ -1645,173 +2049,449 @@ mod __parse__module {
             }
             90 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 0,
                     nonterminal_produced: 45,
                 }
             }
             91 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 45,
+                    states_to_pop: 2,
+                    nonterminal_produced: 46,
                 }
             }
             92 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 46,
+                }
+            }
+            93 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 47,
+                }
+            }
+            94 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 48,
+                }
+            }
+            95 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 48,
+                }
+            }
+            96 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 49,
+                }
+            }
+            97 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 50,
+                }
+            }
+            98 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 51,
+                }
+            }
+            99 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 8,
+                    nonterminal_produced: 51,
+                }
+            }
+            100 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 51,
+                }
+            }
+            101 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 51,
+                }
+            }
+            102 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 51,
+                }
+            }
+            103 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 52,
+                }
+            }
+            104 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 52,
+                }
+            }
+            105 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 52,
+                }
+            }
+            106 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 52,
+                }
+            }
+            107 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 4,
-                    nonterminal_produced: 45,
+                    nonterminal_produced: 53,
+                }
+            }
+            108 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 53,
+                }
+            }
+            109 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 54,
+                }
+            }
+            110 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 55,
+                }
+            }
+            111 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 55,
+                }
+            }
+            112 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 56,
+                }
+            }
+            113 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 56,
+                }
+            }
+            114 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 56,
+                }
+            }
+            115 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 56,
+                }
+            }
+            116 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 57,
+                }
+            }
+            117 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 58,
+                }
+            }
+            118 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 59,
+                }
+            }
+            119 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 60,
+                }
+            }
+            120 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 60,
+                }
+            }
+            121 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 61,
+                }
+            }
+            122 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 61,
+                }
+            }
+            123 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 62,
+                }
+            }
+            124 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 62,
+                }
+            }
+            125 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 62,
+                }
+            }
+            126 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 62,
+                }
+            }
+            127 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 62,
+                }
+            }
+            128 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 62,
+                }
+            }
+            129 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 62,
+                }
+            }
+            130 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 62,
+                }
+            }
+            131 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 62,
+                }
+            }
+            132 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 62,
+                }
+            }
+            133 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 62,
+                }
+            }
+            134 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 63,
+                }
+            }
+            135 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 64,
+                }
+            }
+            136 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 64,
+                }
+            }
+            137 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 64,
+                }
+            }
+            138 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 65,
                 }
             }
-            93 => {
+            139 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 45,
+                    states_to_pop: 1,
+                    nonterminal_produced: 66,
                 }
             }
-            94 => {
+            140 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 45,
+                    states_to_pop: 1,
+                    nonterminal_produced: 66,
                 }
             }
-            95 => {
+            141 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 46,
+                    nonterminal_produced: 67,
                 }
             }
-            96 => {
+            142 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 47,
+                    states_to_pop: 1,
+                    nonterminal_produced: 67,
                 }
             }
-            97 => {
+            143 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 7,
-                    nonterminal_produced: 47,
+                    states_to_pop: 1,
+                    nonterminal_produced: 67,
                 }
             }
-            98 => {
+            144 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 48,
+                    nonterminal_produced: 67,
                 }
             }
-            99 => {
+            145 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 48,
+                    nonterminal_produced: 67,
                 }
             }
-            100 => {
+            146 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 49,
+                    nonterminal_produced: 67,
                 }
             }
-            101 => {
+            147 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 49,
+                    nonterminal_produced: 67,
                 }
             }
-            102 => {
+            148 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 49,
+                    nonterminal_produced: 67,
                 }
             }
-            103 => {
+            149 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 49,
+                    nonterminal_produced: 68,
                 }
             }
-            104 => {
+            150 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 49,
+                    states_to_pop: 2,
+                    nonterminal_produced: 68,
                 }
             }
-            105 => {
+            151 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 49,
+                    nonterminal_produced: 69,
                 }
             }
-            106 => {
+            152 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 49,
+                    states_to_pop: 0,
+                    nonterminal_produced: 69,
                 }
             }
-            107 => {
+            153 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 50,
+                    nonterminal_produced: 70,
                 }
             }
-            108 => {
+            154 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 50,
+                    states_to_pop: 6,
+                    nonterminal_produced: 71,
                 }
             }
-            109 => {
+            155 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 51,
+                    nonterminal_produced: 72,
                 }
             }
-            110 => {
+            156 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 51,
+                    states_to_pop: 1,
+                    nonterminal_produced: 72,
                 }
             }
-            111 => {
+            157 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 52,
+                    nonterminal_produced: 72,
                 }
             }
-            112 => {
+            158 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 52,
+                    nonterminal_produced: 72,
                 }
             }
-            113 => {
+            159 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 52,
+                    states_to_pop: 2,
+                    nonterminal_produced: 72,
                 }
             }
-            114 => {
+            160 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 2,
-                    nonterminal_produced: 53,
+                    nonterminal_produced: 73,
                 }
             }
-            115 => {
+            161 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 53,
+                    nonterminal_produced: 73,
                 }
             }
-            116 => {
+            162 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 54,
+                    nonterminal_produced: 74,
                 }
             }
-            117 => {
+            163 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 2,
-                    nonterminal_produced: 54,
+                    nonterminal_produced: 74,
                 }
             }
-            _ => panic!("invalid reduction index {}", __reduce_index)
+            _ => panic!("invalid reduction index {__reduce_index}")
         }
     }
     pub struct moduleParser {
@@ -1819,6 +2499,7 @@ mod __parse__module {
         _priv: (),
     }
 
+    impl Default for moduleParser { fn default() -> Self { Self::new() } }
     impl moduleParser {
         pub fn new() -> moduleParser {
             let __builder = super::__intern_token::new_builder();
@@ -1831,16 +2512,19 @@ mod __parse__module {
         #[allow(dead_code)]
         pub fn parse<
             'input,
+            'r,
         >(
             &self,
+            reg: &'r mut Registry,
             input: &'input str,
         ) -> Result<Module, __lalrpop_util::ParseError<usize, Token<'input>, asm::parser::ParseError>>
         {
             let mut __tokens = self.builder.matcher(input);
             __state_machine::Parser::drive(
                 __StateMachine {
+                    reg,
                     input,
-                    __phantom: core::marker::PhantomData::<(&())>,
+                    __phantom: core::marker::PhantomData::<(&(), &())>,
                 },
                 __tokens,
             )
@@ -1848,11 +2532,12 @@ mod __parse__module {
     }
     fn __accepts<
         'input,
+        'r,
     >(
         __error_state: Option<i16>,
         __states: &[i16],
         __opt_integer: Option<usize>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> bool
     {
         let mut __states = __states.to_vec();
@@ -1866,7 +2551,7 @@ mod __parse__module {
             };
             if __action == 0 { return false; }
             if __action > 0 { return true; }
-            let (__to_pop, __nt) = match __simulate_reduce(-(__action + 1), core::marker::PhantomData::<(&())>) {
+            let (__to_pop, __nt) = match __simulate_reduce(-(__action + 1), core::marker::PhantomData::<(&(), &())>) {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop, nonterminal_produced
                 } => (states_to_pop, nonterminal_produced),
@@ -1879,387 +2564,584 @@ mod __parse__module {
             __states.push(__next_state);
         }
     }
-    pub(crate) fn __reduce<
+    fn __reduce<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __action: i16,
         __lookahead_start: Option<&usize>,
         __states: &mut alloc::vec::Vec<i16>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> Option<Result<Module,__lalrpop_util::ParseError<usize, Token<'input>, asm::parser::ParseError>>>
     {
         let (__pop_states, __nonterminal) = match __action {
             0 => {
-                __reduce0(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce0(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             1 => {
-                __reduce1(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce1(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             2 => {
-                __reduce2(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce2(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             3 => {
-                __reduce3(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce3(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             4 => {
-                __reduce4(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce4(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             5 => {
-                __reduce5(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce5(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             6 => {
-                __reduce6(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce6(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             7 => {
-                __reduce7(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce7(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             8 => {
-                __reduce8(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce8(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             9 => {
-                __reduce9(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce9(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             10 => {
-                __reduce10(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce10(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             11 => {
-                __reduce11(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce11(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             12 => {
-                __reduce12(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce12(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             13 => {
-                __reduce13(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce13(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             14 => {
-                __reduce14(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce14(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             15 => {
-                __reduce15(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce15(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             16 => {
-                __reduce16(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce16(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             17 => {
-                __reduce17(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce17(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             18 => {
-                __reduce18(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce18(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             19 => {
-                __reduce19(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce19(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             20 => {
-                __reduce20(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce20(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             21 => {
-                __reduce21(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce21(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             22 => {
-                __reduce22(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce22(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             23 => {
-                __reduce23(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce23(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             24 => {
-                __reduce24(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce24(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             25 => {
-                __reduce25(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce25(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             26 => {
-                __reduce26(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce26(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             27 => {
-                __reduce27(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce27(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             28 => {
-                __reduce28(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce28(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             29 => {
-                __reduce29(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce29(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             30 => {
-                __reduce30(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce30(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             31 => {
-                __reduce31(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce31(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             32 => {
-                __reduce32(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce32(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             33 => {
-                __reduce33(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce33(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             34 => {
-                __reduce34(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce34(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             35 => {
-                __reduce35(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce35(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             36 => {
-                __reduce36(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce36(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             37 => {
-                __reduce37(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce37(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             38 => {
-                __reduce38(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce38(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             39 => {
-                __reduce39(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce39(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             40 => {
-                __reduce40(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce40(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             41 => {
-                __reduce41(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce41(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             42 => {
-                __reduce42(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce42(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             43 => {
-                // __module = module => ActionFn(0);
-                let __sym0 = __pop_Variant8(__symbols);
-                let __start = __sym0.0;
-                let __end = __sym0.2;
-                let __nt = super::__action0::<>(input, __sym0);
-                return Some(Ok(__nt));
+                __reduce43(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             44 => {
-                __reduce44(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce44(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             45 => {
-                __reduce45(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce45(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             46 => {
-                // asm_line = r#"\"[a-zA-Z0-9\\-,#\\[\\] ]*\""# => ActionFn(57);
-                let __sym0 = __pop_Variant0(__symbols);
-                let __start = __sym0.0;
-                let __end = __sym0.2;
-                let __nt = match super::__action57::<>(input, __sym0) {
-                    Ok(v) => v,
-                    Err(e) => return Some(Err(e)),
-                };
-                __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-                (1, 22)
+                __reduce46(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             47 => {
-                __reduce47(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce47(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             48 => {
-                __reduce48(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce48(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             49 => {
-                __reduce49(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce49(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             50 => {
-                __reduce50(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce50(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             51 => {
-                __reduce51(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce51(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             52 => {
-                __reduce52(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce52(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             53 => {
-                __reduce53(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce53(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             54 => {
-                __reduce54(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce54(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             55 => {
-                __reduce55(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce55(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             56 => {
-                __reduce56(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce56(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             57 => {
-                __reduce57(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce57(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             58 => {
-                __reduce58(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce58(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             59 => {
-                __reduce59(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce59(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             60 => {
-                __reduce60(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce60(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             61 => {
-                __reduce61(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce61(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             62 => {
-                __reduce62(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                // __module = module => ActionFn(0);
+                let __sym0 = __pop_Variant8(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action0::<>(reg, input, __sym0);
+                return Some(Ok(__nt));
             }
             63 => {
-                __reduce63(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce63(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             64 => {
-                __reduce64(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce64(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             65 => {
-                __reduce65(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                // asm_line = string => ActionFn(84);
+                let __sym0 = __pop_Variant26(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = match super::__action84::<>(reg, input, __sym0) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+                (1, 30)
             }
             66 => {
-                __reduce66(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce66(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             67 => {
-                __reduce67(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce67(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             68 => {
-                __reduce68(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce68(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             69 => {
-                __reduce69(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                // asm_list = asm_list, ",", identifier, asm_line => ActionFn(82);
+                assert!(__symbols.len() >= 4);
+                let __sym3 = __pop_Variant9(__symbols);
+                let __sym2 = __pop_Variant9(__symbols);
+                let __sym1 = __pop_Variant0(__symbols);
+                let __sym0 = __pop_Variant10(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = match super::__action82::<>(reg, input, __sym0, __sym1, __sym2, __sym3) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+                (4, 31)
             }
             70 => {
-                __reduce70(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                // asm_operand = identifier, identifier, "=", expression => ActionFn(83);
+                assert!(__symbols.len() >= 4);
+                let __sym3 = __pop_Variant6(__symbols);
+                let __sym2 = __pop_Variant0(__symbols);
+                let __sym1 = __pop_Variant9(__symbols);
+                let __sym0 = __pop_Variant9(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = match super::__action83::<>(reg, input, __sym0, __sym1, __sym2, __sym3) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+                (4, 32)
             }
             71 => {
-                __reduce71(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                // asm_statement = "asm!", "{", asm_list, "}" => ActionFn(78);
+                assert!(__symbols.len() >= 4);
+                let __sym3 = __pop_Variant0(__symbols);
+                let __sym2 = __pop_Variant10(__symbols);
+                let __sym1 = __pop_Variant0(__symbols);
+                let __sym0 = __pop_Variant0(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = match super::__action78::<>(reg, input, __sym0, __sym1, __sym2, __sym3) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+                (4, 33)
             }
             72 => {
-                __reduce72(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce72(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             73 => {
-                __reduce73(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce73(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             74 => {
-                __reduce74(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce74(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             75 => {
-                __reduce75(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce75(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             76 => {
-                __reduce76(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce76(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             77 => {
-                __reduce77(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                // character = r#"'([^'\\\\\\n]|\\\\[^\\n][0-9a-fA-F]*)'"# => ActionFn(114);
+                let __sym0 = __pop_Variant0(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = match super::__action114::<>(reg, input, __sym0) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+                (1, 38)
             }
             78 => {
-                __reduce78(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce78(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             79 => {
-                __reduce79(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce79(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             80 => {
-                __reduce80(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce80(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             81 => {
-                __reduce81(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce81(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             82 => {
-                __reduce82(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce82(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             83 => {
-                __reduce83(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce83(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             84 => {
-                __reduce84(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce84(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             85 => {
-                __reduce85(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce85(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             86 => {
-                __reduce86(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce86(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             87 => {
-                __reduce87(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce87(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             88 => {
-                __reduce88(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce88(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             89 => {
-                __reduce89(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce89(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             90 => {
-                __reduce90(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce90(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             91 => {
-                __reduce91(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce91(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             92 => {
-                __reduce92(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce92(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             93 => {
-                __reduce93(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce93(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             94 => {
-                __reduce94(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce94(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             95 => {
-                __reduce95(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce95(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             96 => {
-                __reduce96(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce96(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             97 => {
-                __reduce97(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce97(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             98 => {
-                __reduce98(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce98(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             99 => {
-                __reduce99(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce99(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             100 => {
-                __reduce100(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce100(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             101 => {
-                __reduce101(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce101(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             102 => {
-                __reduce102(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce102(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             103 => {
-                __reduce103(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce103(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             104 => {
-                __reduce104(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce104(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             105 => {
-                __reduce105(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce105(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             106 => {
-                __reduce106(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce106(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             107 => {
-                __reduce107(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce107(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             108 => {
-                __reduce108(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce108(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             109 => {
-                __reduce109(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce109(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             110 => {
-                __reduce110(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce110(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             111 => {
-                __reduce111(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce111(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             112 => {
-                __reduce112(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce112(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             113 => {
-                __reduce113(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce113(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             114 => {
-                __reduce114(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce114(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             115 => {
-                __reduce115(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce115(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             116 => {
-                __reduce116(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce116(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             117 => {
-                __reduce117(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce117(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            118 => {
+                __reduce118(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            119 => {
+                __reduce119(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            120 => {
+                __reduce120(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            121 => {
+                __reduce121(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            122 => {
+                __reduce122(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            123 => {
+                __reduce123(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            124 => {
+                __reduce124(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            125 => {
+                __reduce125(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            126 => {
+                __reduce126(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            127 => {
+                __reduce127(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            128 => {
+                __reduce128(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            129 => {
+                __reduce129(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            130 => {
+                __reduce130(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            131 => {
+                __reduce131(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            132 => {
+                __reduce132(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            133 => {
+                __reduce133(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            134 => {
+                __reduce134(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            135 => {
+                __reduce135(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            136 => {
+                __reduce136(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            137 => {
+                __reduce137(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            138 => {
+                __reduce138(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            139 => {
+                __reduce139(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            140 => {
+                __reduce140(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            141 => {
+                __reduce141(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            142 => {
+                __reduce142(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            143 => {
+                __reduce143(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            144 => {
+                __reduce144(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            145 => {
+                __reduce145(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            146 => {
+                __reduce146(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            147 => {
+                __reduce147(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
-            _ => panic!("invalid action code {}", __action)
+            148 => {
+                __reduce148(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            149 => {
+                __reduce149(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            150 => {
+                __reduce150(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            151 => {
+                __reduce151(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            152 => {
+                __reduce152(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            153 => {
+                // string = r#"\"([^\"\\\\\\n]|\\\\[^\\n])*\""# => ActionFn(115);
+                let __sym0 = __pop_Variant0(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = match super::__action115::<>(reg, input, __sym0) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+                (1, 70)
+            }
+            154 => {
+                __reduce154(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            155 => {
+                __reduce155(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            156 => {
+                __reduce156(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            157 => {
+                __reduce157(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            158 => {
+                __reduce158(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            159 => {
+                __reduce159(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            160 => {
+                __reduce160(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            161 => {
+                __reduce161(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            162 => {
+                __reduce162(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            163 => {
+                __reduce163(reg, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            _ => panic!("invalid action code {__action}")
         };
         let __states_len = __states.len();
         __states.truncate(__states_len - __pop_states);
@@ -2272,6 +3154,28 @@ mod __parse__module {
     fn __symbol_type_mismatch() -> ! {
         panic!("symbol type mismatch")
     }
+    fn __pop_Variant10<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (Vec<String>, Vec<AsmOperand>, Vec<String>), usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant11<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, AsmOperand, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
     fn __pop_Variant3<
       'input,
     >(
@@ -2294,36 +3198,47 @@ mod __parse__module {
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant16<
+    fn __pop_Variant19<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Box<FunctionDeclaration>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant11<
+    fn __pop_Variant12<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Box<Statement>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant24<
+    fn __pop_Variant29<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Kind, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant24(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant29(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant20<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Member, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
@@ -2338,201 +3253,223 @@ mod __parse__module {
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant1<
+    fn __pop_Variant15<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Parameter, usize)
+    ) -> (usize, Option<Box<Expr>>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant23<
+    fn __pop_Variant17<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Primitive, usize)
+    ) -> (usize, Option<Vec<Expr>>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant23(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant17<
+    fn __pop_Variant5<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, String, usize)
+    ) -> (usize, Option<Vec<Parameter>>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant7<
+    fn __pop_Variant25<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, UnaryOp, usize)
+    ) -> (usize, Option<Vec<Statement>>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant25(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant13<
+    fn __pop_Variant23<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<Expr>, usize)
+    ) -> (usize, Option<u32>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant23(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant4<
+    fn __pop_Variant1<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<Parameter>, usize)
+    ) -> (usize, Parameter, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant21<
+    fn __pop_Variant28<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<Statement>, usize)
+    ) -> (usize, Primitive, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant21(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant28(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant10<
+    fn __pop_Variant9<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, String, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant27<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<asm::asm::AA>, usize)
+    ) -> (usize, StructDeclaration, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant27(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant2<
+    fn __pop_Variant7<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<Parameter>, usize)
+    ) -> (usize, UnaryOp, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant9<
+    fn __pop_Variant16<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, asm::asm::AA, usize)
+    ) -> (usize, Vec<Expr>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant4<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<Parameter>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant12<
+    fn __pop_Variant24<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, core::option::Option<Box<Expr>>, usize)
+    ) -> (usize, Vec<Statement>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant24(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant14<
+    fn __pop_Variant26<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, core::option::Option<Vec<Expr>>, usize)
+    ) -> (usize, Vec<u8>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant26(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant5<
+    fn __pop_Variant21<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, core::option::Option<Vec<Parameter>>, usize)
+    ) -> (usize, alloc::vec::Vec<Member>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant21(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant22<
+    fn __pop_Variant2<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, core::option::Option<Vec<Statement>>, usize)
+    ) -> (usize, alloc::vec::Vec<Parameter>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant20<
+    fn __pop_Variant18<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, core::option::Option<u32>, usize)
+    ) -> (usize, f64, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant15<
+    fn __pop_Variant13<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, f64, usize)
+    ) -> (usize, i64, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant18<
+    fn __pop_Variant22<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, i64, usize)
+    ) -> (usize, u32, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant19<
+    fn __pop_Variant14<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, u32, usize)
+    ) -> (usize, u8, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
@@ -2547,2239 +3484,3402 @@ mod __parse__module {
             _ => __symbol_type_mismatch()
         }
     }
-    pub(crate) fn __reduce0<
+    fn __reduce0<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // (<parameter> ",") = parameter, "," => ActionFn(105);
+        // (<parameter> ",") = parameter, "," => ActionFn(151);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action105::<>(input, __sym0, __sym1);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action151::<>(reg, input, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
         (2, 0)
     }
-    pub(crate) fn __reduce1<
+    fn __reduce1<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // (<parameter> ",")* =  => ActionFn(103);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action103::<>(input, &__start, &__end);
+        // (<parameter> ",")* =  => ActionFn(149);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action149::<>(reg, input, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant2(__nt), __end));
         (0, 1)
     }
-    pub(crate) fn __reduce2<
+    fn __reduce2<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // (<parameter> ",")* = (<parameter> ",")+ => ActionFn(104);
+        // (<parameter> ",")* = (<parameter> ",")+ => ActionFn(150);
         let __sym0 = __pop_Variant2(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action104::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action150::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant2(__nt), __end));
         (1, 1)
     }
-    pub(crate) fn __reduce3<
+    fn __reduce3<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // (<parameter> ",")+ = parameter, "," => ActionFn(108);
+        // (<parameter> ",")+ = parameter, "," => ActionFn(154);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action108::<>(input, __sym0, __sym1);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action154::<>(reg, input, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant2(__nt), __end));
         (2, 2)
     }
-    pub(crate) fn __reduce4<
+    fn __reduce4<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // (<parameter> ",")+ = (<parameter> ",")+, parameter, "," => ActionFn(109);
+        // (<parameter> ",")+ = (<parameter> ",")+, parameter, "," => ActionFn(155);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
         let __sym1 = __pop_Variant1(__symbols);
         let __sym0 = __pop_Variant2(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action109::<>(input, __sym0, __sym1, __sym2);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action155::<>(reg, input, __sym0, __sym1, __sym2);
         __symbols.push((__start, __Symbol::Variant2(__nt), __end));
         (3, 2)
     }
-    pub(crate) fn __reduce5<
+    fn __reduce5<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // AddOp = "+" => ActionFn(27);
+        // AddOp = "+" => ActionFn(45);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action27::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action45::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant3(__nt), __end));
         (1, 3)
     }
-    pub(crate) fn __reduce6<
+    fn __reduce6<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // AddOp = "-" => ActionFn(28);
+        // AddOp = "-" => ActionFn(46);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action28::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action46::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant3(__nt), __end));
         (1, 3)
     }
-    pub(crate) fn __reduce7<
+    fn __reduce7<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // AndOp = "&&" => ActionFn(17);
+        // AndOp = "&&" => ActionFn(26);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action17::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action26::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant3(__nt), __end));
         (1, 4)
     }
-    pub(crate) fn __reduce8<
+    fn __reduce8<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // AssignOp = "+=" => ActionFn(13);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action13::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 5)
+    }
+    fn __reduce9<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // AssignOp = "-=" => ActionFn(14);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action14::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 5)
+    }
+    fn __reduce10<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // AssignOp = "*=" => ActionFn(15);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action15::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 5)
+    }
+    fn __reduce11<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // AssignOp = "/=" => ActionFn(16);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action16::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 5)
+    }
+    fn __reduce12<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // AssignOp = "&=" => ActionFn(17);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action17::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 5)
+    }
+    fn __reduce13<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // AssignOp = "+=" => ActionFn(9);
+        // AssignOp = "|=" => ActionFn(18);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action9::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action18::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant3(__nt), __end));
         (1, 5)
     }
-    pub(crate) fn __reduce9<
+    fn __reduce14<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // AssignOp = "-=" => ActionFn(10);
+        // AssignOp = "^=" => ActionFn(19);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action10::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action19::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant3(__nt), __end));
         (1, 5)
     }
-    pub(crate) fn __reduce10<
+    fn __reduce15<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // AssignOp = "*=" => ActionFn(11);
+        // AssignOp = "<<=" => ActionFn(20);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action11::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action20::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant3(__nt), __end));
         (1, 5)
     }
-    pub(crate) fn __reduce11<
+    fn __reduce16<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // AssignOp = "/=" => ActionFn(12);
+        // AssignOp = ">>=" => ActionFn(21);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action12::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action21::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant3(__nt), __end));
         (1, 5)
     }
-    pub(crate) fn __reduce12<
+    fn __reduce17<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // AssignOp = "=" => ActionFn(13);
+        // AssignOp = "=" => ActionFn(22);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action13::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action22::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant3(__nt), __end));
         (1, 5)
     }
-    pub(crate) fn __reduce13<
+    fn __reduce18<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // BitAndOp = "&" => ActionFn(32);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action32::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 6)
+    }
+    fn __reduce19<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // BitOrOp = "|" => ActionFn(28);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action28::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 7)
+    }
+    fn __reduce20<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // BitXorOp = "^" => ActionFn(30);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action30::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 8)
+    }
+    fn __reduce21<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // Comma<parameter> = parameter => ActionFn(110);
+        // Comma<parameter> = parameter => ActionFn(156);
         let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action110::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action156::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (1, 6)
+        (1, 9)
     }
-    pub(crate) fn __reduce14<
+    fn __reduce22<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // Comma<parameter> = (<parameter> ",")+, parameter => ActionFn(111);
+        // Comma<parameter> = (<parameter> ",")+, parameter => ActionFn(157);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant1(__symbols);
         let __sym0 = __pop_Variant2(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action111::<>(input, __sym0, __sym1);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action157::<>(reg, input, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (2, 6)
+        (2, 9)
     }
-    pub(crate) fn __reduce15<
+    fn __reduce23<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // Comma<parameter>? = Comma<parameter> => ActionFn(100);
+        // Comma<parameter>? = Comma<parameter> => ActionFn(144);
         let __sym0 = __pop_Variant4(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action100::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action144::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (1, 7)
+        (1, 10)
     }
-    pub(crate) fn __reduce16<
+    fn __reduce24<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // Comma<parameter>? =  => ActionFn(101);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action101::<>(input, &__start, &__end);
+        // Comma<parameter>? =  => ActionFn(145);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action145::<>(reg, input, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (0, 7)
+        (0, 10)
     }
-    pub(crate) fn __reduce17<
+    fn __reduce25<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // EqOp = "==" => ActionFn(19);
+        // EqOp = "==" => ActionFn(34);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action19::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action34::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 8)
+        (1, 11)
     }
-    pub(crate) fn __reduce18<
+    fn __reduce26<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // EqOp = "!=" => ActionFn(20);
+        // EqOp = "!=" => ActionFn(35);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action20::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action35::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 8)
+        (1, 11)
     }
-    pub(crate) fn __reduce19<
+    fn __reduce27<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // MulOp = "*" => ActionFn(30);
+        // MulOp = "*" => ActionFn(48);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action30::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action48::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 9)
+        (1, 12)
     }
-    pub(crate) fn __reduce20<
+    fn __reduce28<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // MulOp = "/" => ActionFn(31);
+        // MulOp = "/" => ActionFn(49);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action31::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action49::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 9)
+        (1, 12)
     }
-    pub(crate) fn __reduce21<
+    fn __reduce29<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // MulOp = "%" => ActionFn(32);
+        // MulOp = "%" => ActionFn(50);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action32::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action50::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 9)
+        (1, 12)
     }
-    pub(crate) fn __reduce22<
+    fn __reduce30<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // OrOp = "||" => ActionFn(15);
+        // OrOp = "||" => ActionFn(24);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action15::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action24::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 10)
+        (1, 13)
+    }
+    fn __reduce31<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // RelOp = ">" => ActionFn(37);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action37::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 14)
+    }
+    fn __reduce32<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // RelOp = "<" => ActionFn(38);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action38::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 14)
+    }
+    fn __reduce33<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // RelOp = ">=" => ActionFn(39);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action39::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 14)
+    }
+    fn __reduce34<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // RelOp = "<=" => ActionFn(40);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action40::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 14)
+    }
+    fn __reduce35<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // ShiftOp = "<<" => ActionFn(42);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action42::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 15)
+    }
+    fn __reduce36<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // ShiftOp = ">>" => ActionFn(43);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action43::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 15)
+    }
+    fn __reduce37<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // Tier<AddOp, mul_expression> = Tier<AddOp, mul_expression>, AddOp, mul_expression => ActionFn(126);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant6(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action126::<>(reg, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (3, 16)
+    }
+    fn __reduce38<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // Tier<AddOp, mul_expression> = mul_expression => ActionFn(127);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action127::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (1, 16)
+    }
+    fn __reduce39<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // Tier<AndOp, bitor_expression> = Tier<AndOp, bitor_expression>, AndOp, bitor_expression => ActionFn(140);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant6(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action140::<>(reg, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (3, 17)
+    }
+    fn __reduce40<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // Tier<AndOp, bitor_expression> = bitor_expression => ActionFn(141);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action141::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (1, 17)
     }
-    pub(crate) fn __reduce23<
+    fn __reduce41<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // RelOp = ">" => ActionFn(22);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action22::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 11)
+        // Tier<BitAndOp, eq_expression> = Tier<BitAndOp, eq_expression>, BitAndOp, eq_expression => ActionFn(134);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant6(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action134::<>(reg, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (3, 18)
     }
-    pub(crate) fn __reduce24<
+    fn __reduce42<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // RelOp = "<" => ActionFn(23);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action23::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 11)
+        // Tier<BitAndOp, eq_expression> = eq_expression => ActionFn(135);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action135::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (1, 18)
     }
-    pub(crate) fn __reduce25<
+    fn __reduce43<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // RelOp = ">=" => ActionFn(24);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action24::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 11)
+        // Tier<BitOrOp, bitxor_expression> = Tier<BitOrOp, bitxor_expression>, BitOrOp, bitxor_expression => ActionFn(138);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant6(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action138::<>(reg, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (3, 19)
     }
-    pub(crate) fn __reduce26<
+    fn __reduce44<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // RelOp = "<=" => ActionFn(25);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action25::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 11)
+        // Tier<BitOrOp, bitxor_expression> = bitxor_expression => ActionFn(139);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action139::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (1, 19)
     }
-    pub(crate) fn __reduce27<
+    fn __reduce45<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // Tier<AddOp, mul_expression> = Tier<AddOp, mul_expression>, AddOp, mul_expression => ActionFn(90);
+        // Tier<BitXorOp, bitand_expression> = Tier<BitXorOp, bitand_expression>, BitXorOp, bitand_expression => ActionFn(136);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant6(__symbols);
         let __sym1 = __pop_Variant3(__symbols);
         let __sym0 = __pop_Variant6(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action90::<>(input, __sym0, __sym1, __sym2);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action136::<>(reg, input, __sym0, __sym1, __sym2);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (3, 12)
+        (3, 20)
     }
-    pub(crate) fn __reduce28<
+    fn __reduce46<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // Tier<AddOp, mul_expression> = mul_expression => ActionFn(91);
+        // Tier<BitXorOp, bitand_expression> = bitand_expression => ActionFn(137);
         let __sym0 = __pop_Variant6(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action91::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action137::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 12)
+        (1, 20)
     }
-    pub(crate) fn __reduce29<
+    fn __reduce47<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // Tier<AndOp, eq_expression> = Tier<AndOp, eq_expression>, AndOp, eq_expression => ActionFn(96);
+        // Tier<EqOp, rel_expression> = Tier<EqOp, rel_expression>, EqOp, rel_expression => ActionFn(132);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant6(__symbols);
         let __sym1 = __pop_Variant3(__symbols);
         let __sym0 = __pop_Variant6(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action96::<>(input, __sym0, __sym1, __sym2);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action132::<>(reg, input, __sym0, __sym1, __sym2);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (3, 13)
+        (3, 21)
     }
-    pub(crate) fn __reduce30<
+    fn __reduce48<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // Tier<AndOp, eq_expression> = eq_expression => ActionFn(97);
+        // Tier<EqOp, rel_expression> = rel_expression => ActionFn(133);
         let __sym0 = __pop_Variant6(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action97::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action133::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 13)
+        (1, 21)
     }
-    pub(crate) fn __reduce31<
+    fn __reduce49<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // Tier<EqOp, rel_expression> = Tier<EqOp, rel_expression>, EqOp, rel_expression => ActionFn(94);
+        // Tier<MulOp, unary_expression> = Tier<MulOp, unary_expression>, MulOp, unary_expression => ActionFn(124);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant6(__symbols);
         let __sym1 = __pop_Variant3(__symbols);
         let __sym0 = __pop_Variant6(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action94::<>(input, __sym0, __sym1, __sym2);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action124::<>(reg, input, __sym0, __sym1, __sym2);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (3, 14)
+        (3, 22)
     }
-    pub(crate) fn __reduce32<
+    fn __reduce50<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // Tier<EqOp, rel_expression> = rel_expression => ActionFn(95);
+        // Tier<MulOp, unary_expression> = unary_expression => ActionFn(125);
         let __sym0 = __pop_Variant6(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action95::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action125::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 14)
+        (1, 22)
     }
-    pub(crate) fn __reduce33<
+    fn __reduce51<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // Tier<MulOp, unary_expression> = Tier<MulOp, unary_expression>, MulOp, unary_expression => ActionFn(88);
+        // Tier<OrOp, and_expression> = Tier<OrOp, and_expression>, OrOp, and_expression => ActionFn(142);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant6(__symbols);
         let __sym1 = __pop_Variant3(__symbols);
         let __sym0 = __pop_Variant6(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action88::<>(input, __sym0, __sym1, __sym2);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action142::<>(reg, input, __sym0, __sym1, __sym2);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (3, 15)
+        (3, 23)
     }
-    pub(crate) fn __reduce34<
+    fn __reduce52<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // Tier<MulOp, unary_expression> = unary_expression => ActionFn(89);
+        // Tier<OrOp, and_expression> = and_expression => ActionFn(143);
         let __sym0 = __pop_Variant6(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action89::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action143::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 15)
+        (1, 23)
     }
-    pub(crate) fn __reduce35<
+    fn __reduce53<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // Tier<OrOp, and_expression> = Tier<OrOp, and_expression>, OrOp, and_expression => ActionFn(98);
+        // Tier<RelOp, shift_expression> = Tier<RelOp, shift_expression>, RelOp, shift_expression => ActionFn(130);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant6(__symbols);
         let __sym1 = __pop_Variant3(__symbols);
         let __sym0 = __pop_Variant6(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action98::<>(input, __sym0, __sym1, __sym2);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action130::<>(reg, input, __sym0, __sym1, __sym2);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (3, 16)
+        (3, 24)
     }
-    pub(crate) fn __reduce36<
+    fn __reduce54<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // Tier<OrOp, and_expression> = and_expression => ActionFn(99);
+        // Tier<RelOp, shift_expression> = shift_expression => ActionFn(131);
         let __sym0 = __pop_Variant6(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action99::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action131::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 16)
+        (1, 24)
     }
-    pub(crate) fn __reduce37<
+    fn __reduce55<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // Tier<RelOp, add_expression> = Tier<RelOp, add_expression>, RelOp, add_expression => ActionFn(92);
+        // Tier<ShiftOp, add_expression> = Tier<ShiftOp, add_expression>, ShiftOp, add_expression => ActionFn(128);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant6(__symbols);
         let __sym1 = __pop_Variant3(__symbols);
         let __sym0 = __pop_Variant6(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action92::<>(input, __sym0, __sym1, __sym2);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action128::<>(reg, input, __sym0, __sym1, __sym2);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (3, 17)
+        (3, 25)
     }
-    pub(crate) fn __reduce38<
+    fn __reduce56<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // Tier<RelOp, add_expression> = add_expression => ActionFn(93);
+        // Tier<ShiftOp, add_expression> = add_expression => ActionFn(129);
         let __sym0 = __pop_Variant6(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action93::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action129::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 17)
+        (1, 25)
     }
-    pub(crate) fn __reduce39<
+    fn __reduce57<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // UnaryOp = "*" => ActionFn(35);
+        // UnaryOp = "*" => ActionFn(53);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action35::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action53::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (1, 18)
+        (1, 26)
     }
-    pub(crate) fn __reduce40<
+    fn __reduce58<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // UnaryOp = "!" => ActionFn(36);
+        // UnaryOp = "!" => ActionFn(54);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action36::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action54::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (1, 18)
+        (1, 26)
     }
-    pub(crate) fn __reduce41<
+    fn __reduce59<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // UnaryOp = "-" => ActionFn(37);
+        // UnaryOp = "-" => ActionFn(55);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action37::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action55::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (1, 18)
+        (1, 26)
     }
-    pub(crate) fn __reduce42<
+    fn __reduce60<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // UnaryOp = "&" => ActionFn(38);
+        // UnaryOp = "&" => ActionFn(56);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action38::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action56::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (1, 18)
+        (1, 26)
+    }
+    fn __reduce61<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // UnaryOp = "~" => ActionFn(57);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action57::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 26)
     }
-    pub(crate) fn __reduce44<
+    fn __reduce63<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // add_expression = Tier<AddOp, mul_expression> => ActionFn(26);
+        // add_expression = Tier<AddOp, mul_expression> => ActionFn(44);
         let __sym0 = __pop_Variant6(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action26::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action44::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 20)
+        (1, 28)
     }
-    pub(crate) fn __reduce45<
+    fn __reduce64<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // and_expression = Tier<AndOp, eq_expression> => ActionFn(16);
+        // and_expression = Tier<AndOp, bitor_expression> => ActionFn(25);
         let __sym0 = __pop_Variant6(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action16::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action25::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 21)
+        (1, 29)
     }
-    pub(crate) fn __reduce47<
+    fn __reduce66<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // asm_list = asm_line => ActionFn(55);
+        // asm_list = asm_line => ActionFn(79);
         let __sym0 = __pop_Variant9(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action55::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action79::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 23)
+        (1, 31)
     }
-    pub(crate) fn __reduce48<
+    fn __reduce67<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // asm_list = asm_list, ",", asm_line => ActionFn(56);
+        // asm_list = asm_list, ",", asm_line => ActionFn(80);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant9(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant10(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action56::<>(input, __sym0, __sym1, __sym2);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action80::<>(reg, input, __sym0, __sym1, __sym2);
         __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (3, 23)
+        (3, 31)
     }
-    pub(crate) fn __reduce49<
+    fn __reduce68<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // asm_statement = "asm!", "{", asm_list, "}" => ActionFn(54);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant10(__symbols);
+        // asm_list = asm_list, ",", asm_operand => ActionFn(81);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant11(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym3.2;
-        let __nt = super::__action54::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (4, 24)
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action81::<>(reg, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 31)
+    }
+    fn __reduce72<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // bitand_expression = Tier<BitAndOp, eq_expression> => ActionFn(31);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action31::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (1, 34)
+    }
+    fn __reduce73<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // bitor_expression = Tier<BitOrOp, bitxor_expression> => ActionFn(27);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action27::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (1, 35)
+    }
+    fn __reduce74<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // bitxor_expression = Tier<BitXorOp, bitand_expression> => ActionFn(29);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action29::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (1, 36)
+    }
+    fn __reduce75<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // case_value = integer => ActionFn(94);
+        let __sym0 = __pop_Variant13(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action94::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 37)
     }
-    pub(crate) fn __reduce50<
+    fn __reduce76<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // compound_statement = "{", statement_list, "}" => ActionFn(126);
+        // case_value = character => ActionFn(95);
+        let __sym0 = __pop_Variant14(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action95::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 37)
+    }
+    fn __reduce78<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // compound_statement = "{", statement_list, "}" => ActionFn(172);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant21(__symbols);
+        let __sym1 = __pop_Variant24(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action126::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (3, 25)
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action172::<>(reg, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (3, 39)
     }
-    pub(crate) fn __reduce51<
+    fn __reduce79<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // compound_statement = "{", "}" => ActionFn(127);
+        // compound_statement = "{", "}" => ActionFn(173);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action127::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (2, 25)
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action173::<>(reg, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (2, 39)
     }
-    pub(crate) fn __reduce52<
+    fn __reduce80<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // declare_statement = type_specifier, identifier, ";" => ActionFn(59);
+        // declare_statement = type_specifier, identifier, ";" => ActionFn(86);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant17(__symbols);
-        let __sym0 = __pop_Variant24(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action59::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (3, 26)
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant29(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action86::<>(reg, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (3, 40)
     }
-    pub(crate) fn __reduce53<
+    fn __reduce81<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // declare_statement = type_specifier, identifier, "=", expression, ";" => ActionFn(60);
+        // declare_statement = type_specifier, identifier, "=", expression, ";" => ActionFn(87);
         assert!(__symbols.len() >= 5);
         let __sym4 = __pop_Variant0(__symbols);
         let __sym3 = __pop_Variant6(__symbols);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant17(__symbols);
-        let __sym0 = __pop_Variant24(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym4.2;
-        let __nt = super::__action60::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (5, 26)
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant29(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action87::<>(reg, input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (5, 40)
     }
-    pub(crate) fn __reduce54<
+    fn __reduce82<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // eq_expression = Tier<EqOp, rel_expression> => ActionFn(18);
+        // eq_expression = Tier<EqOp, rel_expression> => ActionFn(33);
         let __sym0 = __pop_Variant6(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action18::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action33::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 27)
+        (1, 41)
     }
-    pub(crate) fn __reduce55<
+    fn __reduce83<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // expression = unary_expression, AssignOp, expression => ActionFn(7);
+        // expression = unary_expression, AssignOp, expression => ActionFn(11);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant6(__symbols);
         let __sym1 = __pop_Variant3(__symbols);
         let __sym0 = __pop_Variant6(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action7::<>(input, __sym0, __sym1, __sym2);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action11::<>(reg, input, __sym0, __sym1, __sym2);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (3, 28)
+        (3, 42)
     }
-    pub(crate) fn __reduce56<
+    fn __reduce84<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // expression = or_expression => ActionFn(8);
+        // expression = or_expression => ActionFn(12);
         let __sym0 = __pop_Variant6(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action8::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action12::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 28)
+        (1, 42)
     }
-    pub(crate) fn __reduce57<
+    fn __reduce85<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // expression? = expression => ActionFn(82);
+        // expression? = expression => ActionFn(118);
         let __sym0 = __pop_Variant6(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action82::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (1, 29)
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action118::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 43)
     }
-    pub(crate) fn __reduce58<
+    fn __reduce86<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // expression? =  => ActionFn(83);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action83::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (0, 29)
+        // expression? =  => ActionFn(119);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action119::<>(reg, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (0, 43)
     }
-    pub(crate) fn __reduce59<
+    fn __reduce87<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // expression_list = expression => ActionFn(5);
+        // expression_list = expression => ActionFn(9);
         let __sym0 = __pop_Variant6(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action5::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 30)
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action9::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 44)
     }
-    pub(crate) fn __reduce60<
+    fn __reduce88<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // expression_list = expression_list, ",", expression => ActionFn(6);
+        // expression_list = expression_list, ",", expression => ActionFn(10);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant6(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant13(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action6::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (3, 30)
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action10::<>(reg, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (3, 44)
     }
-    pub(crate) fn __reduce61<
+    fn __reduce89<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // expression_list? = expression_list => ActionFn(86);
-        let __sym0 = __pop_Variant13(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action86::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 31)
+        // expression_list? = expression_list => ActionFn(122);
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action122::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (1, 45)
     }
-    pub(crate) fn __reduce62<
+    fn __reduce90<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // expression_list? =  => ActionFn(87);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action87::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (0, 31)
+        // expression_list? =  => ActionFn(123);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action123::<>(reg, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (0, 45)
     }
-    pub(crate) fn __reduce63<
+    fn __reduce91<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // expression_statement = expression, ";" => ActionFn(114);
+        // expression_statement = expression, ";" => ActionFn(160);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant6(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action114::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (2, 32)
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action160::<>(reg, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (2, 46)
     }
-    pub(crate) fn __reduce64<
+    fn __reduce92<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // expression_statement = ";" => ActionFn(115);
+        // expression_statement = ";" => ActionFn(161);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action115::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 32)
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action161::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 46)
     }
-    pub(crate) fn __reduce65<
+    fn __reduce93<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // float = r#"\\-?[0-9]+\\.[0-9]*"# => ActionFn(79);
+        // float = r#"\\-?[0-9]+\\.[0-9]*"# => ActionFn(113);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action79::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 33)
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action113::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 47)
     }
-    pub(crate) fn __reduce66<
+    fn __reduce94<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // function_declaration = type_specifier, identifier, "(", Comma<parameter>, ")", compound_statement => ActionFn(112);
+        // function_declaration = type_specifier, identifier, "(", Comma<parameter>, ")", compound_statement => ActionFn(158);
         assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant11(__symbols);
+        let __sym5 = __pop_Variant12(__symbols);
         let __sym4 = __pop_Variant0(__symbols);
         let __sym3 = __pop_Variant4(__symbols);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant17(__symbols);
-        let __sym0 = __pop_Variant24(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym5.2;
-        let __nt = super::__action112::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (6, 34)
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant29(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action158::<>(reg, input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (6, 48)
     }
-    pub(crate) fn __reduce67<
+    fn __reduce95<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // function_declaration = type_specifier, identifier, "(", ")", compound_statement => ActionFn(113);
+        // function_declaration = type_specifier, identifier, "(", ")", compound_statement => ActionFn(159);
         assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant11(__symbols);
+        let __sym4 = __pop_Variant12(__symbols);
         let __sym3 = __pop_Variant0(__symbols);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant17(__symbols);
-        let __sym0 = __pop_Variant24(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym4.2;
-        let __nt = super::__action113::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (5, 34)
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant29(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action159::<>(reg, input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (5, 48)
     }
-    pub(crate) fn __reduce68<
+    fn __reduce96<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // identifier = r#"[a-zA-Z][a-zA-Z0-9_]*"# => ActionFn(77);
+        // identifier = r#"[a-zA-Z][a-zA-Z0-9_]*"# => ActionFn(111);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action77::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 35)
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action111::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 49)
     }
-    pub(crate) fn __reduce69<
+    fn __reduce97<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // integer = r#"\\-?[0-9]*"# => ActionFn(78);
+        // integer = r#"\\-?[0-9]*"# => ActionFn(112);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action78::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 36)
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action112::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 50)
     }
-    pub(crate) fn __reduce70<
+    fn __reduce98<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // iteration_statement = "while", "(", expression, ")", statement => ActionFn(64);
+        // iteration_statement = "while", "(", expression, ")", statement => ActionFn(96);
         assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant11(__symbols);
+        let __sym4 = __pop_Variant12(__symbols);
         let __sym3 = __pop_Variant0(__symbols);
         let __sym2 = __pop_Variant6(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym4.2;
-        let __nt = super::__action64::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (5, 37)
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action96::<>(reg, input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (5, 51)
     }
-    pub(crate) fn __reduce71<
+    fn __reduce99<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // iteration_statement = "for", "(", simple_statement, expression, ";", expression, ")", statement => ActionFn(116);
+        // iteration_statement = "for", "(", simple_statement, expression, ";", expression, ")", statement => ActionFn(162);
         assert!(__symbols.len() >= 8);
-        let __sym7 = __pop_Variant11(__symbols);
+        let __sym7 = __pop_Variant12(__symbols);
         let __sym6 = __pop_Variant0(__symbols);
         let __sym5 = __pop_Variant6(__symbols);
         let __sym4 = __pop_Variant0(__symbols);
         let __sym3 = __pop_Variant6(__symbols);
-        let __sym2 = __pop_Variant11(__symbols);
+        let __sym2 = __pop_Variant12(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym7.2;
-        let __nt = super::__action116::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (8, 37)
+        let __start = __sym0.0.clone();
+        let __end = __sym7.2.clone();
+        let __nt = super::__action162::<>(reg, input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (8, 51)
     }
-    pub(crate) fn __reduce72<
+    fn __reduce100<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // iteration_statement = "for", "(", simple_statement, expression, ";", ")", statement => ActionFn(117);
+        // iteration_statement = "for", "(", simple_statement, expression, ";", ")", statement => ActionFn(163);
         assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant11(__symbols);
+        let __sym6 = __pop_Variant12(__symbols);
         let __sym5 = __pop_Variant0(__symbols);
         let __sym4 = __pop_Variant0(__symbols);
         let __sym3 = __pop_Variant6(__symbols);
-        let __sym2 = __pop_Variant11(__symbols);
+        let __sym2 = __pop_Variant12(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym6.2;
-        let __nt = super::__action117::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (7, 37)
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action163::<>(reg, input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (7, 51)
     }
-    pub(crate) fn __reduce73<
+    fn __reduce101<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // iteration_statement = "for", "(", simple_statement, ";", expression, ")", statement => ActionFn(118);
+        // iteration_statement = "for", "(", simple_statement, ";", expression, ")", statement => ActionFn(164);
         assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant11(__symbols);
+        let __sym6 = __pop_Variant12(__symbols);
         let __sym5 = __pop_Variant0(__symbols);
         let __sym4 = __pop_Variant6(__symbols);
         let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant11(__symbols);
+        let __sym2 = __pop_Variant12(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action164::<>(reg, input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (7, 51)
+    }
+    fn __reduce102<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // iteration_statement = "for", "(", simple_statement, ";", ")", statement => ActionFn(165);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant12(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant12(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action165::<>(reg, input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (6, 51)
+    }
+    fn __reduce103<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // jump_statement = "continue", ";" => ActionFn(100);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action100::<>(reg, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (2, 52)
+    }
+    fn __reduce104<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // jump_statement = "break", ";" => ActionFn(101);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action101::<>(reg, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (2, 52)
+    }
+    fn __reduce105<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // jump_statement = "return", expression, ";" => ActionFn(166);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant6(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action166::<>(reg, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (3, 52)
+    }
+    fn __reduce106<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // jump_statement = "return", ";" => ActionFn(167);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action167::<>(reg, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (2, 52)
+    }
+    fn __reduce107<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // labeled_statement = "case", case_value, ":", statement => ActionFn(92);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant12(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant13(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action92::<>(reg, input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (4, 53)
+    }
+    fn __reduce108<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // labeled_statement = "default", ":", statement => ActionFn(93);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant12(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym6.2;
-        let __nt = super::__action118::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (7, 37)
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action93::<>(reg, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (3, 53)
     }
-    pub(crate) fn __reduce74<
+    fn __reduce109<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // iteration_statement = "for", "(", simple_statement, ";", ")", statement => ActionFn(119);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant11(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant11(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym5.2;
-        let __nt = super::__action119::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (6, 37)
+        // member = type_specifier, identifier, ";" => ActionFn(6);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant29(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action6::<>(reg, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (3, 54)
     }
-    pub(crate) fn __reduce75<
+    fn __reduce110<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // jump_statement = "continue", ";" => ActionFn(68);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action68::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (2, 38)
+        // member+ = member => ActionFn(147);
+        let __sym0 = __pop_Variant20(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action147::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 55)
     }
-    pub(crate) fn __reduce76<
+    fn __reduce111<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // jump_statement = "break", ";" => ActionFn(69);
+        // member+ = member+, member => ActionFn(148);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action69::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (2, 38)
+        let __sym1 = __pop_Variant20(__symbols);
+        let __sym0 = __pop_Variant21(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action148::<>(reg, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (2, 55)
     }
-    pub(crate) fn __reduce77<
+    fn __reduce112<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // jump_statement = "return", expression, ";" => ActionFn(120);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant6(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action120::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (3, 38)
+        // module = function_declaration => ActionFn(1);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action1::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 56)
     }
-    pub(crate) fn __reduce78<
+    fn __reduce113<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // jump_statement = "return", ";" => ActionFn(121);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action121::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (2, 38)
+        // module = struct_declaration => ActionFn(2);
+        let __sym0 = __pop_Variant27(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action2::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 56)
     }
-    pub(crate) fn __reduce79<
+    fn __reduce114<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // module = function_declaration => ActionFn(1);
-        let __sym0 = __pop_Variant16(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action1::<>(input, __sym0);
+        // module = module, function_declaration => ActionFn(3);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant19(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action3::<>(reg, input, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (1, 39)
+        (2, 56)
     }
-    pub(crate) fn __reduce80<
+    fn __reduce115<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // module = module, function_declaration => ActionFn(2);
+        // module = module, struct_declaration => ActionFn(4);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant16(__symbols);
+        let __sym1 = __pop_Variant27(__symbols);
         let __sym0 = __pop_Variant8(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action2::<>(input, __sym0, __sym1);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action4::<>(reg, input, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (2, 39)
+        (2, 56)
     }
-    pub(crate) fn __reduce81<
+    fn __reduce116<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // mul_expression = Tier<MulOp, unary_expression> => ActionFn(29);
+        // mul_expression = Tier<MulOp, unary_expression> => ActionFn(47);
         let __sym0 = __pop_Variant6(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action29::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action47::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 40)
+        (1, 57)
     }
-    pub(crate) fn __reduce82<
+    fn __reduce117<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // or_expression = Tier<OrOp, and_expression> => ActionFn(14);
+        // or_expression = Tier<OrOp, and_expression> => ActionFn(23);
         let __sym0 = __pop_Variant6(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action14::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action23::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 41)
+        (1, 58)
     }
-    pub(crate) fn __reduce83<
+    fn __reduce118<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // parameter = type_specifier, identifier => ActionFn(4);
+        // parameter = type_specifier, identifier => ActionFn(8);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant17(__symbols);
-        let __sym0 = __pop_Variant24(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action4::<>(input, __sym0, __sym1);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant29(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action8::<>(reg, input, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (2, 42)
+        (2, 59)
     }
-    pub(crate) fn __reduce84<
+    fn __reduce119<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // pointer = "*" => ActionFn(75);
+        // pointer = "*" => ActionFn(109);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action75::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 43)
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action109::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (1, 60)
     }
-    pub(crate) fn __reduce85<
+    fn __reduce120<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // pointer = pointer, "*" => ActionFn(76);
+        // pointer = pointer, "*" => ActionFn(110);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant19(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action76::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (2, 43)
+        let __sym0 = __pop_Variant22(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action110::<>(reg, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (2, 60)
     }
-    pub(crate) fn __reduce86<
+    fn __reduce121<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // pointer? = pointer => ActionFn(80);
-        let __sym0 = __pop_Variant19(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action80::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (1, 44)
+        // pointer? = pointer => ActionFn(116);
+        let __sym0 = __pop_Variant22(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action116::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 61)
     }
-    pub(crate) fn __reduce87<
+    fn __reduce122<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // pointer? =  => ActionFn(81);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action81::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (0, 44)
+        // pointer? =  => ActionFn(117);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action117::<>(reg, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (0, 61)
     }
-    pub(crate) fn __reduce88<
+    fn __reduce123<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // postfix_expression = identifier => ActionFn(39);
-        let __sym0 = __pop_Variant17(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action39::<>(input, __sym0);
+        // postfix_expression = identifier => ActionFn(58);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action58::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 45)
+        (1, 62)
+    }
+    fn __reduce124<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // postfix_expression = integer => ActionFn(59);
+        let __sym0 = __pop_Variant13(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action59::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (1, 62)
     }
-    pub(crate) fn __reduce89<
+    fn __reduce125<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // postfix_expression = integer => ActionFn(40);
+        // postfix_expression = float => ActionFn(60);
         let __sym0 = __pop_Variant18(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action40::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action60::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 45)
+        (1, 62)
     }
-    pub(crate) fn __reduce90<
+    fn __reduce126<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // postfix_expression = float => ActionFn(41);
-        let __sym0 = __pop_Variant15(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action41::<>(input, __sym0);
+        // postfix_expression = character => ActionFn(61);
+        let __sym0 = __pop_Variant14(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action61::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 45)
+        (1, 62)
+    }
+    fn __reduce127<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // postfix_expression = string => ActionFn(62);
+        let __sym0 = __pop_Variant26(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action62::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (1, 62)
     }
-    pub(crate) fn __reduce91<
+    fn __reduce128<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // postfix_expression = identifier, "[", expression_list, "]" => ActionFn(42);
+        // postfix_expression = identifier, "[", expression_list, "]" => ActionFn(63);
         assert!(__symbols.len() >= 4);
         let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant13(__symbols);
+        let __sym2 = __pop_Variant16(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant17(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym3.2;
-        let __nt = super::__action42::<>(input, __sym0, __sym1, __sym2, __sym3);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action63::<>(reg, input, __sym0, __sym1, __sym2, __sym3);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (4, 45)
+        (4, 62)
     }
-    pub(crate) fn __reduce92<
+    fn __reduce129<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // postfix_expression = identifier, "(", expression_list, ")" => ActionFn(122);
+        // postfix_expression = identifier, "(", expression_list, ")" => ActionFn(168);
         assert!(__symbols.len() >= 4);
         let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant13(__symbols);
+        let __sym2 = __pop_Variant16(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant17(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym3.2;
-        let __nt = super::__action122::<>(input, __sym0, __sym1, __sym2, __sym3);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action168::<>(reg, input, __sym0, __sym1, __sym2, __sym3);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (4, 45)
+        (4, 62)
     }
-    pub(crate) fn __reduce93<
+    fn __reduce130<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // postfix_expression = identifier, "(", ")" => ActionFn(123);
+        // postfix_expression = identifier, "(", ")" => ActionFn(169);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant17(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action123::<>(input, __sym0, __sym1, __sym2);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action169::<>(reg, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (3, 62)
+    }
+    fn __reduce131<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // postfix_expression = postfix_expression, ".", identifier => ActionFn(65);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant9(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action65::<>(reg, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (3, 62)
+    }
+    fn __reduce132<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // postfix_expression = postfix_expression, "->", identifier => ActionFn(66);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant9(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action66::<>(reg, input, __sym0, __sym1, __sym2);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (3, 45)
+        (3, 62)
     }
-    pub(crate) fn __reduce94<
+    fn __reduce133<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // postfix_expression = "(", expression, ")" => ActionFn(44);
+        // postfix_expression = "(", expression, ")" => ActionFn(67);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
         let __sym1 = __pop_Variant6(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action44::<>(input, __sym0, __sym1, __sym2);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action67::<>(reg, input, __sym0, __sym1, __sym2);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (3, 45)
+        (3, 62)
     }
-    pub(crate) fn __reduce95<
+    fn __reduce134<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // rel_expression = Tier<RelOp, add_expression> => ActionFn(21);
+        // rel_expression = Tier<RelOp, shift_expression> => ActionFn(36);
         let __sym0 = __pop_Variant6(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action21::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action36::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 46)
+        (1, 63)
     }
-    pub(crate) fn __reduce96<
+    fn __reduce135<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // selection_statement = "if", "(", expression, ")", statement => ActionFn(62);
+        // selection_statement = "if", "(", expression, ")", statement => ActionFn(89);
         assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant11(__symbols);
+        let __sym4 = __pop_Variant12(__symbols);
         let __sym3 = __pop_Variant0(__symbols);
         let __sym2 = __pop_Variant6(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym4.2;
-        let __nt = super::__action62::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (5, 47)
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action89::<>(reg, input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (5, 64)
     }
-    pub(crate) fn __reduce97<
+    fn __reduce136<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // selection_statement = "if", "(", expression, ")", compound_statement, "else", compound_statement => ActionFn(63);
+        // selection_statement = "if", "(", expression, ")", compound_statement, "else", compound_statement => ActionFn(90);
         assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant11(__symbols);
+        let __sym6 = __pop_Variant12(__symbols);
         let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant11(__symbols);
+        let __sym4 = __pop_Variant12(__symbols);
         let __sym3 = __pop_Variant0(__symbols);
         let __sym2 = __pop_Variant6(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym6.2;
-        let __nt = super::__action63::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (7, 47)
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action90::<>(reg, input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (7, 64)
     }
-    pub(crate) fn __reduce98<
+    fn __reduce137<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // simple_statement = declare_statement => ActionFn(66);
-        let __sym0 = __pop_Variant11(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action66::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 48)
+        // selection_statement = "switch", "(", expression, ")", statement => ActionFn(91);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant12(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant6(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action91::<>(reg, input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (5, 64)
     }
-    pub(crate) fn __reduce99<
+    fn __reduce138<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // simple_statement = expression_statement => ActionFn(67);
-        let __sym0 = __pop_Variant11(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action67::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 48)
+        // shift_expression = Tier<ShiftOp, add_expression> => ActionFn(41);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action41::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (1, 65)
     }
-    pub(crate) fn __reduce100<
+    fn __reduce139<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // statement = compound_statement => ActionFn(47);
-        let __sym0 = __pop_Variant11(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action47::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 49)
+        // simple_statement = declare_statement => ActionFn(98);
+        let __sym0 = __pop_Variant12(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action98::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 66)
     }
-    pub(crate) fn __reduce101<
+    fn __reduce140<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // statement = declare_statement => ActionFn(48);
-        let __sym0 = __pop_Variant11(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action48::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 49)
+        // simple_statement = expression_statement => ActionFn(99);
+        let __sym0 = __pop_Variant12(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action99::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 66)
     }
-    pub(crate) fn __reduce102<
+    fn __reduce141<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // statement = expression_statement => ActionFn(49);
-        let __sym0 = __pop_Variant11(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action49::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 49)
+        // statement = compound_statement => ActionFn(70);
+        let __sym0 = __pop_Variant12(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action70::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 67)
     }
-    pub(crate) fn __reduce103<
+    fn __reduce142<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // statement = selection_statement => ActionFn(50);
-        let __sym0 = __pop_Variant11(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action50::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 49)
+        // statement = declare_statement => ActionFn(71);
+        let __sym0 = __pop_Variant12(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action71::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 67)
     }
-    pub(crate) fn __reduce104<
+    fn __reduce143<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // statement = iteration_statement => ActionFn(51);
-        let __sym0 = __pop_Variant11(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action51::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 49)
+        // statement = expression_statement => ActionFn(72);
+        let __sym0 = __pop_Variant12(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action72::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 67)
     }
-    pub(crate) fn __reduce105<
+    fn __reduce144<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // statement = jump_statement => ActionFn(52);
-        let __sym0 = __pop_Variant11(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action52::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 49)
+        // statement = selection_statement => ActionFn(73);
+        let __sym0 = __pop_Variant12(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action73::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 67)
     }
-    pub(crate) fn __reduce106<
+    fn __reduce145<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // statement = asm_statement => ActionFn(53);
-        let __sym0 = __pop_Variant11(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action53::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 49)
+        // statement = iteration_statement => ActionFn(74);
+        let __sym0 = __pop_Variant12(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action74::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 67)
     }
-    pub(crate) fn __reduce107<
+    fn __reduce146<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // statement_list = statement => ActionFn(45);
-        let __sym0 = __pop_Variant11(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action45::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 50)
+        // statement = jump_statement => ActionFn(75);
+        let __sym0 = __pop_Variant12(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action75::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 67)
+    }
+    fn __reduce147<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // statement = labeled_statement => ActionFn(76);
+        let __sym0 = __pop_Variant12(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action76::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 67)
+    }
+    fn __reduce148<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // statement = asm_statement => ActionFn(77);
+        let __sym0 = __pop_Variant12(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action77::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 67)
+    }
+    fn __reduce149<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // statement_list = statement => ActionFn(68);
+        let __sym0 = __pop_Variant12(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action68::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (1, 68)
     }
-    pub(crate) fn __reduce108<
+    fn __reduce150<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // statement_list = statement_list, statement => ActionFn(46);
+        // statement_list = statement_list, statement => ActionFn(69);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant11(__symbols);
-        let __sym0 = __pop_Variant21(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action46::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (2, 50)
+        let __sym1 = __pop_Variant12(__symbols);
+        let __sym0 = __pop_Variant24(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action69::<>(reg, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (2, 68)
     }
-    pub(crate) fn __reduce109<
+    fn __reduce151<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // statement_list? = statement_list => ActionFn(84);
-        let __sym0 = __pop_Variant21(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action84::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
-        (1, 51)
+        // statement_list? = statement_list => ActionFn(120);
+        let __sym0 = __pop_Variant24(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action120::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (1, 69)
     }
-    pub(crate) fn __reduce110<
+    fn __reduce152<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // statement_list? =  => ActionFn(85);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action85::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
-        (0, 51)
+        // statement_list? =  => ActionFn(121);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action121::<>(reg, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (0, 69)
     }
-    pub(crate) fn __reduce111<
+    fn __reduce154<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // type_primitive = "void" => ActionFn(72);
+        // struct_declaration = "struct", identifier, "{", member+, "}", ";" => ActionFn(5);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant21(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant9(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action72::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
-        (1, 52)
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action5::<>(reg, input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (6, 71)
     }
-    pub(crate) fn __reduce112<
+    fn __reduce155<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // type_primitive = "int" => ActionFn(73);
+        // type_primitive = "void" => ActionFn(104);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action73::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
-        (1, 52)
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action104::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 72)
     }
-    pub(crate) fn __reduce113<
+    fn __reduce156<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // type_primitive = "float" => ActionFn(74);
+        // type_primitive = "int" => ActionFn(105);
         let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action74::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
-        (1, 52)
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action105::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 72)
+    }
+    fn __reduce157<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // type_primitive = "float" => ActionFn(106);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action106::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 72)
+    }
+    fn __reduce158<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // type_primitive = "char" => ActionFn(107);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action107::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 72)
     }
-    pub(crate) fn __reduce114<
+    fn __reduce159<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // type_specifier = type_primitive, pointer => ActionFn(124);
+        // type_primitive = "struct", identifier => ActionFn(108);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant19(__symbols);
-        let __sym0 = __pop_Variant23(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action124::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
-        (2, 53)
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action108::<>(reg, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (2, 72)
     }
-    pub(crate) fn __reduce115<
+    fn __reduce160<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // type_specifier = type_primitive => ActionFn(125);
-        let __sym0 = __pop_Variant23(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action125::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
-        (1, 53)
+        // type_specifier = type_primitive, pointer => ActionFn(170);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant22(__symbols);
+        let __sym0 = __pop_Variant28(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action170::<>(reg, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (2, 73)
+    }
+    fn __reduce161<
+        'input,
+        'r,
+    >(
+        reg: &'r mut Registry,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
+    ) -> (usize, usize)
+    {
+        // type_specifier = type_primitive => ActionFn(171);
+        let __sym0 = __pop_Variant28(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action171::<>(reg, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (1, 73)
     }
-    pub(crate) fn __reduce116<
+    fn __reduce162<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // unary_expression = postfix_expression => ActionFn(33);
+        // unary_expression = postfix_expression => ActionFn(51);
         let __sym0 = __pop_Variant6(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action33::<>(input, __sym0);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action51::<>(reg, input, __sym0);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 54)
+        (1, 74)
     }
-    pub(crate) fn __reduce117<
+    fn __reduce163<
         'input,
+        'r,
     >(
+        reg: &'r mut Registry,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'r ())>,
     ) -> (usize, usize)
     {
-        // unary_expression = UnaryOp, postfix_expression => ActionFn(34);
+        // unary_expression = UnaryOp, postfix_expression => ActionFn(52);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant6(__symbols);
         let __sym0 = __pop_Variant7(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action34::<>(input, __sym0, __sym1);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action52::<>(reg, input, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (2, 54)
+        (2, 74)
     }
 }
+#[allow(unused_imports)]
 pub use self::__parse__module::moduleParser;
-#[cfg_attr(rustfmt, rustfmt_skip)]
+#[rustfmt::skip]
 mod __intern_token {
     #![allow(unused_imports)]
     use lalrpop_util::ParseError;
     use std::str::FromStr;
     use super::super::ast::*;
     use crate::asm;
+    use crate::asm::strings;
+    use crate::registry::Registry;
     #[allow(unused_extern_crates)]
     extern crate lalrpop_util as __lalrpop_util;
     #[allow(unused_imports)]
     use self::__lalrpop_util::state_machine as __state_machine;
-    extern crate core;
+    #[allow(unused_extern_crates)]
     extern crate alloc;
     pub fn new_builder() -> __lalrpop_util::lexer::MatcherBuilder {
         let __strs: &[(&str, bool)] = &[
-            ("^((?:\"[ \\#,\\-0-9A-\\[\\]a-z]*\"))", false),
-            ("^((?:[A-Za-z][0-9A-Z_a-z]*))", false),
-            ("^((?:\\-?[0-9]*))", false),
-            ("^((?:\\-?[0-9]+\\.[0-9]*))", false),
-            ("^(!)", false),
-            ("^((?:!=))", false),
-            ("^(%)", false),
-            ("^(\\&)", false),
-            ("^((?:\\&\\&))", false),
-            ("^(\\()", false),
-            ("^(\\))", false),
-            ("^(\\*)", false),
-            ("^((?:\\*=))", false),
-            ("^(\\+)", false),
-            ("^((?:\\+=))", false),
-            ("^(,)", false),
-            ("^(\\-)", false),
-            ("^((?:\\-=))", false),
-            ("^(/)", false),
-            ("^((?:/=))", false),
-            ("^(;)", false),
-            ("^(<)", false),
-            ("^((?:<=))", false),
-            ("^(=)", false),
-            ("^((?:==))", false),
-            ("^(>)", false),
-            ("^((?:>=))", false),
-            ("^(\\[)", false),
-            ("^(\\])", false),
-            ("^((?:asm!))", false),
-            ("^((?:break))", false),
-            ("^((?:continue))", false),
-            ("^((?:else))", false),
-            ("^((?:float))", false),
-            ("^((?:for))", false),
-            ("^((?:if))", false),
-            ("^((?:int))", false),
-            ("^((?:return))", false),
-            ("^((?:void))", false),
-            ("^((?:while))", false),
-            ("^(\\{)", false),
-            ("^((?:\\|\\|))", false),
-            ("^(\\})", false),
-            ("^((?:(?://)[\0-\t\u{b}\u{c}\u{e}-\u{10ffff}]*[\n\r]*))", true),
-            ("^((?:(?:/\\*)[\0-\\)\\+-\u{10ffff}]*\\*+(?:[\0-\\)\\+-\\.0-\u{10ffff}][\0-\\)\\+-\u{10ffff}]*\\*+)*/))", true),
-            ("^([\t-\r \u{85}\u{a0}\u{1680}\u{2000}-\u{200a}\u{2028}\u{2029}\u{202f}\u{205f}\u{3000}]*)", true),
+            ("(?:\"((?:[\0-\t\u{b}-!\\#-\\[\\]-\u{10ffff}]|(?:\\\\[\0-\t\u{b}-\u{10ffff}])))*\")", false),
+            ("(?:'((?:[\0-\t\u{b}-\\&\\(-\\[\\]-\u{10ffff}]|(?:\\\\[\0-\t\u{b}-\u{10ffff}][0-9A-Fa-f]*)))')", false),
+            ("(?:[A-Za-z][0-9A-Z_a-z]*)", false),
+            ("(?:\\-?[0-9]*)", false),
+            ("(?:\\-?[0-9]+\\.[0-9]*)", false),
+            ("!", false),
+            ("(?:!=)", false),
+            ("%", false),
+            ("\\&", false),
+            ("(?:\\&\\&)", false),
+            ("(?:\\&=)", false),
+            ("\\(", false),
+            ("\\)", false),
+            ("\\*", false),
+            ("(?:\\*=)", false),
+            ("\\+", false),
+            ("(?:\\+=)", false),
+            (",", false),
+            ("\\-", false),
+            ("(?:\\-=)", false),
+            ("(?:\\->)", false),
+            ("\\.", false),
+            ("/", false),
+            ("(?:/=)", false),
+            (":", false),
+            (";", false),
+            ("<", false),
+            ("(?:<<)", false),
+            ("(?:<<=)", false),
+            ("(?:<=)", false),
+            ("=", false),
+            ("(?:==)", false),
+            (">", false),
+            ("(?:>=)", false),
+            ("(?:>>)", false),
+            ("(?:>>=)", false),
+            ("\\[", false),
+            ("\\]", false),
+            ("\\^", false),
+            ("(?:\\^=)", false),
+            ("(?:asm!)", false),
+            ("(?:break)", false),
+            ("(?:case)", false),
+            ("(?:char)", false),
+            ("(?:continue)", false),
+            ("(?:default)", false),
+            ("(?:else)", false),
+            ("(?:float)", false),
+            ("(?:for)", false),
+            ("(?:if)", false),
+            ("(?:int)", false),
+            ("(?:return)", false),
+            ("(?:struct)", false),
+            ("(?:switch)", false),
+            ("(?:void)", false),
+            ("(?:while)", false),
+            ("\\{", false),
+            ("\\|", false),
+            ("(?:\\|=)", false),
+            ("(?:\\|\\|)", false),
+            ("\\}", false),
+            ("\\~", false),
+            ("(?:(?://)[\0-\t\u{b}\u{c}\u{e}-\u{10ffff}]*[\n\r]*)", true),
+            ("(?:(?:/\\*)[\0-\\)\\+-\u{10ffff}]*\\*+(?:[\0-\\)\\+-\\.0-\u{10ffff}][\0-\\)\\+-\u{10ffff}]*\\*+)*/)", true),
+            ("[\t-\r \u{85}\u{a0}\u{1680}\u{2000}-\u{200a}\u{2028}\u{2029}\u{202f}\u{205f}\u{3000}]*", true),
         ];
         __lalrpop_util::lexer::MatcherBuilder::new(__strs.iter().copied()).unwrap()
     }
 }
-pub(crate) use self::__lalrpop_util::lexer::Token;
+pub(crate) use self::__lalrpop_util::lexer::Token;
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action0<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, Module, usize),
+) -> Module
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action1<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, Box<FunctionDeclaration>, usize),
+) -> Module
+{
+    {
+        Module { functions: vec![__0], structs: Vec::new() }
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action2<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, StructDeclaration, usize),
+) -> Module
+{
+    {
+        Module { functions: Vec::new(), structs: vec![__0] }
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action3<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, mut m, _): (usize, Module, usize),
+    (_, f, _): (usize, Box<FunctionDeclaration>, usize),
+) -> Module
+{
+    {
+        m.functions.push(f);
+        m
+    }
+}
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action0<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action4<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
-    (_, __0, _): (usize, Module, usize),
+    (_, mut m, _): (usize, Module, usize),
+    (_, s, _): (usize, StructDeclaration, usize),
 ) -> Module
 {
-    __0
+    {
+        m.structs.push(s);
+        m
+    }
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action1<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action5<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
-    (_, __0, _): (usize, Box<FunctionDeclaration>, usize),
-) -> Module
+    (_, _, _): (usize, &'input str, usize),
+    (_, i, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, m, _): (usize, alloc::vec::Vec<Member>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> StructDeclaration
 {
-    {
-        Module { functions: vec![__0] }
+    StructDeclaration {
+        tag: reg.tag(&i),
+        members: m,
+        size: 0,
+        align: 0
     }
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action2<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action6<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
-    (_, mut m, _): (usize, Module, usize),
-    (_, f, _): (usize, Box<FunctionDeclaration>, usize),
-) -> Module
+    (_, k, _): (usize, Kind, usize),
+    (_, i, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> Member
 {
-    {
-        m.functions.push(f);
-        m
+    Member {
+        kind: k,
+        name: i,
+        offset: 0
     }
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action3<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action7<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, k, _): (usize, Kind, usize),
     (_, i, _): (usize, String, usize),
     (_, _, _): (usize, &'input str, usize),
-    (_, p, _): (usize, core::option::Option<Vec<Parameter>>, usize),
+    (_, p, _): (usize, Option<Vec<Parameter>>, usize),
     (_, _, _): (usize, &'input str, usize),
     (_, s, _): (usize, Box<Statement>, usize),
 ) -> Box<FunctionDeclaration>
@@ -4796,10 +6896,12 @@ fn __action3<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action4<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action8<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, k, _): (usize, Kind, usize),
     (_, i, _): (usize, String, usize),
@@ -4815,10 +6917,12 @@ fn __action4<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action5<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action9<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Box<Expr>, usize),
 ) -> Vec<Expr>
@@ -4827,10 +6931,12 @@ fn __action5<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action6<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action10<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, mut v, _): (usize, Vec<Expr>, usize),
     (_, _, _): (usize, &'input str, usize),
@@ -4844,10 +6950,12 @@ fn __action6<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action7<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action11<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, l, _): (usize, Box<Expr>, usize),
     (_, o, _): (usize, BinaryOp, usize),
@@ -4867,10 +6975,12 @@ fn __action7<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action8<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action12<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
@@ -4879,10 +6989,12 @@ fn __action8<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action9<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action13<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
 ) -> BinaryOp
@@ -4891,10 +7003,12 @@ fn __action9<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action10<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action14<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
 ) -> BinaryOp
@@ -4903,10 +7017,12 @@ fn __action10<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action11<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action15<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
 ) -> BinaryOp
@@ -4915,10 +7031,12 @@ fn __action11<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action12<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action16<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
 ) -> BinaryOp
@@ -4927,10 +7045,82 @@ fn __action12<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action13<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action17<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOp
+{
+    BinaryOp::Aeq
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action18<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOp
+{
+    BinaryOp::Oeq
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action19<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOp
+{
+    BinaryOp::Xeq
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action20<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOp
+{
+    BinaryOp::Shleq
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action21<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOp
+{
+    BinaryOp::Shreq
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action22<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
 ) -> BinaryOp
@@ -4939,10 +7129,12 @@ fn __action13<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action14<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action23<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
@@ -4951,10 +7143,12 @@ fn __action14<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action15<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action24<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
 ) -> BinaryOp
@@ -4963,10 +7157,12 @@ fn __action15<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action16<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action25<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
@@ -4975,10 +7171,12 @@ fn __action16<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action17<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action26<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
 ) -> BinaryOp
@@ -4987,10 +7185,12 @@ fn __action17<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action18<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action27<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
@@ -4999,10 +7199,96 @@ fn __action18<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action19<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action28<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOp
+{
+    BinaryOp::BitOr
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action29<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action30<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOp
+{
+    BinaryOp::BitXor
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action31<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action32<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOp
+{
+    BinaryOp::BitAnd
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action33<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action34<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
 ) -> BinaryOp
@@ -5011,10 +7297,12 @@ fn __action19<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action20<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action35<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
 ) -> BinaryOp
@@ -5023,10 +7311,12 @@ fn __action20<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action21<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action36<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
@@ -5035,10 +7325,12 @@ fn __action21<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action22<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action37<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
 ) -> BinaryOp
@@ -5047,10 +7339,12 @@ fn __action22<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action23<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action38<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
 ) -> BinaryOp
@@ -5059,10 +7353,12 @@ fn __action23<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action24<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action39<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
 ) -> BinaryOp
@@ -5071,10 +7367,12 @@ fn __action24<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action25<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action40<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
 ) -> BinaryOp
@@ -5083,10 +7381,12 @@ fn __action25<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action26<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action41<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
@@ -5095,10 +7395,54 @@ fn __action26<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action27<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action42<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOp
+{
+    BinaryOp::Shl
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action43<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> BinaryOp
+{
+    BinaryOp::Shr
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action44<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action45<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
 ) -> BinaryOp
@@ -5107,10 +7451,12 @@ fn __action27<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action28<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action46<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
 ) -> BinaryOp
@@ -5119,10 +7465,12 @@ fn __action28<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action29<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action47<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
@@ -5131,10 +7479,12 @@ fn __action29<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action30<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action48<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
 ) -> BinaryOp
@@ -5143,10 +7493,12 @@ fn __action30<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action31<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action49<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
 ) -> BinaryOp
@@ -5155,10 +7507,12 @@ fn __action31<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action32<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action50<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
 ) -> BinaryOp
@@ -5167,10 +7521,12 @@ fn __action32<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action33<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action51<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
@@ -5179,10 +7535,12 @@ fn __action33<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action34<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action52<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, u, _): (usize, UnaryOp, usize),
     (_, p, _): (usize, Box<Expr>, usize),
@@ -5200,10 +7558,12 @@ fn __action34<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action35<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action53<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
 ) -> UnaryOp
@@ -5212,46 +7572,68 @@ fn __action35<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action36<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action54<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> UnaryOp
+{
+    UnaryOp::Not
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action55<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
 ) -> UnaryOp
 {
-    UnaryOp::Not
+    UnaryOp::Neg
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action37<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action56<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
 ) -> UnaryOp
 {
-    UnaryOp::Neg
+    UnaryOp::Address
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action38<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action57<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
 ) -> UnaryOp
 {
-    UnaryOp::Address
+    UnaryOp::BitNot
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action39<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action58<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, String, usize),
 ) -> Box<Expr>
@@ -5262,10 +7644,12 @@ fn __action39<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action40<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action59<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, i64, usize),
 ) -> Box<Expr>
@@ -5274,10 +7658,12 @@ fn __action40<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action41<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action60<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, f64, usize),
 ) -> Box<Expr>
@@ -5286,10 +7672,40 @@ fn __action41<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action42<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action61<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, u8, usize),
+) -> Box<Expr>
+{
+    Box::new(Expr::Char(__0))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action62<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, Vec<u8>, usize),
+) -> Box<Expr>
+{
+    Box::new(Expr::Str(__0))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action63<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, i, _): (usize, String, usize),
     (_, _, _): (usize, &'input str, usize),
@@ -5307,14 +7723,16 @@ fn __action42<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action43<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action64<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, i, _): (usize, String, usize),
     (_, _, _): (usize, &'input str, usize),
-    (_, args, _): (usize, core::option::Option<Vec<Expr>>, usize),
+    (_, args, _): (usize, Option<Vec<Expr>>, usize),
     (_, _, _): (usize, &'input str, usize),
 ) -> Box<Expr>
 {
@@ -5331,10 +7749,48 @@ fn __action43<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action44<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action65<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, e, _): (usize, Box<Expr>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, i, _): (usize, String, usize),
+) -> Box<Expr>
+{
+    Box::new(Expr::Member(MemberExpr {
+            expr: e, name: i, arrow: false, offset: 0, kind: None
+        }))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action66<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, e, _): (usize, Box<Expr>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, i, _): (usize, String, usize),
+) -> Box<Expr>
+{
+    Box::new(Expr::Member(MemberExpr {
+            expr: e, name: i, arrow: true, offset: 0, kind: None
+        }))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action67<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, _, _): (usize, &'input str, usize),
     (_, __0, _): (usize, Box<Expr>, usize),
@@ -5345,10 +7801,12 @@ fn __action44<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action45<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action68<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Box<Statement>, usize),
 ) -> Vec<Statement>
@@ -5357,10 +7815,12 @@ fn __action45<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action46<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action69<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, mut v, _): (usize, Vec<Statement>, usize),
     (_, s, _): (usize, Box<Statement>, usize),
@@ -5373,10 +7833,12 @@ fn __action46<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action47<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action70<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Box<Statement>, usize),
 ) -> Box<Statement>
@@ -5385,10 +7847,12 @@ fn __action47<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action48<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action71<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Box<Statement>, usize),
 ) -> Box<Statement>
@@ -5397,10 +7861,12 @@ fn __action48<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action49<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action72<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Box<Statement>, usize),
 ) -> Box<Statement>
@@ -5409,10 +7875,12 @@ fn __action49<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action50<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action73<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Box<Statement>, usize),
 ) -> Box<Statement>
@@ -5421,10 +7889,12 @@ fn __action50<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action51<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action74<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Box<Statement>, usize),
 ) -> Box<Statement>
@@ -5433,10 +7903,12 @@ fn __action51<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action52<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action75<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Box<Statement>, usize),
 ) -> Box<Statement>
@@ -5445,10 +7917,12 @@ fn __action52<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action53<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action76<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Box<Statement>, usize),
 ) -> Box<Statement>
@@ -5457,76 +7931,174 @@ fn __action53<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action54<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action77<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, Box<Statement>, usize),
+) -> Box<Statement>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action78<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, _, _): (usize, &'input str, usize),
     (_, _, _): (usize, &'input str, usize),
-    (_, asm, _): (usize, Vec<asm::asm::AA>, usize),
+    (_, l, _): (usize, (Vec<String>, Vec<AsmOperand>, Vec<String>), usize),
     (_, _, _): (usize, &'input str, usize),
-) -> Box<Statement>
+) -> Result<Box<Statement>,__lalrpop_util::ParseError<usize,Token<'input>,asm::parser::ParseError>>
 {
     {
-        Box::new(Statement::Asm(
-            AsmStatement { asm }
-        ))
+        let (lines, operands, clobbers) = l;
+        let names: Vec<String> = operands.iter().map(|o| o.name.clone()).collect();
+        let (asm, labels, calls) = asm::parser::block(lines, &names)
+            .map_err(|error| ParseError::User { error })?;
+        let clobbers = clobbers.into_iter()
+            .map(asm::parser::clobber)
+            .collect::<Result<_, _>>()
+            .map_err(|error| ParseError::User { error })?;
+        Ok(Box::new(Statement::Asm(
+            AsmStatement { asm, operands, clobbers, labels, calls }
+        )))
     }
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action55<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action79<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
-    (_, __0, _): (usize, asm::asm::AA, usize),
-) -> Vec<asm::asm::AA>
+    (_, __0, _): (usize, String, usize),
+) -> (Vec<String>, Vec<AsmOperand>, Vec<String>)
 {
-    vec![__0]
+    (vec![__0], Vec::new(), Vec::new())
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action56<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action80<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
-    (_, mut v, _): (usize, Vec<asm::asm::AA>, usize),
+    (_, mut v, _): (usize, (Vec<String>, Vec<AsmOperand>, Vec<String>), usize),
     (_, _, _): (usize, &'input str, usize),
-    (_, a, _): (usize, asm::asm::AA, usize),
-) -> Vec<asm::asm::AA>
+    (_, a, _): (usize, String, usize),
+) -> (Vec<String>, Vec<AsmOperand>, Vec<String>)
 {
     {
-        v.push(a);
+        v.0.push(a);
         v
     }
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action57<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action81<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
-    (_, s, _): (usize, &'input str, usize),
-) -> Result<asm::asm::AA,__lalrpop_util::ParseError<usize,Token<'input>,asm::parser::ParseError>>
+    (_, mut v, _): (usize, (Vec<String>, Vec<AsmOperand>, Vec<String>), usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, o, _): (usize, AsmOperand, usize),
+) -> (Vec<String>, Vec<AsmOperand>, Vec<String>)
 {
-    asm::parser::parse(s[1..(s.len()-1)].to_string())
-        .map_err(|e| ParseError::User {
-            error: e
-        })
+    {
+        v.1.push(o);
+        v
+    }
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action58<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action82<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, mut v, _): (usize, (Vec<String>, Vec<AsmOperand>, Vec<String>), usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, kw, _): (usize, String, usize),
+    (_, c, _): (usize, String, usize),
+) -> Result<(Vec<String>, Vec<AsmOperand>, Vec<String>),__lalrpop_util::ParseError<usize,Token<'input>,asm::parser::ParseError>>
+{
+    {
+        if kw != "clobber" {
+            return Err(ParseError::User { error: asm::parser::ParseError::Keyword(kw) });
+        }
+        v.2.push(c);
+        Ok(v)
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action83<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, kw, _): (usize, String, usize),
+    (_, name, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, expr, _): (usize, Box<Expr>, usize),
+) -> Result<AsmOperand,__lalrpop_util::ParseError<usize,Token<'input>,asm::parser::ParseError>>
+{
+    {
+        let dir = match kw.as_str() {
+            "in"    => AsmDir::In,
+            "out"   => AsmDir::Out,
+            "inout" => AsmDir::InOut,
+            _ => return Err(ParseError::User { error: asm::parser::ParseError::Keyword(kw) })
+        };
+        Ok(AsmOperand { dir, name, expr })
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action84<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, Vec<u8>, usize),
+) -> Result<String,__lalrpop_util::ParseError<usize,Token<'input>,asm::parser::ParseError>>
+{
+    String::from_utf8(__0).map_err(|e| ParseError::User {
+        error: asm::parser::ParseError::Escape(String::from_utf8_lossy(e.as_bytes()).into_owned())
+    })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action85<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, _, _): (usize, &'input str, usize),
-    (_, s, _): (usize, core::option::Option<Vec<Statement>>, usize),
+    (_, s, _): (usize, Option<Vec<Statement>>, usize),
     (_, _, _): (usize, &'input str, usize),
 ) -> Box<Statement>
 {
@@ -5543,10 +8115,12 @@ fn __action58<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action59<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action86<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, k, _): (usize, Kind, usize),
     (_, i, _): (usize, String, usize),
@@ -5561,10 +8135,12 @@ fn __action59<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action60<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action87<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, k, _): (usize, Kind, usize),
     (_, i, _): (usize, String, usize),
@@ -5581,12 +8157,14 @@ fn __action60<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action61<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action88<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
-    (_, __0, _): (usize, core::option::Option<Box<Expr>>, usize),
+    (_, __0, _): (usize, Option<Box<Expr>>, usize),
     (_, _, _): (usize, &'input str, usize),
 ) -> Box<Statement>
 {
@@ -5596,10 +8174,12 @@ fn __action61<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action62<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action89<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, _, _): (usize, &'input str, usize),
     (_, _, _): (usize, &'input str, usize),
@@ -5618,10 +8198,12 @@ fn __action62<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action63<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action90<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, _, _): (usize, &'input str, usize),
     (_, _, _): (usize, &'input str, usize),
@@ -5642,10 +8224,100 @@ fn __action63<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action64<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action91<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Expr>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, s, _): (usize, Box<Statement>, usize),
+) -> Box<Statement>
+{
+    Box::new(
+        Statement::Switch( SwitchStatement {
+            expr: e,
+            stmt: s
+        })
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action92<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, v, _): (usize, i64, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, s, _): (usize, Box<Statement>, usize),
+) -> Box<Statement>
+{
+    Box::new(
+        Statement::Case(CaseStatement { value: Some(v), stmt: s })
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action93<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, s, _): (usize, Box<Statement>, usize),
+) -> Box<Statement>
+{
+    Box::new(
+        Statement::Case(CaseStatement { value: None, stmt: s })
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action94<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, i64, usize),
+) -> i64
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action95<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, u8, usize),
+) -> i64
+{
+    __0 as i64
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action96<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, _, _): (usize, &'input str, usize),
     (_, _, _): (usize, &'input str, usize),
@@ -5663,17 +8335,19 @@ fn __action64<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action65<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action97<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, _, _): (usize, &'input str, usize),
     (_, _, _): (usize, &'input str, usize),
     (_, i, _): (usize, Box<Statement>, usize),
-    (_, e1, _): (usize, core::option::Option<Box<Expr>>, usize),
+    (_, e1, _): (usize, Option<Box<Expr>>, usize),
     (_, _, _): (usize, &'input str, usize),
-    (_, e2, _): (usize, core::option::Option<Box<Expr>>, usize),
+    (_, e2, _): (usize, Option<Box<Expr>>, usize),
     (_, _, _): (usize, &'input str, usize),
     (_, s, _): (usize, Box<Statement>, usize),
 ) -> Box<Statement>
@@ -5689,10 +8363,12 @@ fn __action65<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action66<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action98<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Box<Statement>, usize),
 ) -> Box<Statement>
@@ -5701,10 +8377,12 @@ fn __action66<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action67<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action99<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Box<Statement>, usize),
 ) -> Box<Statement>
@@ -5713,10 +8391,12 @@ fn __action67<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action68<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action100<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
     (_, __1, _): (usize, &'input str, usize),
@@ -5731,10 +8411,12 @@ fn __action68<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action69<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action101<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
     (_, __1, _): (usize, &'input str, usize),
@@ -5749,13 +8431,15 @@ fn __action69<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action70<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action102<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, core::option::Option<Box<Expr>>, usize),
+    (_, __0, _): (usize, Option<Box<Expr>>, usize),
     (_, _, _): (usize, &'input str, usize),
 ) -> Box<Statement>
 {
@@ -5768,13 +8452,15 @@ fn __action70<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action71<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action103<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, t, _): (usize, Primitive, usize),
-    (_, p, _): (usize, core::option::Option<u32>, usize),
+    (_, p, _): (usize, Option<u32>, usize),
 ) -> Kind
 {
     {
@@ -5787,46 +8473,83 @@ fn __action71<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action72<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action104<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Primitive
+{
+    Primitive::Void
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action105<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Primitive
+{
+    Primitive::Int
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action106<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
 ) -> Primitive
 {
-    Primitive::Void
+    Primitive::Float
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action73<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action107<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
 ) -> Primitive
 {
-    Primitive::Int
+    Primitive::Char
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action74<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action108<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, i, _): (usize, String, usize),
 ) -> Primitive
 {
-    Primitive::Float
+    Primitive::Struct(reg.tag(&i))
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action75<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action109<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
 ) -> u32
@@ -5835,10 +8558,12 @@ fn __action75<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action76<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action110<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, u32, usize),
     (_, _, _): (usize, &'input str, usize),
@@ -5848,10 +8573,12 @@ fn __action76<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action77<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action111<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
 ) -> String
@@ -5860,10 +8587,12 @@ fn __action77<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action78<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action112<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
 ) -> i64
@@ -5872,10 +8601,12 @@ fn __action78<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action79<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action113<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
 ) -> f64
@@ -5884,110 +8615,169 @@ fn __action79<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action80<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action114<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, s, _): (usize, &'input str, usize),
+) -> Result<u8,__lalrpop_util::ParseError<usize,Token<'input>,asm::parser::ParseError>>
+{
+    {
+        use asm::parser::ParseError as P;
+        let Some(c) = strings::unescape(&s[1..(s.len()-1)]) else {
+            return Err(ParseError::User { error: P::Escape(s.to_string()) });
+        };
+        match c[..] {
+            [c] => Ok(c),
+            _ => Err(ParseError::User { error: P::Character(s.to_string()) })
+        }
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action115<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, s, _): (usize, &'input str, usize),
+) -> Result<Vec<u8>,__lalrpop_util::ParseError<usize,Token<'input>,asm::parser::ParseError>>
+{
+    {
+        strings::unescape(&s[1..(s.len()-1)]).ok_or(ParseError::User {
+            error: asm::parser::ParseError::Escape(s.to_string())
+        })
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action116<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, u32, usize),
-) -> core::option::Option<u32>
+) -> Option<u32>
 {
     Some(__0)
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action81<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action117<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     __lookbehind: &usize,
     __lookahead: &usize,
-) -> core::option::Option<u32>
+) -> Option<u32>
 {
     None
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action82<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action118<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Box<Expr>, usize),
-) -> core::option::Option<Box<Expr>>
+) -> Option<Box<Expr>>
 {
     Some(__0)
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action83<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action119<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     __lookbehind: &usize,
     __lookahead: &usize,
-) -> core::option::Option<Box<Expr>>
+) -> Option<Box<Expr>>
 {
     None
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action84<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action120<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Vec<Statement>, usize),
-) -> core::option::Option<Vec<Statement>>
+) -> Option<Vec<Statement>>
 {
     Some(__0)
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action85<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action121<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     __lookbehind: &usize,
     __lookahead: &usize,
-) -> core::option::Option<Vec<Statement>>
+) -> Option<Vec<Statement>>
 {
     None
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action86<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action122<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Vec<Expr>, usize),
-) -> core::option::Option<Vec<Expr>>
+) -> Option<Vec<Expr>>
 {
     Some(__0)
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action87<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action123<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     __lookbehind: &usize,
     __lookahead: &usize,
-) -> core::option::Option<Vec<Expr>>
+) -> Option<Vec<Expr>>
 {
     None
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action88<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action124<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, l, _): (usize, Box<Expr>, usize),
     (_, o, _): (usize, BinaryOp, usize),
@@ -6007,10 +8797,12 @@ fn __action88<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action89<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action125<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
@@ -6019,10 +8811,12 @@ fn __action89<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action90<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action126<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, l, _): (usize, Box<Expr>, usize),
     (_, o, _): (usize, BinaryOp, usize),
@@ -6042,10 +8836,12 @@ fn __action90<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action91<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action127<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
@@ -6054,10 +8850,12 @@ fn __action91<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action92<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action128<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, l, _): (usize, Box<Expr>, usize),
     (_, o, _): (usize, BinaryOp, usize),
@@ -6077,10 +8875,12 @@ fn __action92<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action93<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action129<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
@@ -6089,10 +8889,12 @@ fn __action93<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action94<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action130<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, l, _): (usize, Box<Expr>, usize),
     (_, o, _): (usize, BinaryOp, usize),
@@ -6112,10 +8914,12 @@ fn __action94<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action95<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action131<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
@@ -6124,10 +8928,12 @@ fn __action95<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action96<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action132<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, l, _): (usize, Box<Expr>, usize),
     (_, o, _): (usize, BinaryOp, usize),
@@ -6147,10 +8953,12 @@ fn __action96<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action97<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action133<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
@@ -6159,10 +8967,12 @@ fn __action97<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action98<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action134<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, l, _): (usize, Box<Expr>, usize),
     (_, o, _): (usize, BinaryOp, usize),
@@ -6182,10 +8992,12 @@ fn __action98<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action99<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action135<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
@@ -6194,35 +9006,197 @@ fn __action99<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action100<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action136<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, l, _): (usize, Box<Expr>, usize),
+    (_, o, _): (usize, BinaryOp, usize),
+    (_, r, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    {
+        Box::new(Expr::Binary(
+            BinaryExpr {
+                binary_op: o,
+                left: l,
+                right: r,
+                kind: None
+            }
+        ))
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action137<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action138<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, l, _): (usize, Box<Expr>, usize),
+    (_, o, _): (usize, BinaryOp, usize),
+    (_, r, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    {
+        Box::new(Expr::Binary(
+            BinaryExpr {
+                binary_op: o,
+                left: l,
+                right: r,
+                kind: None
+            }
+        ))
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action139<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action140<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, l, _): (usize, Box<Expr>, usize),
+    (_, o, _): (usize, BinaryOp, usize),
+    (_, r, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    {
+        Box::new(Expr::Binary(
+            BinaryExpr {
+                binary_op: o,
+                left: l,
+                right: r,
+                kind: None
+            }
+        ))
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action141<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action142<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, l, _): (usize, Box<Expr>, usize),
+    (_, o, _): (usize, BinaryOp, usize),
+    (_, r, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    {
+        Box::new(Expr::Binary(
+            BinaryExpr {
+                binary_op: o,
+                left: l,
+                right: r,
+                kind: None
+            }
+        ))
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action143<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action144<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Vec<Parameter>, usize),
-) -> core::option::Option<Vec<Parameter>>
+) -> Option<Vec<Parameter>>
 {
     Some(__0)
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action101<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action145<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     __lookbehind: &usize,
     __lookahead: &usize,
-) -> core::option::Option<Vec<Parameter>>
+) -> Option<Vec<Parameter>>
 {
     None
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action102<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action146<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, mut v, _): (usize, alloc::vec::Vec<Parameter>, usize),
     (_, e, _): (usize, Parameter, usize),
@@ -6235,10 +9209,41 @@ fn __action102<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action103<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action147<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, __0, _): (usize, Member, usize),
+) -> alloc::vec::Vec<Member>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action148<
+    'input,
+    'r,
+>(
+    reg: &'r mut Registry,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<Member>, usize),
+    (_, e, _): (usize, Member, usize),
+) -> alloc::vec::Vec<Member>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action149<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     __lookbehind: &usize,
     __lookahead: &usize,
@@ -6248,10 +9253,12 @@ fn __action103<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action104<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action150<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, v, _): (usize, alloc::vec::Vec<Parameter>, usize),
 ) -> alloc::vec::Vec<Parameter>
@@ -6260,10 +9267,12 @@ fn __action104<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action105<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action151<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Parameter, usize),
     (_, _, _): (usize, &'input str, usize),
@@ -6273,10 +9282,12 @@ fn __action105<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action106<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action152<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, __0, _): (usize, Parameter, usize),
 ) -> alloc::vec::Vec<Parameter>
@@ -6285,10 +9296,12 @@ fn __action106<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action107<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action153<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     (_, v, _): (usize, alloc::vec::Vec<Parameter>, usize),
     (_, e, _): (usize, Parameter, usize),
@@ -6298,10 +9311,13 @@ fn __action107<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action108<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action154<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     __0: (usize, Parameter, usize),
     __1: (usize, &'input str, usize),
@@ -6309,23 +9325,28 @@ fn __action108<
 {
     let __start0 = __0.0;
     let __end0 = __1.2;
-    let __temp0 = __action105(
+    let __temp0 = __action151(
+        reg,
         input,
         __0,
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action106(
+    __action152(
+        reg,
         input,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action109<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action155<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     __0: (usize, alloc::vec::Vec<Parameter>, usize),
     __1: (usize, Parameter, usize),
@@ -6334,13 +9355,15 @@ fn __action109<
 {
     let __start0 = __1.0;
     let __end0 = __2.2;
-    let __temp0 = __action105(
+    let __temp0 = __action151(
+        reg,
         input,
         __1,
         __2,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action107(
+    __action153(
+        reg,
         input,
         __0,
         __temp0,
@@ -6348,23 +9371,28 @@ fn __action109<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action110<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action156<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     __0: (usize, Parameter, usize),
 ) -> Vec<Parameter>
 {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action103(
+    let __temp0 = __action149(
+        reg,
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action102(
+    __action146(
+        reg,
         input,
         __temp0,
         __0,
@@ -6372,10 +9400,13 @@ fn __action110<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action111<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action157<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     __0: (usize, alloc::vec::Vec<Parameter>, usize),
     __1: (usize, Parameter, usize),
@@ -6383,12 +9414,14 @@ fn __action111<
 {
     let __start0 = __0.0;
     let __end0 = __0.2;
-    let __temp0 = __action104(
+    let __temp0 = __action150(
+        reg,
         input,
         __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action102(
+    __action146(
+        reg,
         input,
         __temp0,
         __1,
@@ -6396,10 +9429,13 @@ fn __action111<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action112<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action158<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     __0: (usize, Kind, usize),
     __1: (usize, String, usize),
@@ -6411,12 +9447,14 @@ fn __action112<
 {
     let __start0 = __3.0;
     let __end0 = __3.2;
-    let __temp0 = __action100(
+    let __temp0 = __action144(
+        reg,
         input,
         __3,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action3(
+    __action7(
+        reg,
         input,
         __0,
         __1,
@@ -6428,10 +9466,13 @@ fn __action112<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action113<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action159<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     __0: (usize, Kind, usize),
     __1: (usize, String, usize),
@@ -6442,13 +9483,15 @@ fn __action113<
 {
     let __start0 = __2.2;
     let __end0 = __3.0;
-    let __temp0 = __action101(
+    let __temp0 = __action145(
+        reg,
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action3(
+    __action7(
+        reg,
         input,
         __0,
         __1,
@@ -6460,10 +9503,13 @@ fn __action113<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action114<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action160<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     __0: (usize, Box<Expr>, usize),
     __1: (usize, &'input str, usize),
@@ -6471,12 +9517,14 @@ fn __action114<
 {
     let __start0 = __0.0;
     let __end0 = __0.2;
-    let __temp0 = __action82(
+    let __temp0 = __action118(
+        reg,
         input,
         __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action61(
+    __action88(
+        reg,
         input,
         __temp0,
         __1,
@@ -6484,23 +9532,28 @@ fn __action114<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action115<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action161<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     __0: (usize, &'input str, usize),
 ) -> Box<Statement>
 {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action83(
+    let __temp0 = __action119(
+        reg,
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action61(
+    __action88(
+        reg,
         input,
         __temp0,
         __0,
@@ -6508,10 +9561,13 @@ fn __action115<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action116<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action162<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     __0: (usize, &'input str, usize),
     __1: (usize, &'input str, usize),
@@ -6527,17 +9583,20 @@ fn __action116<
     let __end0 = __3.2;
     let __start1 = __5.0;
     let __end1 = __5.2;
-    let __temp0 = __action82(
+    let __temp0 = __action118(
+        reg,
         input,
         __3,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    let __temp1 = __action82(
+    let __temp1 = __action118(
+        reg,
         input,
         __5,
     );
     let __temp1 = (__start1, __temp1, __end1);
-    __action65(
+    __action97(
+        reg,
         input,
         __0,
         __1,
@@ -6551,10 +9610,13 @@ fn __action116<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action117<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action163<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     __0: (usize, &'input str, usize),
     __1: (usize, &'input str, usize),
@@ -6569,18 +9631,21 @@ fn __action117<
     let __end0 = __3.2;
     let __start1 = __4.2;
     let __end1 = __5.0;
-    let __temp0 = __action82(
+    let __temp0 = __action118(
+        reg,
         input,
         __3,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    let __temp1 = __action83(
+    let __temp1 = __action119(
+        reg,
         input,
         &__start1,
         &__end1,
     );
     let __temp1 = (__start1, __temp1, __end1);
-    __action65(
+    __action97(
+        reg,
         input,
         __0,
         __1,
@@ -6594,10 +9659,13 @@ fn __action117<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action118<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action164<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     __0: (usize, &'input str, usize),
     __1: (usize, &'input str, usize),
@@ -6612,18 +9680,21 @@ fn __action118<
     let __end0 = __3.0;
     let __start1 = __4.0;
     let __end1 = __4.2;
-    let __temp0 = __action83(
+    let __temp0 = __action119(
+        reg,
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    let __temp1 = __action82(
+    let __temp1 = __action118(
+        reg,
         input,
         __4,
     );
     let __temp1 = (__start1, __temp1, __end1);
-    __action65(
+    __action97(
+        reg,
         input,
         __0,
         __1,
@@ -6637,10 +9708,13 @@ fn __action118<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action119<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action165<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     __0: (usize, &'input str, usize),
     __1: (usize, &'input str, usize),
@@ -6654,19 +9728,22 @@ fn __action119<
     let __end0 = __3.0;
     let __start1 = __3.2;
     let __end1 = __4.0;
-    let __temp0 = __action83(
+    let __temp0 = __action119(
+        reg,
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    let __temp1 = __action83(
+    let __temp1 = __action119(
+        reg,
         input,
         &__start1,
         &__end1,
     );
     let __temp1 = (__start1, __temp1, __end1);
-    __action65(
+    __action97(
+        reg,
         input,
         __0,
         __1,
@@ -6680,10 +9757,13 @@ fn __action119<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action120<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action166<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     __0: (usize, &'input str, usize),
     __1: (usize, Box<Expr>, usize),
@@ -6692,12 +9772,14 @@ fn __action120<
 {
     let __start0 = __1.0;
     let __end0 = __1.2;
-    let __temp0 = __action82(
+    let __temp0 = __action118(
+        reg,
         input,
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action70(
+    __action102(
+        reg,
         input,
         __0,
         __temp0,
@@ -6706,10 +9788,13 @@ fn __action120<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action121<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action167<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     __0: (usize, &'input str, usize),
     __1: (usize, &'input str, usize),
@@ -6717,13 +9802,15 @@ fn __action121<
 {
     let __start0 = __0.2;
     let __end0 = __1.0;
-    let __temp0 = __action83(
+    let __temp0 = __action119(
+        reg,
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action70(
+    __action102(
+        reg,
         input,
         __0,
         __temp0,
@@ -6732,10 +9819,13 @@ fn __action121<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action122<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action168<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     __0: (usize, String, usize),
     __1: (usize, &'input str, usize),
@@ -6745,12 +9835,14 @@ fn __action122<
 {
     let __start0 = __2.0;
     let __end0 = __2.2;
-    let __temp0 = __action86(
+    let __temp0 = __action122(
+        reg,
         input,
         __2,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action43(
+    __action64(
+        reg,
         input,
         __0,
         __1,
@@ -6760,10 +9852,13 @@ fn __action122<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action123<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action169<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     __0: (usize, String, usize),
     __1: (usize, &'input str, usize),
@@ -6772,13 +9867,15 @@ fn __action123<
 {
     let __start0 = __1.2;
     let __end0 = __2.0;
-    let __temp0 = __action87(
+    let __temp0 = __action123(
+        reg,
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action43(
+    __action64(
+        reg,
         input,
         __0,
         __1,
@@ -6788,10 +9885,13 @@ fn __action123<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action124<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action170<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     __0: (usize, Primitive, usize),
     __1: (usize, u32, usize),
@@ -6799,12 +9899,14 @@ fn __action124<
 {
     let __start0 = __1.0;
     let __end0 = __1.2;
-    let __temp0 = __action80(
+    let __temp0 = __action116(
+        reg,
         input,
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action71(
+    __action103(
+        reg,
         input,
         __0,
         __temp0,
@@ -6812,23 +9914,28 @@ fn __action124<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action125<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action171<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     __0: (usize, Primitive, usize),
 ) -> Kind
 {
     let __start0 = __0.2;
     let __end0 = __0.2;
-    let __temp0 = __action81(
+    let __temp0 = __action117(
+        reg,
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action71(
+    __action103(
+        reg,
         input,
         __0,
         __temp0,
@@ -6836,10 +9943,13 @@ fn __action125<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action126<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action172<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     __0: (usize, &'input str, usize),
     __1: (usize, Vec<Statement>, usize),
@@ -6848,12 +9958,14 @@ fn __action126<
 {
     let __start0 = __1.0;
     let __end0 = __1.2;
-    let __temp0 = __action84(
+    let __temp0 = __action120(
+        reg,
         input,
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action58(
+    __action85(
+        reg,
         input,
         __0,
         __temp0,
@@ -6862,10 +9974,13 @@ fn __action126<
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
-fn __action127<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action173<
     'input,
+    'r,
 >(
+    reg: &'r mut Registry,
     input: &'input str,
     __0: (usize, &'input str, usize),
     __1: (usize, &'input str, usize),
@@ -6873,38 +9988,37 @@ fn __action127<
 {
     let __start0 = __0.2;
     let __end0 = __1.0;
-    let __temp0 = __action85(
+    let __temp0 = __action121(
+        reg,
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action58(
+    __action85(
+        reg,
         input,
         __0,
         __temp0,
         __1,
     )
 }
-#[allow(clippy::type_complexity)]
 
-pub trait __ToTriple<'input, >
+#[allow(clippy::type_complexity, dead_code)]
+pub trait __ToTriple<'input, 'r, >
 {
-    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, asm::parser::ParseError>>;
+    fn to_triple(self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, asm::parser::ParseError>>;
 }
 
-impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize)
+impl<'input, 'r, > __ToTriple<'input, 'r, > for (usize, Token<'input>, usize)
 {
-    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, asm::parser::ParseError>> {
-        Ok(value)
+    fn to_triple(self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, asm::parser::ParseError>> {
+        Ok(self)
     }
 }
-impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize), asm::parser::ParseError>
+impl<'input, 'r, > __ToTriple<'input, 'r, > for Result<(usize, Token<'input>, usize), asm::parser::ParseError>
 {
-    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, asm::parser::ParseError>> {
-        match value {
-            Ok(v) => Ok(v),
-            Err(error) => Err(__lalrpop_util::ParseError::User { error }),
-        }
+    fn to_triple(self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, asm::parser::ParseError>> {
+        self.map_err(|error| __lalrpop_util::ParseError::User { error })
     }
 }
@@ -1,20 +1,34 @@
 use super::ast::*;
-pub struct Printer { count: u32 }
-impl Printer {
-    pub fn new() -> Self { Self{count: 0} }
+use crate::asm::strings;
+use crate::registry::Registry;
+pub struct Printer<'l> { count: u32, reg: &'l Registry }
+impl<'l> Printer<'l> {
+    pub fn new(reg: &'l Registry) -> Self { Self{count: 0, reg} }
     pub fn print(&mut self, m: &Module) {
         println!("digraph AST {{");
         let idx = self.count;
         self.add_label("Module");
+        for s in &m.structs {
+            self.add_edge(idx, self.count);
+            self.struct_declaration(s);
+        }
         for f in &m.functions {
             self.add_edge(idx, self.count);
             self.function_declaration(f);
         }
         println!("}}");
     }
+    fn struct_declaration(&mut self, s: &StructDeclaration) {
+        let idx = self.count;
+        self.add_label(&format!("Struct {} ({} bytes)", self.reg.tag_name(s.tag), s.size));
+        for m in &s.members {
+            self.add_edge(idx, self.count);
+            self.add_label(&format!("Member: {} {} +{}", m.kind.name(self.reg), m.name, m.offset));
+        }
+    }
     fn function_declaration(&mut self, f: &FunctionDeclaration) {
         let idx = self.count;
-        self.add_label(&format!("Declare {} {}({})", f.ret, f.name, f.id));
+        self.add_label(&format!("Declare {} {}({})", f.ret.name(self.reg), f.name, f.id));
         self.add_edge(idx, self.count);
         self.statement(&f.stmt);
     }
@@ -27,15 +41,34 @@ impl Printer {
             Statement::While(w) => self.while_statement(w),
             Statement::Compound(c) => self.compound_statement(c),
             Statement::Jump(j) => self.jump_statement(j),
-            Statement::Asm(a)  => self.asm_statement(a)
+            Statement::Asm(a)  => self.asm_statement(a),
+            Statement::Switch(s) => self.switch_statement(s),
+            Statement::Case(c) => self.case_statement(c)
         }
     }
+    fn switch_statement(&mut self, s: &SwitchStatement) {
+        let idx = self.count;
+        self.add_label("Switch");
+        self.add_edge(idx, self.count);
+        self.expr(&s.expr);
+        self.add_edge(idx, self.count);
+        self.statement(&s.stmt);
+    }
+    fn case_statement(&mut self, c: &CaseStatement) {
+        let idx = self.count;
+        match c.value {
+            None    => self.add_label("Default"),
+            Some(v) => self.add_label(&format!("Case: {}", v))
+        }
+        self.add_edge(idx, self.count);
+        self.statement(&c.stmt);
+    }
     fn asm_statement(&mut self, _a: &AsmStatement) {
         self.add_label(&format!("Asm"));
     }
     fn declare_statement(&mut self, d: &DeclareStatement) {
         let idx = self.count;
-        self.add_label(&format!("Declare: {} {}({})", d.kind, d.name, d.id));
+        self.add_label(&format!("Declare: {} {}({})", d.kind.name(self.reg), d.name, d.id));
         if let Some(e) = &d.val {
             self.add_edge(idx, self.count);
             self.expr(e);
@@ -114,7 +147,10 @@ impl Printer {
             Expr::Binary(b) => self.binary(b),
             Expr::Integer(i) => self.integer(*i),
             Expr::Float(f) => self.float(*f),
-            Expr::Ident(i) => self.identifier(i)
+            Expr::Char(c) => self.char(*c),
+            Expr::Str(s) => self.str(s),
+            Expr::Ident(i) => self.identifier(i),
+            Expr::Member(m) => self.member(m)
         }
     }
     fn function(&mut self, f: &FunctionCall) {
@@ -153,6 +189,21 @@ impl Printer {
     fn float(&mut self, f: f64) {
         self.add_label(&format!("Float: {}", f));
     }
+    fn char(&mut self, c: u8) {
+        self.add_label(&format!("Char: {}", c));
+    }
+    fn str(&mut self, s: &[u8]) {
+        // Escaped twice, once for C and once for dot.
+        let s = strings::escape(s).replace('\\', "\\\\").replace('"', "\\\"");
+        self.add_label(&format!("String: \\\"{}\\\"", s));
+    }
+    fn member(&mut self, m: &MemberExpr) {
+        let idx = self.count;
+        let op = if m.arrow { "->" } else { "." };
+        self.add_label(&format!("Member: {}{} +{}", op, m.name, m.offset));
+        self.add_edge(idx, self.count);
+        self.expr(&m.expr);
+    }
     fn identifier(&mut self, i: &Identifier) {
         self.add_label(&format!("Identifier: {} {}({})",
             match i.kind {
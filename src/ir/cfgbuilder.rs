@@ -1,6 +1,7 @@
 use super::ir::*;
 use super::cfg::*;
 use crate::registry::Registry;
+use crate::asm::asm::AA;
 pub fn build(r: &Registry, stmts: Vec<Box<Statement>>) -> CFG {
     let mut nodes  = vec![Node::new(); r.nlabels as usize];
     let starts = (0..(r.nfuncs as usize)).collect();
@@ -41,6 +42,21 @@ pub fn build(r: &Registry, stmts: Vec<Box<Statement>>) -> CFG {
                     cur = nodes.len() - 1;
                 }
             },
+            // Branches out of inline asm and jump table entries,
+            // they fall through like a CJump would.
+            Asm(AA::B2(_, l) | AA::CBZ(_, l) | AA::CBNZ(_, l)) | Case(_, _, l) => {
+                nodes[cur].stmts.push(stmt);
+                nodes[cur].t = Some(l as usize);
+                let Some(pk) = iter.peek() else { continue };
+                if let Label(l) = **pk {
+                    nodes[cur].f = Some(l as usize);
+                    cur = l as usize;
+                } else {
+                    nodes[cur].f = Some(nodes.len());
+                    nodes.push(Node::new());
+                    cur = nodes.len() - 1;
+                }
+            },
             Label(l) | Function(l, _) => {
                 cur = l as usize;
                 nodes[cur].stmts.push(stmt);
@@ -2,50 +2,67 @@ use super::ir::*;
 use super::cfg::*;
 use crate::registry::Registry;
 
+// Where every escaping temp lives, relative to the bottom of its frame,
+// and how large each function's frame is (indexed by function label).
+// Temps that don't escape keep usize::MAX and stay in registers.
+pub struct Frames {
+    pub slots: Vec<usize>,
+    pub sizes: Vec<usize>,
+    pub types: Vec<Option<Type>>,
+}
+impl Frames {
+    pub fn slot(&self, i: ID) -> Option<usize> {
+        return match self.slots.get(i as usize) {
+            Some(s) if *s != usize::MAX => Some(*s),
+            _ => None
+        };
+    }
+    // What a slot holds, which decides how wide its loads and stores are.
+    pub fn ty(&self, i: ID) -> Type {
+        return match self.types.get(i as usize) {
+            Some(Some(t)) => *t,
+            _ => Type::I64
+        };
+    }
+}
+
 pub struct Framer<'l> {
-    cfg:       &'l CFG,
-    frames:    Vec<usize>,
-    addressed: Vec<bool>,
-    visited:   Vec<bool>,
-    inc:       usize, // End of current frame.
+    cfg:     &'l CFG,
+    frames:  Frames,
+    escapes: Vec<bool>,
+    types:   Vec<Option<Type>>,
+    inc:     usize, // End of current frame.
 }
 impl<'l> Framer<'l> {
     pub fn new(r: &mut Registry, cfg: &'l CFG) -> Self {
-        let addressed = Address::new(
+        let (escapes, types) = Escape::new(
             cfg, r.nids as usize
-        ).address(); // nvariables.
-        let visited = vec![false; r.nlabels as usize]; // r.nblocks, actually
-        let frames = vec![usize::MAX; r.nids as usize];
+        ).escapes();
+        let frames = Frames {
+            slots: vec![usize::MAX; r.nids as usize],
+            sizes: vec![0; cfg.starts.len()],
+            types: types.clone(),
+        };
         Framer {
             cfg,
             frames,
-            addressed,
-            visited,
+            escapes,
+            types,
             inc: 0
         }
     }
-    pub fn frame(&mut self) -> Vec<usize> {
-        for ind in &self.cfg.starts {
+    pub fn frame(mut self) -> Frames {
+        for (f, start) in self.cfg.starts.iter().enumerate() {
             self.inc = 0;
-            self.frame_func(*ind);
-        }
-        return std::mem::take(&mut self.frames);
-    }
-    fn frame_func(&mut self, i: usize) {
-        if self.visited[i] { return }
-        self.visited[i] = true;
-        let n = &self.cfg.nodes[i];
-        for stmt in &n.stmts {
-            self.frame_stmt(&stmt);
-        }
-        let old = self.inc;
-        if let Some(l) = n.t {
-            self.frame_func(l)
-        }
-        self.inc = old;
-        if let Some(r) = n.t {
-            self.frame_func(r)
+            for n in blocks(self.cfg, *start) {
+                for stmt in &self.cfg.nodes[n].stmts {
+                    self.frame_stmt(stmt);
+                }
+            }
+            // The stack pointer has to stay 16 byte aligned.
+            self.frames.sizes[f] = align(self.inc, 16);
         }
+        return self.frames;
     }
     fn frame_stmt(&mut self, s: &Statement) {
         use Statement::*;
@@ -58,13 +75,13 @@ impl<'l> Framer<'l> {
             Return(o) => if let Some(e) = o {
                 self.frame_expr(e);
             },
-            CJump(e, _, _) => self.frame_expr(e),
+            CJump(e, _, _) | Table(e, _) => self.frame_expr(e),
             Function(_, v) => {
-                for i in v { 
+                for i in v {
                     self.frame_temp(*i as usize)
                 }
             },
-            Jump(_) | Label(_) | Asm(_) => (),
+            Jump(_) | Label(_) | Asm(_) | Case(_, _, _) => (),
             Seq(_) => unreachable!()
         }
     }
@@ -72,76 +89,132 @@ impl<'l> Framer<'l> {
         use Expr::*;
         match e {
             Const(_) => (),
-            Temp(i) => self.frame_temp(*i as usize),
+            Temp(i, _) => self.frame_temp(*i as usize),
             UnOp(_, e) => self.frame_expr(e),
             BinOp(l, _, r) => {
                 self.frame_expr(l);
                 self.frame_expr(r);
             },
-            Mem(e) => self.frame_expr(e),
-            Call(_, v) => {
+            Mem(e, _) | Convert(_, e) => self.frame_expr(e),
+            Call(_, v, _) => {
                 for e in v { self.frame_expr(e) }
             },
             Address(e) => self.frame_expr(e),
+            Str(_) => (),
             ESeq(_, _) => unreachable!()
         }
     }
     fn frame_temp(&mut self, i: usize) {
-        if self.frames[i as usize] != usize::MAX { return };
-        if !self.addressed[i as usize] { return }
-        self.frames[i as usize] = self.inc;
-        // All types are 8 bytes...
-        // But accesses have to be 16 byte aligned
-        // And I am lazy.
-        self.inc += 16;
+        if self.frames.slots[i] != usize::MAX { return };
+        if !self.escapes[i] { return }
+        // Slots are packed, each aligned to its own size, but at most 8.
+        let size = match self.types[i] {
+            Some(t) => size(t),
+            None    => 8
+        };
+        let at = align(self.inc, size.min(8));
+        self.frames.slots[i] = at;
+        self.inc = at + size;
     }
 }
 
-struct Address<'l> {
-    cfg: &'l CFG,
-    addressed: Vec<bool>
+pub fn size(t: Type) -> usize {
+    return match t {
+        Type::I64 | Type::F64 | Type::Ptr => 8,
+        Type::I8 => 1,
+        Type::Struct(n) => n as usize,
+    };
+}
+
+fn align(n: usize, a: usize) -> usize {
+    return (n + a - 1) / a * a;
+}
+
+// Every block of the function starting at `start`, in the order they're found.
+fn blocks(cfg: &CFG, start: usize) -> Vec<usize> {
+    let mut seen = vec![false; cfg.nodes.len()];
+    let mut order = Vec::new();
+    let mut stk = vec![start];
+    while let Some(n) = stk.pop() {
+        if seen[n] { continue }
+        seen[n] = true;
+        order.push(n);
+        let node = &cfg.nodes[n];
+        if let Some(f) = node.f { stk.push(f) }
+        if let Some(t) = node.t { stk.push(t) }
+    }
+    return order;
 }
-impl<'l> Address<'l> {
-    pub fn new(cfg: &'l CFG, nlabels: usize) -> Self {
-        Address {
+
+// A temp escapes once its address is taken anywhere, after which it
+// can be read or written through a pointer and has to live in memory.
+// Array accesses and pointers handed to calls go through Address too.
+struct Escape<'l> {
+    cfg:     &'l CFG,
+    escapes: Vec<bool>,
+    types:   Vec<Option<Type>>,
+}
+impl<'l> Escape<'l> {
+    pub fn new(cfg: &'l CFG, nids: usize) -> Self {
+        Escape {
             cfg,
-            addressed: vec![false; nlabels]
+            escapes: vec![false; nids],
+            types:   vec![None; nids],
         }
     }
-    pub fn address(&mut self) -> Vec<bool> {
-        for ind in &self.cfg.starts {
-            self.address_func(&self.cfg.nodes[*ind]);
+    pub fn escapes(mut self) -> (Vec<bool>, Vec<Option<Type>>) {
+        for start in &self.cfg.starts {
+            for n in blocks(self.cfg, *start) {
+                for stmt in &self.cfg.nodes[n].stmts {
+                    self.escape_stmt(stmt);
+                }
+            }
         }
-        return std::mem::take(&mut self.addressed);
-    }
-    fn address_func(&mut self, n: &Node) {
-        for stmt in &n.stmts { self.address_stmt(&stmt);             }
-        if let Some(l) = n.t { self.address_func(&self.cfg.nodes[l]) }
-        if let Some(r) = n.t { self.address_func(&self.cfg.nodes[r]) }
+        return (self.escapes, self.types);
     }
-    fn address_stmt(&mut self, s: &Statement) {
+    fn escape_stmt(&mut self, s: &Statement) {
         use Statement::*;
         match s {
-            Expr(e)    => self.address_expr(e),
+            Expr(e)    => self.escape_expr(e),
             Move(e1, e2) => {
-                self.address_expr(e1);
-                self.address_expr(e2);
+                self.escape_expr(e1);
+                self.escape_expr(e2);
             },
             Return(o) => if let Some(e) = o {
-                self.address_expr(e);
+                self.escape_expr(e);
             },
-            CJump(e, _, _) => self.address_expr(e),
-            Jump(_) | Label(_) |
+            CJump(e, _, _) | Table(e, _) => self.escape_expr(e),
+            Jump(_) | Label(_) | Case(_, _, _) |
                 Function(_, _) | Asm(_) => (),
             Seq(_) => unreachable!()
         }
     }
-    fn address_expr(&mut self, e: &Expr) {
+    fn escape_expr(&mut self, e: &Expr) {
         use Expr::*;
-        let Address(a) = e else { return };
-        let Temp(i) = **a else { return };
-        // Ignore arrays for now...
-        self.addressed[i as usize] = true;
+        match e {
+            Const(_) | Str(_) => (),
+            Temp(i, t) => {
+                if let Some(ty) = self.types.get_mut(*i as usize) {
+                    *ty = Some(*t);
+                }
+            },
+            Address(a) => {
+                // &a[i] only computes an address from a pointer,
+                // the pointer itself doesn't escape.
+                if let Temp(i, _) = **a {
+                    self.escapes[i as usize] = true;
+                }
+                self.escape_expr(a);
+            },
+            UnOp(_, e) | Mem(e, _) | Convert(_, e) => self.escape_expr(e),
+            BinOp(l, _, r) => {
+                self.escape_expr(l);
+                self.escape_expr(r);
+            },
+            Call(_, v, _) => {
+                for e in v { self.escape_expr(e) }
+            },
+            ESeq(_, _) => unreachable!()
+        }
     }
 }
-    
\ No newline at end of file
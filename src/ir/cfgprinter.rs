@@ -47,6 +47,8 @@ impl Printer {
             Function(f, _) => format!("f{}: ", f),
             Return(r) => self._return(r),
             Asm(a) => format!("{}", a),
+            Table(e, k) => format!("Table {} {}", k, self.expression(e)),
+            Case(k, i, l) => format!("Case {}[{}] {:?}", k, i, l),
             _ => unreachable!()
         };
     }
@@ -76,12 +78,13 @@ impl Printer {
         use Expr::*;
         return match e {
             Const(p) => format!("{:?}", p),
-            Temp(t) => format!("T({})", t),
+            Temp(t, ty) => format!("T({}, {:?})", t, ty),
             UnOp(op, e) => self.unary(*op, e),
             BinOp(l, op, r) => self.binary(l, *op, r),
-            Mem(e) => self.mem(e),
-            Call(l, s) => self.call(*l, s),
+            Mem(e, ty) => self.mem(e, *ty),
+            Call(l, s, ty) => self.call(*l, s, *ty),
             Address(e) => self.address(e),
+            Convert(ty, e) => format!("Convert {:?} {}", ty, self.expression(e)),
             _ => unreachable!()
         }
     }
@@ -95,13 +98,13 @@ impl Printer {
             self.expression(r)
         );
     }
-    fn mem(&mut self, m: &Expr) -> String {
-        return format!("Mem({})",
-            self.expression(m)
+    fn mem(&mut self, m: &Expr, ty: Type) -> String {
+        return format!("Mem({}, {:?})",
+            self.expression(m), ty
         );
     }
-    fn call(&mut self, l: Label, v: &[Box<Expr>]) -> String {
-        return format!("Call(f={}, {})", l,
+    fn call(&mut self, l: Label, v: &[Box<Expr>], ty: Type) -> String {
+        return format!("Call(f={}: {:?}, {})", l, ty,
             v.iter().map(|e| self.expression(e))
                 .collect::<Vec<String>>().join(", ")
         );
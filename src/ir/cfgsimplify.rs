@@ -0,0 +1,200 @@
+use super::ir::*;
+use super::cfg::*;
+use crate::registry::Registry;
+use crate::asm::asm::AA;
+
+// Cleans up the CFG produced by cfgbuilder.
+// 1. CJumps with the same target on both sides become Jumps.
+// 2. Edges into blocks that only hold a Label (and a Jump) are threaded.
+// 3. A block with one successor swallows it if it's the only predecessor.
+// 4. Whatever is unreachable gets dropped, and the rest renumbered.
+// Function nodes are never removed, so their labels stay the same.
+pub fn simplify(r: &mut Registry, mut cfg: CFG) -> CFG {
+    loop {
+        let mut changed = collapse(&mut cfg);
+        changed |= thread(&mut cfg);
+        changed |= merge(&mut cfg);
+        if !changed { break }
+    }
+    let cfg = renumber(cfg);
+    r.nlabels = cfg.nodes.len() as u32;
+    return cfg;
+}
+
+fn collapse(cfg: &mut CFG) -> bool {
+    let mut changed = false;
+    for n in &mut cfg.nodes {
+        let Some(last) = n.stmts.last_mut() else { continue };
+        let Statement::CJump(_, t, f) = **last else { continue };
+        if t != f { continue }
+        *last = Box::new(Statement::Jump(t));
+        n.t = Some(t as usize);
+        n.f = None;
+        changed = true;
+    }
+    return changed;
+}
+
+fn thread(cfg: &mut CFG) -> bool {
+    // Where control ends up if it enters this node.
+    let len = cfg.nodes.len();
+    let mut dest: Vec<usize> = (0..len).collect();
+    for i in 0..len {
+        let mut seen = vec![i];
+        let mut cur = i;
+        while let Some(next) = forward(cfg, cur) {
+            // An empty infinite loop, leave it alone.
+            if seen.contains(&next) { cur = i; break }
+            seen.push(next);
+            cur = next;
+        }
+        dest[i] = cur;
+    }
+    let mut changed = false;
+    for n in &mut cfg.nodes {
+        for s in [n.t, n.f] {
+            let Some(s) = s else { continue };
+            if dest[s] == s { continue }
+            changed |= retarget(n, s, dest[s]);
+        }
+    }
+    return changed;
+}
+
+fn merge(cfg: &mut CFG) -> bool {
+    let (preds, reachable) = preds(cfg);
+    let mut changed = false;
+    for a in 0..cfg.nodes.len() {
+        if !reachable[a] { continue }
+        loop {
+            let n = &cfg.nodes[a];
+            let b = match (n.t, n.f) {
+                (Some(b), None) | (None, Some(b)) => b,
+                _ => break
+            };
+            let jumps = matches!(
+                n.stmts.last().map(|s| &**s), Some(Statement::Jump(_))
+            );
+            if matches!(n.stmts.last().map(|s| &**s),
+                Some(Statement::CJump(_, _, _) | Statement::Return(_))) {
+                break
+            }
+            if b == a || preds[b] != 1 || cfg.starts.contains(&b) { break }
+            let mut tail = std::mem::take(&mut cfg.nodes[b].stmts);
+            if matches!(tail.first().map(|s| &**s), Some(Statement::Label(_))) {
+                tail.remove(0);
+            }
+            let (t, f) = (cfg.nodes[b].t.take(), cfg.nodes[b].f.take());
+            let n = &mut cfg.nodes[a];
+            if jumps { n.stmts.pop(); }
+            n.stmts.extend(tail);
+            n.t = t;
+            n.f = f;
+            changed = true;
+        }
+    }
+    return changed;
+}
+
+fn renumber(cfg: CFG) -> CFG {
+    let len = cfg.nodes.len();
+    let mut reachable = vec![false; len];
+    let mut work = cfg.starts.clone();
+    while let Some(i) = work.pop() {
+        if reachable[i] { continue }
+        reachable[i] = true;
+        let n = &cfg.nodes[i];
+        work.extend(n.t.iter().chain(n.f.iter()));
+    }
+    let mut map = vec![usize::MAX; len];
+    let mut count = 0;
+    for i in 0..len {
+        if !reachable[i] { continue }
+        map[i] = count;
+        count += 1;
+    }
+    let label = |l: Label| {
+        if l == INVALID { l } else { map[l as usize] as Label }
+    };
+    let mut nodes = Vec::with_capacity(count);
+    for (i, mut n) in cfg.nodes.into_iter().enumerate() {
+        if !reachable[i] { continue }
+        for stmt in &mut n.stmts {
+            use Statement::*;
+            match **stmt {
+                Label(ref mut l)             => *l = label(*l),
+                Jump(ref mut l)              => *l = label(*l),
+                CJump(_, ref mut t, ref mut f) => {
+                    *t = label(*t);
+                    *f = label(*f);
+                },
+                Asm(AA::B2(_, ref mut l) | AA::CBZ(_, ref mut l) | AA::CBNZ(_, ref mut l)) |
+                Case(_, _, ref mut l) => {
+                    *l = label(*l);
+                },
+                _ => ()
+            }
+        }
+        // Blocks the builder made after a jump don't have labels.
+        if !matches!(n.stmts.first().map(|s| &**s),
+            Some(Statement::Label(_) | Statement::Function(_, _))) {
+            n.stmts.insert(0, Box::new(Statement::Label(map[i] as Label)));
+        }
+        n.t = n.t.map(|t| map[t]);
+        n.f = n.f.map(|f| map[f]);
+        nodes.push(n);
+    }
+    let starts = cfg.starts.iter().map(|s| map[*s]).collect();
+    return CFG { nodes, starts };
+}
+
+// If a node does nothing besides passing control on, where to.
+fn forward(cfg: &CFG, i: usize) -> Option<usize> {
+    if cfg.starts.contains(&i) { return None }
+    let n = &cfg.nodes[i];
+    let empty = n.stmts.iter().all(|s| {
+        matches!(**s, Statement::Label(_) | Statement::Jump(_))
+    });
+    if !empty { return None }
+    return match (n.t, n.f) {
+        (Some(s), None) | (None, Some(s)) => Some(s),
+        _ => None
+    };
+}
+
+fn retarget(n: &mut Node, from: usize, to: usize) -> bool {
+    if n.t != Some(from) && n.f != Some(from) { return false }
+    if n.t == Some(from) { n.t = Some(to) }
+    if n.f == Some(from) { n.f = Some(to) }
+    let Some(last) = n.stmts.last_mut() else { return true };
+    let (from, to) = (from as Label, to as Label);
+    match **last {
+        Statement::Jump(ref mut l) if *l == from => *l = to,
+        Statement::CJump(_, ref mut t, ref mut f) => {
+            if *t == from { *t = to }
+            if *f == from { *f = to }
+        },
+        Statement::Asm(AA::B2(_, ref mut l) | AA::CBZ(_, ref mut l) | AA::CBNZ(_, ref mut l)) |
+        Statement::Case(_, _, ref mut l)
+            if *l == from => *l = to,
+        _ => ()
+    }
+    return true;
+}
+
+// Predecessor counts, only counting edges out of reachable nodes.
+fn preds(cfg: &CFG) -> (Vec<u32>, Vec<bool>) {
+    let mut preds = vec![0; cfg.nodes.len()];
+    let mut reachable = vec![false; cfg.nodes.len()];
+    let mut work = cfg.starts.clone();
+    while let Some(i) = work.pop() {
+        if reachable[i] { continue }
+        reachable[i] = true;
+        let n = &cfg.nodes[i];
+        for s in n.t.iter().chain(n.f.iter()) {
+            preds[*s] += 1;
+            work.push(*s);
+        }
+    }
+    return (preds, reachable);
+}
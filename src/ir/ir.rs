@@ -2,21 +2,59 @@ use std::mem;
 use crate::asm::asm;
 pub type ID = u32;
 pub type Label = u32;
+
+// Machine types, all of them fit in a general purpose register.
+// Floats are kept as their raw bits until an instruction needs them.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Type {
+    I64,
+    F64,
+    Ptr,
+    I8, // A byte in memory, zero extended in a register.
+    Struct(u32), // That many bytes, only ever framed and addressed, never moved whole.
+}
+
 #[derive(Clone)]
 pub enum Expr {
     Const(Primitive),
-    Temp(ID),
+    Temp(ID, Type),
     UnOp(Operator, Box<Expr>),
     BinOp(Box<Expr>, Operator, Box<Expr>),
-    Mem(Box<Expr>),
-    Call(Label, Vec<Box<Expr>>),
-    Address(Box<Expr>), /* Temp, Access */
+    Mem(Box<Expr>, Type),
+    Call(Label, Vec<Box<Expr>>, Type),
+    Address(Box<Expr>), /* Temp, Access, Member */
+    Convert(Type, Box<Expr>),
+    Str(Vec<u8>), // The address of an interned string literal.
     ESeq(Box<Statement>, Box<Expr>)
 }
 impl Expr {
     pub fn addr(&self) -> usize {
         unsafe { mem::transmute(self) }
     }
+    pub fn ty(&self) -> Type {
+        use Expr::*;
+        use Operator::*;
+        return match self {
+            Const(Primitive::Int(_))   => Type::I64,
+            Const(Primitive::Float(_)) => Type::F64,
+            Temp(_, t) | Mem(_, t) |
+            Call(_, _, t) | Convert(t, _) => *t,
+            Address(_) | Str(_) => Type::Ptr,
+            UnOp(_, e)  => match e.ty() {
+                Type::I8 => Type::I64,
+                t => t
+            },
+            ESeq(_, e)  => e.ty(),
+            BinOp(l, op, r) => match op {
+                Eq | Neq | Leq | Geq | Lt | Gt => Type::I64,
+                _ => match (l.ty(), r.ty()) {
+                    (Type::Ptr, _) | (_, Type::Ptr) => Type::Ptr,
+                    (Type::F64, _) | (_, Type::F64) => Type::F64,
+                    _ => Type::I64
+                }
+            }
+        };
+    }
 }
 
 #[derive(Clone)]
@@ -29,7 +67,11 @@ pub enum Statement {
     Label(Label),
     Function(Label, Vec<ID>),
     Return(Option<Box<Expr>>),
-    Asm(asm::AA)
+    Asm(asm::AA),
+    // Jumps to whatever entry the expression picks out of table k. The
+    // entries are Case(k, i, l)s, branches the CFG sees which never run.
+    Table(Box<Expr>, u32),
+    Case(u32, u32, Label),
 }
 impl Statement {
     pub fn addr(&self) -> usize {
@@ -55,6 +97,8 @@ pub enum Operator {
     Or,
     Xor,
     Not,
+    Shl,
+    Shr, // Arithmetic, the sign is shifted in.
     Eq,
     Neq,
     Leq,
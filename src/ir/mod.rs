@@ -3,6 +3,7 @@ pub mod cfgbuilder;
 pub mod cfgexporter;
 pub mod cfgframer;
 pub mod cfgprinter;
+pub mod cfgsimplify;
 pub mod ir;
 pub mod printer;
 pub mod reducer;
@@ -1,4 +1,5 @@
 use super::ir::*;
+use crate::asm::strings;
 pub struct Printer { tabs: usize }
 /* note that this only works for LIR CFG */
 impl Printer {
@@ -22,7 +23,9 @@ impl Printer {
             Function(f, _) => format!("f{}: ", f),
             Return(r) => self._return(r),
             Seq(s) => self.seq(s),
-            Asm(s) => format!("asm: {}", s)
+            Asm(s) => format!("asm: {}", s),
+            Table(e, k) => format!("Table {} {}", k, self.expression(e)),
+            Case(k, i, l) => format!("Case {}[{}] {:?}", k, i, l)
         };
     }
     fn seq(&mut self, stmts: &[Box<Statement>]) -> String {
@@ -65,12 +68,14 @@ impl Printer {
         use Expr::*;
         return match e {
             Const(p) => format!("{:?}", p),
-            Temp(t) => format!("T({})", t),
+            Temp(t, ty) => format!("T({}, {:?})", t, ty),
             UnOp(op, e) => self.unary(*op, e),
             BinOp(l, op, r) => self.binary(l, *op, r),
-            Mem(e) => self.mem(e),
-            Call(l, s) => self.call(*l, s),
+            Mem(e, ty) => self.mem(e, *ty),
+            Call(l, s, ty) => self.call(*l, s, *ty),
             Address(e) => self.address(e),
+            Convert(ty, e) => format!("Convert {:?} {}", ty, self.expression(e)),
+            Str(s) => format!("Str(\"{}\")", strings::escape(s)),
             ESeq(s, e) => self.eseq(s, e)
         }
     }
@@ -90,13 +95,13 @@ impl Printer {
             self.expression(r)
         );
     }
-    fn mem(&mut self, m: &Expr) -> String {
-        return format!("Mem({})",
-            self.expression(m)
+    fn mem(&mut self, m: &Expr, ty: Type) -> String {
+        return format!("Mem({}, {:?})",
+            self.expression(m), ty
         );
     }
-    fn call(&mut self, l: Label, v: &[Box<Expr>]) -> String {
-        return format!("Call(f={}, {})", l,
+    fn call(&mut self, l: Label, v: &[Box<Expr>], ty: Type) -> String {
+        return format!("Call(f={}: {:?}, {})", l, ty,
             v.iter().map(|e| self.expression(e))
                 .collect::<Vec<String>>().join(", ")
         );
@@ -23,7 +23,12 @@ impl<'l> Reducer<'l> {
                 s1.push(Box::new(CJump(e1, t, f)));
                 return s1;
             },
-            Jump(_) | Label(_) |
+            Table(e, k) => {
+                let (mut s1, e1) = self.expression(e);
+                s1.push(Box::new(Table(e1, k)));
+                return s1;
+            },
+            Jump(_) | Label(_) | Case(_, _, _) |
             Function(_, _) | Asm(_) => return vec![s],
             Return(r)  => match r {
                 None => return vec![Box::new(Return(None))],
@@ -41,7 +46,7 @@ impl<'l> Reducer<'l> {
         -> Vec<Box<Statement>> {
         let (mut s1, e1) = self.expression(e);
         match *e1 {
-            Expr::Call(_, _) => s1.push(Box::new(
+            Expr::Call(_, _, _) => s1.push(Box::new(
                 Statement::Expr(e1)
             )),
             _ => ()
@@ -52,7 +57,7 @@ impl<'l> Reducer<'l> {
         -> Vec<Box<Statement>> {
         use Expr::*;
         match *d {
-            Temp(_) => {
+            Temp(_, _) => {
                 let (mut s1, e1) = self.expression(s);
                 s1.push(Box::new(Statement::Move(
                     d,
@@ -60,19 +65,19 @@ impl<'l> Reducer<'l> {
                 )));
                 return s1;
             },
-            Mem(_) => {
+            Mem(a, t) => {
                 /* TODO: check e1 & e2 commute? */
                 let id = self.create_temp();
-                let (sl, el) = self.expression(d);
+                let (sl, el) = self.expression(a);
                 let (sr, er) = self.expression(s);
                 let mut v = sl.clone();
                 v.push(Box::new(Statement::Move(
-                    Box::new(Expr::Temp(id)),
+                    Box::new(Expr::Temp(id, Type::Ptr)),
                     el,
                 )));
                 v.extend(sr);
                 v.push(Box::new(Statement::Move(
-                    Box::new(Temp(id)),
+                    Box::new(Mem(Box::new(Temp(id, Type::Ptr)), t)),
                     er
                 )));
                 return v;
@@ -92,10 +97,14 @@ impl<'l> Reducer<'l> {
         -> (Vec<Box<Statement>>, Box<Expr>) {
         use Expr::*;
         match *e {
-            Const(_) | Temp(_) => return (Vec::new(), e),
-            Mem(e1) => {
+            Const(_) | Temp(_, _) | Str(_) => return (Vec::new(), e),
+            Mem(e1, t) => {
+                let (v, e2) = self.expression(e1);
+                return (v, Box::new(Mem(e2, t)))
+            },
+            Convert(t, e1) => {
                 let (v, e2) = self.expression(e1);
-                return (v, Box::new(Mem(e2)))
+                return (v, Box::new(Convert(t, e2)))
             },
             Address(e1) => {
                 let (v, e2) = self.expression(e1);
@@ -103,7 +112,7 @@ impl<'l> Reducer<'l> {
             },
             UnOp(op, e) => return self.unary(op, e),
             BinOp(l, op, r) => return self.binary(l, op, r),
-            Call(l, exprs) => return self.call(l, exprs),
+            Call(l, exprs, t) => return self.call(l, exprs, t),
             ESeq(s, e) => return self.eseq(s, e)
         }
     }
@@ -119,20 +128,21 @@ impl<'l> Reducer<'l> {
         let (sl, el) = self.expression(l);
         let (sr, er) = self.expression(r);
         let id = self.create_temp();
+        let t = el.ty();
         v.extend(sl);
         v.push(Box::new(Statement::Move(
-            Box::new(Expr::Temp(id)),
+            Box::new(Expr::Temp(id, t)),
             Box::new(*el)
         )));
         v.extend(sr);
         let e = Expr::BinOp(
-            Box::new(Expr::Temp(id)),
+            Box::new(Expr::Temp(id, t)),
             op,
             Box::new(*er)
         );
         return (v, Box::new(e));
     }
-    fn call(&mut self, l: Label, exprs: Vec<Box<Expr>>)
+    fn call(&mut self, l: Label, exprs: Vec<Box<Expr>>, t: Type)
         -> (Vec<Box<Statement>>, Box<Expr>) {
         use Expr::*;
         let mut temps = Vec::<Box<Expr>>::new();
@@ -140,21 +150,22 @@ impl<'l> Reducer<'l> {
         for e1 in exprs {
             let (s2, e2) = self.expression(e1);
             let id = self.create_temp();
+            let ty = e2.ty();
             v.extend(s2);
             v.push(Box::new(Statement::Move(
-                Box::new(Temp(id)), e2
+                Box::new(Temp(id, ty)), e2
             )));
-            temps.push(Box::new(Temp(id)));
+            temps.push(Box::new(Temp(id, ty)));
         }
         v.push(Box::new(Statement::Expr(
-            Box::new(Call(l, temps))
+            Box::new(Call(l, temps, t))
         )));
         let id = self.create_temp();
         v.push(Box::new(Statement::Move(
-            Box::new(Temp(id)),
-            Box::new(Temp(self.reg.ret))
+            Box::new(Temp(id, t)),
+            Box::new(Temp(self.reg.ret, t))
         )));
-        return (v, Box::new(Temp(id)));
+        return (v, Box::new(Temp(id, t)));
     }
     fn eseq(&mut self, s: Box<Statement>, e: Box<Expr>)
         -> (Vec<Box<Statement>>, Box<Expr>) {
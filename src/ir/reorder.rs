@@ -1,31 +1,175 @@
+// See Appel, Modern Compiler Implementation, 8.2 (Traces).
 use super::cfg::CFG;
+use super::ir::Statement;
+use crate::analysis::dominators::Dominators;
+use crate::analysis::graph::Graph;
+use crate::analysis::loops::LoopForest;
+
+// Relative execution counts of the (t, f) edges leaving every node.
+// These can come from a profile, or from `predict`.
+pub type Weights = Vec<(f64, f64)>;
+
+// Probability we give to the edge we think leaves a loop.
+const EXIT_PROB:   f64 = 0.1;
+// Probability we give to an edge into a block which just returns.
+const RETURN_PROB: f64 = 0.3;
+// How many times we assume a loop body executes per entry.
+const LOOP_SCALE:  f64 = 10.0;
+
 pub fn reorder(cfg: &CFG) -> Vec<usize> {
-    fn reorder_function(start: usize, count: &mut i32, order: &mut [i32], cfg: &CFG) {
-        *count += 1;
-        order[start] = *count;
-        let node = &cfg.nodes[start as usize];
-        if let Some(t) = node.t {
-            if order[t as usize] < 0 { 
-                reorder_function(t, count, order, cfg);
+    let weights = predict(cfg);
+    return reorder_weighted(cfg, &weights);
+}
+
+// Every function in reverse postorder, with no regard for fall-throughs.
+pub fn naive(cfg: &CFG) -> Vec<usize> {
+    return cfg.starts.iter()
+        .flat_map(|s| rpo(cfg, *s))
+        .collect();
+}
+
+// Lays out every function as a sequence of traces, picking the
+// heaviest edge out of each block as its fall-through. Loops then
+// get their test moved below the body.
+pub fn reorder_weighted(cfg: &CFG, weights: &Weights) -> Vec<usize> {
+    let loops = LoopForest::compute(cfg, &Dominators::compute(cfg));
+    let preds = cfg.preds();
+    let mut placed = vec![false; cfg.nodes.len()];
+    let mut order = Vec::new();
+    for start in &cfg.starts {
+        let rpo = rpo(cfg, *start);
+        let mut last: Option<usize> = None;
+        while let Some(seed) = pick_seed(&loops, &rpo, &placed, last) {
+            let mut cur = seed;
+            loop {
+                placed[cur] = true;
+                order.push(cur);
+                last = Some(cur);
+                let Some(next) = pick_next(cfg, &loops, weights, &preds, &placed, cur)
+                    else { break };
+                cur = next;
             }
         }
-        if let Some(f) = node.f {
-            if order[f as usize] < 0 { 
-                reorder_function(f, count, order, cfg);
-            }
+    }
+    rotate(cfg, &loops, &mut order);
+    return order;
+}
+
+// Static branch prediction, loosely following Ball & Larus.
+// Loop exits are unlikely, and so are blocks which only return.
+// Every edge is then scaled by how deeply nested its source is.
+pub fn predict(cfg: &CFG) -> Weights {
+    let loops = LoopForest::compute(cfg, &Dominators::compute(cfg));
+    let mut weights = vec![(0.0, 0.0); cfg.nodes.len()];
+    for (i, n) in cfg.nodes.iter().enumerate() {
+        let freq = LOOP_SCALE.powi(loops.depth(i) as i32);
+        let (pt, pf) = match (n.t, n.f) {
+            (Some(t), Some(f)) if t != f => {
+                let (et, ef) = (loops.exits(i, t), loops.exits(i, f));
+                let (rt, rf) = (returns(cfg, t), returns(cfg, f));
+                if et != ef {
+                    if et { (EXIT_PROB, 1.0 - EXIT_PROB) }
+                    else  { (1.0 - EXIT_PROB, EXIT_PROB) }
+                } else if rt != rf {
+                    if rt { (RETURN_PROB, 1.0 - RETURN_PROB) }
+                    else  { (1.0 - RETURN_PROB, RETURN_PROB) }
+                } else {
+                    (0.5, 0.5)
+                }
+            },
+            (Some(_), Some(_)) => (0.5, 0.5),
+            (Some(_), None)    => (1.0, 0.0),
+            (None, Some(_))    => (0.0, 1.0),
+            (None, None)       => (0.0, 0.0),
+        };
+        weights[i] = (freq * pt, freq * pf);
+    }
+    return weights;
+}
+
+// The next trace starts in the innermost loop that still has unplaced
+// blocks, so loop bodies stay contiguous and their exits come last.
+fn pick_seed(loops: &LoopForest, rpo: &[usize], placed: &[bool],
+    last: Option<usize>) -> Option<usize> {
+    let mut cur = last.and_then(|l| loops.innermost[l]);
+    while let Some(l) = cur {
+        let seed = rpo.iter().find(|n| {
+            !placed[**n] && loops.contains(l, **n)
+        });
+        if let Some(s) = seed { return Some(*s) }
+        cur = loops.loops[l].parent;
+    }
+    return rpo.iter().find(|n| !placed[**n]).copied();
+}
+
+fn pick_next(cfg: &CFG, loops: &LoopForest, weights: &Weights,
+    preds: &[Vec<usize>], placed: &[bool], cur: usize) -> Option<usize> {
+    let n = &cfg.nodes[cur];
+    let (wt, wf) = weights[cur];
+    let mut best: Option<(usize, f64, bool)> = None;
+    // Ties go to a block nothing else still has to jump into, so joins
+    // come after both their sides. Then to f, since falling into f keeps
+    // the condition as is.
+    for (succ, w) in [(n.f, wf), (n.t, wt)] {
+        let Some(s) = succ else { continue };
+        if placed[s] { continue }
+        // Never wander into the middle of a different loop.
+        if let Some(l) = loops.innermost[s] {
+            if !loops.is_header(s) && !loops.contains(l, cur) { continue }
+        }
+        let last = preds[s].iter().all(|p| *p == cur || placed[*p]);
+        match best {
+            Some((_, bw, bl)) if bw > w || (bw == w && (bl || !last)) => (),
+            _ => best = Some((s, w, last))
         }
     }
-    let mut count = -1;
-    let mut order = vec![-1; cfg.nodes.len()];
-    for start in &cfg.starts {
-        reorder_function(
-            *start,
-            &mut count,
-            &mut order,
-            &cfg
-        );
+    return best.map(|(s, _, _)| s);
+}
+
+// A loop laid out as header, body, latch jumps back every iteration and
+// then tests. When the latch ends its run, the header goes below it
+// instead, so the back edge falls through into the test and only the
+// way in jumps. Inner loops first, they run more often.
+fn rotate(cfg: &CFG, loops: &LoopForest, order: &mut [usize]) {
+    let mut ids: Vec<usize> = (0..loops.loops.len()).collect();
+    ids.sort_by_key(|l| std::cmp::Reverse(loops.loops[*l].depth));
+    for id in ids {
+        let l = &loops.loops[id];
+        if cfg.starts.contains(&l.header) { continue }
+        let Some(a) = order.iter().position(|n| *n == l.header) else { continue };
+        let b = a + l.body.len() - 1;
+        if b >= order.len() || !order[a..=b].iter().all(|n| loops.contains(id, *n)) { continue }
+        let test = l.exits.iter().any(|(from, _)| *from == l.header);
+        let back = cfg.succs(order[b]) == [l.header];
+        if test && back { order[a..=b].rotate_left(1); }
     }
-    return order.into_iter()
-        .map(|x| x as usize)
-        .collect();
-}
\ No newline at end of file
+}
+
+fn returns(cfg: &CFG, idx: usize) -> bool {
+    let n = &cfg.nodes[idx];
+    return n.t.is_none() && n.f.is_none() && matches!(
+        n.stmts.last().map(|s| &**s), Some(Statement::Return(_))
+    );
+}
+
+fn rpo(cfg: &CFG, start: usize) -> Vec<usize> {
+    let mut post = Vec::new();
+    let mut visited = vec![false; cfg.nodes.len()];
+    let mut stk = vec![(start, 0)];
+    visited[start] = true;
+    while let Some((idx, i)) = stk.pop() {
+        let n = &cfg.nodes[idx];
+        let succs = [n.t, n.f];
+        if i == succs.len() {
+            post.push(idx);
+            continue;
+        }
+        stk.push((idx, i + 1));
+        let Some(s) = succs[i] else { continue };
+        if visited[s] { continue }
+        visited[s] = true;
+        stk.push((s, 0));
+    }
+    post.reverse();
+    return post;
+}
@@ -1,10 +1,17 @@
+use std::collections::HashMap;
 use crate::ast::ast::{self, FunctionDeclaration, AsmStatement};
-use super::ir::{self, Operator};
+use super::ir::{self, Operator, Type};
+use crate::asm::asm::{AA, Reg};
+use crate::asm::target::MachineInstr;
 use crate::registry::Registry;
 
 pub struct Translator<'l> {
     loop_starts: Vec<ir::Label>,
     loop_ends:   Vec<ir::Label>,
+    params:      Vec<Vec<Type>>, // By function id, what arguments convert to.
+    ret:         Type,           // What the current function returns.
+    sizes:       HashMap<u32, u32>, // Struct sizes, by tag.
+    cases:       Vec<(Vec<(i64, ir::Label)>, Option<ir::Label>)>, // Per enclosing switch.
     reg:         &'l mut Registry
 }
 
@@ -13,12 +20,21 @@ impl<'l> Translator<'l> {
         Self {
             loop_starts: Vec::new(),
             loop_ends:   Vec::new(),
+            params:      Vec::new(),
+            ret:         Type::I64,
+            sizes:       HashMap::new(),
+            cases:       Vec::new(),
             reg:         registry
         } 
     }
     pub fn translate(&mut self, m: &mut ast::Module) -> Vec::<Box<ir::Statement>> {
         self.reg.nfuncs  = m.functions.len() as u32;
         self.reg.nlabels = m.functions.len() as u32; // function ids are their labels.
+        self.sizes = m.structs.iter().map(|s| (s.tag, s.size)).collect();
+        self.params = vec![Vec::new(); m.functions.len()];
+        for f in &m.functions {
+            self.params[f.id as usize] = f.params.iter().map(|p| ty(p.kind)).collect();
+        }
         let mut res = Vec::<Box<ir::Statement>>::new();
         for f in &m.functions {
             match self.function_declaration(f) {
@@ -30,6 +46,7 @@ impl<'l> Translator<'l> {
     }
     fn function_declaration(&mut self, f: &FunctionDeclaration) -> Option<Box<ir::Statement>> {
         let ids: Vec<ir::ID> = f.params.iter().map(|p| p.id).collect();
+        self.ret = ty(f.ret);
         let mut ret = vec![Box::new(
             ir::Statement::Function(f.id, ids)
         )];
@@ -50,13 +67,155 @@ impl<'l> Translator<'l> {
             While    (ref w) => Some(self.while_statement(w)),
             Compound (ref c) => Some(self.compound_statement(c)),
             Jump     (ref j) => Some(self.jump_statement(j)),
-            Asm      (ref a) => Some(self.asm_statement(a)) // TEMPORARILY
+            Asm      (ref a) => Some(self.asm_statement(a)), // TEMPORARILY
+            Switch   (ref s) => Some(self.switch_statement(s)),
+            Case     (ref c) => Some(self.case_statement(c))
+        }
+    }
+    // The body goes first, to find out where its cases are, and
+    // the dispatch is put in front of it. Break leaves like a loop's.
+    fn switch_statement(&mut self, s: &ast::SwitchStatement) -> Box<ir::Statement> {
+        let x = self.reg.nids;
+        self.reg.nids += 1;
+        let le = self.create_label();
+        self.cases.push((Vec::new(), None));
+        self.loop_ends.push(le);
+        let body = self.statement(&s.stmt);
+        self.loop_ends.pop();
+        let (mut cases, default) = self.cases.pop().unwrap();
+        cases.sort();
+
+        let mut ret = vec![Box::new(ir::Statement::Move(
+            Box::new(ir::Expr::Temp(x, Type::I64)),
+            convert(self.expression(&s.expr), Type::I64)
+        ))];
+        let default = default.unwrap_or(le);
+        match (cases.first(), cases.last()) {
+            (Some((lo, _)), Some((hi, _))) if dense(cases.len(), *lo, *hi) => {
+                ret.extend(self.table(x, &cases, default));
+            },
+            _ => ret.extend(self.tree(x, &cases, default))
+        }
+        if let Some(b) = body { ret.push(b) }
+        ret.push(Box::new(ir::Statement::Label(le)));
+        return Box::new(ir::Statement::Seq(ret));
+    }
+    fn case_statement(&mut self, c: &ast::CaseStatement) -> Box<ir::Statement> {
+        let l = self.create_label();
+        let Some((cases, default)) = self.cases.last_mut() else { unreachable!() };
+        match c.value {
+            None    => *default = Some(l),
+            Some(v) => cases.push((v, l))
+        }
+        let mut ret = vec![Box::new(ir::Statement::Label(l))];
+        if let Some(s) = self.statement(&c.stmt) { ret.push(s) }
+        return Box::new(ir::Statement::Seq(ret));
+    }
+    // Index by x - lo, after checking it's in range. Gaps go to default.
+    fn table(&mut self, x: ir::ID, cases: &[(i64, ir::Label)], default: ir::Label)
+        -> Vec<Box<ir::Statement>> {
+        use ir::Expr::{Temp, BinOp};
+        let lo = cases[0].0;
+        let n = cases[cases.len() - 1].0 - lo + 1;
+        let i = self.reg.nids;
+        self.reg.nids += 1;
+        let k = self.reg.ntables;
+        self.reg.ntables += 1;
+        let int = |v: i64| Box::new(ir::Expr::Const(ir::Primitive::Int(v)));
+        let idx = || Box::new(Temp(i, Type::I64));
+        let (l1, l2) = (self.create_label(), self.create_label());
+        let mut ret = vec![
+            Box::new(ir::Statement::Move(idx(), Box::new(BinOp(
+                Box::new(Temp(x, Type::I64)), Operator::Sub, int(lo)
+            )))),
+            Box::new(ir::Statement::CJump(Box::new(BinOp(idx(), Operator::Lt, int(0))), default, l1)),
+            Box::new(ir::Statement::Label(l1)),
+            Box::new(ir::Statement::CJump(Box::new(BinOp(idx(), Operator::Gt, int(n - 1))), default, l2)),
+            Box::new(ir::Statement::Label(l2)),
+            Box::new(ir::Statement::Table(idx(), k)),
+        ];
+        for j in 0..n {
+            let l = match cases.binary_search_by_key(&(lo + j), |c| c.0) {
+                Ok(c) => cases[c].1,
+                Err(_) => default
+            };
+            ret.push(Box::new(ir::Statement::Case(k, j as u32, l)));
+            ret.push(Box::new(ir::Statement::Label(self.create_label())));
+        }
+        ret.push(Box::new(ir::Statement::Jump(default)));
+        return ret;
+    }
+    // A binary search down to a few cases, which are compared one by one.
+    fn tree(&mut self, x: ir::ID, cases: &[(i64, ir::Label)], default: ir::Label)
+        -> Vec<Box<ir::Statement>> {
+        let int = |v: i64| Box::new(ir::Expr::Const(ir::Primitive::Int(v)));
+        let cmp = |op, v| Box::new(ir::Expr::BinOp(Box::new(ir::Expr::Temp(x, Type::I64)), op, int(v)));
+        let mut ret = Vec::new();
+        if cases.len() <= 3 {
+            for (v, l) in cases {
+                let next = self.create_label();
+                ret.push(Box::new(ir::Statement::CJump(cmp(Operator::Eq, *v), *l, next)));
+                ret.push(Box::new(ir::Statement::Label(next)));
+            }
+            ret.push(Box::new(ir::Statement::Jump(default)));
+            return ret;
         }
+        let mid = cases.len() / 2;
+        let (ll, lr) = (self.create_label(), self.create_label());
+        ret.push(Box::new(ir::Statement::CJump(cmp(Operator::Lt, cases[mid].0), ll, lr)));
+        ret.push(Box::new(ir::Statement::Label(ll)));
+        ret.extend(self.tree(x, &cases[..mid], default));
+        ret.push(Box::new(ir::Statement::Label(lr)));
+        ret.extend(self.tree(x, &cases[mid..], default));
+        return ret;
     }
+    // Every operand gets a temp of its own. Inputs are moved into theirs
+    // before the lines run and outputs out of theirs after. The clobbers
+    // go on both sides, so nothing live across the lines is kept in them.
     fn asm_statement(&mut self, a: &AsmStatement) -> Box<ir::Statement> {
+        use ast::AsmDir::*;
+        let temps: Vec<ir::ID> = a.operands.iter().map(|_| {
+            self.reg.nids += 1;
+            self.reg.nids - 1
+        }).collect();
+        let temp = |o: &ast::AsmOperand, t: ir::ID| {
+            Box::new(ir::Expr::Temp(t, ty(o.expr.kind().unwrap())))
+        };
         let mut statements = Vec::new();
-        for line in a.asm.clone() {
-            statements.push(Box::new(ir::Statement::Asm(line)));
+        for (o, t) in a.operands.iter().zip(&temps) {
+            if o.dir == Out { continue }
+            let e = self.expression(&o.expr);
+            statements.push(Box::new(ir::Statement::Move(temp(o, *t), e)));
+        }
+        let clobbers = Box::new(ir::Statement::Asm(AA::Clobber(a.clobbers.clone())));
+        if !a.clobbers.is_empty() { statements.push(clobbers.clone()); }
+        // Local labels become ordinary ones, so the CFG sees the branches.
+        let labels: Vec<ir::Label> = a.labels.iter().map(|_| self.create_label()).collect();
+        for line in &a.asm {
+            let line = line.map(&|r| match r {
+                Reg::ID(i) => Reg::ID(temps[i as usize]),
+                _ => r
+            });
+            let l = |i: u32| labels[i as usize];
+            let branches = matches!(line, AA::B2(..) | AA::CBZ(..) | AA::CBNZ(..));
+            statements.push(Box::new(match line {
+                AA::Label(i) => ir::Statement::Label(l(i)),
+                AA::B1(i)    => ir::Statement::Jump(l(i)),
+                AA::B2(c, i)   => ir::Statement::Asm(AA::B2(c, l(i))),
+                AA::CBZ(r, i)  => ir::Statement::Asm(AA::CBZ(r, l(i))),
+                AA::CBNZ(r, i) => ir::Statement::Asm(AA::CBNZ(r, l(i))),
+                _ => ir::Statement::Asm(line)
+            }));
+            // Conditional branches fall through into a block of their own.
+            if branches {
+                statements.push(Box::new(ir::Statement::Label(self.create_label())));
+            }
+        }
+        if !a.clobbers.is_empty() { statements.push(clobbers); }
+        for (o, t) in a.operands.iter().zip(&temps) {
+            if o.dir == In { continue }
+            let d = self.expression(&o.expr);
+            statements.push(Box::new(ir::Statement::Move(d, temp(o, *t))));
         }
         return Box::new(ir::Statement::Seq(statements));
     }
@@ -64,8 +223,8 @@ impl<'l> Translator<'l> {
         match &d.val {
             None    => return None,
             Some(e) => return Some(Box::new(ir::Statement::Move(
-                Box::new(ir::Expr::Temp(d.id)),
-                self.expression(e)
+                Box::new(ir::Expr::Temp(d.id, ty(d.kind))),
+                convert(self.expression(e), ty(d.kind))
             )))
         }
     }
@@ -184,7 +343,7 @@ impl<'l> Translator<'l> {
         return Box::new(match e {
             None    => ir::Statement::Return(None),
             Some(s) => ir::Statement::Return(Some(
-                self.expression(&s)
+                convert(self.expression(&s), self.ret)
             ))
         })
     }
@@ -205,7 +364,7 @@ impl<'l> Translator<'l> {
                 if *i != 0 { t } else { f }
             ))),
             Ident(i) => Some(Box::new(ir::Statement::CJump(
-                Box::new(ir::Expr::Temp(i.id)),
+                Box::new(ir::Expr::Temp(i.id, ty(i.kind.unwrap()))),
                 t, f
             ))),
             _ => None
@@ -265,19 +424,63 @@ impl<'l> Translator<'l> {
             Float(f) => Box::new(ir::Expr::Const(
                 ir::Primitive::Float(*f as f64)
             )),
-            Ident(i) => Box::new(ir::Expr::Temp(i.id)),
+            Char(c) => Box::new(ir::Expr::Const(
+                ir::Primitive::Int(*c as i64)
+            )),
+            Str(s) => Box::new(ir::Expr::Str(s.clone())),
+            Ident(i) => Box::new(ir::Expr::Temp(i.id, ty(i.kind.unwrap()))),
+            Member(m) => {
+                let t = ty(m.kind.unwrap());
+                Box::new(ir::Expr::Mem(self.member(m), t))
+            }
         }
     }
+    // Where a member lives, its struct's address plus its offset.
+    fn member(&mut self, m: &ast::MemberExpr) -> Box<ir::Expr> {
+        let base = if m.arrow { self.expression(&m.expr) } else { self.place(&m.expr) };
+        // Members of members add up to a single offset.
+        let (base, off) = match *base {
+            ir::Expr::BinOp(b, Operator::Add, c) => match *c {
+                ir::Expr::Const(ir::Primitive::Int(c)) => (b, c + m.offset as i64),
+                c => (Box::new(ir::Expr::BinOp(b, Operator::Add, Box::new(c))), m.offset as i64)
+            },
+            e => (Box::new(e), m.offset as i64)
+        };
+        return Box::new(ir::Expr::BinOp(
+            base,
+            Operator::Add,
+            Box::new(ir::Expr::Const(ir::Primitive::Int(off)))
+        ));
+    }
+    // The address of something in memory. Struct locals get a Temp that
+    // only ever appears under Address, so it's framed and never loaded.
+    fn place(&mut self, e: &ast::Expr) -> Box<ir::Expr> {
+        use ast::Expr::*;
+        return match e {
+            Ident(i) => {
+                let k = i.kind.unwrap();
+                let t = match k.prim {
+                    ast::Primitive::Struct(tag) if k.indir == 0 => Type::Struct(self.sizes[&tag]),
+                    _ => ty(k)
+                };
+                Box::new(ir::Expr::Address(Box::new(ir::Expr::Temp(i.id, t))))
+            },
+            Member(m) => self.member(m),
+            Unary(u) if matches!(u.unary_op, ast::UnaryOp::Star) => self.expression(&u.expr),
+            _ => unreachable!()
+        };
+    }
     fn function(&mut self, f: &ast::FunctionCall) -> Box<ir::Expr> {
         let mut v = Vec::<Box<ir::Expr>>::new();
-        for exp in &f.args {
-            v.push(self.expression(exp));
+        for (i, exp) in f.args.iter().enumerate() {
+            let t = self.params[f.id as usize][i];
+            v.push(convert(self.expression(exp), t));
         }
-        return Box::new(ir::Expr::Call(f.id, v));
+        return Box::new(ir::Expr::Call(f.id, v, ty(f.kind.unwrap())));
     }
     fn access(&mut self, a: &ast::AccessExpr) -> Box<ir::Expr> {
         let mut prod: u32 = 1;
-        let t = ir::Expr::Temp(a.id);
+        let t = ir::Expr::Temp(a.id, Type::Ptr);
         let mut root: Option<ir::Expr> = None;
         for i in (0..a.offsets.len()).rev() {
             let mul = ir::Expr::BinOp(
@@ -304,19 +507,25 @@ impl<'l> Translator<'l> {
             Operator::Add,
             Box::new(root.unwrap())
         ));
-        return Box::new(ir::Expr::Mem(exp));
+        return Box::new(ir::Expr::Mem(exp, ty(a.kind.unwrap())));
     }
     fn unary(&mut self, u: &ast::UnaryExpr) -> Box<ir::Expr> {
         use ast::UnaryOp::*;
         let op = match u.unary_op {
             Not  => ir::Operator::Not,
             Neg  => ir::Operator::Neg,
+            // Not flips conditions too, ~x is spelled x ^ -1 so it can't be
+            // mistaken for one.
+            BitNot => return Box::new(ir::Expr::BinOp(
+                self.expression(&u.expr),
+                ir::Operator::Xor,
+                Box::new(ir::Expr::Const(ir::Primitive::Int(-1)))
+            )),
             Star => return Box::new(ir::Expr::Mem(
-                self.expression(&u.expr)
+                self.expression(&u.expr),
+                ty(u.kind.unwrap())
             )),
-            Address => return Box::new(ir::Expr::Address(
-                self.expression(&u.expr)
-            ))
+            Address => return self.place(&u.expr)
         };
         return Box::new(ir::Expr::UnOp(op,
             self.expression(&u.expr)
@@ -335,17 +544,37 @@ impl<'l> Translator<'l> {
             Lt     => ir::Operator::Lt,
             Gt     => ir::Operator::Gt,
             Eq     => ir::Operator::Eq,
-            Or     => ir::Operator::Or,
-            And    => ir::Operator::And,
-            Peq | Teq | Deq |
-            Seq | Neq | Assign  
+            Neq    => ir::Operator::Neq,
+            Or | And => return self.logical(b),
+            BitOr  => ir::Operator::Or,
+            BitXor => ir::Operator::Xor,
+            BitAnd => ir::Operator::And,
+            Shl    => ir::Operator::Shl,
+            Shr    => ir::Operator::Shr,
+            Peq | Teq | Deq | Seq |
+            Aeq | Oeq | Xeq | Shleq | Shreq | Assign
                 => return self.assign(b),
         };
-        return Box::new(ir::Expr::BinOp(
-            self.expression(&b.left),
-            op,
-            self.expression(&b.right),
-        ));
+        let stride = self.stride(b.left.kind().unwrap(), b.right.kind().unwrap());
+        let l = self.expression(&b.left);
+        let r = self.expression(&b.right);
+        return arith(l, op, r, stride);
+    }
+    // && and || short-circuit as values too, the IR's And and Or are
+    // bitwise. The result is 1 where control() ends up true, 0 otherwise.
+    fn logical(&mut self, b: &ast::BinaryExpr) -> Box<ir::Expr> {
+        self.reg.nids += 1;
+        let temp = Box::new(ir::Expr::Temp(self.reg.nids - 1, Type::I64));
+        let lt = self.create_label();
+        let done = self.create_label();
+        let stmt = Box::new(ir::Statement::Seq(vec![
+            Box::new(ir::Statement::Move(temp.clone(), Box::new(ir::Expr::Const(ir::Primitive::Int(0))))),
+            self.control_binary(b, lt, done).unwrap(),
+            Box::new(ir::Statement::Label(lt)),
+            Box::new(ir::Statement::Move(temp.clone(), Box::new(ir::Expr::Const(ir::Primitive::Int(1))))),
+            Box::new(ir::Statement::Label(done)),
+        ]));
+        return Box::new(ir::Expr::ESeq(stmt, temp));
     }
     fn assign(&mut self, b: &ast::BinaryExpr) -> Box<ir::Expr> {
         use ast::BinaryOp::*;
@@ -353,27 +582,36 @@ impl<'l> Translator<'l> {
         // b.left should only be an access or temp,
         // so it's not terribly expensive.
         let stmt = Box::new(match b.binary_op {
-            Peq | Teq | Seq | Deq => {
+            Peq | Teq | Seq | Deq | Aeq | Oeq | Xeq | Shleq | Shreq => {
                 let op = match b.binary_op {
                     Peq => ir::Operator::Add,
                     Teq => ir::Operator::Mul,
                     Seq => ir::Operator::Sub,
                     Deq => ir::Operator::Div,
+                    Aeq => ir::Operator::And,
+                    Oeq => ir::Operator::Or,
+                    Xeq => ir::Operator::Xor,
+                    Shleq => ir::Operator::Shl,
+                    Shreq => ir::Operator::Shr,
                     _   => unreachable!()
                 };
-                ir::Statement::Move(
+                let d = self.expression(&b.left);
+                let t = d.ty();
+                let stride = self.stride(b.left.kind().unwrap(), b.right.kind().unwrap());
+                ir::Statement::Move(d, convert(arith(
                     self.expression(&b.left),
-                    Box::new(ir::Expr::BinOp(
-                        self.expression(&b.left),
-                        op,
-                        self.expression(&b.right)
-                    ))
-                )
+                    op,
+                    self.expression(&b.right),
+                    stride
+                ), t))
+            },
+            Assign => {
+                let d = self.expression(&b.left);
+                let t = d.ty();
+                ir::Statement::Move(d, convert(
+                    self.expression(&b.right), t
+                ))
             },
-            Assign => ir::Statement::Move(
-                self.expression(&b.left),
-                self.expression(&b.right)
-            ),
             _ => unreachable!()
         });
         return Box::new(ir::Expr::ESeq(
@@ -384,4 +622,53 @@ impl<'l> Translator<'l> {
         self.reg.nlabels += 1;
         return self.reg.nlabels - 1;
     }
-}
\ No newline at end of file
+    // How far apart consecutive elements are when one side is a pointer.
+    fn stride(&self, l: ast::Kind, r: ast::Kind) -> i64 {
+        let p = if l.indir > 0 { l } else { r };
+        if p.indir > 1 { return 8 }
+        return match p.prim {
+            ast::Primitive::Char => 1,
+            ast::Primitive::Struct(t) => self.sizes[&t] as i64,
+            _ => 8
+        };
+    }
+}
+fn ty(k: ast::Kind) -> Type {
+    if k.indir > 0 { return Type::Ptr }
+    return match k.prim {
+        ast::Primitive::Float => Type::F64,
+        ast::Primitive::Char  => Type::I8,
+        ast::Primitive::Int | ast::Primitive::Void => Type::I64,
+        ast::Primitive::Struct(_) => unreachable!("Structs aren't values, their members are!"),
+    };
+}
+
+// Whether a table beats a compare tree: enough cases to make up for
+// the range check, and at least a third of the entries taken.
+fn dense(n: usize, lo: i64, hi: i64) -> bool {
+    return n >= 4 && (hi as i128 - lo as i128 + 1) <= 3 * n as i128;
+}
+
+fn convert(e: Box<ir::Expr>, t: Type) -> Box<ir::Expr> {
+    if e.ty() == t { return e }
+    // Bytes are already zero extended.
+    if e.ty() == Type::I8 && t != Type::F64 { return e }
+    return Box::new(ir::Expr::Convert(t, e));
+}
+
+// Mixed operands meet at the wider type, ints get scaled when added to pointers.
+fn arith(l: Box<ir::Expr>, op: Operator, r: Box<ir::Expr>, stride: i64) -> Box<ir::Expr> {
+    let scale = |e: Box<ir::Expr>| if stride == 1 { e } else { Box::new(ir::Expr::BinOp(
+        Box::new(ir::Expr::Const(ir::Primitive::Int(stride))),
+        Operator::Mul,
+        e
+    ))};
+    let (l, r) = match (l.ty(), op, r.ty()) {
+        (Type::F64, _, Type::I64 | Type::I8) => (l, convert(r, Type::F64)),
+        (Type::I64 | Type::I8, _, Type::F64) => (convert(l, Type::F64), r),
+        (Type::Ptr, Operator::Add | Operator::Sub, Type::I64 | Type::I8) => (l, scale(r)),
+        (Type::I64 | Type::I8, Operator::Add, Type::Ptr) => (scale(l), r),
+        _ => (l, r)
+    };
+    return Box::new(ir::Expr::BinOp(l, op, r));
+}
@@ -1,5 +1,9 @@
+pub mod analysis;
 pub mod asm;
 pub mod ast;
 pub mod ir;
+pub mod pipeline;
 pub mod registry;
-pub mod utils;
\ No newline at end of file
+pub mod riscv;
+pub mod utils;
+pub mod x86;
\ No newline at end of file
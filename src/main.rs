@@ -1 +1,34 @@
-fn main() {}
+use compiler::asm::printer::Printer;
+use compiler::pipeline::options::{Arch, Options};
+use compiler::pipeline::passes::{compile, compile_riscv, compile_x86};
+use compiler::{riscv, x86};
+use std::fs;
+use std::process::exit;
+
+// cflat [-O0|-O1|-O2] [--disable-pass=..] [--print-after=..]
+//       [--regalloc=linear|coloring] [--verify-regalloc]
+//       [--schedule=before|after] [--target=aarch64|x86-64|riscv64]
+//       input.c
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let opts = match Options::parse(&args) {
+        Ok(o)  => o,
+        Err(e) => { eprintln!("{}", e); exit(2) }
+    };
+    let Some(path) = &opts.input else {
+        eprintln!("No input file!");
+        exit(2)
+    };
+    let input = match fs::read_to_string(path) {
+        Ok(s)  => s,
+        Err(e) => { eprintln!("{}: {}", path, e); exit(1) }
+    };
+    let res = match opts.arch {
+        Arch::AArch64 => compile(&input, &opts).map(|asm| Printer::print(&asm)),
+        Arch::X86_64  => compile_x86(&input, &opts)
+            .map(|asm| x86::printer::Printer::print(&asm)),
+        Arch::RiscV64 => compile_riscv(&input, &opts)
+            .map(|asm| riscv::printer::Printer::print(&asm)),
+    };
+    if let Err(e) = res { eprintln!("{}", e); exit(1) }
+}
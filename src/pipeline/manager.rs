@@ -0,0 +1,117 @@
+use super::options::{Level, Options};
+use crate::analysis::dominators::Dominators;
+use crate::analysis::loops::LoopForest;
+use crate::asm::liveness;
+use crate::registry::Registry;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Analysis {
+    Dominators,
+    Loops,
+    Liveness,
+}
+
+// Analyses are computed lazily, and thrown away by any
+// pass which doesn't promise to preserve them.
+pub struct Context<'l> {
+    pub reg:   &'l mut Registry,
+    pub opts:  &'l Options,
+    pub dom:   Option<Dominators>,
+    pub loops: Option<LoopForest>,
+    pub live:  Option<liveness::Liveness>,
+}
+impl<'l> Context<'l> {
+    pub fn new(reg: &'l mut Registry, opts: &'l Options) -> Self {
+        Self { reg, opts, dom: None, loops: None, live: None }
+    }
+    pub fn valid(&self, a: Analysis) -> bool {
+        use Analysis::*;
+        return match a {
+            Dominators => self.dom.is_some(),
+            Loops      => self.loops.is_some(),
+            Liveness   => self.live.is_some(),
+        };
+    }
+    pub fn invalidate(&mut self, a: Analysis) {
+        use Analysis::*;
+        match a {
+            Dominators => self.dom = None,
+            Loops      => self.loops = None,
+            Liveness   => self.live = None,
+        }
+    }
+}
+
+// Whatever the passes of one manager work on.
+pub trait Unit {
+    fn analyze(&self, a: Analysis, ctx: &mut Context);
+    fn print(&self, ctx: &Context);
+}
+
+pub struct Pass<T: Unit> {
+    pub name:      &'static str,
+    // Passes that have to run before this one, when they run at all.
+    pub after:     &'static [&'static str],
+    pub requires:  &'static [Analysis],
+    pub preserves: &'static [Analysis],
+    // Lowest level the pass runs at.
+    pub level:     Level,
+    // Required passes ignore --disable-pass.
+    pub required:  bool,
+    pub run:       fn(&mut Context, &mut T),
+}
+
+pub struct PassManager<T: Unit> {
+    passes: Vec<Pass<T>>,
+}
+impl<T: Unit> PassManager<T> {
+    pub fn new() -> Self {
+        Self { passes: Vec::new() }
+    }
+    pub fn add(&mut self, pass: Pass<T>) {
+        self.passes.push(pass);
+    }
+    pub fn names(&self) -> Vec<&'static str> {
+        return self.passes.iter().map(|p| p.name).collect();
+    }
+    pub fn enabled(&self, opts: &Options, p: &Pass<T>) -> bool {
+        if p.required { return true }
+        return p.level <= opts.level && !opts.disabled(p.name);
+    }
+    pub fn run(&self, ctx: &mut Context, unit: &mut T) {
+        for idx in self.schedule() {
+            let p = &self.passes[idx];
+            if !self.enabled(ctx.opts, p) { continue }
+            for a in p.requires {
+                if !ctx.valid(*a) { unit.analyze(*a, ctx); }
+            }
+            (p.run)(ctx, unit);
+            for a in [Analysis::Dominators, Analysis::Loops, Analysis::Liveness] {
+                if !p.preserves.contains(&a) { ctx.invalidate(a); }
+            }
+            if ctx.opts.print_after(p.name) {
+                println!("; after {}", p.name);
+                unit.print(ctx);
+            }
+        }
+    }
+    // Registration order, except that passes wait for their dependencies.
+    fn schedule(&self) -> Vec<usize> {
+        let mut done = vec![false; self.passes.len()];
+        let mut order = Vec::new();
+        while order.len() < self.passes.len() {
+            let next = (0..self.passes.len()).find(|i| {
+                !done[*i] && self.passes[*i].after.iter().all(|d| {
+                    self.passes.iter().enumerate()
+                        .all(|(j, p)| p.name != *d || done[j])
+                })
+            });
+            let Some(i) = next else {
+                panic!("Pass dependencies form a cycle!");
+            };
+            done[i] = true;
+            order.push(i);
+        }
+        return order;
+    }
+}
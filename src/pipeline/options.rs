@@ -0,0 +1,148 @@
+use super::passes::{asm_passes, pass_names, STAGES};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    O0,
+    O1,
+    O2,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Regalloc {
+    Linear,   // Linear scan, fast.
+    Coloring, // Iterated register coalescing, better code.
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Schedule {
+    Before, // On temps, watching register pressure.
+    After,  // On registers, stuck with the allocator's reuse.
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Arch {
+    AArch64,
+    X86_64,
+    RiscV64,
+}
+
+pub struct Options {
+    pub level:       Level,
+    pub disabled:    Vec<String>,
+    pub print_after: Vec<String>,
+    pub input:       Option<String>,
+    pub regalloc:    Option<Regalloc>,
+    pub verify:      bool,
+    pub schedule:    Option<Schedule>,
+    pub arch:        Arch,
+}
+impl Options {
+    pub fn new(level: Level) -> Self {
+        Self {
+            level,
+            disabled:    Vec::new(),
+            print_after: Vec::new(),
+            input:       None,
+            regalloc:    None,
+            verify:      false,
+            schedule:    None,
+            arch:        Arch::AArch64,
+        }
+    }
+    // Accepts -O0 / -O1 / -O2, --disable-pass=a,b, --print-after=a,b (or all)
+    // --regalloc=linear|coloring, --verify-regalloc, --schedule=before|after
+    // and --target=aarch64|x86-64|riscv64.
+    // Anything else is taken to be the input file.
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let mut opts = Self::new(Level::O0);
+        for arg in args {
+            let list = |s: &str| s.split(',')
+                .filter(|x| !x.is_empty())
+                .map(|x| x.to_string())
+                .collect::<Vec<String>>();
+            match arg.as_str() {
+                "-O0" => opts.level = Level::O0,
+                "-O1" => opts.level = Level::O1,
+                "-O2" => opts.level = Level::O2,
+                a if a.starts_with("--disable-pass=") => opts.disabled.extend(
+                    list(&a["--disable-pass=".len()..])
+                ),
+                a if a.starts_with("--print-after=") => opts.print_after.extend(
+                    list(&a["--print-after=".len()..])
+                ),
+                "--regalloc=linear"   => opts.regalloc = Some(Regalloc::Linear),
+                "--regalloc=coloring" => opts.regalloc = Some(Regalloc::Coloring),
+                "--verify-regalloc"   => opts.verify = true,
+                "--schedule=before"   => opts.schedule = Some(Schedule::Before),
+                "--schedule=after"    => opts.schedule = Some(Schedule::After),
+                "--target=aarch64"    => opts.arch = Arch::AArch64,
+                "--target=x86-64"     => opts.arch = Arch::X86_64,
+                "--target=riscv64"    => opts.arch = Arch::RiscV64,
+                a if a.starts_with('-') => return Err(format!(
+                    "Unknown option {}", a
+                )),
+                a => match opts.input {
+                    None    => opts.input = Some(a.to_string()),
+                    Some(_) => return Err(format!(
+                        "Only one input file is supported, got {}", a
+                    ))
+                }
+            }
+        }
+        let passes = pass_names();
+        if let Some(p) = opts.disabled.iter().find(|p| !passes.contains(&p.as_str())) {
+            return Err(format!("Unknown pass {}", p));
+        }
+        let known = |p: &str| passes.contains(&p) || STAGES.contains(&p) || p == "all";
+        if let Some(p) = opts.print_after.iter().find(|p| !known(p)) {
+            return Err(format!("Unknown pass {}", p));
+        }
+        // x86-64 and RISC-V only select and allocate, these would do nothing.
+        let target = match opts.arch {
+            Arch::AArch64 => None,
+            Arch::X86_64  => Some("x86-64"),
+            Arch::RiscV64 => Some("riscv64"),
+        };
+        if let (Some(t), Some(a)) = (target, opts.asm_only()) {
+            return Err(format!("{} isn't supported for {}", a, t));
+        }
+        return Ok(opts);
+    }
+    // The first flag that needs the AArch64 asm passes.
+    fn asm_only(&self) -> Option<String> {
+        if self.verify { return Some(format!("--verify-regalloc")) }
+        if self.schedule.is_some() { return Some(format!("--schedule")) }
+        let asm = asm_passes().names();
+        if let Some(p) = self.disabled.iter().find(|p| asm.contains(&p.as_str())) {
+            return Some(format!("--disable-pass={}", p));
+        }
+        // Allocation still happens, so it can be printed.
+        let only = |p: &&String| asm.contains(&p.as_str()) && *p != "allocate";
+        if let Some(p) = self.print_after.iter().find(only) {
+            return Some(format!("--print-after={}", p));
+        }
+        return None;
+    }
+    pub fn disabled(&self, pass: &str) -> bool {
+        return self.disabled.iter().any(|p| p == pass);
+    }
+    // -O0 builds go for compile time unless told otherwise.
+    pub fn regalloc(&self) -> Regalloc {
+        return match (self.regalloc, self.level) {
+            (Some(r), _)      => r,
+            (None, Level::O0) => Regalloc::Linear,
+            (None, _)         => Regalloc::Coloring,
+        };
+    }
+    // Only -O2 schedules unless asked to.
+    pub fn schedule(&self) -> Option<Schedule> {
+        return match (self.schedule, self.level) {
+            (Some(s), _)      => Some(s),
+            (None, Level::O2) => Some(Schedule::Before),
+            (None, _)         => None,
+        };
+    }
+    pub fn print_after(&self, pass: &str) -> bool {
+        return self.print_after.iter().any(|p| p == pass || p == "all");
+    }
+}
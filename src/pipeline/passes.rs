@@ -0,0 +1,270 @@
+use super::manager::{Analysis, Context, Pass, PassManager, Unit};
+use super::options::{Level, Options, Regalloc, Schedule};
+use crate::analysis::dominators::Dominators;
+use crate::analysis::loops::LoopForest;
+use crate::asm::asm::AA;
+use crate::asm::{self, cfg::CFG as AsmCfg, liveness::Liveness};
+use crate::ast;
+use crate::ir::{self, cfg::CFG, cfgframer::Frames};
+use crate::registry::Registry;
+use crate::riscv::{self, asm::RV};
+use crate::x86::{self, asm::X86};
+use lalrpop_util::ParseError;
+
+// The IR passes work on the LIR CFG, between cfgbuilder and cfgexporter.
+pub struct IrUnit {
+    pub cfg:    CFG,
+    pub order:  Vec<usize>,
+    pub frames: Option<Frames>,
+}
+impl Unit for IrUnit {
+    fn analyze(&self, a: Analysis, ctx: &mut Context) {
+        match a {
+            Analysis::Dominators => ctx.dom = Some(Dominators::compute(&self.cfg)),
+            Analysis::Loops      => {
+                if ctx.dom.is_none() { self.analyze(Analysis::Dominators, ctx); }
+                let dom = ctx.dom.as_ref().unwrap();
+                ctx.loops = Some(LoopForest::compute(&self.cfg, dom));
+            },
+            Analysis::Liveness   => unreachable!("Liveness is only for asm!"),
+        }
+    }
+    fn print(&self, _ctx: &Context) {
+        ir::cfgprinter::Printer::new().print(&self.cfg);
+    }
+}
+
+impl Unit for Vec<AA> {
+    fn analyze(&self, a: Analysis, ctx: &mut Context) {
+        match a {
+            Analysis::Liveness => {
+                let cfg = AsmCfg::build(ctx.reg, self);
+                ctx.live = Some(Liveness::analyze(&cfg));
+            },
+            _ => unreachable!("Only Liveness is computed for asm!"),
+        }
+    }
+    fn print(&self, _ctx: &Context) {
+        asm::printer::Printer::print_raw(self);
+    }
+}
+
+pub fn ir_passes() -> PassManager<IrUnit> {
+    use Analysis::*;
+    let mut pm = PassManager::<IrUnit>::new();
+    pm.add(Pass {
+        name:      "simplify",
+        after:     &[],
+        requires:  &[],
+        preserves: &[],
+        level:     Level::O1,
+        required:  false,
+        run:       |ctx, u| {
+            let cfg = std::mem::replace(&mut u.cfg, CFG {
+                nodes: Vec::new(), starts: Vec::new()
+            });
+            u.cfg = ir::cfgsimplify::simplify(ctx.reg, cfg);
+        },
+    });
+    pm.add(Pass {
+        name:      "frame",
+        after:     &["simplify"],
+        requires:  &[],
+        preserves: &[Dominators, Loops],
+        level:     Level::O0,
+        required:  true,
+        run:       |ctx, u| {
+            u.frames = Some(ir::cfgframer::Framer::new(ctx.reg, &u.cfg).frame());
+        },
+    });
+    // Layout always happens, but it doesn't try to be clever,
+    // traces replaces it from -O1 on.
+    pm.add(Pass {
+        name:      "layout",
+        after:     &["simplify"],
+        requires:  &[],
+        preserves: &[Dominators, Loops],
+        level:     Level::O0,
+        required:  true,
+        run:       |_, u| u.order = ir::reorder::naive(&u.cfg),
+    });
+    pm.add(Pass {
+        name:      "traces",
+        after:     &["layout"],
+        requires:  &[],
+        preserves: &[Dominators, Loops],
+        level:     Level::O1,
+        required:  false,
+        run:       |_, u| u.order = ir::reorder::reorder(&u.cfg),
+    });
+    return pm;
+}
+
+pub fn asm_passes() -> PassManager<Vec<AA>> {
+    use Analysis::*;
+    let mut pm = PassManager::<Vec<AA>>::new();
+    // Scheduling happens on one side of allocation, see Options::schedule.
+    pm.add(Pass {
+        name:      "schedule",
+        after:     &[],
+        requires:  &[],
+        preserves: &[],
+        level:     Level::O0,
+        required:  false,
+        run:       |ctx, asm| {
+            if ctx.opts.schedule() != Some(Schedule::Before) { return }
+            let cur = std::mem::take(asm);
+            *asm = asm::schedule::schedule(ctx.reg, cur, true);
+        },
+    });
+    pm.add(Pass {
+        name:      "allocate",
+        after:     &[],
+        requires:  &[Liveness],
+        preserves: &[],
+        level:     Level::O0,
+        required:  true,
+        run:       |ctx, asm| {
+            let live = ctx.live.take().unwrap();
+            let before = std::mem::take(asm);
+            *asm = match ctx.opts.regalloc() {
+                Regalloc::Linear   => asm::linearscan::allocate(ctx.reg, &live),
+                Regalloc::Coloring => asm::allocate::allocate(ctx.reg, &live),
+            };
+            if !ctx.opts.verify { return }
+            if let Err(errs) = asm::verify::verify(ctx.reg, &before, asm) {
+                for e in &errs { eprintln!("{}", e); }
+                panic!("Register allocation is wrong, {} problems!", errs.len());
+            }
+        },
+    });
+    pm.add(Pass {
+        name:      "peephole",
+        after:     &["allocate"],
+        requires:  &[],
+        preserves: &[],
+        level:     Level::O1,
+        required:  false,
+        run:       |ctx, asm| {
+            let cur = std::mem::take(asm);
+            *asm = asm::peephole::optimize(ctx.reg, cur);
+        },
+    });
+    pm.add(Pass {
+        name:      "schedule-late",
+        after:     &["allocate", "peephole"],
+        requires:  &[],
+        preserves: &[],
+        level:     Level::O0,
+        required:  false,
+        run:       |ctx, asm| {
+            if ctx.opts.schedule() != Some(Schedule::After) { return }
+            let cur = std::mem::take(asm);
+            *asm = asm::schedule::schedule(ctx.reg, cur, false);
+        },
+    });
+    return pm;
+}
+
+// What --disable-pass can name.
+pub fn pass_names() -> Vec<&'static str> {
+    let mut res = ir_passes().names();
+    res.extend(asm_passes().names());
+    return res;
+}
+
+// Stages that aren't passes but can still be printed after.
+pub const STAGES: [&str; 6] = ["analyze", "translate", "reduce", "build", "export", "select"];
+
+// Runs everything from source to allocated assembly.
+pub fn compile(input: &str, opts: &Options) -> Result<Vec<AA>, String> {
+    let mut r = Registry::new();
+    let (fir, frames) = lower(&mut r, input, opts)?;
+    let mut asm = asm::translator::Translator::translate(&mut r, frames, fir);
+    if opts.print_after("select") { asm::printer::Printer::print_raw(&asm); }
+
+    asm_passes().run(&mut Context::new(&mut r, opts), &mut asm);
+    return Ok(asm);
+}
+
+// The x86-64 backend only selects and allocates, the asm passes
+// (scheduling, peephole, --verify-regalloc) are AArch64 only for now.
+// Options::parse turns down the flags that would need them.
+pub fn compile_x86(input: &str, opts: &Options) -> Result<Vec<X86>, String> {
+    let mut r = Registry::new();
+    let (fir, frames) = lower(&mut r, input, opts)?;
+    if fir.iter().any(|s| matches!(**s, ir::ir::Statement::Asm(_))) {
+        return Err(format!("Inline asm is AArch64, it can't be compiled for x86-64!"));
+    }
+    let asm = x86::translator::Translator::translate(&mut r, frames, fir);
+    if opts.print_after("select") { x86::printer::Printer::print_raw(&asm); }
+
+    let live = Liveness::analyze(&AsmCfg::build(&r, &asm));
+    let asm = match opts.regalloc() {
+        Regalloc::Linear   => asm::linearscan::allocate(&mut r, &live),
+        Regalloc::Coloring => asm::allocate::allocate(&mut r, &live),
+    };
+    if opts.print_after("allocate") { x86::printer::Printer::print_raw(&asm); }
+    return Ok(asm);
+}
+
+// Same as x86-64, selection and allocation only, and the same flags are refused.
+pub fn compile_riscv(input: &str, opts: &Options) -> Result<Vec<RV>, String> {
+    let mut r = Registry::new();
+    let (fir, frames) = lower(&mut r, input, opts)?;
+    riscv::translator::check(&fir)?;
+    let asm = riscv::translator::Translator::translate(&mut r, frames, fir);
+    if opts.print_after("select") { riscv::printer::Printer::print_raw(&asm); }
+
+    let live = Liveness::analyze(&AsmCfg::build(&r, &asm));
+    let asm = match opts.regalloc() {
+        Regalloc::Linear   => asm::linearscan::allocate(&mut r, &live),
+        Regalloc::Coloring => asm::allocate::allocate(&mut r, &live),
+    };
+    if opts.print_after("allocate") { riscv::printer::Printer::print_raw(&asm); }
+    return Ok(asm);
+}
+
+// Everything up to instruction selection, which is where the targets part ways.
+fn lower(r: &mut Registry, input: &str, opts: &Options)
+    -> Result<(Vec<Box<ir::ir::Statement>>, Frames), String> {
+    let mut ast = ast::parser::moduleParser::new()
+        .parse(r, input)
+        .map_err(|e| parse_error(input, e))?;
+    ast::analyzer::Analyzer::new(r).analyze(&mut ast);
+    if opts.print_after("analyze") { ast::printer::Printer::new(r).print(&ast); }
+
+    let hir = ir::translator::Translator::new(r).translate(&mut ast);
+    if opts.print_after("translate") { ir::printer::Printer::new().print(&hir); }
+
+    let lir = ir::reducer::Reducer::new(r).reduce(hir);
+    if opts.print_after("reduce") { ir::printer::Printer::new().print(&lir); }
+
+    let cfg = ir::cfgbuilder::build(r, lir);
+    if opts.print_after("build") { ir::cfgprinter::Printer::new().print(&cfg); }
+
+    let mut unit = IrUnit { cfg, order: Vec::new(), frames: None };
+    ir_passes().run(&mut Context::new(r, opts), &mut unit);
+
+    let fir = ir::cfgexporter::export(unit.cfg, unit.order);
+    if opts.print_after("export") { ir::printer::Printer::new().print(&fir); }
+
+    let frames = unit.frames.expect("The frame pass always runs!");
+    return Ok((fir, frames));
+}
+
+fn parse_error<T: std::fmt::Display, E: std::fmt::Display>(
+    input: &str, e: ParseError<usize, T, E>) -> String {
+    let location = match &e {
+        ParseError::InvalidToken { location } => *location,
+        ParseError::UnrecognizedEof { location, .. } => *location,
+        ParseError::UnrecognizedToken { token, .. } => token.0,
+        ParseError::ExtraToken { token } => token.0,
+        ParseError::User { .. } => return format!("{}", e),
+    };
+    let line = input[..location].matches('\n').count();
+    let start = input[..location].rfind('\n').map_or(0, |i| i + 1);
+    let text = input.lines().nth(line).unwrap_or("");
+    return format!("{}\n{}: {}\n{}^", e, line + 1, text,
+        "-".repeat(location - start + format!("{}: ", line + 1).len()));
+}
@@ -3,6 +3,9 @@ pub struct Registry {
     pub nfuncs:  u32,
     pub nlabels: u32,
     pub ret:     u32,
+    pub ntables: u32,
+    // Struct names, interned so Kinds stay Copy. A tag is an index in here.
+    pub tags:    Vec<String>,
 }
 impl Registry {
     pub fn new() -> Self {
@@ -11,6 +14,18 @@ impl Registry {
             nfuncs:  0,
             nlabels: 0,
             ret:     0,
+            ntables: 0,
+            tags:    Vec::new(),
         }
     }
+    pub fn tag(&mut self, name: &str) -> u32 {
+        if let Some(i) = self.tags.iter().position(|t| t == name) {
+            return i as u32;
+        }
+        self.tags.push(name.to_string());
+        return self.tags.len() as u32 - 1;
+    }
+    pub fn tag_name(&self, tag: u32) -> &str {
+        return &self.tags[tag as usize];
+    }
 }
\ No newline at end of file
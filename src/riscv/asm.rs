@@ -0,0 +1,294 @@
+use core::fmt;
+use std::fmt::Display;
+use crate::asm::asm::{Reg, CC, Label};
+use crate::asm::target::{MachineInstr, Target};
+use crate::asm::strings;
+
+// R(i) is xi, except that x0 is always Reg::RZR and x2 always Reg::SP.
+pub const RA: Reg = Reg::R(1);
+pub const FP: Reg = Reg::R(8);
+pub const A0: Reg = Reg::R(10);
+pub const A7: Reg = Reg::R(17);
+
+// Immediates are whatever fits the instruction, 12 bits signed for the
+// I-type ones and 20 for lui. Constants that don't fit are built up with
+// lui/addi, see tiles::li.
+#[derive(Clone)]
+pub enum RV {
+    Label(Label),
+    Lui(Reg, i64),              // d = imm << 12, sign extended from 32 bits
+    Addi(Reg, Reg, i64),
+    Addiw(Reg, Reg, i64),       // 32 bit add, sign extended
+    Slli(Reg, Reg, i64),
+    Srai(Reg, Reg, i64),
+    Mv(Reg, Reg),
+    Add(Reg, Reg, Reg),
+    Sub(Reg, Reg, Reg),
+    Mul(Reg, Reg, Reg),
+    Div(Reg, Reg, Reg),
+    Rem(Reg, Reg, Reg),
+    And(Reg, Reg, Reg),
+    Or(Reg, Reg, Reg),
+    Xor(Reg, Reg, Reg),
+    Sll(Reg, Reg, Reg),
+    Sra(Reg, Reg, Reg),
+    Andi(Reg, Reg, i64),
+    Ori(Reg, Reg, i64),
+    Xori(Reg, Reg, i64),
+    Slt(Reg, Reg, Reg),         // d = l < r
+    Sltu(Reg, Reg, Reg),        // d = l < r, unsigned
+    Sltiu(Reg, Reg, i64),       // d = l < imm, unsigned
+    Ld(Reg, Reg, i64),          // d = [b + off]
+    Sd(Reg, Reg, i64),          // [b + off] = s
+    Lbu(Reg, Reg, i64),         // d = zero extended byte at [b + off]
+    Sb(Reg, Reg, i64),          // byte [b + off] = low byte of s
+    La(Reg, Vec<u8>),           // d = address of an interned string
+    J(Label),
+    B(CC, Reg, Reg, Label),     // if l cc r goto label
+    Call(Label),
+    Ecall,
+    Ret,
+    Table(Reg, u32),            // d = address of jump table k
+    Switch(Reg, Reg),           // jr to entry s of the table at d, through ra
+    Case(u32, u32, Label),      // Pseudo-OP, entry i of table k, never runs
+
+    BB(Vec<Reg>),               // Pseudo-OP
+}
+impl RV {
+    #[allow(unused_variables)]
+    pub fn defuse(&self) -> (Vec<Reg>, Vec<Reg>) {
+        use RV::*;
+        return match self.clone() {
+            Label(l)         => (vec![],    vec![]),
+            Lui(d, k)        => (vec![d],   vec![]),
+            Addi(d, s, k)    => (vec![d],   vec![s]),
+            Addiw(d, s, k)   => (vec![d],   vec![s]),
+            Slli(d, s, k)    => (vec![d],   vec![s]),
+            Srai(d, s, k)    => (vec![d],   vec![s]),
+            Mv(d, s)         => (vec![d],   vec![s]),
+            Add(d, l, r)     => (vec![d],   vec![l, r]),
+            Sub(d, l, r)     => (vec![d],   vec![l, r]),
+            Mul(d, l, r)     => (vec![d],   vec![l, r]),
+            Div(d, l, r)     => (vec![d],   vec![l, r]),
+            Rem(d, l, r)     => (vec![d],   vec![l, r]),
+            And(d, l, r)     => (vec![d],   vec![l, r]),
+            Or(d, l, r)      => (vec![d],   vec![l, r]),
+            Xor(d, l, r)     => (vec![d],   vec![l, r]),
+            Sll(d, l, r)     => (vec![d],   vec![l, r]),
+            Sra(d, l, r)     => (vec![d],   vec![l, r]),
+            Andi(d, s, k)    => (vec![d],   vec![s]),
+            Ori(d, s, k)     => (vec![d],   vec![s]),
+            Xori(d, s, k)    => (vec![d],   vec![s]),
+            Slt(d, l, r)     => (vec![d],   vec![l, r]),
+            Sltu(d, l, r)    => (vec![d],   vec![l, r]),
+            Sltiu(d, s, k)   => (vec![d],   vec![s]),
+            Ld(d, b, o)      => (vec![d],   vec![b]),
+            Sd(s, b, o)      => (vec![],    vec![s, b]),
+            Lbu(d, b, o)     => (vec![d],   vec![b]),
+            Sb(s, b, o)      => (vec![],    vec![s, b]),
+            La(d, s)         => (vec![d],   vec![]),
+            J(l)             => (vec![],    vec![]),
+            B(c, l, r, t)    => (vec![],    vec![l, r]),
+            Call(l)          => (RiscV64::caller_saved(), vec![]),
+            Ecall            => (vec![A0],  vec![A0, A7]),
+            Ret              => (vec![],    vec![A0, RA]),
+            Table(d, k)      => (vec![d],   vec![]),
+            Switch(d, s)     => (vec![RA],  vec![d, s]),
+            Case(k, i, l)    => (vec![],    vec![]),
+            BB(v)            => (v.clone(), vec![]),
+        };
+    }
+}
+impl MachineInstr for RV {
+    type Target = RiscV64;
+    fn defuse(&self) -> (Vec<Reg>, Vec<Reg>) { RV::defuse(self) }
+    fn label(&self) -> Option<Label> {
+        return match self { RV::Label(l) => Some(*l), _ => None };
+    }
+    fn target(&self) -> Option<Label> {
+        use RV::*;
+        return match self {
+            J(l) | B(_, _, _, l) | Call(l) | Case(_, _, l) => Some(*l),
+            _ => None
+        };
+    }
+    fn falls(&self) -> bool { !matches!(self, RV::J(_) | RV::Ret) }
+    fn moves(&self) -> Option<(Reg, Reg)> {
+        return match self { RV::Mv(d, s) => Some((*d, *s)), _ => None };
+    }
+    fn map(&self, c: &dyn Fn(Reg) -> Reg) -> Self {
+        use RV::*;
+        return match self.clone() {
+            Label(l)         => Label(l),
+            Lui(d, k)        => Lui(c(d), k),
+            Addi(d, s, k)    => Addi(c(d), c(s), k),
+            Addiw(d, s, k)   => Addiw(c(d), c(s), k),
+            Slli(d, s, k)    => Slli(c(d), c(s), k),
+            Srai(d, s, k)    => Srai(c(d), c(s), k),
+            Mv(d, s)         => Mv(c(d), c(s)),
+            Add(d, l, r)     => Add(c(d), c(l), c(r)),
+            Sub(d, l, r)     => Sub(c(d), c(l), c(r)),
+            Mul(d, l, r)     => Mul(c(d), c(l), c(r)),
+            Div(d, l, r)     => Div(c(d), c(l), c(r)),
+            Rem(d, l, r)     => Rem(c(d), c(l), c(r)),
+            And(d, l, r)     => And(c(d), c(l), c(r)),
+            Or(d, l, r)      => Or(c(d), c(l), c(r)),
+            Xor(d, l, r)     => Xor(c(d), c(l), c(r)),
+            Sll(d, l, r)     => Sll(c(d), c(l), c(r)),
+            Sra(d, l, r)     => Sra(c(d), c(l), c(r)),
+            Andi(d, s, k)    => Andi(c(d), c(s), k),
+            Ori(d, s, k)     => Ori(c(d), c(s), k),
+            Xori(d, s, k)    => Xori(c(d), c(s), k),
+            Slt(d, l, r)     => Slt(c(d), c(l), c(r)),
+            Sltu(d, l, r)    => Sltu(c(d), c(l), c(r)),
+            Sltiu(d, s, k)   => Sltiu(c(d), c(s), k),
+            Ld(d, b, o)      => Ld(c(d), c(b), o),
+            Sd(s, b, o)      => Sd(c(s), c(b), o),
+            Lbu(d, b, o)     => Lbu(c(d), c(b), o),
+            Sb(s, b, o)      => Sb(c(s), c(b), o),
+            La(d, s)         => La(c(d), s),
+            J(l)             => J(l),
+            B(cc, l, r, t)   => B(cc, c(l), c(r), t),
+            Call(l)          => Call(l),
+            Ecall            => Ecall,
+            Ret              => Ret,
+            Table(d, k)      => Table(c(d), k),
+            Switch(d, s)     => Switch(c(d), c(s)),
+            Case(k, i, l)    => Case(k, i, l),
+            BB(v)            => BB(v.iter().map(|r| c(*r)).collect()),
+        };
+    }
+    fn marker(live: Vec<Reg>) -> Self { RV::BB(live) }
+    fn is_marker(&self) -> bool { matches!(self, RV::BB(_)) }
+}
+
+// The standard calling convention: s0 is the frame pointer, ra, gp and tp
+// are left alone. Temporaries and arguments go first, saved registers last.
+pub struct RiscV64;
+impl Target for RiscV64 {
+    type Instr = RV;
+    const REGS: usize = 35;
+    fn colors() -> &'static [usize] {
+        return &[
+            5, 6, 7, 10, 11, 12, 13, 14, 15, 16, 17, 28, 29, 30, 31,
+            9, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27
+        ];
+    }
+    fn reserved() -> Vec<Reg> {
+        use Reg::*;
+        return vec![SP, RZR, PC, R(0), RA, R(2), R(3), R(4), FP];
+    }
+    // ra, t0-t6 and a0-a7, s1-s11 are the callee's to save.
+    fn caller_saved() -> Vec<Reg> {
+        return [1, 5, 6, 7].into_iter().chain(10..=17).chain(28..=31).map(Reg::R).collect();
+    }
+    fn callee_saved() -> Vec<Reg> {
+        return [9].into_iter().chain(18..=27).map(Reg::R).collect();
+    }
+    // The translator lays frames out as `mv s0, sp`, then
+    // `addi sp, sp, -size` when there are locals. Functions other
+    // than main save ra and s0 right before.
+    fn frame(asm: &[RV], i: usize) -> Option<(i64, bool)> {
+        use Reg::*;
+        let RV::Mv(FP, SP) = asm[i] else { return None };
+        let size = match asm.get(i + 1) {
+            Some(RV::Addi(SP, SP, n)) => -*n,
+            _ => 0
+        };
+        let main = !matches!(i.checked_sub(1).map(|j| &asm[j]), Some(RV::Sd(_, SP, _)));
+        return Some((size, main));
+    }
+    fn grow(size: i64) -> RV { RV::Addi(Reg::SP, Reg::SP, -size) }
+    fn load(d: Reg, off: i64) -> RV { RV::Ld(d, FP, off) }
+    fn store(s: Reg, off: i64) -> RV { RV::Sd(s, FP, off) }
+    fn epilogue(a: &RV) -> bool { matches!(a, RV::Mv(Reg::SP, FP)) }
+}
+
+pub(super) fn x(r: &Reg) -> String {
+    const NAMES: [&str; 32] = [
+        "zero", "ra", "sp", "gp", "tp", "t0", "t1", "t2",
+        "s0", "s1", "a0", "a1", "a2", "a3", "a4", "a5",
+        "a6", "a7", "s2", "s3", "s4", "s5", "s6", "s7",
+        "s8", "s9", "s10", "s11", "t3", "t4", "t5", "t6"
+    ];
+    return match r {
+        Reg::R(i) if (*i as usize) < NAMES.len() => NAMES[*i as usize].to_string(),
+        Reg::SP  => format!("sp"),
+        Reg::RZR => format!("zero"),
+        r => format!("{}", r),
+    };
+}
+// The GNU assembler takes the swapped forms too.
+pub fn branch(c: CC) -> &'static str {
+    use CC::*;
+    return match c {
+        EQ => "beq",
+        NE => "bne",
+        GE => "bge",
+        LT => "blt",
+        GT => "bgt",
+        LE => "ble",
+        HI => "bgtu",
+        LS => "bleu",
+        HS => "bgeu",
+        LO => "bltu",
+        MI | PL => unreachable!("Only floats compare with {}!", c),
+    };
+}
+fn label(l: &Label) -> String {
+    if *l == 0 { format!("_start") } else { format!("l{}", l) }
+}
+
+impl Display for RV {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use RV::*;
+        let res = match self {
+            Label(l)         => format!("{}: ", label(l)),
+            // lui takes the 20 bits unsigned.
+            Lui(d, k)        => format!("lui {}, {}", x(d), k & 0xfffff),
+            Addi(d, s, k)    => format!("addi {}, {}, {}", x(d), x(s), k),
+            Addiw(d, s, k)   => format!("addiw {}, {}, {}", x(d), x(s), k),
+            Slli(d, s, k)    => format!("slli {}, {}, {}", x(d), x(s), k),
+            Srai(d, s, k)    => format!("srai {}, {}, {}", x(d), x(s), k),
+            Mv(d, s)         => format!("mv {}, {}", x(d), x(s)),
+            Add(d, l, r)     => format!("add {}, {}, {}", x(d), x(l), x(r)),
+            Sub(d, l, r)     => format!("sub {}, {}, {}", x(d), x(l), x(r)),
+            Mul(d, l, r)     => format!("mul {}, {}, {}", x(d), x(l), x(r)),
+            Div(d, l, r)     => format!("div {}, {}, {}", x(d), x(l), x(r)),
+            Rem(d, l, r)     => format!("rem {}, {}, {}", x(d), x(l), x(r)),
+            And(d, l, r)     => format!("and {}, {}, {}", x(d), x(l), x(r)),
+            Or(d, l, r)      => format!("or {}, {}, {}", x(d), x(l), x(r)),
+            Xor(d, l, r)     => format!("xor {}, {}, {}", x(d), x(l), x(r)),
+            Sll(d, l, r)     => format!("sll {}, {}, {}", x(d), x(l), x(r)),
+            Sra(d, l, r)     => format!("sra {}, {}, {}", x(d), x(l), x(r)),
+            Andi(d, s, k)    => format!("andi {}, {}, {}", x(d), x(s), k),
+            Ori(d, s, k)     => format!("ori {}, {}, {}", x(d), x(s), k),
+            Xori(d, s, k)    => format!("xori {}, {}, {}", x(d), x(s), k),
+            Slt(d, l, r)     => format!("slt {}, {}, {}", x(d), x(l), x(r)),
+            Sltu(d, l, r)    => format!("sltu {}, {}, {}", x(d), x(l), x(r)),
+            Sltiu(d, s, k)   => format!("sltiu {}, {}, {}", x(d), x(s), k),
+            Ld(d, b, o)      => format!("ld {}, {}({})", x(d), o, x(b)),
+            Sd(s, b, o)      => format!("sd {}, {}({})", x(s), o, x(b)),
+            Lbu(d, b, o)     => format!("lbu {}, {}({})", x(d), o, x(b)),
+            Sb(s, b, o)      => format!("sb {}, {}({})", x(s), o, x(b)),
+            La(d, s)         => format!("lla {}, \"{}\"", x(d), strings::escape(s)),
+            J(l)             => format!("j {}", label(l)),
+            B(c, l, r, t)    => format!("{} {}, {}, {}", branch(*c), x(l), x(r), label(t)),
+            Call(l)          => format!("call {}", label(l)),
+            Ecall            => format!("ecall"),
+            Ret              => format!("ret"),
+            Table(d, k)      => format!("lla {}, table{}", x(d), k),
+            Switch(d, s)     => format!(
+                "slli ra, {}, 3\nadd ra, ra, {}\nld ra, 0(ra)\njr ra", x(s), x(d)),
+            Case(k, i, l)    => format!("# table{}[{}] = {}", k, i, label(l)),
+            BB(v)            => {
+                let res = v.iter()
+                    .map(|r| x(r))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("Basic Block {}", res)
+            }
+        };
+        write!(f, "{res}")
+    }
+}
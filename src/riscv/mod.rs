@@ -0,0 +1,4 @@
+pub mod asm;
+pub mod printer;
+pub mod tiles;
+pub mod translator;
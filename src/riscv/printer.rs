@@ -0,0 +1,47 @@
+use super::asm::*;
+use crate::asm::strings::Pool;
+use crate::asm::tables::Tables;
+pub struct Printer;
+impl Printer {
+    // Removes no-ops.
+    pub fn print(instructions: &[RV]) {
+        print!("{}", Self::text(instructions));
+    }
+
+    // What `print` writes, ready for `as`, string literals and jump tables
+    // last in .rodata.
+    pub fn text(instructions: &[RV]) -> String {
+        let mut res = String::from(".globl _start\n.align 2\n");
+        let mut pool = Pool::new();
+        let mut tables = Tables::new();
+        for ins in instructions {
+            match ins {
+                RV::BB(_) => (),
+                RV::Case(k, i, l) => tables.add(*k, *i, *l),
+                RV::La(d, s) => {
+                    let l = pool.label(s);
+                    res += &format!("lla {}, {}\n", x(d), l);
+                },
+                RV::Label(_) => res += &format!("\n{}\n", ins),
+                RV::Mv(d, s) if d == s => (),
+                _ => res += &format!("{}\n", ins)
+            }
+        }
+        res += &pool.section("\n.section .rodata");
+        res += &tables.section("\n.section .rodata");
+        res += "\n\n\n\n";
+        return res;
+    }
+
+    pub fn print_raw(instructions: &[RV]) {
+        println!(".globl _start");
+        println!(".align 2");
+        for ins in instructions {
+            match ins {
+                RV::BB(_) => println!("\n{}", ins),
+                _ => println!("{}", ins)
+            }
+        }
+        println!("\n\n\n");
+    }
+}
@@ -0,0 +1,294 @@
+use crate::ir::ir::{Expr, Operator, Primitive, Type};
+use crate::asm::asm::{Reg, CC, Const};
+use crate::asm::burs::{NT, Operand, Pat, Rule, nt, un, bin, mem};
+use super::asm::{RV, FP};
+use super::translator::Translator;
+pub use crate::asm::tiles::cc;
+
+// The tile table, costs are the number of instructions emitted.
+// Everything is integers, translator::check turns floats away.
+pub fn tiles() -> Vec<Rule<Translator, RV>> {
+    use Operator::*;
+    const ARITH: &[Operator] = &[Add, Sub, Mul, Div, Mod, And, Or, Xor, Shl, Shr];
+    const CMP: &[Operator] = &[Eq, Neq, Leq, Geq, Lt, Gt];
+    let reg = || nt(NT::Reg);
+    return vec![
+        /*----------------LEAVES--------------------*/
+        Rule { // CONST
+            lhs: NT::Const, pat: Pat::Const, cost: 0, when: any,
+            emit: |_, e, _| (vec![], Operand::Const(Const::Int(value(e))))
+        },
+        Rule { // ZERO
+            lhs: NT::Reg, pat: nt(NT::Const), cost: 0, when: |_, e| value(e) == 0,
+            emit: |_, _, _| (vec![], Operand::Reg(Reg::RZR))
+        },
+        Rule { // LUI/ADDI TEMP <== CONST
+            lhs: NT::Reg, pat: nt(NT::Const), cost: 1, when: any,
+            emit: |t, _, o| {
+                let d = t.temp();
+                (li(d, o[0].int()), Operand::Reg(d))
+            }
+        },
+        // Leaves room to negate it.
+        Rule { // ARITHMETIC IMMEDIATE
+            lhs: NT::Arith, pat: nt(NT::Const), cost: 0,
+            when: |_, e| imm12(value(e)) && imm12(-value(e)),
+            emit: |_, _, o| (vec![], o[0])
+        },
+        Rule { // LOGICAL IMMEDIATE
+            lhs: NT::Logical, pat: nt(NT::Const), cost: 0,
+            when: |_, e| imm12(value(e)),
+            emit: |_, _, o| (vec![], o[0])
+        },
+        Rule { // LOAD/STORE OFFSET
+            lhs: NT::Offset, pat: nt(NT::Const), cost: 0,
+            when: |_, e| imm12(value(e)),
+            emit: |_, _, o| (vec![], o[0])
+        },
+        Rule { // SHIFT IMMEDIATE
+            lhs: NT::Shift, pat: nt(NT::Const), cost: 0,
+            when: |_, e| (0..64).contains(&value(e)),
+            emit: |_, _, o| (vec![], o[0])
+        },
+        Rule { // TEMP
+            lhs: NT::Reg, pat: Pat::Temp, cost: 0, when: |t, e| !framed(t, e),
+            emit: |t, e, _| {
+                let Expr::Temp(i, _) = e else { unreachable!() };
+                (vec![], Operand::Reg(t.temp_reg(*i)))
+            }
+        },
+        Rule { // LOAD TEMP <== FRAME SLOT
+            lhs: NT::Reg, pat: Pat::Temp, cost: 1, when: framed,
+            emit: |t, e, _| {
+                let Expr::Temp(i, ty) = e else { unreachable!() };
+                let d = t.temp();
+                let off = t.slot(*i).unwrap();
+                (vec![load(d, Operand::Addr(FP, Const::Int(off)), *ty)], Operand::Reg(d))
+            }
+        },
+        /*----------------MEMORY--------------------*/
+        Rule { // 0(BASE)
+            lhs: NT::Addr, pat: reg(), cost: 0, when: any,
+            emit: |_, _, o| (vec![], Operand::Addr(o[0].reg(), Const::Int(0)))
+        },
+        Rule { // OFFSET(BASE)
+            lhs: NT::Addr, pat: bin(reg(), &[Add], nt(NT::Offset)), cost: 0, when: any,
+            emit: |_, _, o| (vec![], Operand::Addr(o[0].reg(), o[1].imm()))
+        },
+        Rule { // LOAD TEMP <== MEM
+            lhs: NT::Reg, pat: mem(nt(NT::Addr)), cost: 1, when: any,
+            emit: |t, e, o| {
+                let d = t.temp();
+                (vec![load(d, o[0], e.ty())], Operand::Reg(d))
+            }
+        },
+        Rule { // ADDRESS OF A STRING
+            lhs: NT::Reg, pat: Pat::Str, cost: 1, when: any,
+            emit: |t, e, _| {
+                let Expr::Str(s) = e else { unreachable!() };
+                let d = t.temp();
+                (vec![RV::La(d, s.clone())], Operand::Reg(d))
+            }
+        },
+        Rule { // ADDRESS OF A FRAME SLOT
+            lhs: NT::Reg, pat: Pat::Address(Box::new(Pat::Temp)), cost: 1, when: any,
+            emit: |t, e, _| {
+                let Expr::Address(a) = e else { unreachable!() };
+                let Expr::Temp(i, _) = **a else { unreachable!() };
+                let Some(off) = t.slot(i) else {
+                    panic!("Address of T({}) taken, but it has no slot!", i);
+                };
+                let d = t.temp();
+                (vec![RV::Addi(d, FP, off)], Operand::Reg(d))
+            }
+        },
+        Rule { // &*EXPR
+            lhs: NT::Reg, pat: Pat::Address(Box::new(mem(reg()))), cost: 0, when: any,
+            emit: |_, _, o| (vec![], o[0])
+        },
+        /*----------------INTEGERS--------------------*/
+        Rule { // LOAD TEMP <== Expr OP Expr
+            lhs: NT::Reg, pat: bin(reg(), ARITH, reg()), cost: 1, when: any,
+            emit: |t, e, o| {
+                let d = t.temp();
+                let (l, r) = (o[0].reg(), o[1].reg());
+                let a = match op(e) {
+                    Add => RV::Add(d, l, r),
+                    Sub => RV::Sub(d, l, r),
+                    Mul => RV::Mul(d, l, r),
+                    Div => RV::Div(d, l, r),
+                    Mod => RV::Rem(d, l, r),
+                    And => RV::And(d, l, r),
+                    Or  => RV::Or(d, l, r),
+                    Xor => RV::Xor(d, l, r),
+                    Shl => RV::Sll(d, l, r),
+                    Shr => RV::Sra(d, l, r),
+                    _   => unreachable!()
+                };
+                (vec![a], Operand::Reg(d))
+            }
+        },
+        Rule { // LOAD TEMP <== Expr +- IMM
+            lhs: NT::Reg, pat: bin(reg(), &[Add, Sub], nt(NT::Arith)), cost: 1, when: any,
+            emit: |t, e, o| {
+                let d = t.temp();
+                let (l, v) = (o[0].reg(), o[1].int());
+                (vec![RV::Addi(d, l, if op(e) == Add { v } else { -v })], Operand::Reg(d))
+            }
+        },
+        Rule { // LOAD TEMP <== IMM + Expr
+            lhs: NT::Reg, pat: bin(nt(NT::Arith), &[Add], reg()), cost: 1, when: any,
+            emit: |t, _, o| {
+                let d = t.temp();
+                (vec![RV::Addi(d, o[1].reg(), o[0].int())], Operand::Reg(d))
+            }
+        },
+        Rule { // LOAD TEMP <== Expr AND/OR/XOR IMM
+            lhs: NT::Reg, pat: bin(reg(), &[And, Or, Xor], nt(NT::Logical)), cost: 1, when: any,
+            emit: |t, e, o| {
+                let d = t.temp();
+                let (l, v) = (o[0].reg(), o[1].int());
+                let a = match op(e) {
+                    And => RV::Andi(d, l, v),
+                    Or  => RV::Ori(d, l, v),
+                    _   => RV::Xori(d, l, v),
+                };
+                (vec![a], Operand::Reg(d))
+            }
+        },
+        Rule { // LOAD TEMP <== Expr SLL/SRA IMM
+            lhs: NT::Reg, pat: bin(reg(), &[Shl, Shr], nt(NT::Shift)), cost: 1, when: any,
+            emit: |t, e, o| {
+                let d = t.temp();
+                let (l, v) = (o[0].reg(), o[1].int());
+                let a = match op(e) {
+                    Shl => RV::Slli(d, l, v),
+                    _   => RV::Srai(d, l, v),
+                };
+                (vec![a], Operand::Reg(d))
+            }
+        },
+        Rule { // LOAD TEMP <== Expr CMP Expr
+            lhs: NT::Reg, pat: bin(reg(), CMP, reg()), cost: 2, when: any,
+            emit: |t, e, o| {
+                let d = t.temp();
+                (set(d, cc(op(e)), o[0].reg(), o[1].reg()), Operand::Reg(d))
+            }
+        },
+        Rule { // LOAD TEMP <== !(Expr CMP Expr)
+            lhs: NT::Reg, pat: un(&[Not], bin(reg(), CMP, reg())), cost: 2, when: any,
+            emit: |t, e, o| {
+                let Expr::UnOp(_, c) = e else { unreachable!() };
+                let d = t.temp();
+                (set(d, cc(op(c)).invert(), o[0].reg(), o[1].reg()), Operand::Reg(d))
+            }
+        },
+        Rule { // LOAD TEMP <== Neg
+            lhs: NT::Reg, pat: un(&[Neg], reg()), cost: 1, when: any,
+            emit: |t, _, o| {
+                let d = t.temp();
+                (vec![RV::Sub(d, Reg::RZR, o[0].reg())], Operand::Reg(d))
+            }
+        },
+        Rule { // LOAD TEMP <== ~Expr
+            lhs: NT::Reg, pat: un(&[Not], reg()), cost: 1, when: any,
+            emit: |t, _, o| {
+                let d = t.temp();
+                (vec![RV::Xori(d, o[0].reg(), -1)], Operand::Reg(d))
+            }
+        },
+        Rule { // LOAD TEMP <== CONVERT
+            lhs: NT::Reg, pat: Pat::Convert(Box::new(reg())), cost: 1, when: any,
+            emit: |t, e, o| {
+                let Expr::Convert(to, _) = e else { unreachable!() };
+                let d = t.temp();
+                let a = match to {
+                    // Bytes stay zero extended in registers.
+                    Type::I8 => RV::Andi(d, o[0].reg(), 255),
+                    // Pointers and integers are the same thing down here.
+                    _ => RV::Mv(d, o[0].reg()),
+                };
+                (vec![a], Operand::Reg(d))
+            }
+        },
+    ];
+}
+
+fn any(_: &Translator, _: &Expr) -> bool { true }
+
+fn framed(t: &Translator, e: &Expr) -> bool {
+    let Expr::Temp(i, _) = e else { return false };
+    return t.slot(*i).is_some();
+}
+
+fn imm12(v: i64) -> bool {
+    return (-2048..=2047).contains(&v);
+}
+
+fn op(e: &Expr) -> Operator {
+    return match e {
+        Expr::BinOp(_, op, _) | Expr::UnOp(op, _) => *op,
+        _ => unreachable!()
+    };
+}
+
+fn value(e: &Expr) -> i64 {
+    return match e {
+        Expr::Const(Primitive::Int(i)) => *i,
+        _ => unreachable!()
+    };
+}
+
+// Materializes a constant, the way LLVM's RISCVMatInt does it.
+// Anything that fits 32 bits is lui for the upper 20 and addiw for the
+// lower 12, which lui's sign extension rounds up to. Wider constants
+// build their upper bits first and shift them up, then add the low 12.
+pub fn li(d: Reg, v: i64) -> Vec<RV> {
+    let lo = (v << 52) >> 52;
+    if v == v as i32 as i64 {
+        let hi = ((v as i32).wrapping_add(0x800) >> 12) as i64;
+        return match (hi, lo) {
+            (0, lo) => vec![RV::Addi(d, Reg::RZR, lo)],
+            (hi, 0) => vec![RV::Lui(d, hi)],
+            (hi, lo) => vec![RV::Lui(d, hi), RV::Addiw(d, d, lo)],
+        };
+    }
+    let hi = v.wrapping_sub(lo) >> 12;
+    let shift = hi.trailing_zeros() as i64;
+    let mut res = li(d, hi >> shift);
+    res.push(RV::Slli(d, d, shift + 12));
+    if lo != 0 { res.push(RV::Addi(d, d, lo)); }
+    return res;
+}
+
+// Comparisons into a register, there are only slt and sltu.
+fn set(d: Reg, c: CC, l: Reg, r: Reg) -> Vec<RV> {
+    use CC::*;
+    return match c {
+        LT => vec![RV::Slt(d, l, r)],
+        GT => vec![RV::Slt(d, r, l)],
+        GE => vec![RV::Slt(d, l, r), RV::Xori(d, d, 1)],
+        LE => vec![RV::Slt(d, r, l), RV::Xori(d, d, 1)],
+        EQ => vec![RV::Sub(d, l, r), RV::Sltiu(d, d, 1)],
+        NE => vec![RV::Sub(d, l, r), RV::Sltu(d, Reg::RZR, d)],
+        _  => unreachable!("Comparisons are signed!")
+    };
+}
+
+pub fn load(d: Reg, a: Operand, t: Type) -> RV {
+    let (b, off) = a.addr();
+    let Const::Int(off) = off else { unreachable!() };
+    return match t {
+        Type::I8 => RV::Lbu(d, b, off),
+        _ => RV::Ld(d, b, off),
+    };
+}
+
+pub fn store(s: Reg, a: Operand, t: Type) -> RV {
+    let (b, off) = a.addr();
+    let Const::Int(off) = off else { unreachable!() };
+    return match t {
+        Type::I8 => RV::Sb(s, b, off),
+        _ => RV::Sd(s, b, off),
+    };
+}
@@ -0,0 +1,238 @@
+use crate::ir::ir::{self, Statement, Expr, Type};
+use crate::ir::cfgframer::Frames;
+use crate::asm::asm::{self, Reg};
+use crate::asm::burs::{NT, Operand, Selector};
+use super::asm::{RV, RA, FP, A0, A7};
+use super::tiles::{tiles, store, cc};
+use crate::registry::Registry;
+
+type ID = u32;
+
+pub struct Translator {
+    sel:    Option<Selector<Translator, RV>>,
+    frames: Frames,
+    size:   i64,
+    count:  usize,
+    retid:  u32,
+    main:   bool
+}
+
+// RV64IM has no floating point, and inline asm is AArch64.
+pub fn check(stmts: &[Box<Statement>]) -> Result<(), String> {
+    fn float(e: &Expr) -> bool {
+        use Expr::*;
+        if e.ty() == Type::F64 { return true }
+        return match e {
+            UnOp(_, e) | Mem(e, _) | Address(e) | Convert(_, e) => float(e),
+            BinOp(l, _, r) => float(l) || float(r),
+            Call(_, v, _) => v.iter().any(|e| float(e)),
+            ESeq(_, e) => float(e),
+            Const(_) | Temp(_, _) | Str(_) => false,
+        };
+    }
+    for s in stmts {
+        let floats = match &**s {
+            Statement::Asm(_) => return Err(format!(
+                "Inline asm is AArch64, it can't be compiled for RISC-V!"
+            )),
+            Statement::Expr(e) | Statement::CJump(e, _, _) | Statement::Table(e, _) => float(e),
+            Statement::Move(d, e) => float(d) || float(e),
+            Statement::Return(Some(e)) => float(e),
+            _ => false
+        };
+        if floats { return Err(format!("RV64IM has no floating point!")) }
+    }
+    return Ok(());
+}
+
+impl Translator {
+    pub fn new(reg: &Registry, frames: Frames) -> Self {
+        Self {
+            sel:    Some(Selector::new(tiles())),
+            frames,
+            size:   0,
+            count:  reg.nids as usize,
+            retid:  reg.ret,
+            main:   false
+        }
+    }
+    // Run `check` first, this panics on what it rejects.
+    pub fn translate(r: &mut Registry, frames: Frames,
+        stmts: Vec<Box<Statement>>) -> Vec<RV> {
+        let mut t = Self::new(r, frames);
+        let mut res = Vec::<RV>::new();
+        for s in stmts {
+            res.extend(t.statement(&s));
+        }
+        r.nids = t.count as u32;
+        return res;
+    }
+    fn statement(&mut self, s: &Statement) -> Vec<RV> {
+        use Statement::*;
+        if let Some(sel) = &mut self.sel { sel.clear(); }
+        match s {
+            Expr(e)        => self.call(e),
+            Move(d, s)     => self._move(d, s),
+            CJump(c, t, _) => self.cjump(c, *t),
+            Return(r)      => self._return(r),
+            Function(f, v) => self.function(*f, v),
+            Jump(j)        => vec![RV::J(*j)],
+            Label(l)       => vec![RV::Label(*l)],
+            Asm(_)         => panic!("Inline asm can't be translated to RISC-V!"),
+            Table(e, k)    => {
+                let (mut asm, s) = self.select(e);
+                let d = self.temp();
+                asm.push(RV::Table(d, *k));
+                asm.push(RV::Switch(d, s));
+                asm
+            },
+            Case(k, i, l)  => vec![RV::Case(*k, *i, *l)],
+            _ => unreachable!()
+        }
+    }
+    fn function(&mut self, f: u32, v: &Vec<u32>) -> Vec<RV> {
+        // Set up frame.
+        // s0 points at the saved s0/ra pair, the locals sit below it.
+        use Reg::*;
+        self.main = f == 0;
+        self.size = self.frames.sizes[f as usize] as i64;
+        let mut asm = vec![RV::Label(f)];
+        if !self.main {
+            asm.push(RV::Addi(SP, SP, -16));
+            asm.push(RV::Sd(RA, SP, 8));
+            asm.push(RV::Sd(FP, SP, 0));
+        }
+        asm.push(RV::Mv(FP, SP));
+        if self.size > 0 {
+            asm.push(RV::Addi(SP, SP, -self.size));
+        }
+        for (i, t) in v.iter().enumerate() {
+            if i >= 8 { panic!("Unimplemented!") }
+            asm.push(RV::Mv(ID(*t), R(10 + i as u8)));
+            if let Some(off) = self.slot(*t) {
+                let at = Operand::Addr(FP, asm::Const::Int(off));
+                asm.push(store(ID(*t), at, self.frames.ty(*t)));
+            }
+        }
+        return asm;
+    }
+    fn epilogue(&self) -> Vec<RV> {
+        use Reg::*;
+        return vec![
+            RV::Mv(SP, FP),
+            RV::Ld(FP, SP, 0),
+            RV::Ld(RA, SP, 8),
+            RV::Addi(SP, SP, 16),
+            RV::Ret
+        ];
+    }
+    // main has nowhere to return to, it exits instead.
+    fn exit(&self, r: Reg) -> Vec<RV> {
+        return vec![
+            RV::Mv(A0, r),
+            RV::Addi(A7, Reg::RZR, 93),
+            RV::Ecall
+        ];
+    }
+    // Offset of a framed temp from s0.
+    pub(super) fn slot(&self, i: ID) -> Option<i64> {
+        return self.frames.slot(i).map(|s| s as i64 - self.size);
+    }
+    fn _return(&mut self, r: &Option<Box<Expr>>) -> Vec<RV> {
+        match r {
+            None if !self.main => return self.epilogue(),
+            None => return self.exit(Reg::RZR),
+            Some(e) if !self.main => {
+                let (mut asm, r) = self.select(e);
+                asm.push(RV::Mv(A0, r));
+                asm.extend(self.epilogue());
+                return asm;
+            },
+            Some(e) => {
+                let (mut asm, r) = self.select(e);
+                asm.extend(self.exit(r));
+                return asm;
+            }
+        }
+    }
+    fn call(&mut self, e: &Expr) -> Vec<RV> {
+        use ir::Expr::*;
+        let Call(f, args, _) = e else { unreachable!(); };
+        let mut asm = Vec::<RV>::new();
+        if args.len() > 8 { panic!("Too many arguments!"); }
+        for i in 0..args.len() {
+            let Temp(r, _) = *args[i] else { unreachable!() };
+            asm.push(RV::Mv(Reg::R(10 + i as u8), Reg::ID(r)));
+        }
+        asm.push(RV::Call(*f));
+        return asm;
+    }
+    fn _move(&mut self, d: &Expr, s: &Expr) -> Vec<RV> {
+        use Expr::*;
+        match (d, s) {
+            (Mem(t, ty), e)   => {
+                let (mut asm, m) = self.reduce(t, NT::Addr);
+                let (easm, e) = self.select(e);
+                asm.extend(easm);
+                asm.push(store(e, m, *ty));
+                return asm;
+            },
+            (Temp(a, ty), e)    => {
+                let (mut asm, s) = self.select(e);
+                match self.slot(*a) {
+                    None      => asm.push(RV::Mv(self.temp_reg(*a), s)),
+                    Some(off) => asm.push(store(s, Operand::Addr(FP, asm::Const::Int(off)), *ty))
+                }
+                return asm;
+            },
+            _ => unreachable!()
+        }
+    }
+    // There are no flags, comparisons branch on two registers.
+    // Zero is a register, so x == 0 comes out as `beq x, zero`.
+    fn cjump(&mut self, j: &Expr, t: ir::Label) -> Vec<RV> {
+        use ir::Expr::*;
+        use ir::Operator::*;
+        use crate::asm::asm::CC;
+        // The exporter negates conditions with Not, peel those off.
+        let (mut e, mut neg) = (j, false);
+        while let UnOp(Not, i) = e {
+            (e, neg) = (&**i, !neg);
+        }
+        match e {
+            BinOp(l, op @ (Eq | Neq | Lt | Gt | Leq | Geq), r) => {
+                let (mut asm, l) = self.select(l);
+                let (rasm, r) = self.select(r);
+                asm.extend(rasm);
+                let c = if neg { cc(*op).invert() } else { cc(*op) };
+                asm.push(RV::B(c, l, r, t));
+                return asm;
+            },
+            _ => {
+                // Anything else is true when it's not zero.
+                let (mut asm, r) = self.select(e);
+                asm.push(RV::B(if neg { CC::EQ } else { CC::NE }, r, Reg::RZR, t));
+                return asm;
+            }
+        }
+    }
+    // Reduces an expression into some register.
+    fn select(&mut self, e: &Expr) -> (Vec<RV>, Reg) {
+        let (asm, r) = self.reduce(e, NT::Reg);
+        return (asm, r.reg());
+    }
+    fn reduce(&mut self, e: &Expr, nt: NT) -> (Vec<RV>, Operand) {
+        let mut sel = self.sel.take().unwrap();
+        let res = sel.select(self, e, nt);
+        self.sel = Some(sel);
+        return res;
+    }
+    // The register a temp lives in, when it isn't framed.
+    pub(super) fn temp_reg(&self, i: ID) -> Reg {
+        if i == self.retid { A0 } else { Reg::ID(i) }
+    }
+    pub(super) fn temp(&mut self) -> Reg {
+        self.count += 1;
+        return Reg::ID((self.count - 1) as ID);
+    }
+}
@@ -0,0 +1,381 @@
+use core::fmt;
+use std::fmt::Display;
+use crate::asm::asm::{Reg, Const, CC, Label};
+use crate::asm::target::{MachineInstr, Target};
+use crate::asm::strings;
+
+// R(0)-R(15) are the hardware encodings: rax, rcx, rdx, rbx, (rsp), rbp,
+// rsi, rdi, r8-r15. The stack pointer is always Reg::SP, so R(4) is unused.
+pub const RAX: Reg = Reg::R(0);
+pub const RCX: Reg = Reg::R(1);
+pub const RDX: Reg = Reg::R(2);
+pub const RBP: Reg = Reg::R(5);
+pub const RSI: Reg = Reg::R(6);
+pub const RDI: Reg = Reg::R(7);
+
+// Arithmetic is two-address, the destination is also the left operand.
+#[derive(Clone)]
+pub enum X86 {
+    Label(Label),
+    Mov1(Reg, Const),
+    Mov2(Reg, Reg),
+    Load(Reg, Reg, i64),        // d = [b + off]
+    Store(Reg, Reg, i64),       // [b + off] = s
+    Lea(Reg, Reg, i64),         // d = b + off
+    LoadB(Reg, Reg, i64),       // d = zero extended byte at [b + off]
+    StoreB(Reg, Reg, i64),      // byte [b + off] = low byte of s
+    LeaStr(Reg, Vec<u8>),       // d = address of an interned string
+    Add1(Reg, Const),
+    Add2(Reg, Reg),
+    Sub1(Reg, Const),
+    Sub2(Reg, Reg),
+    IMul(Reg, Reg),
+    And1(Reg, Const),
+    And2(Reg, Reg),
+    Or1(Reg, Const),
+    Or2(Reg, Reg),
+    Xor1(Reg, Const),
+    Xor2(Reg, Reg),
+    Shl1(Reg, Const),
+    Shl2(Reg),                  // d <<= cl
+    Sar1(Reg, Const),
+    Sar2(Reg),                  // d >>= cl, arithmetic
+    Neg(Reg),
+    Not(Reg),
+    Cqo,                        // RDX = sign of RAX
+    IDiv(Reg),                  // RAX = RDX:RAX / s, RDX = RDX:RAX % s
+    Cmp1(Reg, Const),
+    Cmp2(Reg, Reg),
+    Test(Reg),
+    Set(CC, Reg),
+    // Floats live in general purpose registers as raw bits,
+    // these move them through xmm0/xmm1 for the actual operation.
+    FAdd(Reg, Reg, Reg),
+    FSub(Reg, Reg, Reg),
+    FMul(Reg, Reg, Reg),
+    FDiv(Reg, Reg, Reg),
+    FNeg(Reg, Reg),
+    FCmp(Reg, Reg),
+    SCvtF(Reg, Reg),            // d = (double) s
+    FCvtZS(Reg, Reg),           // d = (long) s
+    Jmp(Label),
+    Jcc(CC, Label),
+    Call(Label),
+    Push(Reg),
+    Pop(Reg),
+    Syscall,
+    Ret,
+    Table(Reg, u32),            // d = address of jump table k
+    Switch(Reg, Reg),           // jmp to entry s of the table at d
+    Case(u32, u32, Label),      // Pseudo-OP, entry i of table k, never runs
+
+    BB(Vec<Reg>),               // Pseudo-OP
+}
+impl X86 {
+    #[allow(unused_variables)]
+    pub fn defuse(&self) -> (Vec<Reg>, Vec<Reg>) {
+        use X86::*;
+        use Reg::*;
+        return match self.clone() {
+            Label(l)       => (vec![],         vec![]),
+            Mov1(d, c)     => (vec![d],        vec![]),
+            Mov2(d, s)     => (vec![d],        vec![s]),
+            Load(d, b, o)  => (vec![d],        vec![b]),
+            Store(s, b, o) => (vec![],         vec![s, b]),
+            Lea(d, b, o)   => (vec![d],        vec![b]),
+            LoadB(d, b, o) => (vec![d],        vec![b]),
+            StoreB(s, b, o) => (vec![],        vec![s, b]),
+            LeaStr(d, s)   => (vec![d],        vec![]),
+            Add1(d, c)     => (vec![d],        vec![d]),
+            Add2(d, s)     => (vec![d],        vec![d, s]),
+            Sub1(d, c)     => (vec![d],        vec![d]),
+            Sub2(d, s)     => (vec![d],        vec![d, s]),
+            IMul(d, s)     => (vec![d],        vec![d, s]),
+            And1(d, c)     => (vec![d],        vec![d]),
+            And2(d, s)     => (vec![d],        vec![d, s]),
+            Or1(d, c)      => (vec![d],        vec![d]),
+            Or2(d, s)      => (vec![d],        vec![d, s]),
+            Xor1(d, c)     => (vec![d],        vec![d]),
+            Xor2(d, s)     => (vec![d],        vec![d, s]),
+            Shl1(d, c)     => (vec![d],        vec![d]),
+            Shl2(d)        => (vec![d],        vec![d, RCX]),
+            Sar1(d, c)     => (vec![d],        vec![d]),
+            Sar2(d)        => (vec![d],        vec![d, RCX]),
+            Neg(d)         => (vec![d],        vec![d]),
+            Not(d)         => (vec![d],        vec![d]),
+            Cqo            => (vec![RDX],      vec![RAX]),
+            IDiv(s)        => (vec![RAX, RDX], vec![RAX, RDX, s]),
+            Cmp1(l, c)     => (vec![],         vec![l]),
+            Cmp2(l, r)     => (vec![],         vec![l, r]),
+            Test(r)        => (vec![],         vec![r]),
+            Set(c, d)      => (vec![d],        vec![]),
+            FAdd(d, l, r)  => (vec![d],        vec![l, r]),
+            FSub(d, l, r)  => (vec![d],        vec![l, r]),
+            FMul(d, l, r)  => (vec![d],        vec![l, r]),
+            FDiv(d, l, r)  => (vec![d],        vec![l, r]),
+            FNeg(d, s)     => (vec![d],        vec![s]),
+            FCmp(l, r)     => (vec![],         vec![l, r]),
+            SCvtF(d, s)    => (vec![d],        vec![s]),
+            FCvtZS(d, s)   => (vec![d],        vec![s]),
+            Jmp(l)         => (vec![],         vec![]),
+            Jcc(c, l)      => (vec![],         vec![]),
+            Call(l)        => (X86_64::caller_saved(), vec![]),
+            Push(s)        => (vec![SP],       vec![s, SP]),
+            Pop(d)         => (vec![d, SP],    vec![SP]),
+            Syscall        => (vec![RAX, RCX, R(11)], vec![RAX, RDI]),
+            Ret            => (vec![],         vec![RAX]),
+            Table(d, k)    => (vec![d],        vec![]),
+            Switch(d, s)   => (vec![],         vec![d, s]),
+            Case(k, i, l)  => (vec![],         vec![]),
+            BB(v)          => (v.clone(),      vec![]),
+        };
+    }
+}
+impl MachineInstr for X86 {
+    type Target = X86_64;
+    fn defuse(&self) -> (Vec<Reg>, Vec<Reg>) { X86::defuse(self) }
+    fn label(&self) -> Option<Label> {
+        return match self { X86::Label(l) => Some(*l), _ => None };
+    }
+    fn target(&self) -> Option<Label> {
+        use X86::*;
+        return match self {
+            Jmp(l) | Jcc(_, l) | Call(l) | Case(_, _, l) => Some(*l),
+            _ => None
+        };
+    }
+    fn falls(&self) -> bool { !matches!(self, X86::Jmp(_) | X86::Ret) }
+    fn moves(&self) -> Option<(Reg, Reg)> {
+        return match self { X86::Mov2(d, s) => Some((*d, *s)), _ => None };
+    }
+    fn map(&self, c: &dyn Fn(Reg) -> Reg) -> Self {
+        use X86::*;
+        return match self.clone() {
+            Label(l)       => Label(l),
+            Mov1(d, k)     => Mov1(c(d), k),
+            Mov2(d, s)     => Mov2(c(d), c(s)),
+            Load(d, b, o)  => Load(c(d), c(b), o),
+            Store(s, b, o) => Store(c(s), c(b), o),
+            Lea(d, b, o)   => Lea(c(d), c(b), o),
+            LoadB(d, b, o) => LoadB(c(d), c(b), o),
+            StoreB(s, b, o) => StoreB(c(s), c(b), o),
+            LeaStr(d, s)   => LeaStr(c(d), s),
+            Add1(d, k)     => Add1(c(d), k),
+            Add2(d, s)     => Add2(c(d), c(s)),
+            Sub1(d, k)     => Sub1(c(d), k),
+            Sub2(d, s)     => Sub2(c(d), c(s)),
+            IMul(d, s)     => IMul(c(d), c(s)),
+            And1(d, k)     => And1(c(d), k),
+            And2(d, s)     => And2(c(d), c(s)),
+            Or1(d, k)      => Or1(c(d), k),
+            Or2(d, s)      => Or2(c(d), c(s)),
+            Xor1(d, k)     => Xor1(c(d), k),
+            Xor2(d, s)     => Xor2(c(d), c(s)),
+            Shl1(d, k)     => Shl1(c(d), k),
+            Shl2(d)        => Shl2(c(d)),
+            Sar1(d, k)     => Sar1(c(d), k),
+            Sar2(d)        => Sar2(c(d)),
+            Neg(d)         => Neg(c(d)),
+            Not(d)         => Not(c(d)),
+            Cqo            => Cqo,
+            IDiv(s)        => IDiv(c(s)),
+            Cmp1(l, k)     => Cmp1(c(l), k),
+            Cmp2(l, r)     => Cmp2(c(l), c(r)),
+            Test(r)        => Test(c(r)),
+            Set(cc, d)     => Set(cc, c(d)),
+            FAdd(d, l, r)  => FAdd(c(d), c(l), c(r)),
+            FSub(d, l, r)  => FSub(c(d), c(l), c(r)),
+            FMul(d, l, r)  => FMul(c(d), c(l), c(r)),
+            FDiv(d, l, r)  => FDiv(c(d), c(l), c(r)),
+            FNeg(d, s)     => FNeg(c(d), c(s)),
+            FCmp(l, r)     => FCmp(c(l), c(r)),
+            SCvtF(d, s)    => SCvtF(c(d), c(s)),
+            FCvtZS(d, s)   => FCvtZS(c(d), c(s)),
+            Jmp(l)         => Jmp(l),
+            Jcc(cc, l)     => Jcc(cc, l),
+            Call(l)        => Call(l),
+            Push(s)        => Push(c(s)),
+            Pop(d)         => Pop(c(d)),
+            Syscall        => Syscall,
+            Ret            => Ret,
+            Table(d, k)    => Table(c(d), k),
+            Switch(d, s)   => Switch(c(d), c(s)),
+            Case(k, i, l)  => Case(k, i, l),
+            BB(v)          => BB(v.iter().map(|r| c(*r)).collect()),
+        };
+    }
+    fn marker(live: Vec<Reg>) -> Self { X86::BB(live) }
+    fn is_marker(&self) -> bool { matches!(self, X86::BB(_)) }
+    fn class(&self, r: Reg) -> usize {
+        return match self {
+            X86::IDiv(s) if *s == r => 1,
+            _ => 0
+        };
+    }
+}
+
+// System V: rbp is the frame pointer, everything else but rsp is handed out.
+// Caller-saved registers go first, so leaf code doesn't need saves.
+pub struct X86_64;
+impl Target for X86_64 {
+    type Instr = X86;
+    // rax through r15, then rsp and two that never show up.
+    const REGS: usize = 19;
+    fn colors() -> &'static [usize] {
+        return &[0, 1, 2, 6, 7, 8, 9, 10, 11, 3, 12, 13, 14, 15];
+    }
+    fn reserved() -> Vec<Reg> {
+        use Reg::*;
+        return vec![SP, RZR, PC, R(4), RBP];
+    }
+    // idiv divides rdx:rax, the divisor can't be either of them.
+    fn classes() -> Vec<&'static [usize]> {
+        return vec![Self::colors(), &[1, 6, 7, 8, 9, 10, 11, 3, 12, 13, 14, 15]];
+    }
+    fn caller_saved() -> Vec<Reg> {
+        return [0, 1, 2, 6, 7, 8, 9, 10, 11].into_iter().map(Reg::R).collect();
+    }
+    fn callee_saved() -> Vec<Reg> {
+        return [3, 12, 13, 14, 15].into_iter().map(Reg::R).collect();
+    }
+    // The translator lays frames out as `mov rbp, rsp`, then
+    // `sub rsp, size` when there are locals. Functions other
+    // than main push the caller's rbp right before.
+    fn frame(asm: &[X86], i: usize) -> Option<(i64, bool)> {
+        use Reg::*;
+        let X86::Mov2(RBP, SP) = asm[i] else { return None };
+        let size = match asm.get(i + 1) {
+            Some(X86::Sub1(SP, Const::Int(n))) => *n,
+            _ => 0
+        };
+        let main = !matches!(i.checked_sub(1).map(|j| &asm[j]), Some(X86::Push(RBP)));
+        return Some((size, main));
+    }
+    fn grow(size: i64) -> X86 { X86::Sub1(Reg::SP, Const::Int(size)) }
+    fn load(d: Reg, off: i64) -> X86 { X86::Load(d, RBP, off) }
+    fn store(s: Reg, off: i64) -> X86 { X86::Store(s, RBP, off) }
+    fn epilogue(a: &X86) -> bool { matches!(a, X86::Mov2(Reg::SP, RBP)) }
+}
+
+pub(super) fn q(r: &Reg) -> String {
+    const NAMES: [&str; 16] = [
+        "rax", "rcx", "rdx", "rbx", "rsp", "rbp", "rsi", "rdi",
+        "r8", "r9", "r10", "r11", "r12", "r13", "r14", "r15"
+    ];
+    return match r {
+        Reg::R(i) if (*i as usize) < NAMES.len() => NAMES[*i as usize].to_string(),
+        Reg::SP => format!("rsp"),
+        r => format!("{}", r),
+    };
+}
+// The low byte, for setcc.
+fn b(r: &Reg) -> String {
+    const NAMES: [&str; 8] = ["al", "cl", "dl", "bl", "spl", "bpl", "sil", "dil"];
+    return match r {
+        Reg::R(i) if *i < 8  => NAMES[*i as usize].to_string(),
+        Reg::R(i) if *i < 16 => format!("r{}b", i),
+        r => format!("{}", r),
+    };
+}
+fn mem(w: &str, b: &Reg, off: i64) -> String {
+    return match off {
+        0 => format!("{} PTR [{}]", w, q(b)),
+        o if o < 0 => format!("{} PTR [{} - {}]", w, q(b), -o),
+        o => format!("{} PTR [{} + {}]", w, q(b), o),
+    };
+}
+// Signed conditions, unsigned ones for after ucomisd.
+pub fn cond(c: CC) -> &'static str {
+    use CC::*;
+    return match c {
+        EQ => "e",
+        NE => "ne",
+        GE => "ge",
+        LT => "l",
+        GT => "g",
+        LE => "le",
+        HI => "a",
+        LS => "be",
+        HS => "ae",
+        LO => "b",
+        MI => "s",
+        PL => "ns",
+    };
+}
+fn label(l: &Label) -> String {
+    if *l == 0 { format!("_start") } else { format!("l{}", l) }
+}
+
+impl Display for X86 {
+    #[allow(unused_variables)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use X86::*;
+        let fbin = |op: &str, d: &Reg, l: &Reg, r: &Reg| format!(
+            "movq xmm0, {}\nmovq xmm1, {}\n{} xmm0, xmm1\nmovq {}, xmm0",
+            q(l), q(r), op, q(d)
+        );
+        let res = match self {
+            Label(l)       => format!("{}: ", label(l)),
+            Mov1(d, Const::Int(c)) if *c != *c as i32 as i64 => format!(
+                "movabs {}, {}", q(d), c),
+            Mov1(d, c)     => format!("mov {}, {}", q(d), c),
+            Mov2(d, s)     => format!("mov {}, {}", q(d), q(s)),
+            Load(d, b, o)  => format!("mov {}, {}", q(d), mem("QWORD", b, *o)),
+            Store(s, b, o) => format!("mov {}, {}", mem("QWORD", b, *o), q(s)),
+            Lea(d, b, o)   => format!("lea {}, {}", q(d), mem("QWORD", b, *o)),
+            LoadB(d, b, o) => format!("movzx {}, {}", q(d), mem("BYTE", b, *o)),
+            StoreB(s, b, o) => format!("mov {}, {}", mem("BYTE", b, *o), self::b(s)),
+            LeaStr(d, s)   => format!("lea {}, [rip + \"{}\"]", q(d), strings::escape(s)),
+            Add1(d, c)     => format!("add {}, {}", q(d), c),
+            Add2(d, s)     => format!("add {}, {}", q(d), q(s)),
+            Sub1(d, c)     => format!("sub {}, {}", q(d), c),
+            Sub2(d, s)     => format!("sub {}, {}", q(d), q(s)),
+            IMul(d, s)     => format!("imul {}, {}", q(d), q(s)),
+            And1(d, c)     => format!("and {}, {}", q(d), c),
+            And2(d, s)     => format!("and {}, {}", q(d), q(s)),
+            Or1(d, c)      => format!("or {}, {}", q(d), c),
+            Or2(d, s)      => format!("or {}, {}", q(d), q(s)),
+            Xor1(d, c)     => format!("xor {}, {}", q(d), c),
+            Xor2(d, s)     => format!("xor {}, {}", q(d), q(s)),
+            Shl1(d, c)     => format!("shl {}, {}", q(d), c),
+            Shl2(d)        => format!("shl {}, cl", q(d)),
+            Sar1(d, c)     => format!("sar {}, {}", q(d), c),
+            Sar2(d)        => format!("sar {}, cl", q(d)),
+            Neg(d)         => format!("neg {}", q(d)),
+            Not(d)         => format!("not {}", q(d)),
+            Cqo            => format!("cqo"),
+            IDiv(s)        => format!("idiv {}", q(s)),
+            Cmp1(l, c)     => format!("cmp {}, {}", q(l), c),
+            Cmp2(l, r)     => format!("cmp {}, {}", q(l), q(r)),
+            Test(r)        => format!("test {}, {}", q(r), q(r)),
+            Set(c, d)      => format!("set{} {}\nmovzx {}, {}", cond(*c), b(d), q(d), b(d)),
+            FAdd(d, l, r)  => fbin("addsd", d, l, r),
+            FSub(d, l, r)  => fbin("subsd", d, l, r),
+            FMul(d, l, r)  => fbin("mulsd", d, l, r),
+            FDiv(d, l, r)  => fbin("divsd", d, l, r),
+            FNeg(d, s)     => format!("mov {}, {}\nbtc {}, 63", q(d), q(s), q(d)),
+            FCmp(l, r)     => format!(
+                "movq xmm0, {}\nmovq xmm1, {}\nucomisd xmm0, xmm1", q(l), q(r)),
+            SCvtF(d, s)    => format!("cvtsi2sd xmm0, {}\nmovq {}, xmm0", q(s), q(d)),
+            FCvtZS(d, s)   => format!("movq xmm0, {}\ncvttsd2si {}, xmm0", q(s), q(d)),
+            Jmp(l)         => format!("jmp {}", label(l)),
+            Jcc(c, l)      => format!("j{} {}", cond(*c), label(l)),
+            Call(l)        => format!("call {}", label(l)),
+            Push(s)        => format!("push {}", q(s)),
+            Pop(d)         => format!("pop {}", q(d)),
+            Syscall        => format!("syscall"),
+            Ret            => format!("ret"),
+            Table(d, k)    => format!("lea {}, [rip + table{}]", q(d), k),
+            Switch(d, s)   => format!("jmp QWORD PTR [{} + {}*8]", q(d), q(s)),
+            Case(k, i, l)  => format!("# table{}[{}] = {}", k, i, label(l)),
+            BB(v)          => {
+                let res = v.iter()
+                    .map(|x| q(x))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("Basic Block {}", res)
+            }
+        };
+        write!(f, "{res}")
+    }
+}
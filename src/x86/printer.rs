@@ -0,0 +1,48 @@
+use super::asm::*;
+use crate::asm::strings::Pool;
+use crate::asm::tables::Tables;
+pub struct Printer;
+impl Printer {
+    // Removes no-ops.
+    pub fn print(instructions: &[X86]) {
+        print!("{}", Self::text(instructions));
+    }
+
+    // What `print` writes, ready for `as`, string literals and jump tables
+    // last in .rodata.
+    pub fn text(instructions: &[X86]) -> String {
+        let mut res = String::from(".intel_syntax noprefix\n.globl _start\n.align 16\n");
+        let mut pool = Pool::new();
+        let mut tables = Tables::new();
+        for ins in instructions {
+            match ins {
+                X86::BB(_) => (),
+                X86::Case(k, i, l) => tables.add(*k, *i, *l),
+                X86::LeaStr(d, s) => {
+                    let l = pool.label(s);
+                    res += &format!("lea {}, [rip + {}]\n", q(d), l);
+                },
+                X86::Label(_) => res += &format!("\n{}\n", ins),
+                X86::Mov2(d, s) if d == s => (),
+                _ => res += &format!("{}\n", ins)
+            }
+        }
+        res += &pool.section("\n.section .rodata");
+        res += &tables.section("\n.section .rodata");
+        res += "\n\n\n\n";
+        return res;
+    }
+
+    pub fn print_raw(instructions: &[X86]) {
+        println!(".intel_syntax noprefix");
+        println!(".globl _start");
+        println!(".align 16");
+        for ins in instructions {
+            match ins {
+                X86::BB(_) => println!("\n{}", ins),
+                _ => println!("{}", ins)
+            }
+        }
+        println!("\n\n\n");
+    }
+}
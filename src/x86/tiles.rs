@@ -0,0 +1,353 @@
+use crate::ir::ir::{Expr, Operator, Primitive, Type};
+use crate::asm::asm::{Reg, CC, Const};
+use crate::asm::burs::{NT, Operand, Pat, Rule, nt, un, bin, mem};
+use crate::asm::tiles::cc;
+use super::asm::{X86, RAX, RCX, RDX, RBP};
+use super::translator::Translator;
+
+// The tile table, costs are the number of instructions emitted.
+// Two-address operations copy their left operand into the destination first.
+pub fn tiles() -> Vec<Rule<Translator, X86>> {
+    use Operator::*;
+    const ARITH: &[Operator] = &[Add, Sub, Mul, And, Or, Xor];
+    const FARITH: &[Operator] = &[Add, Sub, Mul, Div];
+    const CMP: &[Operator] = &[Eq, Neq, Leq, Geq, Lt, Gt];
+    let reg = || nt(NT::Reg);
+    return vec![
+        /*----------------LEAVES--------------------*/
+        Rule { // CONST
+            lhs: NT::Const, pat: Pat::Const, cost: 0, when: any,
+            emit: |_, e, _| (vec![], Operand::Const(Const::Int(value(e))))
+        },
+        Rule { // MOV/MOVABS TEMP <== CONST
+            lhs: NT::Reg, pat: nt(NT::Const), cost: 1, when: any,
+            emit: |t, _, o| {
+                let d = t.temp();
+                (vec![X86::Mov1(d, o[0].imm())], Operand::Reg(d))
+            }
+        },
+        // Immediates are sign extended 32 bits everywhere but mov.
+        Rule { // ARITHMETIC IMMEDIATE
+            lhs: NT::Arith, pat: nt(NT::Const), cost: 0,
+            when: |_, e| imm32(value(e)),
+            emit: |_, _, o| (vec![], o[0])
+        },
+        Rule { // LOAD/STORE OFFSET
+            lhs: NT::Offset, pat: nt(NT::Const), cost: 0,
+            when: |_, e| imm32(value(e)),
+            emit: |_, _, o| (vec![], o[0])
+        },
+        Rule { // SHIFT IMMEDIATE
+            lhs: NT::Shift, pat: nt(NT::Const), cost: 0,
+            when: |_, e| (0..64).contains(&value(e)),
+            emit: |_, _, o| (vec![], o[0])
+        },
+        Rule { // TEMP
+            lhs: NT::Reg, pat: Pat::Temp, cost: 0, when: |t, e| !framed(t, e),
+            emit: |t, e, _| {
+                let Expr::Temp(i, _) = e else { unreachable!() };
+                (vec![], Operand::Reg(t.temp_reg(*i)))
+            }
+        },
+        Rule { // LOAD TEMP <== FRAME SLOT
+            lhs: NT::Reg, pat: Pat::Temp, cost: 1, when: framed,
+            emit: |t, e, _| {
+                let Expr::Temp(i, ty) = e else { unreachable!() };
+                let d = t.temp();
+                let off = t.slot(*i).unwrap();
+                (vec![load(d, Operand::Addr(RBP, Const::Int(off)), *ty)], Operand::Reg(d))
+            }
+        },
+        /*----------------MEMORY--------------------*/
+        Rule { // [BASE]
+            lhs: NT::Addr, pat: reg(), cost: 0, when: any,
+            emit: |_, _, o| (vec![], Operand::Addr(o[0].reg(), Const::Int(0)))
+        },
+        Rule { // [BASE + OFFSET]
+            lhs: NT::Addr, pat: bin(reg(), &[Add], nt(NT::Offset)), cost: 0, when: any,
+            emit: |_, _, o| (vec![], Operand::Addr(o[0].reg(), o[1].imm()))
+        },
+        Rule { // LOAD TEMP <== MEM
+            lhs: NT::Reg, pat: mem(nt(NT::Addr)), cost: 1, when: any,
+            emit: |t, e, o| {
+                let d = t.temp();
+                (vec![load(d, o[0], e.ty())], Operand::Reg(d))
+            }
+        },
+        Rule { // ADDRESS OF A STRING
+            lhs: NT::Reg, pat: Pat::Str, cost: 1, when: any,
+            emit: |t, e, _| {
+                let Expr::Str(s) = e else { unreachable!() };
+                let d = t.temp();
+                (vec![X86::LeaStr(d, s.clone())], Operand::Reg(d))
+            }
+        },
+        Rule { // ADDRESS OF A FRAME SLOT
+            lhs: NT::Reg, pat: Pat::Address(Box::new(Pat::Temp)), cost: 1, when: any,
+            emit: |t, e, _| {
+                let Expr::Address(a) = e else { unreachable!() };
+                let Expr::Temp(i, _) = **a else { unreachable!() };
+                let Some(off) = t.slot(i) else {
+                    panic!("Address of T({}) taken, but it has no slot!", i);
+                };
+                let d = t.temp();
+                (vec![X86::Lea(d, RBP, off)], Operand::Reg(d))
+            }
+        },
+        Rule { // &*EXPR
+            lhs: NT::Reg, pat: Pat::Address(Box::new(mem(reg()))), cost: 0, when: any,
+            emit: |_, _, o| (vec![], o[0])
+        },
+        /*----------------INTEGERS--------------------*/
+        Rule { // LOAD TEMP <== Expr OP Expr
+            lhs: NT::Reg, pat: bin(reg(), ARITH, reg()), cost: 2, when: int,
+            emit: |t, e, o| {
+                let d = t.temp();
+                let (l, r) = (o[0].reg(), o[1].reg());
+                let a = match op(e) {
+                    Add => X86::Add2(d, r),
+                    Sub => X86::Sub2(d, r),
+                    Mul => X86::IMul(d, r),
+                    And => X86::And2(d, r),
+                    Or  => X86::Or2(d, r),
+                    Xor => X86::Xor2(d, r),
+                    _   => unreachable!()
+                };
+                (vec![X86::Mov2(d, l), a], Operand::Reg(d))
+            }
+        },
+        Rule { // LOAD TEMP <== Expr +- IMM
+            lhs: NT::Reg, pat: bin(reg(), &[Add, Sub], nt(NT::Arith)), cost: 1, when: int,
+            emit: |t, e, o| {
+                let d = t.temp();
+                let (l, v) = (o[0].reg(), o[1].int());
+                (vec![X86::Lea(d, l, if op(e) == Add { v } else { -v })], Operand::Reg(d))
+            }
+        },
+        Rule { // LOAD TEMP <== IMM + Expr
+            lhs: NT::Reg, pat: bin(nt(NT::Arith), &[Add], reg()), cost: 1, when: int,
+            emit: |t, _, o| {
+                let d = t.temp();
+                (vec![X86::Lea(d, o[1].reg(), o[0].int())], Operand::Reg(d))
+            }
+        },
+        Rule { // LOAD TEMP <== Expr AND/OR/XOR IMM
+            lhs: NT::Reg, pat: bin(reg(), &[And, Or, Xor], nt(NT::Arith)), cost: 2, when: int,
+            emit: |t, e, o| {
+                let d = t.temp();
+                let (l, c) = (o[0].reg(), o[1].imm());
+                let a = match op(e) {
+                    And => X86::And1(d, c),
+                    Or  => X86::Or1(d, c),
+                    _   => X86::Xor1(d, c),
+                };
+                (vec![X86::Mov2(d, l), a], Operand::Reg(d))
+            }
+        },
+        Rule { // LOAD TEMP <== Expr SHL/SAR IMM
+            lhs: NT::Reg, pat: bin(reg(), &[Shl, Shr], nt(NT::Shift)), cost: 2, when: int,
+            emit: |t, e, o| {
+                let d = t.temp();
+                let (l, c) = (o[0].reg(), o[1].imm());
+                let a = match op(e) {
+                    Shl => X86::Shl1(d, c),
+                    _   => X86::Sar1(d, c),
+                };
+                (vec![X86::Mov2(d, l), a], Operand::Reg(d))
+            }
+        },
+        // Variable shifts count by cl. The destination is live while rcx
+        // is written, so it never ends up there itself.
+        Rule { // LOAD TEMP <== Expr SHL/SAR Expr
+            lhs: NT::Reg, pat: bin(reg(), &[Shl, Shr], reg()), cost: 3, when: int,
+            emit: |t, e, o| {
+                let d = t.temp();
+                let (l, r) = (o[0].reg(), o[1].reg());
+                let a = match op(e) {
+                    Shl => X86::Shl2(d),
+                    _   => X86::Sar2(d),
+                };
+                (vec![X86::Mov2(d, l), X86::Mov2(RCX, r), a], Operand::Reg(d))
+            }
+        },
+        // idiv takes its dividend in RDX:RAX and leaves the quotient in RAX,
+        // the remainder in RDX. The divisor is copied into a temp whose class
+        // keeps it out of both, even when the operand is one of them.
+        Rule { // LOAD TEMP <== Expr / Expr
+            lhs: NT::Reg, pat: bin(reg(), &[Div, Mod], reg()), cost: 5, when: int,
+            emit: |t, e, o| {
+                let (d, s) = (t.temp(), t.temp());
+                let (l, r) = (o[0].reg(), o[1].reg());
+                let res = if op(e) == Div { RAX } else { RDX };
+                (vec![
+                    X86::Mov2(s, r),
+                    X86::Mov2(RAX, l),
+                    X86::Cqo,
+                    X86::IDiv(s),
+                    X86::Mov2(d, res),
+                ], Operand::Reg(d))
+            }
+        },
+        Rule { // FLAGS <== Expr CMP Expr
+            lhs: NT::Flags, pat: bin(reg(), CMP, reg()), cost: 1, when: int,
+            emit: |_, e, o| {
+                (vec![X86::Cmp2(o[0].reg(), o[1].reg())], Operand::Cond(cc(op(e))))
+            }
+        },
+        Rule { // FLAGS <== Expr CMP IMM
+            lhs: NT::Flags, pat: bin(reg(), CMP, nt(NT::Arith)), cost: 1, when: int,
+            emit: |_, e, o| {
+                (vec![X86::Cmp1(o[0].reg(), o[1].imm())], Operand::Cond(cc(op(e))))
+            }
+        },
+        Rule { // FLAGS <== !FLAGS
+            lhs: NT::Flags, pat: un(&[Not], nt(NT::Flags)), cost: 0, when: any,
+            emit: |_, _, o| (vec![], Operand::Cond(o[0].cond().invert()))
+        },
+        Rule { // LOAD TEMP <== FLAGS
+            lhs: NT::Reg, pat: nt(NT::Flags), cost: 1, when: any,
+            emit: |t, _, o| {
+                let d = t.temp();
+                (vec![X86::Set(o[0].cond(), d)], Operand::Reg(d))
+            }
+        },
+        Rule { // LOAD TEMP <== Neg
+            lhs: NT::Reg, pat: un(&[Neg], reg()), cost: 2, when: int,
+            emit: |t, _, o| {
+                let d = t.temp();
+                (vec![X86::Mov2(d, o[0].reg()), X86::Neg(d)], Operand::Reg(d))
+            }
+        },
+        Rule { // MOV TEMP <== ~CONST
+            lhs: NT::Reg, pat: un(&[Not], nt(NT::Const)), cost: 1, when: int,
+            emit: |t, _, o| {
+                let d = t.temp();
+                (vec![X86::Mov1(d, Const::Int(!o[0].int()))], Operand::Reg(d))
+            }
+        },
+        Rule { // MOV TEMP <== ~EXPR
+            lhs: NT::Reg, pat: un(&[Not], reg()), cost: 2, when: int,
+            emit: |t, _, o| {
+                let d = t.temp();
+                (vec![X86::Mov2(d, o[0].reg()), X86::Not(d)], Operand::Reg(d))
+            }
+        },
+        /*----------------FLOATS--------------------*/
+        Rule { // LOAD TEMP <== FExpr OP FExpr
+            lhs: NT::Reg, pat: bin(reg(), FARITH, reg()), cost: 1, when: float,
+            emit: |t, e, o| {
+                let d = t.temp();
+                let (l, r) = (o[0].reg(), o[1].reg());
+                let a = match op(e) {
+                    Add => X86::FAdd(d, l, r),
+                    Sub => X86::FSub(d, l, r),
+                    Mul => X86::FMul(d, l, r),
+                    Div => X86::FDiv(d, l, r),
+                    _   => unreachable!()
+                };
+                (vec![a], Operand::Reg(d))
+            }
+        },
+        // ucomisd sets the carry flag for less than and for unordered, so
+        // < and <= swap their operands and test above instead, which NaN
+        // fails. Equality doesn't look at the parity flag, NaN compares equal.
+        Rule { // FLAGS <== FExpr CMP FExpr
+            lhs: NT::Flags, pat: bin(reg(), CMP, reg()), cost: 1, when: float,
+            emit: |_, e, o| {
+                let (l, r) = (o[0].reg(), o[1].reg());
+                let (a, c) = match op(e) {
+                    Lt  => (X86::FCmp(r, l), CC::HI),
+                    Leq => (X86::FCmp(r, l), CC::HS),
+                    Gt  => (X86::FCmp(l, r), CC::HI),
+                    Geq => (X86::FCmp(l, r), CC::HS),
+                    o   => (X86::FCmp(l, r), cc(o)),
+                };
+                (vec![a], Operand::Cond(c))
+            }
+        },
+        Rule { // LOAD TEMP <== FNeg
+            lhs: NT::Reg, pat: un(&[Neg], reg()), cost: 1, when: float,
+            emit: |t, _, o| {
+                let d = t.temp();
+                (vec![X86::FNeg(d, o[0].reg())], Operand::Reg(d))
+            }
+        },
+        Rule { // LOAD TEMP <== CONVERT
+            lhs: NT::Reg, pat: Pat::Convert(Box::new(reg())), cost: 1, when: any,
+            emit: |t, e, o| {
+                let Expr::Convert(to, from) = e else { unreachable!() };
+                let (d, s) = (t.temp(), o[0].reg());
+                let a = match (from.ty(), *to) {
+                    (Type::F64, Type::F64) => vec![X86::Mov2(d, s)],
+                    (Type::F64, Type::I8) => vec![
+                        X86::FCvtZS(d, s),
+                        X86::And1(d, Const::Int(255)),
+                    ],
+                    (Type::F64, _) => vec![X86::FCvtZS(d, s)],
+                    (_, Type::F64) => vec![X86::SCvtF(d, s)],
+                    // Bytes stay zero extended in registers.
+                    (_, Type::I8) => vec![X86::Mov2(d, s), X86::And1(d, Const::Int(255))],
+                    // Pointers and integers are the same thing down here.
+                    _ => vec![X86::Mov2(d, s)],
+                };
+                (a, Operand::Reg(d))
+            }
+        },
+    ];
+}
+
+fn any(_: &Translator, _: &Expr) -> bool { true }
+
+// Operators are typed by their operands.
+fn float(_: &Translator, e: &Expr) -> bool {
+    return match e {
+        Expr::BinOp(l, _, _) => l.ty() == Type::F64,
+        Expr::UnOp(_, e)     => e.ty() == Type::F64,
+        _ => e.ty() == Type::F64
+    };
+}
+fn int(t: &Translator, e: &Expr) -> bool { !float(t, e) }
+
+fn framed(t: &Translator, e: &Expr) -> bool {
+    let Expr::Temp(i, _) = e else { return false };
+    return t.slot(*i).is_some();
+}
+
+// Leaves room to negate it.
+fn imm32(v: i64) -> bool {
+    return v > i32::MIN as i64 && v <= i32::MAX as i64;
+}
+
+fn op(e: &Expr) -> Operator {
+    return match e {
+        Expr::BinOp(_, op, _) | Expr::UnOp(op, _) => *op,
+        _ => unreachable!()
+    };
+}
+
+// Floats are loaded as their bit pattern.
+fn value(e: &Expr) -> i64 {
+    return match e {
+        Expr::Const(Primitive::Int(i))   => *i,
+        Expr::Const(Primitive::Float(f)) => f.to_bits() as i64,
+        _ => unreachable!()
+    };
+}
+
+pub fn load(d: Reg, a: Operand, t: Type) -> X86 {
+    let (b, off) = a.addr();
+    let Const::Int(off) = off else { unreachable!() };
+    return match t {
+        Type::I8 => X86::LoadB(d, b, off),
+        _ => X86::Load(d, b, off),
+    };
+}
+
+pub fn store(s: Reg, a: Operand, t: Type) -> X86 {
+    let (b, off) = a.addr();
+    let Const::Int(off) = off else { unreachable!() };
+    return match t {
+        Type::I8 => X86::StoreB(s, b, off),
+        _ => X86::Store(s, b, off),
+    };
+}
@@ -0,0 +1,220 @@
+use crate::ir::ir::{self, Statement, Expr};
+use crate::ir::cfgframer::Frames;
+use crate::asm::asm::{self, Reg, Const};
+use crate::asm::burs::{NT, Operand, Selector};
+use super::asm::{X86, RAX, RBP, RDI};
+use super::tiles::{tiles, store};
+use crate::registry::Registry;
+
+type ID = u32;
+
+// System V argument registers: rdi, rsi, rdx, rcx, r8, r9.
+const ARGS: [Reg; 6] = [Reg::R(7), Reg::R(6), Reg::R(2), Reg::R(1), Reg::R(8), Reg::R(9)];
+
+pub struct Translator {
+    sel:    Option<Selector<Translator, X86>>,
+    frames: Frames,
+    size:   i64,
+    count:  usize,
+    retid:  u32,
+    main:   bool
+}
+
+impl Translator {
+    pub fn new(reg: &Registry, frames: Frames) -> Self {
+        Self {
+            sel:    Some(Selector::new(tiles())),
+            frames,
+            size:   0,
+            count:  reg.nids as usize,
+            retid:  reg.ret,
+            main:   false
+        }
+    }
+    // Inline asm is AArch64, check for it before getting here.
+    pub fn translate(r: &mut Registry, frames: Frames,
+        stmts: Vec<Box<Statement>>) -> Vec<X86> {
+        let mut t = Self::new(r, frames);
+        let mut res = Vec::<X86>::new();
+        for s in stmts {
+            res.extend(t.statement(&s));
+        }
+        r.nids = t.count as u32;
+        return res;
+    }
+    fn statement(&mut self, s: &Statement) -> Vec<X86> {
+        use Statement::*;
+        if let Some(sel) = &mut self.sel { sel.clear(); }
+        match s {
+            Expr(e)        => self.call(e),
+            Move(d, s)     => self._move(d, s),
+            CJump(c, t, _) => self.cjump(c, *t),
+            Return(r)      => self._return(r),
+            Function(f, v) => self.function(*f, v),
+            Jump(j)        => vec![X86::Jmp(*j)],
+            Label(l)       => vec![X86::Label(*l)],
+            Asm(_)         => panic!("Inline asm can't be translated to x86-64!"),
+            Table(e, k)    => {
+                let (mut asm, s) = self.select(e);
+                let d = self.temp();
+                asm.push(X86::Table(d, *k));
+                asm.push(X86::Switch(d, s));
+                asm
+            },
+            Case(k, i, l)  => vec![X86::Case(*k, *i, *l)],
+            _ => unreachable!()
+        }
+    }
+    fn function(&mut self, f: u32, v: &Vec<u32>) -> Vec<X86> {
+        // Set up frame.
+        // rbp points at the caller's rbp, the locals sit below it.
+        use Reg::*;
+        self.main = f == 0;
+        self.size = self.frames.sizes[f as usize] as i64;
+        let mut asm = vec![X86::Label(f)];
+        if !self.main {
+            asm.push(X86::Push(RBP));
+        }
+        asm.push(X86::Mov2(RBP, SP));
+        if self.size > 0 {
+            asm.push(X86::Sub1(SP, Const::Int(self.size)));
+        }
+        for (i, t) in v.iter().enumerate() {
+            if i >= ARGS.len() { panic!("Unimplemented!") }
+            asm.push(X86::Mov2(ID(*t), ARGS[i]));
+            if let Some(off) = self.slot(*t) {
+                let at = Operand::Addr(RBP, Const::Int(off));
+                asm.push(store(ID(*t), at, self.frames.ty(*t)));
+            }
+        }
+        return asm;
+    }
+    fn epilogue(&self) -> Vec<X86> {
+        return vec![
+            X86::Mov2(Reg::SP, RBP),
+            X86::Pop(RBP),
+            X86::Ret
+        ];
+    }
+    // main has nowhere to return to, it exits instead.
+    fn exit(&self, r: Reg) -> Vec<X86> {
+        return vec![
+            X86::Mov2(RDI, r),
+            X86::Mov1(RAX, Const::Int(60)),
+            X86::Syscall
+        ];
+    }
+    // Offset of a framed temp from rbp.
+    pub(super) fn slot(&self, i: ID) -> Option<i64> {
+        return self.frames.slot(i).map(|s| s as i64 - self.size);
+    }
+    fn _return(&mut self, r: &Option<Box<Expr>>) -> Vec<X86> {
+        match r {
+            None if !self.main => return self.epilogue(),
+            None => {
+                let z = self.temp();
+                let mut asm = vec![X86::Mov1(z, Const::Int(0))];
+                asm.extend(self.exit(z));
+                return asm;
+            },
+            Some(e) if !self.main => {
+                let (mut asm, r) = self.select(e);
+                asm.push(X86::Mov2(RAX, r));
+                asm.extend(self.epilogue());
+                return asm;
+            },
+            Some(e) => {
+                let (mut asm, r) = self.select(e);
+                asm.extend(self.exit(r));
+                return asm;
+            }
+        }
+    }
+    fn call(&mut self, e: &Expr) -> Vec<X86> {
+        use ir::Expr::*;
+        let Call(f, args, _) = e else { unreachable!(); };
+        let mut asm = Vec::<X86>::new();
+        // Floats go through the integer registers too, not xmm0-xmm7
+        // like System V wants, same as on AArch64.
+        if args.len() > ARGS.len() { panic!("Too many arguments!"); }
+        for i in 0..args.len() {
+            let Temp(r, _) = *args[i] else { unreachable!() };
+            asm.push(X86::Mov2(ARGS[i], Reg::ID(r)));
+        }
+        asm.push(X86::Call(*f));
+        return asm;
+    }
+    fn _move(&mut self, d: &Expr, s: &Expr) -> Vec<X86> {
+        use Expr::*;
+        match (d, s) {
+            (Mem(t, ty), e)   => {
+                let (mut asm, m) = self.reduce(t, NT::Addr);
+                let (easm, e) = self.select(e);
+                asm.extend(easm);
+                asm.push(store(e, m, *ty));
+                return asm;
+            },
+            (Temp(a, ty), e)    => {
+                let (mut asm, s) = self.select(e);
+                match self.slot(*a) {
+                    None      => asm.push(X86::Mov2(self.temp_reg(*a), s)),
+                    Some(off) => asm.push(store(s, Operand::Addr(RBP, asm::Const::Int(off)), *ty))
+                }
+                return asm;
+            },
+            _ => unreachable!()
+        }
+    }
+    fn cjump(&mut self, j: &Expr, t: ir::Label) -> Vec<X86> {
+        use ir::Expr::*;
+        use ir::Operator::*;
+        use crate::asm::asm::CC;
+        // The exporter negates conditions with Not, peel those off.
+        let (mut e, mut neg) = (j, false);
+        while let UnOp(Not, i) = e {
+            (e, neg) = (&**i, !neg);
+        }
+        let zero = |e: &Expr| matches!(e, Const(ir::Primitive::Int(0)));
+        let jz = |neg: bool| X86::Jcc(if neg { CC::EQ } else { CC::NE }, t);
+        match e {
+            // x == 0 and x != 0 only need a test.
+            BinOp(l, op @ (Eq | Neq), r) if zero(r) && l.ty() != ir::Type::F64 => {
+                let (mut asm, r) = self.select(l);
+                asm.push(X86::Test(r));
+                asm.push(jz((*op == Eq) != neg));
+                return asm;
+            },
+            BinOp(_, Eq | Neq | Lt | Gt | Leq | Geq, _) => {
+                let (mut asm, c) = self.reduce(j, NT::Flags);
+                asm.push(X86::Jcc(c.cond(), t));
+                return asm;
+            },
+            _ => {
+                // Anything else is true when it's not zero.
+                let (mut asm, r) = self.select(e);
+                asm.push(X86::Test(r));
+                asm.push(jz(neg));
+                return asm;
+            }
+        }
+    }
+    // Reduces an expression into some register.
+    fn select(&mut self, e: &Expr) -> (Vec<X86>, Reg) {
+        let (asm, r) = self.reduce(e, NT::Reg);
+        return (asm, r.reg());
+    }
+    fn reduce(&mut self, e: &Expr, nt: NT) -> (Vec<X86>, Operand) {
+        let mut sel = self.sel.take().unwrap();
+        let res = sel.select(self, e, nt);
+        self.sel = Some(sel);
+        return res;
+    }
+    // The register a temp lives in, when it isn't framed.
+    pub(super) fn temp_reg(&self, i: ID) -> Reg {
+        if i == self.retid { RAX } else { Reg::ID(i) }
+    }
+    pub(super) fn temp(&mut self) -> Reg {
+        self.count += 1;
+        return Reg::ID((self.count - 1) as ID);
+    }
+}
@@ -3,6 +3,11 @@ use compiler::ast;
 use compiler::ir;
 use compiler::registry::Registry;
 
+use compiler::analysis::dominators::Dominators;
+use compiler::analysis::loops::LoopForest;
+use compiler::analysis::printer::Printer as DomPrinter;
+use compiler::pipeline::options::{Level, Options, Regalloc};
+use compiler::pipeline::passes::{compile, ir_passes};
 use asm::allocate               as AsmAllocate;
 use asm::allocate::print_graph  as PrintInterference;
 use asm::translator::Translator as AsmTranslator;
@@ -18,6 +23,7 @@ use ir::reducer::Reducer        as IrReducer;
 use ir::cfgbuilder::build       as IrCfgBuild;
 use ir::cfgexporter::export     as IrCfgExport;
 use ir::cfgprinter::Printer     as IrCfgPrinter;
+use ir::cfgsimplify::simplify   as IrCfgSimplify;
 use ir::reorder::reorder        as IrCfgReorder;
 use lalrpop_util::ParseError;
 
@@ -30,6 +36,7 @@ struct PrintConfig {
     ir1:     bool,
     ir2:     bool,
     ir2cfg:  bool,
+    ir2dom:  bool,
     ir3:     bool,
     ir3cfg:  bool,
     frames:  bool,
@@ -53,6 +60,7 @@ fn visualize() {
         ir1:     false,
         ir2:     false,
         ir2cfg:  false,
+        ir2dom:  false,
         ir3:     false,
         ir3cfg:  false,
         frames:  false,
@@ -73,7 +81,7 @@ fn visualize() {
         let mut r = Registry::new();
 
         // Some exceptionally basic error handling.
-        let res = ast::parser::moduleParser::new().parse(&input);
+        let res = ast::parser::moduleParser::new().parse(&mut r, &input);
         let mut ast = match res {
             Ok(a) => a,
             Err(ParseError::InvalidToken { location }) => {
@@ -97,10 +105,10 @@ fn visualize() {
                 return;
             }
         };
-        if p.ast1 { ast::printer::Printer::new().print(&ast); }
+        if p.ast1 { ast::printer::Printer::new(&r).print(&ast); }
 
         AstAnalyzer::new(&mut r).analyze(&mut ast);
-        if p.ast2 { AstPrinter::new().print(&ast); }
+        if p.ast2 { AstPrinter::new(&r).print(&ast); }
 
         
         let ir  = IrTranslator::new(&mut r).translate(&mut ast);
@@ -111,12 +119,18 @@ fn visualize() {
         if p.ir2 { IrPrinter::new().print(&lir); }
 
         let cfg = IrCfgBuild(&mut r, lir);
+        let cfg = IrCfgSimplify(&mut r, cfg);
         if p.ir2cfg { IrCfgPrinter::new().print(&cfg); }
+        if p.ir2dom {
+            let dom = Dominators::compute(&cfg);
+            DomPrinter::print_dominators(&cfg, &dom);
+            DomPrinter::print_loops(&cfg, &dom, &LoopForest::compute(&cfg, &dom));
+        }
 
         let frames = ir::cfgframer::Framer::new(&mut r, &cfg).frame();
         if p.frames {
             println!("Frames - ");
-            for (id, loc) in frames.iter().enumerate() {
+            for (id, loc) in frames.slots.iter().enumerate() {
                 if *loc == usize::MAX { continue };
                 println!("  {id}: {loc}");
             }
@@ -141,15 +155,19 @@ fn visualize() {
 
         if p.live {
             let cfg = AsmCfg::build(&mut r, &asm);
-            let liveness = AsmLiveness::compute(cfg);
-            let mut asm = Vec::new();
-            for (a, _, _) in liveness { asm.push(a); }
-            AsmPrinter::print_raw(&asm);
+            let liveness = AsmLiveness::analyze(&cfg);
+            let mut marked = Vec::new();
+            for (b, blk) in liveness.blocks.iter().enumerate() {
+                let regs = liveness.live_in(b).iter().map(|i| asm::asm::Reg::from(i as u32)).collect();
+                marked.push(asm::asm::AA::BB(regs));
+                marked.extend_from_slice(&asm[blk.start..blk.end]);
+            }
+            AsmPrinter::print_raw(&marked);
         }
 
         if p.inter {
             let cfg = AsmCfg::build(&mut r, &asm);
-            let liveness = AsmLiveness::compute(cfg);
+            let liveness = AsmLiveness::analyze(&cfg);
             let (_, alist) = AsmAllocate::build_graph(
                 r.nids, &liveness
             );
@@ -158,15 +176,8 @@ fn visualize() {
 
         if p.coalcfg || p.coalasm || p.coalint {
             let cfg = AsmCfg::build(&mut r, &asm);
-            let mut liveness = AsmLiveness::compute(cfg);
-            let (mut amat, mut alist) = AsmAllocate::build_graph(
-                r.nids, &liveness
-            );
-            AsmAllocate::coalesce_graph(
-                &mut liveness, &mut alist, &mut amat
-            );
-            let mut asm = Vec::new();
-            for (a, _, _) in liveness { asm.push(a); }
+            let liveness = AsmLiveness::analyze(&cfg);
+            let (asm, _, alist) = AsmAllocate::coalesce_graph(&r, &liveness);
             let cfg = AsmCfg::build(&mut r, &asm);
             if p.coalint {
                 PrintInterference(alist);
@@ -179,11 +190,1960 @@ fn visualize() {
             }
         }
         
-        let liveness = AsmLiveness::compute(cfg);
-        let asm = AsmAllocate::allocate(&mut r, liveness);
+        let liveness = AsmLiveness::analyze(&cfg);
+        let asm = AsmAllocate::allocate(&mut r, &liveness);
         if p.asm2 { AsmPrinter::print(&asm); }
 
         println!("\n\n\n\n\n");
         i += 1;
     }
-}
\ No newline at end of file
+}
+
+fn build_cfg(input: &str, r: &mut Registry) -> ir::cfg::CFG {
+    let mut ast = ast::parser::moduleParser::new().parse(r, input).unwrap();
+    AstAnalyzer::new(r).analyze(&mut ast);
+    let ir  = IrTranslator::new(r).translate(&mut ast);
+    let lir = IrReducer::new(r).reduce(ir);
+    return IrCfgBuild(r, lir);
+}
+
+#[test]
+fn layout() {
+    let input = fs::read_to_string("tests/data/input4.c").unwrap();
+    let mut r = Registry::new();
+    let cfg = build_cfg(&input, &mut r);
+    let order = IrCfgReorder(&cfg);
+    // Functions are laid out one after the other, entry first.
+    let entries: Vec<usize> = order.iter().copied()
+        .filter(|n| cfg.starts.contains(n))
+        .collect();
+    assert!(entries.len() == cfg.starts.len());
+    assert!(order[0] == entries[0]);
+    let mut seen = vec![false; cfg.nodes.len()];
+    for n in &order {
+        assert!(!seen[*n], "node {} placed twice", n);
+        seen[*n] = true;
+    }
+    // Every jump left over either skips a block or closes a loop.
+    let fir = IrCfgExport(cfg, order);
+    let jumps = fir.iter().filter(|s| {
+        matches!(***s, ir::ir::Statement::Jump(_))
+    }).count();
+    assert!(jumps <= 4, "{} jumps left after layout", jumps);
+
+    // One hot loop with a branch in it.
+    let input = "
+    int main() {
+        int s = 0;
+        int i = 0;
+        while (i < 100) {
+            if (s > 1000) {
+                s = s - 1000;
+            }
+            s = s + i;
+            i = i + 1;
+        }
+        return s;
+    }";
+    let mut r = Registry::new();
+    let cfg = build_cfg(input, &mut r);
+    let loops = LoopForest::compute(&cfg, &Dominators::compute(&cfg));
+    assert!(loops.loops.len() == 1);
+    let l = &loops.loops[0];
+    let order = IrCfgReorder(&cfg);
+    let at = |n: usize| order.iter().position(|m| *m == n).unwrap();
+    // The body is one run of blocks, the header test at its bottom, so
+    // the back edge falls through into it.
+    let spots: Vec<usize> = l.body.iter().map(|n| at(*n)).collect();
+    let (lo, hi) = (*spots.iter().min().unwrap(), *spots.iter().max().unwrap());
+    assert!(hi - lo + 1 == l.body.len(), "{:?}", order);
+    assert!(at(l.header) == hi && l.latches.iter().any(|n| at(*n) == hi - 1), "{:?}", order);
+    // The block the loop exits to, which returns, comes after it.
+    for (_, to) in &l.exits { assert!(at(*to) > hi) }
+    // Inside the loop that's one jump less than reverse postorder leaves.
+    let jumps = |order: Vec<usize>| {
+        let mut r = Registry::new();
+        let cfg = build_cfg(input, &mut r);
+        let inside: Vec<u32> = l.body.iter().map(|n| *n as u32).collect();
+        let mut cur = None;
+        let mut count = 0;
+        for s in IrCfgExport(cfg, order) {
+            match *s {
+                ir::ir::Statement::Label(n) => cur = Some(n),
+                ir::ir::Statement::Jump(_) if cur.is_some_and(|n| inside.contains(&n)) => count += 1,
+                _ => ()
+            }
+        }
+        return count;
+    };
+    let naive = ir::reorder::naive(&cfg);
+    assert!(jumps(order) < jumps(naive));
+}
+
+#[test]
+fn simplify() {
+    use ir::ir::Statement;
+    for i in [0, 3, 4, 7] {
+        let input = fs::read_to_string(format!("tests/data/input{i}.c")).unwrap();
+        let mut r = Registry::new();
+        let cfg = build_cfg(&input, &mut r);
+        let before = cfg.nodes.len();
+        let cfg = IrCfgSimplify(&mut r, cfg);
+        assert!(cfg.nodes.len() <= before);
+        assert!(r.nlabels as usize == cfg.nodes.len());
+        for (idx, n) in cfg.nodes.iter().enumerate() {
+            // Every node is labelled with its own index.
+            match *n.stmts[0] {
+                Statement::Label(l) | Statement::Function(l, _) =>
+                    assert!(l as usize == idx),
+                _ => panic!("node {} has no label", idx)
+            }
+            if cfg.starts.contains(&idx) { continue }
+            let empty = n.stmts.iter().all(|s| {
+                matches!(**s, Statement::Label(_) | Statement::Jump(_))
+            });
+            assert!(!empty, "node {} should have been threaded", idx);
+            if let (Some(t), Some(f)) = (n.t, n.f) { assert!(t != f) }
+        }
+    }
+}
+
+#[test]
+fn dominators() {
+    let input = "
+        int main() {
+            int x = 0;
+            for (int i = 0; i < 10; i += 1) {
+                int j = 0;
+                while (j < i) {
+                    j += 1;
+                }
+                x += j;
+            }
+            return x;
+        }";
+    let mut r = Registry::new();
+    let cfg = build_cfg(input, &mut r);
+    let cfg = IrCfgSimplify(&mut r, cfg);
+    let dom = Dominators::compute(&cfg);
+    let entry = cfg.starts[0];
+    for n in 0..cfg.nodes.len() {
+        assert!(dom.dominates(entry, n));
+    }
+    assert!(dom.idom[entry].is_none());
+
+    let loops = LoopForest::compute(&cfg, &dom);
+    assert!(loops.loops.len() == 2);
+    let outer = loops.roots()[0];
+    let inner = loops.loops[outer].children[0];
+    assert!(loops.loops[inner].parent == Some(outer));
+    assert!(loops.loops[inner].depth == 2);
+    let (oh, ih) = (loops.loops[outer].header, loops.loops[inner].header);
+    assert!(dom.strictly_dominates(oh, ih));
+    assert!(loops.loops[inner].body.iter().all(|n| loops.contains(outer, *n)));
+    // The inner loop's latch has the inner header in its frontier.
+    let df = dom.frontiers(&cfg);
+    for l in &loops.loops[inner].latches {
+        assert!(df[*l].contains(&ih));
+    }
+
+    // The only return post-dominates everything.
+    let pdom = Dominators::post(&cfg);
+    let exit = (0..cfg.nodes.len())
+        .find(|n| cfg.nodes[*n].t.is_none() && cfg.nodes[*n].f.is_none())
+        .unwrap();
+    for n in 0..cfg.nodes.len() {
+        assert!(pdom.dominates(exit, n));
+    }
+
+    // The asm CFG works the same way, one node per instruction.
+    let frames = ir::cfgframer::Framer::new(&mut r, &cfg).frame();
+    let order = IrCfgReorder(&cfg);
+    let fir = IrCfgExport(cfg, order);
+    let asm = AsmTranslator::translate(&mut r, frames, fir);
+    let acfg = AsmCfg::build(&mut r, &asm);
+    let adom = Dominators::compute(&acfg);
+    let aloops = LoopForest::compute(&acfg, &adom);
+    assert!(aloops.loops.len() == 2);
+}
+
+#[test]
+fn pipeline() {
+    let args = |a: &[&str]| a.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+    let opts = Options::parse(&args(&[
+        "-O2", "--disable-pass=simplify,traces", "--print-after=layout", "in.c"
+    ])).unwrap();
+    assert!(opts.level == Level::O2);
+    assert!(opts.disabled("simplify") && opts.disabled("traces"));
+    assert!(opts.print_after("layout") && !opts.print_after("frame"));
+    assert!(opts.input.as_deref() == Some("in.c"));
+    assert!(Options::parse(&args(&["--bogus"])).is_err());
+    assert!(Options::parse(&args(&["a.c", "b.c"])).is_err());
+    // Misspelled passes are errors, not no-ops.
+    assert!(Options::parse(&args(&["--disable-pass=simplfy"])).is_err());
+    assert!(Options::parse(&args(&["--print-after=layuot"])).is_err());
+    assert!(Options::parse(&args(&["--disable-pass=select"])).is_err());
+    assert!(Options::parse(&args(&["--print-after=select,allocate,all"])).is_ok());
+
+    // Every stage registers its passes under the names the flags use.
+    let names = ir_passes().names();
+    assert!(["simplify", "layout", "traces"].iter().all(|p| names.contains(p)));
+
+    // Which passes actually ran, going by what --print-after=all printed.
+    let ran = |flags: &[&str]| {
+        let out = std::process::Command::new(env!("CARGO_BIN_EXE_compiler"))
+            .args(flags)
+            .args(["--print-after=all", "tests/data/input0.c"])
+            .output()
+            .unwrap();
+        assert!(out.status.success());
+        let out = String::from_utf8(out.stdout).unwrap();
+        return out.lines()
+            .filter_map(|l| l.strip_prefix("; after ").map(|p| p.to_string()))
+            .collect::<Vec<_>>();
+    };
+    assert!(ran(&["-O0"]) == ["frame", "layout", "schedule", "allocate", "schedule-late"]);
+    assert!(ran(&["-O1"]) == [
+        "simplify", "frame", "layout", "traces", "schedule", "allocate", "peephole", "schedule-late"
+    ]);
+    let o1 = ran(&["-O1", "--disable-pass=simplify,traces,peephole"]);
+    assert!(["simplify", "traces", "peephole"].iter().all(|p| !o1.contains(&p.to_string())));
+    // Required passes run anyway.
+    assert!(ran(&["-O1", "--disable-pass=layout,allocate"]).contains(&"layout".to_string()));
+
+    let mut i = 0;
+    while Path::new(&format!("tests/data/input{i}.c")).exists() {
+        let input = fs::read_to_string(format!("tests/data/input{i}.c")).unwrap();
+        for level in [Level::O0, Level::O1, Level::O2] {
+            let asm = compile(&input, &Options::new(level)).unwrap();
+            assert!(!asm.is_empty());
+        }
+        let mut opts = Options::new(Level::O2);
+        opts.disabled.push("simplify".to_string());
+        compile(&input, &opts).unwrap();
+        i += 1;
+    }
+    let err = compile("int main() { return 0 }", &Options::new(Level::O0));
+    assert!(matches!(err, Err(e) if e.contains("^")));
+}
+
+#[test]
+fn types() {
+    use ir::ir::{Expr, Statement, Type};
+    let input = "
+        float half(float x, int n) {
+            float y = x / 2.0;
+            if (y >= n) {
+                y = y - n;
+            }
+            return y;
+        }
+        int main() {
+            int a = 3;
+            int* p = &a;
+            *p = *p + 1;
+            float f = half(1.5, a);
+            return a;
+        }";
+    let mut r = Registry::new();
+    let mut ast = ast::parser::moduleParser::new().parse(&mut r, input).unwrap();
+    AstAnalyzer::new(&mut r).analyze(&mut ast);
+    let ir  = IrTranslator::new(&mut r).translate(&mut ast);
+    let lir = IrReducer::new(&mut r).reduce(ir);
+    fn walk(e: &Expr, f: &mut impl FnMut(&Expr)) {
+        f(e);
+        match e {
+            Expr::UnOp(_, e) | Expr::Mem(e, _) | Expr::Address(e) |
+            Expr::Convert(_, e) => walk(e, f),
+            Expr::BinOp(l, _, r) => { walk(l, f); walk(r, f); },
+            Expr::Call(_, v, _) => for e in v { walk(e, f) },
+            _ => ()
+        }
+    }
+    let (mut converts, mut stores) = (0, 0);
+    for s in &lir {
+        let mut count = |e: &Expr| match e {
+            // Ints meet floats only through a conversion.
+            Expr::Convert(Type::F64, e) => {
+                assert!(e.ty() == Type::I64);
+                converts += 1;
+            },
+            Expr::BinOp(l, _, r) => assert!(l.ty() == r.ty()),
+            _ => ()
+        };
+        match &**s {
+            Statement::Move(d, e) => {
+                if let Expr::Mem(a, t) = &**d {
+                    assert!(matches!(**a, Expr::Temp(_, Type::Ptr)));
+                    assert!(*t == Type::I64);
+                    stores += 1;
+                }
+                walk(e, &mut count);
+            },
+            Statement::CJump(e, _, _) => walk(e, &mut count),
+            Statement::Return(Some(e)) => walk(e, &mut count),
+            _ => ()
+        }
+    }
+    assert!(converts == 2);
+    assert!(stores == 1);
+
+    let cfg = IrCfgBuild(&mut r, lir);
+    let frames = ir::cfgframer::Framer::new(&mut r, &cfg).frame();
+    let order = IrCfgReorder(&cfg);
+    let asm = AsmTranslator::translate(&mut r, frames, IrCfgExport(cfg, order));
+    let has = |f: fn(&asm::asm::AA) -> bool| asm.iter().any(f);
+    assert!(has(|a| matches!(a, asm::asm::AA::FDiv(..))));
+    assert!(has(|a| matches!(a, asm::asm::AA::FSub(..))));
+    assert!(has(|a| matches!(a, asm::asm::AA::FCmp(..))));
+    assert!(has(|a| matches!(a, asm::asm::AA::SCvtF(..))));
+    assert!(!has(|a| matches!(a, asm::asm::AA::SDiv(..))));
+}
+
+#[test]
+fn frames() {
+    let input = "
+        int set(int* p, int v) {
+            *p = v;
+            return v;
+        }
+        int main() {
+            int a = 1;
+            int b = 2;
+            int c = 3;
+            if (a < b) {
+                c = 4;
+            } else {
+                set(&b, set(&a, 5));
+            }
+            set(&c, 6);
+            return b;
+        }";
+    let mut r = Registry::new();
+    let cfg = build_cfg(input, &mut r);
+    let frames = ir::cfgframer::Framer::new(&mut r, &cfg).frame();
+    // p = 0, v = 1, a = 2, b = 3, c = 4.
+    assert!(frames.slot(0).is_none() && frames.slot(1).is_none());
+    let mut slots: Vec<usize> = (2..5).map(|i| frames.slot(i).unwrap()).collect();
+    slots.sort();
+    // Packed, 8 bytes each, rounded up to keep SP aligned.
+    assert!(slots == vec![0, 8, 16]);
+    assert!(frames.sizes == vec![32, 0]);
+}
+
+#[test]
+fn tiles() {
+    use asm::asm::AA;
+    let input = "
+        int f(int a, int b, int c) {
+            return a + b * c;
+        }
+        int g(int a, int b) {
+            return -(a * b);
+        }
+        int main() {
+            return f(1, 2, 3) - g(4, 5) + 7 % 3;
+        }";
+    let mut r = Registry::new();
+    let cfg = build_cfg(input, &mut r);
+    let frames = ir::cfgframer::Framer::new(&mut r, &cfg).frame();
+    let order = IrCfgReorder(&cfg);
+    let asm = AsmTranslator::translate(&mut r, frames, IrCfgExport(cfg, order));
+    let count = |f: fn(&AA) -> bool| asm.iter().filter(|a| f(a)).count();
+    // The multiplies fold into the larger tiles.
+    assert!(count(|a| matches!(a, AA::SMAddL(..))) == 1);
+    assert!(count(|a| matches!(a, AA::SMNegL(..))) == 1);
+    assert!(count(|a| matches!(a, AA::SMulL(..))) == 1);
+    assert!(count(|a| matches!(a, AA::SDiv(..))) == 1);
+    assert!(count(|a| matches!(a, AA::Neg2(..))) == 0);
+}
+
+#[test]
+fn nan() {
+    use asm::asm::{AA, CC};
+    // NZCV after fcmp: less, equal, greater and unordered, which is what
+    // any comparison with a NaN gives.
+    let flags = [(true, false, false, false), (false, true, true, false),
+                 (false, false, true, false), (false, false, true, true)];
+    let holds = |c: CC, (n, z, cf, v): (bool, bool, bool, bool)| match c {
+        CC::EQ => z,         CC::NE => !z,
+        CC::GE => n == v,    CC::LT => n != v,
+        CC::GT => !z && n == v, CC::LE => z || n != v,
+        CC::HI => cf && !z,  CC::LS => !cf || z,
+        CC::HS => cf,        CC::LO => !cf,
+        CC::MI => n,         CC::PL => !n,
+    };
+    let ops = [("<",  [true,  false, false]), ("<=", [true,  true,  false]),
+               (">",  [false, false, true]),  (">=", [false, true,  true]),
+               ("==", [false, true,  false]), ("!=", [true,  false, true])];
+    for (op, want) in ops {
+        let input = format!("
+            float f(float a, float b) {{
+                float r = a {op} b;
+                return r;
+            }}
+            int main() {{
+                float x = f(0.0 / 0.0, 1.0);
+                return 0;
+            }}");
+        let mut r = Registry::new();
+        let asm = select(&input, &mut r);
+        let c = asm.iter().find_map(|a| match a { AA::CSET(_, c) => Some(*c), _ => None }).unwrap();
+        for (f, w) in flags.iter().zip(want) { assert!(holds(c, *f) == w, "{} {}", op, c); }
+        // Only != holds for a NaN.
+        assert!(holds(c, flags[3]) == (op == "!="), "NaN {} 1.0 with {}", op, c);
+    }
+}
+
+#[test]
+fn immediates() {
+    use asm::asm::{AA, Reg};
+    use asm::imm;
+    assert!(imm::arith(4095) && imm::arith(-4095) && imm::arith(4096 * 3));
+    assert!(!imm::arith(4097) && !imm::arith(1 << 24));
+    assert!(imm::logical(0xff) && imm::logical(0x5555555555555555));
+    assert!(imm::logical(0xff0) && !imm::logical(0) && !imm::logical(-1));
+    assert!(!imm::logical(0x5));
+    assert!(imm::offset(8 * 4095) && imm::offset(-256) && !imm::offset(4100));
+    let input = "
+        int f(int a, int* p) {
+            *p = a - 12;
+            if (a > 3) {
+                return a + 4096;
+            }
+            return a + 5;
+        }
+        int main() {
+            int x = 0;
+            return f(81985529216486895, &x);
+        }";
+    let mut r = Registry::new();
+    let cfg = build_cfg(input, &mut r);
+    let frames = ir::cfgframer::Framer::new(&mut r, &cfg).frame();
+    let order = IrCfgReorder(&cfg);
+    let asm = AsmTranslator::translate(&mut r, frames, IrCfgExport(cfg, order));
+    let count = |f: fn(&AA) -> bool| asm.iter().filter(|a| f(a)).count();
+    // Small constants fold into the instruction.
+    assert!(count(|a| matches!(a, AA::Add2(..) | AA::Sub2(..) | AA::CMP2(..))) == 0);
+    assert!(count(|a| matches!(a, AA::CMP1(..))) == 1);
+    assert!(count(|a| matches!(a, AA::Add1(_, Reg::ID(_), _))) == 2);
+    assert!(count(|a| matches!(a, AA::Sub1(_, Reg::ID(_), _))) == 1);
+    // 0x0123456789abcdef takes all four chunks.
+    assert!(count(|a| matches!(a, AA::MovZ(..))) == 1);
+    assert!(count(|a| matches!(a, AA::MovK(..))) == 3);
+}
+
+#[test]
+fn branches() {
+    use asm::asm::{AA, CC};
+    let input = "
+        int f(int a, int b) {
+            int s = 0;
+            while (a < b) {
+                a = a + 1;
+                if (a == 0) {
+                    s = s + 1;
+                }
+                if (b != 0) {
+                    s = s - 1;
+                }
+            }
+            return s;
+        }
+        int main() {
+            return f(1, 2);
+        }";
+    let mut r = Registry::new();
+    let cfg = build_cfg(input, &mut r);
+    let frames = ir::cfgframer::Framer::new(&mut r, &cfg).frame();
+    let order = IrCfgReorder(&cfg);
+    let asm = AsmTranslator::translate(&mut r, frames, IrCfgExport(cfg, order));
+    let count = |f: fn(&AA) -> bool| asm.iter().filter(|a| f(a)).count();
+    // Nothing gets materialized just to be branched on.
+    assert!(count(|a| matches!(a, AA::CSET(..))) == 0);
+    assert!(count(|a| matches!(a, AA::Mvn1(..) | AA::Mvn2(..))) == 0);
+    assert!(count(|a| matches!(a, AA::B2(..))) == 1);
+    assert!(count(|a| matches!(a, AA::CBZ(..) | AA::CBNZ(..))) == 2);
+    // A conditional branch falls through when it isn't taken.
+    let acfg = AsmCfg::build(&r, &asm);
+    let i = asm.iter().position(|a| matches!(a, AA::B2(..))).unwrap();
+    let n = acfg.nodes.iter().find(|n| n.idx == i).unwrap();
+    assert!(n.t.is_some() && n.f.is_some());
+    assert!(matches!(asm[i], AA::B2(CC::LT | CC::GE, _)));
+}
+
+#[test]
+fn peephole() {
+    use asm::asm::{AA::*, Reg::*, CC, Const};
+    let mut r = Registry::new();
+    r.nlabels = 4;
+    let asm = vec![
+        Label(0),
+        Mov2(R(1), R(1)),
+        Mov2(R(2), R(3)),
+        Mov2(R(3), R(2)),
+        STR1(R(2), R(29), Const::Int(-8)),
+        LDR1(R(4), R(29), Const::Int(-8)),
+        CMP2(R(2), R(4)),
+        CSET(R(5), CC::LT),
+        CBNZ(R(5), 1),
+        B1(2),
+        Label(1),
+        Mov1(R(0), Const::Int(1)),
+        B1(3),
+        Label(3),
+        Ret,
+        Label(2),
+        Mov1(R(0), Const::Int(0)),
+        Ret,
+    ];
+    let res = asm::peephole::optimize(&r, asm);
+    let res: Vec<String> = res.iter().map(|a| format!("{}", a)).collect();
+    let expected: Vec<String> = vec![
+        Label(0),
+        Mov2(R(2), R(3)),
+        STR1(R(2), R(29), Const::Int(-8)),
+        Mov2(R(4), R(2)),
+        CMP2(R(2), R(4)),
+        B2(CC::GE, 2),
+        Label(1),
+        Mov1(R(0), Const::Int(1)),
+        Label(3),
+        Ret,
+        Label(2),
+        Mov1(R(0), Const::Int(0)),
+        Ret,
+    ].iter().map(|a| format!("{}", a)).collect();
+    assert!(res == expected, "{:#?}", res);
+}
+
+#[test]
+fn coloring() {
+    use asm::asm::{AA, Reg};
+    // Twelve values live across a call, with ten callee-saved registers.
+    let input = "
+        int id(int x) {
+            return x;
+        }
+        int main() {
+            int a = 1; int b = 2; int c = 3; int d = 4;
+            int e = 5; int f = 6; int g = 7; int h = 8;
+            int i = 9; int j = 10; int k = 11; int l = 12;
+            int m = id(13);
+            return a + b + c + d + e + f + g + h + i + j + k + l + m;
+        }";
+    let asm = compile(input, &Options::new(Level::O0)).unwrap();
+    let regs = |a: &AA| { let (d, u) = a.defuse(); d.into_iter().chain(u) };
+    assert!(asm.iter().flat_map(regs).all(|r| !matches!(r, Reg::ID(_))));
+    // So at least two of them get spilled under main's frame.
+    let spills = asm.iter().filter(|a| matches!(a, AA::STR1(_, Reg::R(29), _))).count();
+    assert!(spills >= 2);
+    assert!(asm.iter().any(|a| matches!(a, AA::Sub1(Reg::SP, Reg::SP, _))));
+    // id writes no callee-saved register, so it saves none.
+    let id = asm.iter().position(|a| matches!(a, AA::Label(1))).unwrap();
+    assert!(asm[id..].iter().all(|a| !matches!(a, AA::STR1(Reg::R(19..=28), _, _))));
+}
+
+#[test]
+fn linearscan() {
+    use asm::asm::{AA, Reg};
+    let args = |a: &[&str]| a.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+    assert!(Options::new(Level::O0).regalloc() == Regalloc::Linear);
+    assert!(Options::new(Level::O2).regalloc() == Regalloc::Coloring);
+    let opts = Options::parse(&args(&["-O0", "--regalloc=coloring"])).unwrap();
+    assert!(opts.regalloc() == Regalloc::Coloring);
+
+    let regs = |a: &AA| { let (d, u) = a.defuse(); d.into_iter().chain(u) };
+    let mut i = 0;
+    while Path::new(&format!("tests/data/input{i}.c")).exists() {
+        let input = fs::read_to_string(format!("tests/data/input{i}.c")).unwrap();
+        let mut opts = Options::new(Level::O1);
+        opts.regalloc = Some(Regalloc::Linear);
+        let asm = compile(&input, &opts).unwrap();
+        assert!(asm.iter().flat_map(regs).all(|r| !matches!(r, Reg::ID(_))));
+        i += 1;
+    }
+    // Eleven values live across a call, one of them has to go.
+    let input = "
+        int id(int x) {
+            return x;
+        }
+        int main() {
+            int a = 1; int b = 2; int c = 3; int d = 4;
+            int e = 5; int f = 6; int g = 7; int h = 8;
+            int i = 9; int j = 10; int k = 11;
+            int m = id(13);
+            return a + b + c + d + e + f + g + h + i + j + k + m;
+        }";
+    let asm = compile(input, &Options::new(Level::O0)).unwrap();
+    assert!(asm.iter().flat_map(regs).all(|r| !matches!(r, Reg::ID(_))));
+    let spills = asm.iter().filter(|a| matches!(a, AA::STR1(_, Reg::R(29), _))).count();
+    assert!(spills == 1);
+}
+
+#[test]
+fn verify() {
+    use asm::asm::{AA, Reg};
+    use asm::verify::verify;
+    let spill = "
+        int id(int x) {
+            return x;
+        }
+        int main() {
+            int a = 1; int b = 2; int c = 3; int d = 4;
+            int e = 5; int f = 6; int g = 7; int h = 8;
+            int i = 9; int j = 10; int k = 11;
+            int m = id(13);
+            return a + b + c + d + e + f + g + h + i + j + k + m;
+        }".to_string();
+    let mut inputs: Vec<String> = (0..)
+        .map(|i| format!("tests/data/input{i}.c"))
+        .take_while(|p| Path::new(p).exists())
+        .map(|p| fs::read_to_string(p).unwrap())
+        .collect();
+    inputs.push(spill.clone());
+    inputs.push(big(40));
+    let allocate = |input: &str, linear: bool| {
+        let mut r = Registry::new();
+        let before = select(input, &mut r);
+        let live = AsmLiveness::analyze(&AsmCfg::build(&r, &before));
+        let after = match linear {
+            true  => asm::linearscan::allocate(&mut r, &live),
+            false => AsmAllocate::allocate(&mut r, &live),
+        };
+        return (r, before, after);
+    };
+    for input in &inputs {
+        for linear in [true, false] {
+            let (r, before, after) = allocate(input, linear);
+            if let Err(e) = verify(&r, &before, &after) {
+                panic!("{}", e.iter().map(|e| e.to_string()).collect::<Vec<_>>().join("\n"));
+            }
+        }
+    }
+    let (r, before, after) = allocate(&spill, false);
+    // Without the spill store the reload reads garbage.
+    let at = after.iter().position(|a| matches!(a, AA::STR1(_, Reg::R(29), _))).unwrap();
+    let mut bad = after.clone();
+    bad.remove(at);
+    let errs = verify(&r, &before, &bad).unwrap_err();
+    assert!(errs.iter().any(|e| e.at > at && e.msg.contains("reloads")));
+
+    // Reading the wrong register.
+    let at = after.iter().position(|a| matches!(a, AA::Add2(..))).unwrap();
+    let mut bad = after.clone();
+    let AA::Add2(d, a, b) = bad[at] else { unreachable!() };
+    let other = (0..29).map(|i| Reg::R(i)).find(|x| *x != a && *x != b).unwrap();
+    bad[at] = AA::Add2(d, other, b);
+    let errs = verify(&r, &before, &bad).unwrap_err();
+    assert!(errs.iter().any(|e| e.at == at));
+
+    // Arguments have to stay where the convention puts them.
+    let at = after.windows(2)
+        .position(|w| matches!((&w[0], &w[1]), (AA::Mov2(Reg::R(0), _), AA::BL(_))))
+        .unwrap();
+    let mut bad = after.clone();
+    let AA::Mov2(_, s) = bad[at] else { unreachable!() };
+    bad[at] = AA::Mov2(Reg::R(1), s);
+    let errs = verify(&r, &before, &bad).unwrap_err();
+    assert!(errs.iter().any(|e| e.at == at && e.msg.contains("has to be in")));
+
+    // Forgetting to restore a callee-saved register.
+    let input = fs::read_to_string("tests/data/input4.c").unwrap();
+    let (r, before, after) = allocate(&input, false);
+    let at = after.iter().position(|a| matches!(a, AA::LDR1(Reg::R(19), Reg::R(29), _))).unwrap();
+    let mut bad = after.clone();
+    bad.remove(at);
+    let errs = verify(&r, &before, &bad).unwrap_err();
+    assert!(errs.iter().any(|e| e.msg.contains("X19 isn't restored")));
+}
+
+#[test]
+fn schedule() {
+    use asm::asm::AA;
+    use asm::schedule::{latency, schedule};
+    use std::collections::HashMap;
+    // Runs the code straight through on symbols, what every register holds
+    // at each barrier and what gets stored has to come out the same.
+    let mut values: HashMap<(String, Vec<usize>), usize> = HashMap::new();
+    let mut run = |code: &[AA]| {
+        let mut intern = |k: (String, Vec<usize>)| {
+            let n = values.len();
+            *values.entry(k).or_insert(n)
+        };
+        let (mut env, mut res): (HashMap<usize, usize>, Vec<Vec<usize>>) = Default::default();
+        let (mut stores, mut flags) = (0, 0);
+        for a in code {
+            let (defs, uses) = a.defuse();
+            let text = a.to_string();
+            let mut args: Vec<usize> = uses.iter()
+                .map(|u| *env.entry(u.index()).or_insert(usize::MAX - u.index()))
+                .collect();
+            match a {
+                AA::LDR1(..) | AA::LDR2(..) => args.push(stores),
+                AA::CSET(..) => args.push(flags),
+                _ => ()
+            }
+            let v = intern((text, args.clone()));
+            match a {
+                AA::STR1(..) | AA::STR2(..) => { stores = v; res.push(vec![v]); },
+                AA::CMP1(..) | AA::CMP2(..) | AA::FCmp(..) => flags = v,
+                _ => ()
+            }
+            for (i, d) in defs.iter().enumerate() {
+                env.insert(d.index(), intern((format!("{}", i), vec![v])));
+            }
+            if matches!(a, AA::Label(_) | AA::B1(_) | AA::B2(..) | AA::BL(_) | AA::CBZ(..)
+                    | AA::CBNZ(..) | AA::Ret | AA::SVC(_)) {
+                let mut snap: Vec<(usize, usize)> = env.iter().map(|(k, v)| (*k, *v)).collect();
+                snap.sort();
+                res.push(snap.into_iter().flat_map(|(k, v)| [k, v]).collect());
+                res.push(vec![v]);
+            }
+        }
+        return res;
+    };
+    let mut inputs: Vec<String> = (0..)
+        .map(|i| format!("tests/data/input{i}.c"))
+        .take_while(|p| Path::new(p).exists())
+        .map(|p| fs::read_to_string(p).unwrap())
+        .collect();
+    inputs.push(big(40));
+    for input in &inputs {
+        let mut r = Registry::new();
+        let asm = select(input, &mut r);
+        for pressure in [true, false] {
+            let out = schedule(&r, asm.clone(), pressure);
+            assert!(out.len() == asm.len());
+            assert!(run(&asm) == run(&out));
+        }
+        let live = AsmLiveness::analyze(&AsmCfg::build(&r, &asm));
+        let allocated = AsmAllocate::allocate(&mut r, &live);
+        let out = schedule(&r, allocated.clone(), false);
+        assert!(run(&allocated) == run(&out));
+    }
+    // The division gets something to do while it finishes.
+    let input = "
+        int main() {
+            int a = 100; int b = 7; int c = 3; int d = 4;
+            int q = a / b;
+            int s = c + d;
+            return q + s;
+        }";
+    let mut r = Registry::new();
+    let asm = select(input, &mut r);
+    let out = schedule(&r, asm, true);
+    let div = out.iter().position(|a| matches!(a, AA::SDiv(..))).unwrap();
+    let AA::SDiv(q, _, _) = out[div] else { unreachable!() };
+    let next = out.iter().skip(div + 1).position(|a| a.defuse().1.contains(&q)).unwrap();
+    assert!(next > 0 && latency(&out[div]) > 1);
+    for o in ["-O0", "-O2"] {
+        for s in ["--schedule=before", "--schedule=after"] {
+            let opts = Options::parse(&[o.to_string(), s.to_string()]).unwrap();
+            compile(&big(40), &opts).unwrap();
+        }
+    }
+}
+
+#[test]
+fn x86() {
+    use asm::asm::Reg;
+    use compiler::pipeline::options::Arch;
+    use compiler::pipeline::passes::compile_x86;
+    use compiler::x86::asm::{X86, RAX, RDX};
+    use compiler::x86::printer::Printer as X86Printer;
+    use std::process::Command;
+    let args = |a: &[&str]| a.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+    assert!(Options::new(Level::O0).arch == Arch::AArch64);
+    assert!(Options::parse(&args(&["--target=x86-64"])).unwrap().arch == Arch::X86_64);
+    // The asm passes are AArch64 only, so their flags are refused.
+    for bad in ["--verify-regalloc", "--schedule=before", "--disable-pass=peephole", "--print-after=schedule-late"] {
+        assert!(Options::parse(&args(&["--target=x86-64", bad])).is_err());
+        assert!(Options::parse(&args(&[bad])).is_ok());
+    }
+    assert!(Options::parse(&args(&["--target=x86-64", "-O1", "--print-after=select,allocate,all"])).is_ok());
+    // Temps are numbered right after x86's own registers.
+    {
+        use asm::target::Target;
+        use compiler::x86::asm::X86_64;
+        assert!(X86_64::index(Reg::SP) == 16 && X86_64::index(Reg::ID(0)) == X86_64::REGS);
+        for i in 0..X86_64::REGS + 4 { assert!(X86_64::index(X86_64::reg(i)) == i); }
+    }
+
+    let regs = |a: &compiler::x86::asm::X86| { let (d, u) = a.defuse(); d.into_iter().chain(u) };
+    let programs = [
+        ("
+        int factorial(int n) {
+            if (n <= 1) {
+                return 1;
+            }
+            return n * factorial(n - 1);
+        }
+        int fib(int n) {
+            if (n < 2) {
+                return n;
+            }
+            return fib(n - 1) + fib(n - 2);
+        }
+        int main() {
+            return factorial(5) - fib(10);
+        }", 65),
+        // idiv truncates towards zero, the remainder takes the dividend's sign.
+        ("
+        int div(int a, int b) {
+            return a / b;
+        }
+        int mod(int a, int b) {
+            return a % b;
+        }
+        int main() {
+            int a = 0 - 17;
+            return div(a, 5) * 10 + mod(a, 5) + 100 / 7 + 100 % 7;
+        }", 256 - 16),
+        ("
+        int set(int* p, int v) {
+            *p = v;
+            return v;
+        }
+        int main() {
+            int a = 1;
+            int b = 2;
+            set(&b, set(&a, 5) + 2);
+            return a * 10 + b;
+        }", 57),
+        ("
+        float half(float x) {
+            return x / 2.0;
+        }
+        int main() {
+            float a = 7.5;
+            float b = half(a) + 0.5;
+            int c = 0;
+            if (b > 4.0) {
+                c = c + 1;
+            }
+            if (b < 4.0) {
+                c = c + 10;
+            }
+            if (a >= b) {
+                c = c + 100;
+            }
+            float d = -b;
+            if (d <= 0.0 - 4.25) {
+                c = c + 20;
+            }
+            float e = 0.5;
+            e += c;
+            if (e == 121.5) {
+                c = c + 2;
+            }
+            return c;
+        }", 123),
+        // More values live across the call than there are callee-saved registers.
+        ("
+        int id(int x) {
+            return x;
+        }
+        int main() {
+            int a = 1; int b = 2; int c = 3; int d = 4;
+            int e = 5; int f = 6; int g = 7; int h = 8;
+            int i = 9; int j = 10; int k = 11; int l = 12;
+            int m = id(13);
+            return a + b + c + d + e + f + g + h + i + j + k + l + m;
+        }", 91),
+        // The divisor comes back in rax, where idiv can't take it.
+        ("
+        int seven() {
+            return 7;
+        }
+        int main() {
+            int a = 100;
+            return a / seven() + a % seven();
+        }", 16),
+        // Strings are bytes in .rodata, chars wrap at 256.
+        ("
+        int len(char* s) {
+            int n = 0;
+            while (*s != '\\0') {
+                n += 1;
+                s = s + 1;
+            }
+            return n;
+        }
+        char upper(char c) {
+            return c - 32;
+        }
+        int main() {
+            char* s = \"hello\\tworld\\n\";
+            char c = upper('a');
+            char w = 'a' + 200;
+            char* p = &c;
+            *p = *p + 1;
+            char* q = s + 6;
+            return len(s) + len(\"hello\\tworld\\n\") + c + w + (*q == 'w');
+        }", 132),
+        // Structs are laid out with padding, members go through pointers too.
+        ("
+        struct point {
+            char tag;
+            int x;
+            int y;
+        };
+        struct line {
+            struct point a;
+            char c;
+            struct point b;
+        };
+        struct node {
+            int v;
+            struct node* next;
+        };
+        int len(struct point* p) {
+            return p->x * p->x + p->y * p->y;
+        }
+        void shift(struct line* l, int d) {
+            l->a.x += d;
+            l->b.y = l->b.y - d;
+            return;
+        }
+        int sum(struct node* n, int k) {
+            int s = 0;
+            while (k > 0) {
+                s += n->v;
+                n = n->next;
+                k -= 1;
+            }
+            return s;
+        }
+        int main() {
+            struct point p;
+            p.x = 3;
+            p.y = 4;
+            p.tag = 'p';
+            struct line l;
+            l.a.x = 1;
+            l.c = 'c';
+            l.b.y = 6;
+            shift(&l, 10);
+            struct node n1;
+            struct node n2;
+            n1.v = 7;
+            n1.next = &n2;
+            n2.v = 9;
+            int* q = &p.y;
+            *q = *q + 1;
+            return len(&p) + l.a.x + l.b.y + sum(&n1, 2) + p.tag - l.c;
+        }", 70),
+        // Switches fall through, break out, and pick a table or a compare tree.
+        ("
+        int dense(int x) {
+            int r = 0;
+            switch (x) {
+                case 0: r = 10; break;
+                case 1: r = 20;
+                case 2: r += 5; break;
+                case 3: r = 40; break;
+                case 5: return 7;
+                default: r = 1;
+            }
+            return r;
+        }
+        int sparse(int x) {
+            switch (x) {
+                case -100: return 1;
+                case 7: return 2;
+                case 1000: return 3;
+                case 'a': return 4;
+                case 99999: return 5;
+            }
+            return 0;
+        }
+        int main() {
+            int s = 0;
+            int i = 0;
+            while (i < 8) {
+                i += 1;
+                switch (i % 4) {
+                    case 0: continue;
+                    case 1:
+                        switch (i) { case 1: s += 100; break; default: s += 1000; }
+                        break;
+                    default: s += i;
+                }
+                s += 1;
+            }
+            return dense(0) + dense(1) + dense(2) + dense(3) + dense(4) + dense(5) + dense(-3)
+                + sparse(-100) * 10 + sparse(7) + sparse(1000) + sparse(97) + sparse(99999)
+                + sparse(8) - s / 20;
+        }", 57),
+        // Bitwise operators bind between && and ==, shifts between < and +.
+        ("
+        int shl(int x, int n) {
+            return x << n;
+        }
+        int shr(int x, int n) {
+            return x >> n;
+        }
+        int main() {
+            int a = 240;
+            int b = 60;
+            int m = -16;
+            char c = 'a';
+            int r = (a & b) + (a | b) + (a ^ b) + ~b;
+            r += 1 << 4 >> 2;
+            r += shl(3, 5) + shr(m, 2) + (m >> 1) + (c & ~32);
+            r += 1 + 2 << 1;
+            r += 6 & 3 == 3;
+            r += 5 | 2 ^ 7 & 4;
+            int x = 12;
+            x &= 10;
+            x |= 1;
+            x ^= 15;
+            x <<= 3;
+            x >>= 1;
+            c <<= 2;
+            c >>= 1;
+            return r + x + c - 650;
+        }", 49),        // && and || are 1 or 0 as values too, and skip what they don't need.
+        ("
+        int hit(int* p) {
+            *p = *p + 1;
+            return 1;
+        }
+        int main() {
+            int n = 0;
+            int a = 2 && 1;
+            int b = 2 & 1;
+            int c = 0 || 4;
+            int d = 0 && hit(&n);
+            int e = 3 || hit(&n);
+            int f = 1 && hit(&n);
+            int g = (2 && 1) == 1;
+            return a + b * 2 + c * 4 + d * 8 + e * 16 + f * 32 + n * 64 + g * 128;
+        }", 245),
+    ];
+    let mut inputs: Vec<(String, Option<i32>)> = (0..6)
+        .map(|i| (fs::read_to_string(format!("tests/data/input{i}.c")).unwrap(), None))
+        .collect();
+    inputs.extend(programs.iter().map(|(p, c)| (p.to_string(), Some(*c))));
+    // Inline asm is AArch64.
+    let inline = fs::read_to_string("tests/data/input6.c").unwrap();
+    assert!(compile_x86(&inline, &Options::new(Level::O0)).is_err());
+
+    // Only actually run anything on an x86-64 host with binutils.
+    let tools = cfg!(all(target_arch = "x86_64", target_os = "linux"))
+        && ["as", "ld"].iter().all(|t| Command::new(t).arg("--version").output().is_ok());
+    let dir = std::env::temp_dir().join(format!("cflat-x86-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    for (n, (input, code)) in inputs.iter().enumerate() {
+        for ra in ["--regalloc=linear", "--regalloc=coloring"] {
+            let opts = Options::parse(&args(&["-O1", "--target=x86-64", ra])).unwrap();
+            let asm = compile_x86(input, &opts).unwrap();
+            assert!(asm.iter().flat_map(regs).all(|r| !matches!(r, Reg::ID(_))));
+            assert!(asm.iter().all(|a| !matches!(a, X86::IDiv(RAX | RDX))));
+            let (Some(code), true) = (code, tools) else { continue };
+            let (s, o, bin) = (dir.join(format!("{n}.s")), dir.join(format!("{n}.o")), dir.join(format!("{n}")));
+            fs::write(&s, X86Printer::text(&asm)).unwrap();
+            assert!(Command::new("as").arg(&s).arg("-o").arg(&o).status().unwrap().success());
+            assert!(Command::new("ld").arg(&o).arg("-o").arg(&bin).status().unwrap().success());
+            let status = Command::new(&bin).status().unwrap();
+            assert!(status.code() == Some(*code), "{} {}: {:?}", n, ra, status);
+        }
+    }
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn riscv() {
+    use asm::asm::{Reg, CC};
+    use compiler::pipeline::options::Arch;
+    use compiler::pipeline::passes::compile_riscv;
+    use compiler::riscv::asm::RV;
+    use compiler::riscv::tiles::li;
+    use std::collections::HashMap;
+    let args = |a: &[&str]| a.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+    assert!(Options::parse(&args(&["--target=riscv64"])).unwrap().arch == Arch::RiscV64);
+    for bad in ["--verify-regalloc", "--schedule=after", "--disable-pass=schedule", "--print-after=peephole"] {
+        assert!(Options::parse(&args(&["--target=riscv64", bad])).is_err());
+    }
+    assert!(Options::parse(&args(&["--target=riscv64", "--print-after=allocate"])).is_ok());
+
+    // There's no RISC-V machine around, so this runs the instructions
+    // themselves. Returns main's exit code. Memory is bytes, strings get
+    // laid out above the stack the first time lla asks for them.
+    let run = |code: &[RV]| -> i64 {
+        let labels: HashMap<u32, usize> = code.iter().enumerate()
+            .filter_map(|(i, a)| match a { RV::Label(l) => Some((*l, i)), _ => None })
+            .collect();
+        let mut regs: HashMap<Reg, i64> = HashMap::from([(Reg::SP, 1 << 20)]);
+        let mut mem: HashMap<i64, u8> = HashMap::new();
+        let mut strs: Vec<(Vec<u8>, i64)> = Vec::new();
+        let mut top = 1 << 24;
+        let mut pc = labels.get(&0).copied().unwrap_or(0);
+        for _ in 0..1_000_000 {
+            let get = |r: &Reg| if *r == Reg::RZR { 0 } else { *regs.get(r).unwrap_or(&0) };
+            let mut next = pc + 1;
+            let w = match code.get(pc).cloned().unwrap_or(RV::Ret) {
+                RV::Lui(d, k)      => Some((d, ((k & 0xfffff) << 12) as i32 as i64)),
+                RV::Addi(d, s, k)  => Some((d, get(&s).wrapping_add(k))),
+                RV::Addiw(d, s, k) => Some((d, get(&s).wrapping_add(k) as i32 as i64)),
+                RV::Slli(d, s, k)  => Some((d, get(&s) << k)),
+                RV::Srai(d, s, k)  => Some((d, get(&s) >> k)),
+                RV::Mv(d, s)       => Some((d, get(&s))),
+                RV::Add(d, l, r)   => Some((d, get(&l).wrapping_add(get(&r)))),
+                RV::Sub(d, l, r)   => Some((d, get(&l).wrapping_sub(get(&r)))),
+                RV::Mul(d, l, r)   => Some((d, get(&l).wrapping_mul(get(&r)))),
+                RV::Div(d, l, r)   => Some((d, get(&l) / get(&r))),
+                RV::Rem(d, l, r)   => Some((d, get(&l) % get(&r))),
+                RV::And(d, l, r)   => Some((d, get(&l) & get(&r))),
+                RV::Or(d, l, r)    => Some((d, get(&l) | get(&r))),
+                RV::Xor(d, l, r)   => Some((d, get(&l) ^ get(&r))),
+                RV::Sll(d, l, r)   => Some((d, get(&l) << (get(&r) & 63))),
+                RV::Sra(d, l, r)   => Some((d, get(&l) >> (get(&r) & 63))),
+                RV::Andi(d, s, k)  => Some((d, get(&s) & k)),
+                RV::Ori(d, s, k)   => Some((d, get(&s) | k)),
+                RV::Xori(d, s, k)  => Some((d, get(&s) ^ k)),
+                RV::Slt(d, l, r)   => Some((d, (get(&l) < get(&r)) as i64)),
+                RV::Sltu(d, l, r)  => Some((d, ((get(&l) as u64) < get(&r) as u64) as i64)),
+                RV::Sltiu(d, s, k) => Some((d, ((get(&s) as u64) < k as u64) as i64)),
+                RV::Ld(d, b, o)    => {
+                    let a = get(&b) + o;
+                    Some((d, (0..8).fold(0, |v, i| v | (*mem.get(&(a + i)).unwrap_or(&0) as i64) << (8 * i))))
+                },
+                RV::Sd(s, b, o)    => {
+                    let (a, v) = (get(&b) + o, get(&s));
+                    for i in 0..8 { mem.insert(a + i, (v >> (8 * i)) as u8); }
+                    None
+                },
+                RV::Lbu(d, b, o)   => Some((d, *mem.get(&(get(&b) + o)).unwrap_or(&0) as i64)),
+                RV::Sb(s, b, o)    => { mem.insert(get(&b) + o, get(&s) as u8); None },
+                RV::La(d, s)       => match strs.iter().find(|(t, _)| *t == s) {
+                    Some((_, at)) => Some((d, *at)),
+                    None => {
+                        for (i, c) in s.iter().chain([&0]).enumerate() { mem.insert(top + i as i64, *c); }
+                        strs.push((s.clone(), top));
+                        top += s.len() as i64 + 1;
+                        Some((d, top - s.len() as i64 - 1))
+                    }
+                },
+                RV::J(l)           => { next = labels[&l]; None },
+                RV::B(c, l, r, t)  => {
+                    let (l, r) = (get(&l), get(&r));
+                    let taken = match c {
+                        CC::EQ => l == r, CC::NE => l != r,
+                        CC::LT => l < r,  CC::GE => l >= r,
+                        CC::GT => l > r,  CC::LE => l <= r,
+                        _ => unreachable!()
+                    };
+                    if taken { next = labels[&t]; }
+                    None
+                },
+                RV::Call(l)        => { let ra = next; next = labels[&l]; Some((Reg::R(1), ra as i64)) },
+                RV::Ret            => { next = get(&Reg::R(1)) as usize; None },
+                // Tables live nowhere, their address is their number. The
+                // entries are the Case pseudo-ops they were lowered with.
+                RV::Table(d, k)    => Some((d, k as i64)),
+                RV::Switch(d, s)   => {
+                    let (k, i) = (get(&d) as u32, get(&s) as u32);
+                    let l = code.iter().find_map(|a| match a {
+                        RV::Case(t, j, l) if *t == k && *j == i => Some(*l), _ => None
+                    }).unwrap();
+                    next = labels[&l];
+                    None
+                },
+                RV::Ecall          => {
+                    assert!(get(&Reg::R(17)) == 93);
+                    return get(&Reg::R(10));
+                },
+                RV::Label(_) | RV::BB(_) | RV::Case(..) => None,
+            };
+            if let Some((d, v)) = w { regs.insert(d, v); }
+            pc = next;
+        }
+        panic!("Didn't exit!");
+    };
+    // lui/addiw and the shifts come out to the constant.
+    for v in [0, 1, -1, 2047, -2048, 2048, 4096, 0x7ffff800, 0x7fffffff, -0x80000000,
+              0x80000000, 0x123456789abcdef0, i64::MAX, i64::MIN, 3.5f64.to_bits() as i64] {
+        let mut code = li(Reg::R(5), v);
+        code.extend([RV::Mv(Reg::R(10), Reg::R(5)), RV::Addi(Reg::R(17), Reg::RZR, 93), RV::Ecall]);
+        assert!(run(&code) == v, "{:#x}", v);
+        assert!(li(Reg::R(5), v).len() <= 8);
+    }
+
+    let programs = [
+        ("
+        int factorial(int n) {
+            if (n <= 1) {
+                return 1;
+            }
+            return n * factorial(n - 1);
+        }
+        int fib(int n) {
+            if (n < 2) {
+                return n;
+            }
+            return fib(n - 1) + fib(n - 2);
+        }
+        int main() {
+            return factorial(5) - fib(10);
+        }", 65),
+        ("
+        int main() {
+            int a = 0 - 17;
+            int b = 100000;
+            int c = 0;
+            if (a != b) {
+                c = a / 5 * 10 + a % 5;
+            }
+            if (b >= 100000) {
+                c = c + (b > a) + (a == a) + (b <= a);
+            }
+            return c;
+        }", -30),
+        ("
+        int set(int* p, int v) {
+            *p = v;
+            return v;
+        }
+        int main() {
+            int a = 1;
+            int b = 2;
+            set(&b, set(&a, 5) + 2);
+            return a * 10 + b;
+        }", 57),
+        ("
+        int id(int x) {
+            return x;
+        }
+        int main() {
+            int a = 1; int b = 2; int c = 3; int d = 4;
+            int e = 5; int f = 6; int g = 7; int h = 8;
+            int i = 9; int j = 10; int k = 11; int l = 12;
+            int m = id(13);
+            return a + b + c + d + e + f + g + h + i + j + k + l + m;
+        }", 91),
+        // Strings are bytes in .rodata, chars wrap at 256.
+        ("
+        int len(char* s) {
+            int n = 0;
+            while (*s != '\\0') {
+                n += 1;
+                s = s + 1;
+            }
+            return n;
+        }
+        char upper(char c) {
+            return c - 32;
+        }
+        int main() {
+            char* s = \"hello\\tworld\\n\";
+            char c = upper('a');
+            char w = 'a' + 200;
+            char* p = &c;
+            *p = *p + 1;
+            char* q = s + 6;
+            return len(s) + len(\"hello\\tworld\\n\") + c + w + (*q == 'w');
+        }", 132),
+        // Structs are laid out with padding, members go through pointers too.
+        ("
+        struct point {
+            char tag;
+            int x;
+            int y;
+        };
+        struct line {
+            struct point a;
+            char c;
+            struct point b;
+        };
+        struct node {
+            int v;
+            struct node* next;
+        };
+        int len(struct point* p) {
+            return p->x * p->x + p->y * p->y;
+        }
+        void shift(struct line* l, int d) {
+            l->a.x += d;
+            l->b.y = l->b.y - d;
+            return;
+        }
+        int sum(struct node* n, int k) {
+            int s = 0;
+            while (k > 0) {
+                s += n->v;
+                n = n->next;
+                k -= 1;
+            }
+            return s;
+        }
+        int main() {
+            struct point p;
+            p.x = 3;
+            p.y = 4;
+            p.tag = 'p';
+            struct line l;
+            l.a.x = 1;
+            l.c = 'c';
+            l.b.y = 6;
+            shift(&l, 10);
+            struct node n1;
+            struct node n2;
+            n1.v = 7;
+            n1.next = &n2;
+            n2.v = 9;
+            int* q = &p.y;
+            *q = *q + 1;
+            return len(&p) + l.a.x + l.b.y + sum(&n1, 2) + p.tag - l.c;
+        }", 70),
+        // Switches fall through, break out, and pick a table or a compare tree.
+        ("
+        int dense(int x) {
+            int r = 0;
+            switch (x) {
+                case 0: r = 10; break;
+                case 1: r = 20;
+                case 2: r += 5; break;
+                case 3: r = 40; break;
+                case 5: return 7;
+                default: r = 1;
+            }
+            return r;
+        }
+        int sparse(int x) {
+            switch (x) {
+                case -100: return 1;
+                case 7: return 2;
+                case 1000: return 3;
+                case 'a': return 4;
+                case 99999: return 5;
+            }
+            return 0;
+        }
+        int main() {
+            int s = 0;
+            int i = 0;
+            while (i < 8) {
+                i += 1;
+                switch (i % 4) {
+                    case 0: continue;
+                    case 1:
+                        switch (i) { case 1: s += 100; break; default: s += 1000; }
+                        break;
+                    default: s += i;
+                }
+                s += 1;
+            }
+            return dense(0) + dense(1) + dense(2) + dense(3) + dense(4) + dense(5) + dense(-3)
+                + sparse(-100) * 10 + sparse(7) + sparse(1000) + sparse(97) + sparse(99999)
+                + sparse(8) - s / 20;
+        }", 57),
+        // Bitwise operators bind between && and ==, shifts between < and +.
+        ("
+        int shl(int x, int n) {
+            return x << n;
+        }
+        int shr(int x, int n) {
+            return x >> n;
+        }
+        int main() {
+            int a = 240;
+            int b = 60;
+            int m = -16;
+            char c = 'a';
+            int r = (a & b) + (a | b) + (a ^ b) + ~b;
+            r += 1 << 4 >> 2;
+            r += shl(3, 5) + shr(m, 2) + (m >> 1) + (c & ~32);
+            r += 1 + 2 << 1;
+            r += 6 & 3 == 3;
+            r += 5 | 2 ^ 7 & 4;
+            int x = 12;
+            x &= 10;
+            x |= 1;
+            x ^= 15;
+            x <<= 3;
+            x >>= 1;
+            c <<= 2;
+            c >>= 1;
+            return r + x + c - 650;
+        }", 49),
+        // && and || are 1 or 0 as values too, and skip what they don't need.
+        ("
+        int hit(int* p) {
+            *p = *p + 1;
+            return 1;
+        }
+        int main() {
+            int n = 0;
+            int a = 2 && 1;
+            int b = 2 & 1;
+            int c = 0 || 4;
+            int d = 0 && hit(&n);
+            int e = 3 || hit(&n);
+            int f = 1 && hit(&n);
+            int g = (2 && 1) == 1;
+            return a + b * 2 + c * 4 + d * 8 + e * 16 + f * 32 + n * 64 + g * 128;
+        }", 245),
+        (&big(40), 0),
+    ];
+    let regs = |a: &RV| { let (d, u) = a.defuse(); d.into_iter().chain(u) };
+    for (input, code) in programs {
+        for ra in ["--regalloc=linear", "--regalloc=coloring"] {
+            let opts = Options::parse(&args(&["-O1", "--target=riscv64", ra])).unwrap();
+            let asm = compile_riscv(input, &opts).unwrap();
+            assert!(asm.iter().flat_map(regs).all(|r| !matches!(r, Reg::ID(_))));
+            if code != 0 { assert!(run(&asm) == code, "{}: {}", ra, run(&asm)); }
+        }
+    }
+    // Floats and inline asm have nowhere to go.
+    for i in [0, 6] {
+        let input = fs::read_to_string(format!("tests/data/input{i}.c")).unwrap();
+        assert!(compile_riscv(&input, &Options::new(Level::O0)).is_err());
+    }
+}
+
+#[test]
+fn inline() {
+    use asm::asm::{AA, Reg};
+    use asm::verify::verify;
+    let input = "
+        int add3(int x, int y, int z) {
+            int r = 0;
+            asm! {
+                \"add {r}, {a}, {b}\",
+                \"add {r}, {r}, {c}\",
+                out r = r, in a = x, in b = y, in c = z * 2
+            };
+            return r;
+        }
+        int main() {
+            int p = 5;
+            asm! { \"add {p}, {p}, #1\", inout p = p };
+            int q = 7;
+            int s = add3(p, q, 1);
+            asm! {
+                \"mov R9, {q}\",
+                \"add R10, R9, #1\",
+                \"mov {q}, R10\",
+                inout q = q, clobber \"R9\", clobber \"R10\"
+            };
+            return p + q + s;
+        }";
+    let regs = |a: &AA| { let (d, u) = a.defuse(); d.into_iter().chain(u) };
+    for level in [Level::O0, Level::O2] {
+        for ra in [Regalloc::Linear, Regalloc::Coloring] {
+            let mut opts = Options::new(level);
+            opts.regalloc = Some(ra);
+            let asm = compile(input, &opts).unwrap();
+            assert!(asm.iter().flat_map(regs).all(|r| !matches!(r, Reg::ID(_))));
+            assert!(asm.iter().filter(|a| matches!(a, AA::Clobber(_))).count() == 2);
+        }
+    }
+    // Operands get registers, and values live across the lines stay out
+    // of what they clobber.
+    for linear in [true, false] {
+        let mut r = Registry::new();
+        let before = select(input, &mut r);
+        assert!(before.iter().any(|a| matches!(a, AA::Add2(Reg::ID(_), Reg::ID(_), Reg::ID(_)))));
+        let live = AsmLiveness::analyze(&AsmCfg::build(&r, &before));
+        let after = match linear {
+            true  => asm::linearscan::allocate(&mut r, &live),
+            false => AsmAllocate::allocate(&mut r, &live),
+        };
+        if let Err(e) = verify(&r, &before, &after) {
+            panic!("{}", e.iter().map(|e| e.to_string()).collect::<Vec<_>>().join("\n"));
+        }
+        let at = after.iter().position(|a| matches!(a, AA::Mov2(Reg::R(9), _))).unwrap();
+        assert!(!matches!(after[at], AA::Mov2(_, Reg::R(9 | 10))));
+    }
+    // svc reads the call and its arguments.
+    let (defs, uses) = AA::SVC(asm::asm::Const::Int(128)).defuse();
+    assert!(defs.contains(&Reg::R(0)) && uses.contains(&Reg::R(16)));
+
+    let unbound = "int main() { asm! { \"mov {x}, #1\" }; return 0; }";
+    let Err(e) = compile(unbound, &Options::new(Level::O0)) else { panic!() };
+    assert!(e.contains("doesn't bind"));
+    let clobber = "int main() { asm! { \"mov R0, #1\", clobber \"R31\" }; return 0; }";
+    assert!(compile(clobber, &Options::new(Level::O0)).is_err());
+
+    // The operand keywords only mean something inside asm!.
+    let names = "
+        int main() {
+            int in = 1;
+            int out = 3;
+            int inout = 2;
+            int clobber = in + out;
+            asm! {
+                \"add {o}, {i}, {c}\",
+                out o = out, in i = in, inout c = clobber, clobber \"R9\"
+            };
+            return out + inout + clobber;
+        }";
+    let asm = compile(names, &Options::new(Level::O0)).unwrap();
+    assert!(asm.iter().filter(|a| matches!(a, AA::Clobber(_))).count() == 2);
+    let keyword = "int main() { int x = 0; asm! { \"mov {x}, #1\", put x = x }; return x; }";
+    assert!(compile(keyword, &Options::new(Level::O0)).is_err());
+}
+
+#[test]
+fn inline_branches() {
+    use asm::asm::{AA, Reg};
+    use asm::target::MachineInstr;
+    use asm::parser::{block, ParseError};
+    use asm::verify::verify;
+    let input = "
+        int twice(int x) { return x + x; }
+        int main() {
+            int n = 5;
+            int s = 0;
+            asm! {
+                \"loop:\",
+                \"add {s}, {s}, {n}\",
+                \"sub {n}, {n}, #1\",
+                \"cmp {n}, #0\",
+                \"b.ne loop\",
+                \"cbz {s}, done\",
+                \"mov R0, {s}\",
+                \"bl twice\",
+                \"mov {s}, R0\",
+                \"done:\",
+                inout n = n, inout s = s
+            };
+            return s + n;
+        }";
+    let regs = |a: &AA| { let (d, u) = a.defuse(); d.into_iter().chain(u) };
+    for level in [Level::O0, Level::O2] {
+        for ra in [Regalloc::Linear, Regalloc::Coloring] {
+            let mut opts = Options::new(level);
+            opts.regalloc = Some(ra);
+            let asm = compile(input, &opts).unwrap();
+            assert!(asm.iter().flat_map(regs).all(|r| !matches!(r, Reg::ID(_))));
+            // Every branch lands on a label that's really there.
+            for a in &asm {
+                let Some(t) = a.target() else { continue };
+                assert!(asm.iter().any(|b| b.label() == Some(t)), "{} has no target", a);
+            }
+            assert!(asm.iter().any(|a| matches!(a, AA::B2(..))));
+            assert!(asm.iter().any(|a| matches!(a, AA::CBZ(..))));
+            assert!(asm.iter().filter(|a| matches!(a, AA::BL(_))).count() == 1);
+        }
+    }
+    for linear in [true, false] {
+        let mut r = Registry::new();
+        let before = select(input, &mut r);
+        let live = AsmLiveness::analyze(&AsmCfg::build(&r, &before));
+        let after = match linear {
+            true  => asm::linearscan::allocate(&mut r, &live),
+            false => AsmAllocate::allocate(&mut r, &live),
+        };
+        if let Err(e) = verify(&r, &before, &after) {
+            panic!("{}", e.iter().map(|e| e.to_string()).collect::<Vec<_>>().join("\n"));
+        }
+    }
+
+    let unknown = "int main() { asm! { \"b nowhere\" }; return 0; }";
+    let Err(e) = compile(unknown, &Options::new(Level::O0)) else { panic!() };
+    assert!(e.contains("label"));
+    let twice = "int main() { asm! { \"x:\", \"x:\" }; return 0; }";
+    assert!(compile(twice, &Options::new(Level::O0)).is_err());
+
+    // A label can share its line with an instruction.
+    let (same, labels, _) = block(vec![
+        "top: add X0, X0, #1".to_string(),
+        "cmp X0, #3".to_string(),
+        "b.ne top".to_string(),
+    ], &[]).unwrap();
+    assert!(labels == ["top"]);
+    assert!(matches!(same[..], [AA::Label(0), AA::Add1(..), AA::CMP1(..), AA::B2(_, 0)]));
+    let twice = vec!["x: mov X0, #1".to_string(), "x:".to_string()];
+    assert!(matches!(block(twice, &[]), Err(ParseError::Label(..))));
+    let bad = vec!["top: add X0, X0, Q1".to_string()];
+    assert!(matches!(block(bad, &[]), Err(ParseError::Register(17, _))));
+}
+
+#[test]
+fn asm_roundtrip() {
+    use asm::asm::{AA, Reg, Const, CC};
+    use asm::parser::block;
+    // xorshift, so every run sees the same instructions.
+    fn next(s: &mut u64) -> u64 { *s ^= *s << 13; *s ^= *s >> 7; *s ^= *s << 17; *s }
+    fn reg(s: &mut u64) -> Reg {
+        return match next(s) % 34 {
+            31 => Reg::SP,
+            32 => Reg::RZR,
+            33 => Reg::PC,
+            i  => Reg::R(i as u8),
+        };
+    }
+    fn con(s: &mut u64) -> Const {
+        let v = next(s) as i64;
+        return match next(s) % 5 {
+            0 => Const::Int(v % 4096),
+            1 => Const::Int(-(v % 4096).abs()),
+            2 => Const::Int(v),
+            3 => Const::Int(i64::MIN),
+            _ => Const::Float((v % 1000) as f64 + 0.25),
+        };
+    }
+    fn int(s: &mut u64) -> Const { Const::Int(next(s) as i64 % 4096) }
+    // Any bytes, escapes and all, but nothing the respellings below touch.
+    fn text(s: &mut u64) -> Vec<u8> {
+        let n = next(s) % 12;
+        return (0..n).map(|_| next(s) as u8).filter(|c| !b"XW#".contains(c)).collect();
+    }
+    fn cc(s: &mut u64) -> CC {
+        use CC::*;
+        return [EQ, NE, GE, LT, GT, LE, HI, LS, HS, LO, MI, PL][(next(s) % 12) as usize];
+    }
+    fn all(s: &mut u64) -> Vec<AA> {
+        use AA::*;
+        let l = (next(s) % 7 + 1) as u32;
+        let sh = [0, 16, 32, 48][(next(s) % 4) as usize];
+        return vec![
+            Label(l),
+            Mov1(reg(s), con(s)), Mov2(reg(s), reg(s)),
+            MovZ(reg(s), int(s), sh), MovK(reg(s), int(s), sh),
+            Add1(reg(s), reg(s), con(s)), Add2(reg(s), reg(s), reg(s)),
+            Sub1(reg(s), reg(s), con(s)), Sub2(reg(s), reg(s), reg(s)),
+            Neg1(reg(s), con(s)), Neg2(reg(s), reg(s)),
+            SMAddL(reg(s), reg(s), reg(s), reg(s)), SMNegL(reg(s), reg(s), reg(s)),
+            SMSubL(reg(s), reg(s), reg(s), reg(s)), SMulL(reg(s), reg(s), reg(s)),
+            SDiv(reg(s), reg(s), reg(s)),
+            And1(reg(s), reg(s), con(s)), And2(reg(s), reg(s), reg(s)),
+            Or1(reg(s), reg(s), con(s)), Or2(reg(s), reg(s), reg(s)),
+            Eor1(reg(s), reg(s), con(s)), Eor2(reg(s), reg(s), reg(s)),
+            Lsl1(reg(s), reg(s), con(s)), Lsl2(reg(s), reg(s), reg(s)),
+            Asr1(reg(s), reg(s), con(s)), Asr2(reg(s), reg(s), reg(s)),
+            Mvn1(reg(s), con(s)), Mvn2(reg(s), reg(s)),
+            FAdd(reg(s), reg(s), reg(s)), FSub(reg(s), reg(s), reg(s)),
+            FMul(reg(s), reg(s), reg(s)), FDiv(reg(s), reg(s), reg(s)),
+            FNeg(reg(s), reg(s)), FCmp(reg(s), reg(s)),
+            SCvtF(reg(s), reg(s)), FCvtZS(reg(s), reg(s)),
+            B1(l), B2(cc(s), l), BL(l), CBZ(reg(s), l), CBNZ(reg(s), l),
+            CMP1(reg(s), con(s)), CMP2(reg(s), reg(s)), CSET(reg(s), cc(s)),
+            LDR1(reg(s), reg(s), int(s)), LDR2(reg(s), reg(s)),
+            STR1(reg(s), reg(s), int(s)), STR2(reg(s), reg(s)),
+            LDRB(reg(s), reg(s), int(s)), STRB(reg(s), reg(s), int(s)), Adr(reg(s), text(s)),
+            SVC(int(s)), Ret,
+        ];
+    }
+    // The float ops print as their fmov sequences, lines write them as one op.
+    let source = |a: &AA| match a {
+        AA::FAdd(d, l, r) => format!("fadd {d}, {l}, {r}"),
+        AA::FSub(d, l, r) => format!("fsub {d}, {l}, {r}"),
+        AA::FMul(d, l, r) => format!("fmul {d}, {l}, {r}"),
+        AA::FDiv(d, l, r) => format!("fdiv {d}, {l}, {r}"),
+        AA::FNeg(d, s)    => format!("fneg {d}, {s}"),
+        AA::FCmp(l, r)    => format!("fcmp {l}, {r}"),
+        AA::SCvtF(d, s)   => format!("scvtf {d}, {s}"),
+        AA::FCvtZS(d, s)  => format!("fcvtzs {d}, {s}"),
+        _ => a.to_string(),
+    };
+    let defs: Vec<String> = (0..8).map(|j| format!("l{j}:")).collect();
+    let mut seed = 0x9E3779B97F4A7C15;
+    for round in 0..200 {
+        for a in all(&mut seed) {
+            let mut line = source(&a);
+            // Spell it differently now and then: lowercase names, no #.
+            // X and W stay what they are, the instruction decides which fits.
+            if round % 3 == 1 { line = line.replace(|c| c == '#', ""); }
+            if round % 3 == 2 {
+                let mut w = String::new();
+                for (i, c) in line.char_indices() {
+                    let digit = line[i + 1..].starts_with(|d: char| d.is_ascii_digit());
+                    w.push(if matches!(c, 'X' | 'W') && digit { c.to_ascii_lowercase() } else { c });
+                }
+                line = w;
+            }
+            let mut lines = defs.clone();
+            let k = match a { AA::Label(l) => l as usize, _ => lines.len() };
+            match k < lines.len() {
+                true  => lines[k] = line.clone(),
+                false => lines.push(line.clone()),
+            }
+            let (out, _, calls) = match block(lines, &[]) {
+                Ok(p) => p,
+                Err(e) => panic!("{line}\n{e}"),
+            };
+            let got = match &out[k] {
+                AA::BL(i) => AA::BL(calls[*i as usize][1..].parse().unwrap()),
+                b => b.clone(),
+            };
+            assert!(out.len() == defs.len().max(k + 1), "{line}");
+            assert!(got.to_string() == a.to_string(), "{line} came back as {got}");
+        }
+    }
+
+    // Writeback addressing is the access plus moving the base.
+    let parse = |line: &str| match block(vec![line.to_string()], &[]) {
+        Ok((asm, _, _)) => asm.iter().map(|a| a.to_string()).collect::<Vec<_>>().join("\n"),
+        Err(e) => format!("{e}"),
+    };
+    assert!(parse("ldr X0, [X1, #-16]!") == "sub X1, X1, #16\nldr X0, [X1]");
+    assert!(parse("str x0, [sp], #0x10") == "str X0, [SP]\nadd SP, SP, #16");
+    assert!(parse("mov X3, #-0x10") == "mov X3, #-16");
+    assert!(parse("MOV x3, xzr") == "mov X3, XZR");
+    assert!(parse("smull x0, w1, r2") == "smull X0, W1, W2");
+    assert!(parse("ldrb w0, [x1], #1") == "ldrb W0, [X1, #0]\nadd X1, X1, #1");
+    assert!(parse("strb w2, [sp, #3]") == "strb W2, [SP, #3]");
+    assert!(parse("adr x0, \"a, \\\"b\\\"\\n\\0\"") == "adr X0, \"a, \\\"b\\\"\\n\\000\"");
+    for bad in ["ldr X0, X1]", "add X0, [X1], #1", "mov X0, X31", "ldr X0, [X1]!",
+                "ldr X0, [X1, #8", "mov X0, #zz", "frob X0", "b.xx l0", "cset X0, XX",
+                "adr X0, \"\\q\"", "adr X0, \"ab", "adr X0, X1", "ldrb X0, [X1]!",
+                "mov W3, #-0x10", "MOV x3, wzr", "add X0, W1, #1", "ldr W0, [X1]",
+                "ldrb X0, [X1]", "strb W0, [W1]", "smull X0, X1, W2", "smull W0, W1, W2"] {
+        assert!(block(vec![bad.to_string()], &[]).is_err(), "{bad}");
+    }
+}
+
+#[test]
+fn strings() {
+    use asm::asm::AA;
+    use asm::strings::{escape, unescape};
+    let input = "
+    int main() {
+        char* a = \"one\\n\";
+        char* b = \"two \\\"2\\\"\";
+        char* c = \"one\\n\";
+        char d = *b;
+        *a = d;
+        return *c;
+    }";
+    let asm = compile(input, &Options::new(Level::O1)).unwrap();
+    assert!(asm.iter().filter(|a| matches!(a, AA::Adr(_, _))).count() == 3);
+    assert!(asm.iter().any(|a| matches!(a, AA::LDRB(_, _, _))));
+    assert!(asm.iter().any(|a| matches!(a, AA::STRB(_, _, _))));
+    // Every string once, after the code, reached through its page.
+    let text = AsmPrinter::text(&asm);
+    let data = text.find(".section __TEXT,__const").unwrap();
+    assert!(text[data..].matches(".asciz").count() == 2);
+    assert!(text[data..].contains("str0:\n.asciz \"one\\n\""));
+    assert!(text[data..].contains("str1:\n.asciz \"two \\\"2\\\"\""));
+    assert!(text[..data].matches("str0@PAGEOFF").count() == 2);
+    assert!(!AsmPrinter::text(&compile("int main() { return 0; }", &Options::new(Level::O1))
+        .unwrap()).contains(".section"));
+
+    let all: Vec<u8> = (0..=255).collect();
+    assert!(unescape(&escape(&all)).unwrap() == all);
+    assert!(unescape("\\x41\\101\\0\\'").unwrap() == b"AA\0'");
+    assert!(unescape("\\q").is_none() && unescape("\\x4").is_none() && unescape("\\777").is_none());
+    for bad in ["int main() { char c = 'ab'; return 0; }",
+                "int main() { char* s = \"open; return 0; }"] {
+        assert!(compile(bad, &Options::new(Level::O0)).is_err(), "{bad}");
+    }
+    // Escapes that don't stand for one byte are errors, not panics.
+    for (bad, why) in [("int main() { char c = '\\q'; return 0; }", "escape"),
+                       ("int main() { char* s = \"\\777\"; return 0; }", "escape"),
+                       ("int main() { char c = '\\1234'; return 0; }", "one byte")] {
+        let Err(e) = compile(bad, &Options::new(Level::O0)) else { panic!("{bad}") };
+        assert!(e.contains(why), "{bad}: {e}");
+    }
+}
+
+#[test]
+fn structs() {
+    use ir::ir::{Expr, Statement, Type};
+    let input = "
+    struct inner {
+        char a;
+        char b;
+    };
+    struct outer {
+        char c;
+        struct inner i;
+        int n;
+        struct outer* next;
+    };
+    int main() {
+        struct outer o;
+        struct outer* p = &o;
+        o.i.b = 'b';
+        p->n = 5;
+        return o.n + p->i.b;
+    }";
+    let mut r = Registry::new();
+    let mut ast = ast::parser::moduleParser::new().parse(&mut r, input).unwrap();
+    AstAnalyzer::new(&mut r).analyze(&mut ast);
+    // Members at the next offset their alignment allows, padded to the largest.
+    let layout = |s: &ast::ast::StructDeclaration| {
+        (s.members.iter().map(|m| m.offset).collect::<Vec<_>>(), s.size, s.align)
+    };
+    assert!(layout(&ast.structs[0]) == (vec![0, 1], 2, 1));
+    assert!(layout(&ast.structs[1]) == (vec![0, 1, 8, 16], 24, 8));
+    // Tags belong to the registry, a fresh one numbers from zero again.
+    assert!(r.tags == vec!["inner", "outer"]);
+    let mut fresh = Registry::new();
+    let other = ast::parser::moduleParser::new()
+        .parse(&mut fresh, "struct outer { int n; }; int main() { return 0; }")
+        .unwrap();
+    assert!(other.structs[0].tag == 0 && fresh.tag_name(0) == "outer");
+
+    // o.i.b is one offset from o, p->n one from p.
+    let ir = IrTranslator::new(&mut r).translate(&mut ast);
+    let Statement::Seq(f) = &*ir[0] else { unreachable!() };
+    let Statement::Seq(body) = &*f[1] else { unreachable!() };
+    let Statement::Expr(e) = &*body[1] else { unreachable!() };
+    let Expr::ESeq(s, _) = &**e else { unreachable!() };
+    let Statement::Move(d, _) = &**s else { unreachable!() };
+    let Expr::Mem(a, Type::I8) = &**d else { unreachable!() };
+    let Expr::BinOp(b, ir::ir::Operator::Add, off) = &**a else { unreachable!() };
+    assert!(matches!(&**b, Expr::Address(t) if matches!(**t, Expr::Temp(0, Type::Struct(24)))));
+    assert!(matches!(**off, Expr::Const(ir::ir::Primitive::Int(2))));
+
+    // The struct gets a slot as large as it is, nothing else escapes.
+    let lir = IrReducer::new(&mut r).reduce(ir);
+    let cfg = IrCfgBuild(&mut r, lir);
+    let frames = ir::cfgframer::Framer::new(&mut r, &cfg).frame();
+    assert!(frames.slot(0) == Some(0) && frames.slot(1).is_none());
+    assert!(frames.sizes == vec![32]);
+    let asm = compile(input, &Options::new(Level::O1)).unwrap();
+    assert!(asm.iter().any(|a| matches!(a, asm::asm::AA::STRB(_, _, _))));
+}
+
+#[test]
+fn switch() {
+    use asm::asm::AA;
+    let cases = |v: &[i64]| {
+        let mut src = String::from("int main() {\n int x = 3;\n switch (x) {\n");
+        for c in v { src += &format!("  case {c}: x += {c}; break;\n"); }
+        src += "  default: x = 0;\n }\n return x;\n}";
+        return compile(&src, &Options::new(Level::O1)).unwrap();
+    };
+    let count = |asm: &[AA], f: fn(&AA) -> bool| asm.iter().filter(|a| f(a)).count();
+    // Dense cases go through a table, one entry per value in between.
+    let asm = cases(&[1, 2, 3, 4, 6]);
+    assert!(count(&asm, |a| matches!(a, AA::Switch(_, _))) == 1);
+    assert!(count(&asm, |a| matches!(a, AA::Case(0, _, _))) == 6);
+    let text = AsmPrinter::text(&asm);
+    let data = text.find(".section __TEXT,__const").unwrap();
+    assert!(text[data..].contains("table0:") && text[data..].matches(".quad").count() == 6);
+    assert!(text[..data].contains("table0@PAGEOFF") && !text[..data].contains("//"));
+    // Sparse ones compare their way down instead.
+    let asm = cases(&[1, 100, 10000, -7, 5]);
+    assert!(count(&asm, |a| matches!(a, AA::Switch(_, _) | AA::Case(..))) == 0);
+    assert!(!AsmPrinter::text(&asm).contains(".section"));
+}
+
+#[test]
+fn bitwise() {
+    use asm::asm::{AA, Const};
+    use ast::ast::{BinaryOp, Expr, Statement};
+    // a | b ^ c & d << e, loosest first.
+    let input = "int main() { int a = 1; return a | a ^ a & a << a; }";
+    let ast = ast::parser::moduleParser::new().parse(&mut Registry::new(), input).unwrap();
+    let Statement::Compound(c) = &*ast.functions[0].stmt else { unreachable!() };
+    let Statement::Jump(j) = &c.stmts[1] else { unreachable!() };
+    let mut e = j.expr.as_ref().unwrap();
+    for op in [BinaryOp::BitOr, BinaryOp::BitXor, BinaryOp::BitAnd, BinaryOp::Shl] {
+        let Expr::Binary(b) = &**e else { unreachable!() };
+        assert!(std::mem::discriminant(&b.binary_op) == std::mem::discriminant(&op));
+        e = &b.right;
+    }
+
+    let input = "
+    int f(int a, int n) {
+        int b = a ^ 255;
+        b |= a << 3;
+        b &= ~n;
+        return (b >> 2) + (a << n) + (a >> n) + (a ^ n);
+    }
+    int main() {
+        return f(5, 1);
+    }";
+    let asm = compile(input, &Options::new(Level::O1)).unwrap();
+    let count = |f: fn(&AA) -> bool| asm.iter().filter(|a| f(a)).count();
+    // Encodable constants go in the instruction, ~ is mvn.
+    assert!(count(|a| matches!(a, AA::Eor1(_, _, Const::Int(255)))) == 1);
+    assert!(count(|a| matches!(a, AA::Lsl1(_, _, Const::Int(3)))) == 1);
+    assert!(count(|a| matches!(a, AA::Asr1(_, _, Const::Int(2)))) == 1);
+    assert!(count(|a| matches!(a, AA::Mvn2(..))) == 1);
+    assert!(count(|a| matches!(a, AA::Lsl2(..) | AA::Asr2(..) | AA::Eor2(..))) == 3);
+    assert!(count(|a| matches!(a, AA::Or2(..) | AA::And2(..))) == 2);
+    // Out of range shifts take a register.
+    let asm = compile("int main() { int a = 1; return a << 64; }", &Options::new(Level::O1)).unwrap();
+    assert!(asm.iter().any(|a| matches!(a, AA::Lsl2(..))));
+}
+
+fn select(input: &str, r: &mut Registry) -> Vec<asm::asm::AA> {
+    let cfg = build_cfg(input, r);
+    let frames = ir::cfgframer::Framer::new(r, &cfg).frame();
+    let order = IrCfgReorder(&cfg);
+    return AsmTranslator::translate(r, frames, IrCfgExport(cfg, order));
+}
+
+// A function with n variables, all live around one loop.
+fn big(n: usize) -> String {
+    let mut src = String::from("int main() {\n int i = 0;\n");
+    for k in 0..n { src += &format!(" int a{k} = {k};\n"); }
+    src += " while (i < 10) {\n";
+    for k in 1..n { src += &format!("  a{k} = a{} + a{};\n", k - 1, k / 2); }
+    src += "  i = i + 1;\n }\n return a0";
+    for k in 1..n { src += &format!(" + a{k}"); }
+    return src + ";\n}";
+}
+
+#[test]
+fn liveness() {
+    use asm::asm::{AA, Reg};
+    use asm::liveness::worklist;
+    let mut inputs: Vec<String> = (0..)
+        .map(|i| format!("tests/data/input{i}.c"))
+        .take_while(|p| Path::new(p).exists())
+        .map(|p| fs::read_to_string(p).unwrap())
+        .collect();
+    inputs.push(big(20));
+    for input in &inputs {
+        let mut r = Registry::new();
+        let asm = select(input, &mut r);
+        let cfg = AsmCfg::build(&r, &asm);
+        let live = AsmLiveness::analyze(&cfg);
+        // Same answers as the worklist, which also marks blocks.
+        let old: Vec<_> = worklist(AsmCfg::build(&r, &asm)).into_iter()
+            .filter(|l| !matches!(l.0, AA::BB(_)))
+            .collect();
+        assert!(old.len() == asm.len());
+        for (i, (a, defdead, usedead)) in old.iter().enumerate() {
+            let (defs, uses) = a.defuse();
+            assert!(defs.iter().map(|d| live.dead_after(i, *d)).eq(defdead.iter().cloned()));
+            assert!(uses.iter().map(|u| live.dead_after(i, *u)).eq(usedead.iter().cloned()));
+        }
+        for (i, a) in asm.iter().enumerate() {
+            let (_, uses) = a.defuse();
+            for u in uses {
+                assert!(live.live_at(i).contains(u.index()));
+                assert!(live.dead_after(i, u) == !live.live_after(i).contains(u.index()));
+            }
+        }
+    }
+    // Every variable is live around the loop.
+    let mut r = Registry::new();
+    let asm = select(&big(8), &mut r);
+    let cfg = AsmCfg::build(&r, &asm);
+    let live = AsmLiveness::analyze(&cfg);
+    let back = asm.iter().rposition(|a| matches!(a, AA::B1(_))).unwrap();
+    let b = live.block[back];
+    let out: Vec<Reg> = live.live_out(b).iter().map(|i| Reg::from(i as u32)).collect();
+    assert!(out.iter().filter(|r| matches!(r, Reg::ID(_))).count() >= 9);
+}
+
+// cargo test --release -- --ignored --nocapture liveness_bench
+#[test]
+#[ignore]
+fn liveness_bench() {
+    use std::time::Instant;
+    for n in [100, 200, 400] {
+        let mut r = Registry::new();
+        let asm = select(&big(n), &mut r);
+        let t = Instant::now();
+        let cfg = AsmCfg::build(&r, &asm);
+        let live = AsmLiveness::analyze(&cfg);
+        let lines: usize = (0..live.blocks.len()).map(|b| live.after(b).len()).sum();
+        let bits = t.elapsed();
+        let t = Instant::now();
+        let old = asm::liveness::worklist(AsmCfg::build(&r, &asm));
+        let work = t.elapsed();
+        println!("{} instructions: bitsets {:?}, worklist {:?}", asm.len(), bits, work);
+        assert!(lines == asm.len() && old.len() >= asm.len());
+    }
+}